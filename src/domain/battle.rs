@@ -0,0 +1,153 @@
+use crate::domain::game_mode::PlayerSlot;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleAttack {
+    ExtraInvaders,
+    FasterShots,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BattleOutcome {
+    Winner(PlayerSlot),
+    Draw,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Battle {
+    cleared_rows: [usize; 2],
+    attacks_sent: [usize; 2],
+}
+
+impl Battle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn track_cleared_rows(
+        &mut self,
+        side: PlayerSlot,
+        cleared_rows: usize,
+    ) -> Vec<BattleAttack> {
+        let previous = self.cleared_rows[side.index()];
+        self.cleared_rows[side.index()] = cleared_rows;
+
+        (previous..cleared_rows)
+            .map(|_| self.next_attack(side))
+            .collect()
+    }
+
+    pub fn track_wave_cleared(&mut self, side: PlayerSlot, rows: usize) -> Vec<BattleAttack> {
+        let attacks = self.track_cleared_rows(side, rows);
+        self.cleared_rows[side.index()] = 0;
+
+        attacks
+    }
+
+    pub fn get_attacks_sent(&self, side: PlayerSlot) -> usize {
+        self.attacks_sent[side.index()]
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    fn next_attack(&mut self, side: PlayerSlot) -> BattleAttack {
+        let sent = &mut self.attacks_sent[side.index()];
+        *sent += 1;
+
+        if *sent % 2 == 1 {
+            BattleAttack::ExtraInvaders
+        } else {
+            BattleAttack::FasterShots
+        }
+    }
+}
+
+pub fn battle_outcome(one_lost: bool, two_lost: bool) -> Option<BattleOutcome> {
+    match (one_lost, two_lost) {
+        (true, true) => Some(BattleOutcome::Draw),
+        (true, false) => Some(BattleOutcome::Winner(PlayerSlot::Two)),
+        (false, true) => Some(BattleOutcome::Winner(PlayerSlot::One)),
+        (false, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::ROWS;
+
+    #[test]
+    fn clearing_a_row_attacks_the_opponent() {
+        let mut battle = Battle::new();
+
+        assert_eq!(battle.track_cleared_rows(PlayerSlot::One, 0), vec![]);
+        assert_eq!(
+            battle.track_cleared_rows(PlayerSlot::One, 1),
+            vec![BattleAttack::ExtraInvaders]
+        );
+        assert_eq!(battle.track_cleared_rows(PlayerSlot::One, 1), vec![]);
+        assert_eq!(battle.get_attacks_sent(PlayerSlot::One), 1);
+        assert_eq!(battle.get_attacks_sent(PlayerSlot::Two), 0);
+    }
+
+    #[test]
+    fn attacks_alternate_between_invaders_and_faster_shots() {
+        let mut battle = Battle::new();
+
+        assert_eq!(
+            battle.track_cleared_rows(PlayerSlot::Two, 3),
+            vec![
+                BattleAttack::ExtraInvaders,
+                BattleAttack::FasterShots,
+                BattleAttack::ExtraInvaders
+            ]
+        );
+    }
+
+    #[test]
+    fn refilled_rows_can_be_cleared_again() {
+        let mut battle = Battle::new();
+        battle.track_cleared_rows(PlayerSlot::One, 2);
+
+        assert_eq!(battle.track_cleared_rows(PlayerSlot::One, 1), vec![]);
+        assert_eq!(
+            battle.track_cleared_rows(PlayerSlot::One, 2),
+            vec![BattleAttack::ExtraInvaders]
+        );
+    }
+
+    #[test]
+    fn clearing_a_wave_sends_the_remaining_rows() {
+        let mut battle = Battle::new();
+        battle.track_cleared_rows(PlayerSlot::One, ROWS - 2);
+
+        assert_eq!(battle.track_wave_cleared(PlayerSlot::One, ROWS).len(), 2);
+        assert_eq!(battle.track_cleared_rows(PlayerSlot::One, 0), vec![]);
+        assert_eq!(battle.track_cleared_rows(PlayerSlot::One, 1).len(), 1);
+    }
+
+    #[test]
+    fn reset_forgets_the_cleared_rows() {
+        let mut battle = Battle::new();
+        battle.track_cleared_rows(PlayerSlot::One, 2);
+
+        battle.reset();
+
+        assert_eq!(battle, Battle::new());
+    }
+
+    #[test]
+    fn the_last_arena_standing_wins() {
+        assert_eq!(battle_outcome(false, false), None);
+        assert_eq!(
+            battle_outcome(true, false),
+            Some(BattleOutcome::Winner(PlayerSlot::Two))
+        );
+        assert_eq!(
+            battle_outcome(false, true),
+            Some(BattleOutcome::Winner(PlayerSlot::One))
+        );
+        assert_eq!(battle_outcome(true, true), Some(BattleOutcome::Draw));
+    }
+}
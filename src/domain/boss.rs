@@ -0,0 +1,435 @@
+use crate::domain::enemy::EnemyId;
+use crate::domain::wave::Wave;
+
+pub const BOSS_HEALTH: u32 = 60;
+pub const BOSS_HEALTH_PER_ENCOUNTER: u32 = 20;
+pub const BOSS_POINTS: u32 = 500;
+pub const WEAK_POINT_DAMAGE: u32 = 3;
+pub const BOSS_ATTACK_INTERVAL: f32 = 1.6;
+pub const ENRAGED_ATTACK_INTERVAL: f32 = 1.0;
+pub const BOSS_SWAY_SPEED: f32 = 0.2;
+pub const ENRAGED_SWAY_SPEED: f32 = 0.35;
+const BOSS_ID_BASE: usize = 100_000;
+const ATTACK_PATTERN: [BossAttackKind; 4] = [
+    BossAttackKind::Spread,
+    BossAttackKind::Laser,
+    BossAttackKind::Spread,
+    BossAttackKind::Minions,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossPartKind {
+    Core,
+    Turret,
+    LaserCannon,
+    Hangar,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossAttackKind {
+    Spread,
+    Laser,
+    Minions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BossHit {
+    Missed,
+    Armor,
+    WeakPoint,
+    PartDestroyed,
+    Defeated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BossAttack {
+    pub part: EnemyId,
+    pub kind: BossAttackKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BossPart {
+    id: EnemyId,
+    kind: BossPartKind,
+    column: i32,
+    row: i32,
+    damage: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boss {
+    parts: Vec<BossPart>,
+    health: u32,
+    max_health: u32,
+    sway: f32,
+    direction: f32,
+    cooldown: f32,
+    pattern_index: usize,
+    minions_launched: usize,
+}
+
+pub fn boss_encounter(wave: Wave, interval: u32) -> Option<u32> {
+    let number = wave.get_number();
+
+    (interval > 0 && number.is_multiple_of(interval)).then(|| number / interval)
+}
+
+impl BossPartKind {
+    pub fn hit_points(&self) -> Option<u32> {
+        match self {
+            BossPartKind::Core => None,
+            BossPartKind::Turret => Some(6),
+            BossPartKind::LaserCannon => Some(8),
+            BossPartKind::Hangar => Some(5),
+        }
+    }
+
+    pub fn is_weak_point(&self) -> bool {
+        *self == BossPartKind::Core
+    }
+
+    pub fn attack(&self) -> Option<BossAttackKind> {
+        match self {
+            BossPartKind::Core => None,
+            BossPartKind::Turret => Some(BossAttackKind::Spread),
+            BossPartKind::LaserCannon => Some(BossAttackKind::Laser),
+            BossPartKind::Hangar => Some(BossAttackKind::Minions),
+        }
+    }
+}
+
+impl BossHit {
+    pub fn is_hit(&self) -> bool {
+        *self != BossHit::Missed
+    }
+}
+
+impl BossPart {
+    pub fn get_id(&self) -> EnemyId {
+        self.id
+    }
+
+    pub fn get_kind(&self) -> BossPartKind {
+        self.kind
+    }
+
+    pub fn get_column(&self) -> i32 {
+        self.column
+    }
+
+    pub fn get_row(&self) -> i32 {
+        self.row
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.damage > 0
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.kind
+            .hit_points()
+            .is_some_and(|hit_points| self.damage >= hit_points)
+    }
+}
+
+impl Boss {
+    pub fn new(encounter: u32) -> Self {
+        let layout = [
+            (BossPartKind::Turret, -2, 0),
+            (BossPartKind::Hangar, -1, 0),
+            (BossPartKind::Core, 0, 0),
+            (BossPartKind::Hangar, 1, 0),
+            (BossPartKind::Turret, 2, 0),
+            (BossPartKind::LaserCannon, 0, 1),
+        ];
+        let max_health = BOSS_HEALTH
+            + encounter
+                .saturating_sub(1)
+                .saturating_mul(BOSS_HEALTH_PER_ENCOUNTER);
+
+        Self {
+            parts: layout
+                .iter()
+                .enumerate()
+                .map(|(index, (kind, column, row))| BossPart {
+                    id: EnemyId::new(BOSS_ID_BASE + index),
+                    kind: *kind,
+                    column: *column,
+                    row: *row,
+                    damage: 0,
+                })
+                .collect(),
+            health: max_health,
+            max_health,
+            sway: 0.0,
+            direction: 1.0,
+            cooldown: BOSS_ATTACK_INTERVAL,
+            pattern_index: 0,
+            minions_launched: 0,
+        }
+    }
+
+    pub fn get_parts(&self) -> &[BossPart] {
+        &self.parts
+    }
+
+    pub fn get_part(&self, id: EnemyId) -> Option<&BossPart> {
+        self.parts.iter().find(|part| part.id == id)
+    }
+
+    pub fn intact_parts(&self) -> impl Iterator<Item = &BossPart> {
+        self.parts.iter().filter(|part| !part.is_destroyed())
+    }
+
+    pub fn get_health(&self) -> u32 {
+        self.health
+    }
+
+    pub fn get_max_health(&self) -> u32 {
+        self.max_health
+    }
+
+    pub fn health_fraction(&self) -> f32 {
+        self.health as f32 / self.max_health as f32
+    }
+
+    pub fn get_sway(&self) -> f32 {
+        self.sway
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.health == 0
+    }
+
+    pub fn is_enraged(&self) -> bool {
+        self.health * 2 <= self.max_health
+    }
+
+    pub fn hit(&mut self, id: EnemyId) -> BossHit {
+        if self.is_defeated() {
+            return BossHit::Missed;
+        }
+
+        let Some(part) = self
+            .parts
+            .iter_mut()
+            .find(|part| part.id == id && !part.is_destroyed())
+        else {
+            return BossHit::Missed;
+        };
+
+        let (damage, outcome) = if part.kind.is_weak_point() {
+            (WEAK_POINT_DAMAGE, BossHit::WeakPoint)
+        } else {
+            part.damage += 1;
+
+            if part.is_destroyed() {
+                (1, BossHit::PartDestroyed)
+            } else {
+                (1, BossHit::Armor)
+            }
+        };
+        self.health = self.health.saturating_sub(damage);
+
+        if self.is_defeated() {
+            BossHit::Defeated
+        } else {
+            outcome
+        }
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) -> Vec<BossAttack> {
+        if self.is_defeated() {
+            return Vec::new();
+        }
+
+        let (sway_speed, attack_interval) = if self.is_enraged() {
+            (ENRAGED_SWAY_SPEED, ENRAGED_ATTACK_INTERVAL)
+        } else {
+            (BOSS_SWAY_SPEED, BOSS_ATTACK_INTERVAL)
+        };
+
+        self.sway += self.direction * sway_speed * delta_secs;
+        if self.sway.abs() >= 1.0 {
+            self.sway = self.sway.clamp(-1.0, 1.0);
+            self.direction = -self.direction;
+        }
+
+        self.cooldown -= delta_secs;
+        if self.cooldown > 0.0 {
+            return Vec::new();
+        }
+        self.cooldown = attack_interval;
+
+        for _ in 0..ATTACK_PATTERN.len() {
+            let kind = ATTACK_PATTERN[self.pattern_index % ATTACK_PATTERN.len()];
+            self.pattern_index += 1;
+
+            let attacks: Vec<BossAttack> = self
+                .intact_parts()
+                .filter(|part| part.kind.attack() == Some(kind))
+                .map(|part| BossAttack {
+                    part: part.id,
+                    kind,
+                })
+                .collect();
+
+            if !attacks.is_empty() {
+                return attacks;
+            }
+        }
+
+        Vec::new()
+    }
+
+    pub fn launch_minion(&mut self) -> EnemyId {
+        self.minions_launched += 1;
+        EnemyId::new(BOSS_ID_BASE + self.parts.len() + self.minions_launched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part_of(boss: &Boss, kind: BossPartKind) -> EnemyId {
+        boss.get_parts()
+            .iter()
+            .find(|part| part.get_kind() == kind)
+            .map(|part| part.get_id())
+            .expect("Boss should have the part")
+    }
+
+    #[test]
+    fn bosses_appear_every_few_waves() {
+        assert_eq!(boss_encounter(Wave::new(4), 5), None);
+        assert_eq!(boss_encounter(Wave::new(5), 5), Some(1));
+        assert_eq!(boss_encounter(Wave::new(10), 5), Some(2));
+        assert_eq!(boss_encounter(Wave::new(5), 0), None);
+    }
+
+    #[test]
+    fn later_bosses_are_tougher() {
+        assert_eq!(Boss::new(1).get_max_health(), BOSS_HEALTH);
+        assert_eq!(
+            Boss::new(3).get_max_health(),
+            BOSS_HEALTH + 2 * BOSS_HEALTH_PER_ENCOUNTER
+        );
+    }
+
+    #[test]
+    fn armor_hits_wear_down_a_part_and_the_health() {
+        let mut boss = Boss::new(1);
+        let hangar = part_of(&boss, BossPartKind::Hangar);
+
+        for _ in 1..5 {
+            assert_eq!(boss.hit(hangar), BossHit::Armor);
+        }
+        assert_eq!(boss.hit(hangar), BossHit::PartDestroyed);
+        assert_eq!(boss.hit(hangar), BossHit::Missed);
+
+        assert!(boss.get_part(hangar).is_some_and(BossPart::is_destroyed));
+        assert_eq!(boss.get_health(), BOSS_HEALTH - 5);
+        assert_eq!(boss.intact_parts().count(), 5);
+    }
+
+    #[test]
+    fn the_weak_point_takes_extra_damage_and_is_never_destroyed() {
+        let mut boss = Boss::new(1);
+        let core = part_of(&boss, BossPartKind::Core);
+
+        assert_eq!(boss.hit(core), BossHit::WeakPoint);
+
+        assert_eq!(boss.get_health(), BOSS_HEALTH - WEAK_POINT_DAMAGE);
+        assert!(!boss.get_part(core).is_some_and(BossPart::is_destroyed));
+    }
+
+    #[test]
+    fn the_boss_is_defeated_when_its_health_runs_out() {
+        let mut boss = Boss::new(1);
+        let core = part_of(&boss, BossPartKind::Core);
+
+        let hits: Vec<BossHit> = (0..BOSS_HEALTH / WEAK_POINT_DAMAGE)
+            .map(|_| boss.hit(core))
+            .collect();
+
+        assert_eq!(hits.last(), Some(&BossHit::Defeated));
+        assert!(boss.is_defeated());
+        assert_eq!(boss.hit(core), BossHit::Missed);
+        assert!(boss.tick(BOSS_ATTACK_INTERVAL).is_empty());
+    }
+
+    #[test]
+    fn attacks_follow_the_pattern() {
+        let mut boss = Boss::new(1);
+
+        let kinds: Vec<Vec<BossAttackKind>> = (0..4)
+            .map(|_| {
+                boss.tick(BOSS_ATTACK_INTERVAL)
+                    .iter()
+                    .map(|attack| attack.kind)
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                vec![BossAttackKind::Spread; 2],
+                vec![BossAttackKind::Laser],
+                vec![BossAttackKind::Spread; 2],
+                vec![BossAttackKind::Minions; 2],
+            ]
+        );
+    }
+
+    #[test]
+    fn destroyed_parts_stop_attacking() {
+        let mut boss = Boss::new(1);
+        let cannon = part_of(&boss, BossPartKind::LaserCannon);
+        while boss.hit(cannon) != BossHit::PartDestroyed {}
+
+        boss.tick(BOSS_ATTACK_INTERVAL);
+        let attacks = boss.tick(BOSS_ATTACK_INTERVAL);
+
+        assert!(
+            attacks
+                .iter()
+                .all(|attack| attack.kind == BossAttackKind::Spread)
+        );
+    }
+
+    #[test]
+    fn an_enraged_boss_attacks_more_often() {
+        let mut boss = Boss::new(1);
+        let core = part_of(&boss, BossPartKind::Core);
+        boss.tick(BOSS_ATTACK_INTERVAL);
+        while !boss.is_enraged() {
+            boss.hit(core);
+        }
+        boss.tick(BOSS_ATTACK_INTERVAL);
+
+        assert!(!boss.tick(ENRAGED_ATTACK_INTERVAL).is_empty());
+    }
+
+    #[test]
+    fn the_boss_sways_within_bounds() {
+        let mut boss = Boss::new(1);
+
+        for _ in 0..100 {
+            boss.tick(0.5);
+            assert!((-1.0..=1.0).contains(&boss.get_sway()));
+        }
+    }
+
+    #[test]
+    fn minions_get_their_own_ids() {
+        let mut boss = Boss::new(1);
+
+        let first = boss.launch_minion();
+        let second = boss.launch_minion();
+
+        assert_ne!(first, second);
+        assert!(boss.get_parts().iter().all(|part| part.get_id() != first));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::domain::lives::DEFAULT_LIVES;
+use crate::domain::named_enum::named_enum;
+use std::collections::VecDeque;
+
+pub const DYNAMIC_WINDOW: usize = 30;
+pub const DYNAMIC_MIN_SHOTS: usize = 5;
+pub const DYNAMIC_MIN_SCALE: f64 = 0.5;
+pub const DYNAMIC_MAX_SCALE: f64 = 1.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Arcade,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BonusLives {
+    pub first: u32,
+    pub every: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Miss,
+    Hit,
+    Death,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DynamicDifficulty {
+    recent: VecDeque<Outcome>,
+}
+
+named_enum!(Difficulty, "difficulty", {
+    Easy => "easy",
+    Normal => "normal",
+    Hard => "hard",
+    Arcade => "arcade",
+});
+
+impl Difficulty {
+    pub fn tempo_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.25,
+            Difficulty::Arcade => 1.5,
+        }
+    }
+
+    pub fn scale_fire_probability(&self, probability: f64) -> f64 {
+        let scale = match self {
+            Difficulty::Easy => 0.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.5,
+            Difficulty::Arcade => 2.0,
+        };
+
+        (probability * scale).clamp(0.0, 1.0)
+    }
+
+    pub fn enemy_projectile_speed_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 0.8,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 1.2,
+            Difficulty::Arcade => 1.4,
+        }
+    }
+
+    pub fn player_projectile_speed_scale(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.2,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.9,
+            Difficulty::Arcade => 0.8,
+        }
+    }
+
+    pub fn starting_lives(&self) -> u8 {
+        match self {
+            Difficulty::Easy => 5,
+            Difficulty::Normal | Difficulty::Hard => DEFAULT_LIVES,
+            Difficulty::Arcade => 2,
+        }
+    }
+
+    pub fn bonus_lives(&self) -> BonusLives {
+        match self {
+            Difficulty::Easy => BonusLives {
+                first: 500,
+                every: Some(1000),
+            },
+            Difficulty::Normal => BonusLives {
+                first: 1000,
+                every: Some(2000),
+            },
+            Difficulty::Hard => BonusLives {
+                first: 1500,
+                every: None,
+            },
+            Difficulty::Arcade => BonusLives {
+                first: 3000,
+                every: None,
+            },
+        }
+    }
+}
+
+impl BonusLives {
+    pub fn earned(&self, score: u32) -> u32 {
+        if score < self.first {
+            return 0;
+        }
+
+        match self.every {
+            Some(every) if every > 0 => 1 + (score - self.first) / every,
+            _ => 1,
+        }
+    }
+
+    pub fn earned_between(&self, before: u32, after: u32) -> u32 {
+        self.earned(after).saturating_sub(self.earned(before))
+    }
+}
+
+impl DynamicDifficulty {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_shot(&mut self) {
+        self.push(Outcome::Miss);
+    }
+
+    pub fn record_hit(&mut self) {
+        if let Some(outcome) = self
+            .recent
+            .iter_mut()
+            .rev()
+            .find(|outcome| **outcome == Outcome::Miss)
+        {
+            *outcome = Outcome::Hit;
+        }
+    }
+
+    pub fn record_death(&mut self) {
+        self.push(Outcome::Death);
+    }
+
+    pub fn accuracy(&self) -> Option<f64> {
+        let hits = self.count(Outcome::Hit);
+        let shots = hits + self.count(Outcome::Miss);
+
+        (shots >= DYNAMIC_MIN_SHOTS).then(|| hits as f64 / shots as f64)
+    }
+
+    pub fn recent_deaths(&self) -> usize {
+        self.count(Outcome::Death)
+    }
+
+    pub fn fire_scale(&self) -> f64 {
+        let accuracy = self.accuracy().unwrap_or(0.5);
+        let scale = 1.0 + (accuracy - 0.5) - 0.25 * self.recent_deaths() as f64;
+
+        scale.clamp(DYNAMIC_MIN_SCALE, DYNAMIC_MAX_SCALE)
+    }
+
+    fn push(&mut self, outcome: Outcome) {
+        if self.recent.len() == DYNAMIC_WINDOW {
+            self.recent.pop_front();
+        }
+
+        self.recent.push_back(outcome);
+    }
+
+    fn count(&self, outcome: Outcome) -> usize {
+        self.recent
+            .iter()
+            .filter(|recent| **recent == outcome)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_difficulty_is_normal() {
+        assert_eq!(Difficulty::default(), Difficulty::Normal);
+    }
+
+    #[test]
+    fn normal_difficulty_does_not_scale() {
+        let difficulty = Difficulty::Normal;
+
+        assert_eq!(difficulty.tempo_scale(), 1.0);
+        assert_eq!(difficulty.scale_fire_probability(0.2), 0.2);
+    }
+
+    #[test]
+    fn harder_difficulties_are_faster() {
+        assert!(Difficulty::Easy.tempo_scale() < Difficulty::Normal.tempo_scale());
+        assert!(Difficulty::Hard.tempo_scale() < Difficulty::Arcade.tempo_scale());
+    }
+
+    #[test]
+    fn scaled_fire_probability_never_exceeds_one() {
+        assert_eq!(Difficulty::Arcade.scale_fire_probability(0.8), 1.0);
+    }
+
+    #[test]
+    fn presets_scale_lives_and_shots() {
+        assert_eq!(Difficulty::Normal.starting_lives(), DEFAULT_LIVES);
+        assert!(Difficulty::Easy.starting_lives() > Difficulty::Arcade.starting_lives());
+        assert_eq!(Difficulty::Normal.enemy_projectile_speed_scale(), 1.0);
+        assert!(
+            Difficulty::Easy.enemy_projectile_speed_scale()
+                < Difficulty::Arcade.enemy_projectile_speed_scale()
+        );
+        assert_eq!(Difficulty::Normal.player_projectile_speed_scale(), 1.0);
+        assert!(
+            Difficulty::Easy.player_projectile_speed_scale()
+                > Difficulty::Arcade.player_projectile_speed_scale()
+        );
+    }
+
+    #[test]
+    fn bonus_lives_are_earned_at_each_threshold() {
+        let repeating = BonusLives {
+            first: 1000,
+            every: Some(2000),
+        };
+        assert_eq!(repeating.earned(990), 0);
+        assert_eq!(repeating.earned(1000), 1);
+        assert_eq!(repeating.earned(2990), 1);
+        assert_eq!(repeating.earned(3000), 2);
+        assert_eq!(repeating.earned_between(990, 1000), 1);
+        assert_eq!(repeating.earned_between(1000, 1010), 0);
+
+        let once = Difficulty::Hard.bonus_lives();
+        assert_eq!(once.earned(100_000), 1);
+    }
+
+    #[test]
+    fn dynamic_difficulty_starts_neutral() {
+        let dynamic = DynamicDifficulty::new();
+
+        assert_eq!(dynamic.accuracy(), None);
+        assert_eq!(dynamic.fire_scale(), 1.0);
+    }
+
+    #[test]
+    fn accurate_players_face_more_fire() {
+        let mut dynamic = DynamicDifficulty::new();
+        for _ in 0..DYNAMIC_MIN_SHOTS {
+            dynamic.record_shot();
+            dynamic.record_hit();
+        }
+
+        assert_eq!(dynamic.accuracy(), Some(1.0));
+        assert_eq!(dynamic.fire_scale(), DYNAMIC_MAX_SCALE);
+    }
+
+    #[test]
+    fn recent_deaths_ease_the_fire() {
+        let mut dynamic = DynamicDifficulty::new();
+        dynamic.record_death();
+        dynamic.record_death();
+
+        assert_eq!(dynamic.recent_deaths(), 2);
+        assert_eq!(dynamic.fire_scale(), DYNAMIC_MIN_SCALE);
+
+        for _ in 0..DYNAMIC_WINDOW {
+            dynamic.record_shot();
+        }
+
+        assert_eq!(dynamic.recent_deaths(), 0);
+        assert_eq!(dynamic.accuracy(), Some(0.0));
+    }
+
+    #[test]
+    fn should_parse_names_ignoring_case() {
+        assert_eq!("Hard".parse::<Difficulty>(), Ok(Difficulty::Hard));
+        assert_eq!(Difficulty::Arcade.as_str().parse(), Ok(Difficulty::Arcade));
+    }
+
+    #[test]
+    fn should_reject_unknown_names() {
+        assert!("impossible".parse::<Difficulty>().is_err());
+    }
+}
@@ -0,0 +1,132 @@
+pub const DIVE_DURATION: f32 = 3.0;
+pub const DIVE_SWING: f32 = 160.0;
+pub const DIVE_DEPTH: f32 = 80.0;
+pub const DIVE_EXIT_CHANCE: f64 = 0.3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiveEnding {
+    Return,
+    Exit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dive {
+    start: (f32, f32),
+    target: (f32, f32),
+    swing: f32,
+    exit_y: f32,
+    ending: DiveEnding,
+    elapsed: f32,
+}
+
+impl Dive {
+    pub fn new(
+        start: (f32, f32),
+        target: (f32, f32),
+        swing: f32,
+        exit_y: f32,
+        ending: DiveEnding,
+    ) -> Self {
+        Self {
+            start,
+            target,
+            swing,
+            exit_y,
+            ending,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn get_ending(&self) -> DiveEnding {
+        self.ending
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.elapsed = (self.elapsed + delta_secs).min(DIVE_DURATION);
+    }
+
+    pub fn progress(&self) -> f32 {
+        self.elapsed / DIVE_DURATION
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= DIVE_DURATION
+    }
+
+    pub fn position(&self, slot: (f32, f32)) -> (f32, f32) {
+        let end = match self.ending {
+            DiveEnding::Return => slot,
+            DiveEnding::Exit => (self.target.0 - self.swing, self.exit_y),
+        };
+
+        cubic_bezier(
+            [
+                self.start,
+                (self.start.0 + self.swing, self.start.1),
+                (self.target.0, self.target.1 - DIVE_DEPTH),
+                end,
+            ],
+            self.progress(),
+        )
+    }
+}
+
+fn cubic_bezier(points: [(f32, f32); 4], t: f32) -> (f32, f32) {
+    let u = 1.0 - t;
+    let weights = [u * u * u, 3.0 * u * u * t, 3.0 * u * t * t, t * t * t];
+
+    points
+        .iter()
+        .zip(weights)
+        .fold((0.0, 0.0), |(x, y), (point, weight)| {
+            (x + point.0 * weight, y + point.1 * weight)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dive(ending: DiveEnding) -> Dive {
+        Dive::new((0.0, 200.0), (100.0, -200.0), DIVE_SWING, -400.0, ending)
+    }
+
+    #[test]
+    fn a_dive_starts_at_the_slot_it_left() {
+        assert_eq!(
+            dive(DiveEnding::Return).position((0.0, 200.0)),
+            (0.0, 200.0)
+        );
+    }
+
+    #[test]
+    fn a_dive_swoops_down_toward_the_target() {
+        let mut dive = dive(DiveEnding::Return);
+
+        dive.tick(DIVE_DURATION / 2.0);
+        let (x, y) = dive.position((0.0, 200.0));
+
+        assert!(x > 0.0);
+        assert!(y < 50.0);
+    }
+
+    #[test]
+    fn returning_dives_end_at_the_current_slot() {
+        let mut dive = dive(DiveEnding::Return);
+
+        dive.tick(DIVE_DURATION * 2.0);
+
+        assert!(dive.is_finished());
+        assert_eq!(dive.progress(), 1.0);
+        assert_eq!(dive.position((30.0, 180.0)), (30.0, 180.0));
+    }
+
+    #[test]
+    fn exiting_dives_end_below_the_screen() {
+        let mut dive = dive(DiveEnding::Exit);
+
+        dive.tick(DIVE_DURATION);
+
+        assert_eq!(dive.position((0.0, 200.0)), (100.0 - DIVE_SWING, -400.0));
+    }
+}
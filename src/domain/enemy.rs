@@ -1,6 +1,10 @@
-use crate::domain::weapons::{Fireable, WeaponState};
+use crate::domain::weapons::{Armed, Weapon};
+
+pub const ARMORED_HIT_POINTS: u32 = 3;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct EnemyId(usize);
 
 impl EnemyId {
@@ -13,53 +17,121 @@ impl EnemyId {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum EnemyKind {
+    Squid,
+    #[default]
+    Crab,
+    Octopus,
+    Armored,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Enemy {
     id: EnemyId,
-    weapon_state: WeaponState,
+    #[cfg_attr(feature = "serde", serde(default))]
+    kind: EnemyKind,
+    #[cfg_attr(feature = "serde", serde(default))]
+    weapon: Weapon,
+    #[cfg_attr(feature = "serde", serde(default))]
+    damage: u32,
+}
+
+impl EnemyKind {
+    pub const ALL: [EnemyKind; 4] = [
+        EnemyKind::Squid,
+        EnemyKind::Crab,
+        EnemyKind::Octopus,
+        EnemyKind::Armored,
+    ];
+
+    pub fn symbol(&self) -> char {
+        match self {
+            EnemyKind::Squid => 'S',
+            EnemyKind::Crab => 'C',
+            EnemyKind::Octopus => 'O',
+            EnemyKind::Armored => 'A',
+        }
+    }
+
+    pub fn hit_points(&self) -> u32 {
+        match self {
+            EnemyKind::Armored => ARMORED_HIT_POINTS,
+            EnemyKind::Squid | EnemyKind::Crab | EnemyKind::Octopus => 1,
+        }
+    }
+
+    pub fn from_symbol(symbol: char) -> Option<EnemyKind> {
+        EnemyKind::ALL
+            .into_iter()
+            .find(|kind| kind.symbol() == symbol)
+    }
 }
 
 impl Enemy {
     pub fn new(id: usize) -> Self {
+        Self::with_kind(id, EnemyKind::default())
+    }
+
+    pub fn with_kind(id: usize, kind: EnemyKind) -> Self {
         Enemy {
             id: EnemyId::new(id),
-            weapon_state: WeaponState::Ready,
+            kind,
+            weapon: Weapon::single_shot(),
+            damage: 0,
         }
     }
 
     pub fn get_id(&self) -> EnemyId {
         self.id
     }
-}
 
-impl Fireable for Enemy {
-    fn start_firing(&mut self) {
-        self.weapon_state = WeaponState::Firing;
+    pub fn get_kind(&self) -> EnemyKind {
+        self.kind
+    }
+
+    pub fn get_hit_points(&self) -> u32 {
+        self.kind.hit_points().saturating_sub(self.damage)
+    }
+
+    pub fn get_damage(&self) -> u32 {
+        self.damage
+    }
+
+    pub fn is_damaged(&self) -> bool {
+        self.damage > 0
     }
 
-    fn reload(&mut self) {
-        self.weapon_state = WeaponState::Ready;
+    pub fn with_damage(self, damage: u32) -> Self {
+        Enemy {
+            damage: damage.min(self.kind.hit_points().saturating_sub(1)),
+            ..self
+        }
     }
 
-    fn can_fire(&self) -> bool {
-        self.weapon_state == WeaponState::Ready
+    pub fn damage(&mut self) -> bool {
+        self.damage = (self.damage + 1).min(self.kind.hit_points());
+        self.get_hit_points() == 0
     }
+}
 
-    fn is_firing(&self) -> bool {
-        self.weapon_state == WeaponState::Firing
+impl Armed for Enemy {
+    fn weapon(&self) -> &Weapon {
+        &self.weapon
     }
 
-    fn toggle_fire(&mut self) {
-        self.weapon_state = match self.weapon_state {
-            WeaponState::Ready => WeaponState::Firing,
-            WeaponState::Firing => WeaponState::Ready,
-        };
+    fn weapon_mut(&mut self) -> &mut Weapon {
+        &mut self.weapon
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::weapons::WeaponEvent;
 
     fn create_enemy_with_id(id: usize) -> Enemy {
         Enemy::new(id)
@@ -74,50 +146,98 @@ mod tests {
     #[test]
     fn new_enemy_is_not_firing() {
         let enemy = create_enemy_with_id(1);
-        assert!(!enemy.is_firing());
+        assert!(!enemy.weapon().is_firing());
     }
 
     #[test]
-    fn toggling_fire_starts_firing() {
-        let mut enemy = create_enemy_with_id(1);
-        enemy.toggle_fire();
-        assert!(enemy.is_firing());
+    fn enemy_keeps_its_kind() {
+        let enemy = Enemy::with_kind(3, EnemyKind::Octopus);
+
+        assert_eq!(enemy.get_kind(), EnemyKind::Octopus);
+        assert_eq!(create_enemy_with_id(1).get_kind(), EnemyKind::Crab);
+    }
+
+    #[test]
+    fn kinds_round_trip_through_their_symbols() {
+        for kind in EnemyKind::ALL {
+            assert_eq!(EnemyKind::from_symbol(kind.symbol()), Some(kind));
+        }
+        assert_eq!(EnemyKind::from_symbol('.'), None);
     }
 
     #[test]
-    fn toggling_fire_twice_stops_firing() {
+    fn ordinary_enemies_die_on_the_first_hit() {
         let mut enemy = create_enemy_with_id(1);
-        enemy.toggle_fire();
-        enemy.toggle_fire();
-        assert!(!enemy.is_firing());
+
+        assert_eq!(enemy.get_hit_points(), 1);
+        assert!(enemy.damage());
+        assert_eq!(enemy.get_hit_points(), 0);
+    }
+
+    #[test]
+    fn armored_enemies_survive_until_their_last_hit_point() {
+        let mut enemy = Enemy::with_kind(1, EnemyKind::Armored);
+        assert!(!enemy.is_damaged());
+
+        for remaining in (1..ARMORED_HIT_POINTS).rev() {
+            assert!(!enemy.damage());
+            assert_eq!(enemy.get_hit_points(), remaining);
+        }
+
+        assert!(enemy.is_damaged());
+        assert!(enemy.damage());
+    }
+
+    #[test]
+    fn restored_damage_leaves_the_enemy_alive() {
+        let enemy = Enemy::with_kind(1, EnemyKind::Armored).with_damage(1);
+        assert_eq!(enemy.get_damage(), 1);
+        assert_eq!(enemy.get_hit_points(), ARMORED_HIT_POINTS - 1);
+
+        let enemy = enemy.with_damage(ARMORED_HIT_POINTS);
+        assert_eq!(enemy.get_hit_points(), 1);
+        assert!(!create_enemy_with_id(2).with_damage(1).is_damaged());
     }
 
     #[test]
     fn new_enemy_can_fire() {
         let enemy = create_enemy_with_id(1);
-        assert!(enemy.can_fire());
+        assert!(enemy.weapon().can_fire());
     }
 
     #[test]
-    fn enemy_cannot_fire_while_firing() {
+    fn enemy_cannot_fire_while_its_shot_is_in_flight() {
         let mut enemy = create_enemy_with_id(1);
-        enemy.start_firing();
-        assert!(!enemy.can_fire());
+        assert_eq!(enemy.weapon_mut().fire(), Some(WeaponEvent::Fired));
+        assert!(enemy.weapon().is_firing());
+        assert!(!enemy.weapon().can_fire());
     }
 
     #[test]
-    fn start_firing_changes_state_to_firing() {
+    fn ending_the_shot_reloads_the_enemy() {
         let mut enemy = create_enemy_with_id(1);
-        enemy.start_firing();
-        assert!(enemy.is_firing());
+        enemy.weapon_mut().fire();
+        assert_eq!(enemy.weapon_mut().shot_ended(), Some(WeaponEvent::Reloaded));
+        assert!(!enemy.weapon().is_firing());
+        assert!(enemy.weapon().can_fire());
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn reload_changes_state_to_ready() {
-        let mut enemy = create_enemy_with_id(1);
-        enemy.start_firing();
-        enemy.reload();
-        assert!(!enemy.is_firing());
-        assert!(enemy.can_fire());
+    fn enemy_survives_a_serde_round_trip() {
+        let mut enemy = Enemy::new(7);
+        enemy.weapon_mut().fire();
+
+        let restored: Enemy =
+            ron::from_str(&ron::to_string(&enemy).expect("Enemy should serialize"))
+                .expect("Enemy should deserialize");
+
+        assert_eq!(restored, enemy);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn enemy_id_serializes_as_a_plain_number() {
+        assert_eq!(ron::to_string(&EnemyId::new(7)).ok(), Some("7".to_string()));
     }
 }
@@ -1,26 +1,42 @@
 use crate::domain::enemy::{Enemy, EnemyId};
+use crate::domain::formation_layout::FormationLayout;
+use crate::domain::movement_pattern::{Bounds, FormationMovement, March};
+use crate::domain::weapons::Armed;
 use tracing::info;
 
 pub const NUMBER_OF_STEPS_ON_X_AXE: usize = 41;
 pub const COLUMNS: usize = 11;
 pub const ROWS: usize = 5;
-const FREE_MOVING_SPACE_ON_X_AXE: usize = NUMBER_OF_STEPS_ON_X_AXE - COLUMNS;
+const BREACH_ROW: usize = 18;
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EnemyFormation {
+    #[cfg_attr(feature = "serde", serde(default))]
+    layout: FormationLayout,
     enemies: Vec<Vec<Option<Enemy>>>,
-    position: (usize, usize),
-    direction: MovingDirection,
+    march: March,
+    #[cfg_attr(feature = "serde", serde(default))]
+    movement: FormationMovement,
     status: FormationStatus,
     enemies_alive: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    away: Vec<EnemyId>,
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MovingDirection {
+    #[cfg_attr(feature = "serde", serde(alias = "left"))]
     ToLeft,
+    #[cfg_attr(feature = "serde", serde(alias = "right"))]
     ToRight,
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormationStatus {
     Assembled,
     Advancing,
@@ -28,6 +44,23 @@ pub enum FormationStatus {
     Annihilated,
 }
 
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum DamageOutcome {
+    Missed,
+    Wounded,
+    Killed,
+}
+
+impl DamageOutcome {
+    pub fn is_hit(&self) -> bool {
+        *self != DamageOutcome::Missed
+    }
+
+    pub fn died(&self) -> bool {
+        *self == DamageOutcome::Killed
+    }
+}
+
 impl Default for EnemyFormation {
     fn default() -> Self {
         Self::new()
@@ -36,120 +69,357 @@ impl Default for EnemyFormation {
 
 impl EnemyFormation {
     pub fn new() -> Self {
-        let mut enemies: Vec<Vec<Option<Enemy>>> = vec![];
-        let mut id = 1;
-
-        for _ in 0..ROWS {
-            let mut row = vec![];
-            for _ in 0..COLUMNS {
-                row.push(Some(Enemy::new(id)));
-                id += 1;
-            }
-            enemies.push(row);
-        }
+        Self::from_layout(&FormationLayout::classic())
+    }
+
+    pub fn new_at_row(row: usize) -> Self {
+        Self::from_layout_at_row(&FormationLayout::classic(), row)
+    }
+
+    pub fn from_layout(layout: &FormationLayout) -> Self {
+        let enemies: Vec<Vec<Option<Enemy>>> = (0..layout.get_rows())
+            .map(|row| Self::spawn_row(layout, row))
+            .collect();
 
         EnemyFormation {
+            layout: layout.clone(),
             enemies,
-            position: (0, 0),
-            direction: MovingDirection::ToRight,
+            march: March::new((0, 0), MovingDirection::ToRight),
+            movement: FormationMovement::default(),
             status: FormationStatus::Assembled,
-            enemies_alive: COLUMNS * ROWS,
+            enemies_alive: layout.count_enemies(),
+            away: Vec::new(),
         }
     }
 
-    pub fn advance(&mut self) {
-        if self.status == FormationStatus::Breached {
-            info!("Enemy formation already breached");
-            return;
-        }
+    pub fn from_layout_at_row(layout: &FormationLayout, row: usize) -> Self {
+        let mut formation = Self::from_layout(layout);
+        formation.march.anchor.1 = row;
+        formation
+    }
 
-        let current_x = self.position.0;
-        const BREACH_Y_LIMIT: usize = 14;
-
-        let mut is_breaching = || {
-            return if self.position.1 + 1 >= BREACH_Y_LIMIT {
-                self.status = FormationStatus::Breached;
-                info!("Enemy formation breached!");
-                true
-            } else {
-                false
-            };
-        };
+    pub fn with_movement(mut self, movement: FormationMovement) -> Self {
+        self.movement = movement;
+        self
+    }
 
-        match self.direction {
-            MovingDirection::ToRight => {
-                if current_x < FREE_MOVING_SPACE_ON_X_AXE {
-                    self.position.0 += 1;
-                } else {
-                    if is_breaching() {
-                        return;
-                    }
+    pub fn resume(mut self, movement: FormationMovement, step: usize) -> Self {
+        let (x, y) = movement.pattern().offset(step);
+        let (position_x, position_y) = self.march.anchor;
+
+        self.movement = movement;
+        self.march.anchor = (position_x.saturating_sub(x), position_y.saturating_sub(y));
+        self.march.step = step;
+        self
+    }
 
-                    self.position.1 += 1;
-                    self.direction = MovingDirection::ToLeft;
+    pub fn with_damage(mut self, damage: &[Vec<u32>]) -> Self {
+        for (row, damage_row) in self.enemies.iter_mut().zip(damage) {
+            for (slot, damage) in row.iter_mut().zip(damage_row) {
+                if let Some(enemy) = slot {
+                    *enemy = enemy.with_damage(*damage);
                 }
             }
-            MovingDirection::ToLeft => {
-                if current_x > 0 {
-                    self.position.0 -= 1;
-                } else {
-                    if is_breaching() {
-                        return;
-                    }
+        }
+
+        self
+    }
 
-                    self.position.1 += 1;
-                    self.direction = MovingDirection::ToRight;
+    pub fn cleared() -> Self {
+        let mut formation = Self::new();
+        formation
+            .enemies
+            .iter_mut()
+            .flatten()
+            .for_each(|slot| *slot = None);
+        formation.enemies_alive = 0;
+        formation.status = FormationStatus::Annihilated;
+        formation
+    }
+
+    pub fn restore(
+        layout: &FormationLayout,
+        alive: &[Vec<bool>],
+        position: (usize, usize),
+        direction: MovingDirection,
+        status: FormationStatus,
+    ) -> Self {
+        let mut formation = Self::from_layout(layout);
+
+        for (row, alive_row) in formation.enemies.iter_mut().zip(alive) {
+            for (slot, is_alive) in row.iter_mut().zip(alive_row) {
+                if !is_alive && slot.take().is_some() {
+                    formation.enemies_alive -= 1;
                 }
             }
         }
 
+        formation.march = March::new(position, direction);
+        formation.status = if formation.enemies_alive == 0 {
+            FormationStatus::Annihilated
+        } else {
+            status
+        };
+
+        formation
+    }
+
+    fn spawn_row(layout: &FormationLayout, row: usize) -> Vec<Option<Enemy>> {
+        let columns = layout.get_columns();
+
+        (0..columns)
+            .map(|column| {
+                layout
+                    .get_cell(row, column)
+                    .map(|kind| Enemy::with_kind(row * columns + column + 1, kind))
+            })
+            .collect()
+    }
+
+    pub fn advance(&mut self) {
+        if self.status == FormationStatus::Breached {
+            info!("Enemy formation already breached");
+            return;
+        }
+
+        let bounds = Bounds {
+            right: NUMBER_OF_STEPS_ON_X_AXE.saturating_sub(self.get_columns()),
+            bottom: BREACH_ROW.saturating_sub(self.get_rows()),
+        };
+
+        if !self.movement.pattern().advance(&mut self.march, bounds) {
+            self.status = FormationStatus::Breached;
+            info!("Enemy formation breached!");
+            return;
+        }
+
         self.status = FormationStatus::Advancing;
 
         info!(
             "Formation moved to {:?}, direction: {:?}",
-            self.position, self.direction
+            self.get_position(),
+            self.march.direction
         );
     }
 
+    pub fn get_layout(&self) -> &FormationLayout {
+        &self.layout
+    }
+
     pub fn get_enemies(&self) -> &Vec<Vec<Option<Enemy>>> {
         &self.enemies
     }
 
+    pub fn get_rows(&self) -> usize {
+        self.enemies.len()
+    }
+
+    pub fn get_columns(&self) -> usize {
+        self.enemies.first().map_or(0, Vec::len)
+    }
+
     pub fn get_position(&self) -> (usize, usize) {
-        self.position
+        self.movement.pattern().position(&self.march)
+    }
+
+    pub fn get_direction(&self) -> MovingDirection {
+        self.march.direction
+    }
+
+    pub fn get_movement(&self) -> FormationMovement {
+        self.movement
+    }
+
+    pub fn get_step(&self) -> usize {
+        self.march.step
     }
 
     pub fn get_status(&self) -> FormationStatus {
         self.status
     }
 
-    pub fn kill(&mut self, id: EnemyId) {
-        let id_value = id.value();
+    pub fn count_alive(&self) -> usize {
+        self.enemies_alive
+    }
 
-        if id_value == 0 || id_value > COLUMNS * ROWS {
-            return;
-        }
+    pub fn bottom_enemy(&self, column: usize) -> Option<EnemyId> {
+        self.enemies
+            .iter()
+            .rev()
+            .filter_map(|row| row.get(column).copied().flatten())
+            .map(|enemy| enemy.get_id())
+            .find(|id| !self.is_away(*id))
+    }
 
-        let id_index = id_value - 1;
-        let row = id_index / COLUMNS;
-        let col = id_index % COLUMNS;
+    pub fn in_formation(&self) -> impl Iterator<Item = &Enemy> {
+        self.enemies
+            .iter()
+            .flatten()
+            .flatten()
+            .filter(|enemy| !self.is_away(enemy.get_id()))
+    }
+
+    pub fn is_away(&self, id: EnemyId) -> bool {
+        self.away.contains(&id)
+    }
 
-        if row < ROWS
-            && let Some(enemy) = &self.enemies[row][col]
-            && enemy.get_id() == id
+    pub fn count_away(&self) -> usize {
+        self.away.len()
+    }
+
+    pub fn dive(&mut self, id: EnemyId) -> bool {
+        if matches!(
+            self.status,
+            FormationStatus::Breached | FormationStatus::Annihilated
+        ) || self.get_enemy(id).is_none()
+            || self.is_away(id)
         {
-            self.enemies[row][col] = None;
-            self.enemies_alive -= 1;
+            return false;
+        }
+
+        self.away.push(id);
+        true
+    }
+
+    pub fn return_to_slot(&mut self, id: EnemyId) -> bool {
+        let was_away = self.is_away(id);
+        self.away.retain(|away| *away != id);
+        was_away
+    }
+
+    pub fn leave(&mut self, id: EnemyId) -> bool {
+        self.return_to_slot(id) && self.kill(id)
+    }
+
+    pub fn populated_rows(&self) -> usize {
+        (0..self.get_rows())
+            .filter(|row| self.layout.is_row_populated(*row))
+            .count()
+    }
+
+    pub fn cleared_rows(&self) -> usize {
+        (0..self.get_rows())
+            .filter(|row| self.is_row_cleared(*row))
+            .count()
+    }
+
+    fn is_row_cleared(&self, row: usize) -> bool {
+        self.layout.is_row_populated(row) && self.enemies[row].iter().all(Option::is_none)
+    }
+
+    pub fn reinforce(&mut self) -> bool {
+        if self.status == FormationStatus::Annihilated {
+            return false;
+        }
+
+        let Some(row) = (0..self.get_rows())
+            .rev()
+            .find(|row| self.is_row_cleared(*row))
+        else {
+            return false;
+        };
+
+        self.enemies[row] = Self::spawn_row(&self.layout, row);
+        self.enemies_alive += self.enemies[row].iter().flatten().count();
+
+        info!("Enemy formation reinforced on row {}", row);
+        true
+    }
+
+    pub fn get_enemy(&self, id: EnemyId) -> Option<&Enemy> {
+        let (row, col) = self.get_slot(id)?;
+
+        self.enemies[row][col]
+            .as_ref()
+            .filter(|enemy| enemy.get_id() == id)
+    }
+
+    pub fn fire(&mut self, id: EnemyId) -> bool {
+        self.get_enemy_mut(id)
+            .and_then(|enemy| enemy.weapon_mut().fire())
+            .is_some()
+    }
+
+    pub fn end_shot(&mut self, id: EnemyId) {
+        if let Some(enemy) = self.get_enemy_mut(id) {
+            enemy.weapon_mut().shot_ended();
+        }
+    }
+
+    pub fn kill(&mut self, id: EnemyId) -> bool {
+        let killed = self.get_enemy(id).is_some();
+
+        if killed {
+            self.remove(id);
         }
 
         if self.enemies_alive == 0 {
             self.status = FormationStatus::Annihilated;
         }
+
+        killed
+    }
+
+    pub fn damage(&mut self, id: EnemyId) -> DamageOutcome {
+        let Some((row, col)) = self.get_slot(id) else {
+            return DamageOutcome::Missed;
+        };
+
+        let outcome = match &mut self.enemies[row][col] {
+            Some(enemy) if enemy.get_id() == id => {
+                if enemy.damage() {
+                    DamageOutcome::Killed
+                } else {
+                    DamageOutcome::Wounded
+                }
+            }
+            _ => DamageOutcome::Missed,
+        };
+
+        if outcome.died() {
+            self.kill(id);
+        }
+
+        outcome
+    }
+
+    fn get_enemy_mut(&mut self, id: EnemyId) -> Option<&mut Enemy> {
+        let (row, col) = self.get_slot(id)?;
+
+        self.enemies[row][col]
+            .as_mut()
+            .filter(|enemy| enemy.get_id() == id)
+    }
+
+    pub fn get_slot(&self, id: EnemyId) -> Option<(usize, usize)> {
+        let id_value = id.value();
+        let columns = self.get_columns();
+
+        if id_value == 0 || id_value > columns * self.get_rows() {
+            return None;
+        }
+
+        let id_index = id_value - 1;
+        Some((id_index / columns, id_index % columns))
+    }
+
+    fn remove(&mut self, id: EnemyId) {
+        self.away.retain(|away| *away != id);
+
+        if let Some((row, col)) = self.get_slot(id)
+            && self.enemies[row][col].take().is_some()
+        {
+            self.enemies_alive -= 1;
+        }
     }
 }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::enemy::{ARMORED_HIT_POINTS, EnemyKind};
+    use crate::domain::formation_layout::MAX_LAYOUT_ROWS;
+
+    const FREE_MOVING_SPACE: usize = NUMBER_OF_STEPS_ON_X_AXE - COLUMNS;
 
     fn create_formation() -> EnemyFormation {
         EnemyFormation::new()
@@ -179,6 +449,14 @@ mod tests {
         assert_eq!(enemies[0].len(), 11);
     }
 
+    #[test]
+    fn new_formation_can_start_lower() {
+        let formation = EnemyFormation::new_at_row(3);
+
+        assert_eq!(formation.get_position(), (0, 3));
+        assert_eq!(formation.get_status(), FormationStatus::Assembled);
+    }
+
     #[test]
     fn advancing_formation_moves_right_and_changes_status() {
         let mut formation = create_formation();
@@ -193,7 +471,7 @@ mod tests {
     fn formation_drops_down_and_reverses_at_right_boundary() {
         let mut formation = create_formation();
 
-        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE_ON_X_AXE);
+        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE);
 
         assert_eq!(formation.get_position(), (30, 0));
 
@@ -206,7 +484,7 @@ mod tests {
     fn formation_moves_left_after_hitting_right_boundary() {
         let mut formation = create_formation();
 
-        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE_ON_X_AXE + 1);
+        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE + 1);
 
         let (x_before, y_before) = formation.get_position();
         formation.advance();
@@ -220,9 +498,9 @@ mod tests {
     fn formation_drops_down_and_reverses_at_left_boundary() {
         let mut formation = create_formation();
 
-        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE_ON_X_AXE);
+        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE);
         formation.advance();
-        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE_ON_X_AXE);
+        advance_formation_n_times(&mut formation, FREE_MOVING_SPACE);
 
         assert_eq!(formation.get_position(), (0, 1));
 
@@ -262,26 +540,102 @@ mod tests {
     fn killing_enemy_removes_it_from_formation() {
         let mut formation = create_formation();
 
-        formation.kill(EnemyId::new(3));
+        assert!(formation.kill(EnemyId::new(3)));
 
         let enemies = formation.get_enemies();
         assert!(enemies[0][2].is_none());
+        assert_eq!(formation.count_alive(), 54);
+    }
+
+    #[test]
+    fn killing_a_dead_enemy_again_reports_nothing() {
+        let mut formation = create_formation();
+        formation.kill(EnemyId::new(3));
+
+        assert!(!formation.kill(EnemyId::new(3)));
+        assert_eq!(formation.count_alive(), 54);
     }
 
     #[test]
     fn killing_all_enemies_annihilates_formation() {
         let mut formation = create_formation();
 
-        (1..=55).for_each(|id| formation.kill(EnemyId::new(id)));
+        (1..=55).for_each(|id| {
+            formation.kill(EnemyId::new(id));
+        });
 
         assert_eq!(formation.get_status(), FormationStatus::Annihilated);
     }
 
+    #[test]
+    fn enemies_hold_their_fire_until_their_shot_ends() {
+        let mut formation = create_formation();
+
+        assert!(formation.fire(EnemyId::new(3)));
+        assert!(!formation.fire(EnemyId::new(3)));
+        assert!(formation.fire(EnemyId::new(4)));
+
+        formation.end_shot(EnemyId::new(3));
+
+        assert!(formation.fire(EnemyId::new(3)));
+    }
+
+    #[test]
+    fn dead_enemies_cannot_fire() {
+        let mut formation = create_formation();
+        formation.kill(EnemyId::new(3));
+
+        assert!(!formation.fire(EnemyId::new(3)));
+    }
+
+    #[test]
+    fn damaging_an_ordinary_enemy_kills_it() {
+        let mut formation = create_formation();
+
+        assert_eq!(formation.damage(EnemyId::new(3)), DamageOutcome::Killed);
+        assert_eq!(formation.damage(EnemyId::new(3)), DamageOutcome::Missed);
+        assert_eq!(formation.count_alive(), 54);
+    }
+
+    #[test]
+    fn armored_enemies_die_on_their_last_hit_point() {
+        let layout = FormationLayout::parse(&["AC"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+        let armored = EnemyId::new(1);
+
+        for _ in 1..ARMORED_HIT_POINTS {
+            assert_eq!(formation.damage(armored), DamageOutcome::Wounded);
+        }
+        assert!(
+            formation
+                .get_enemy(armored)
+                .is_some_and(|enemy| enemy.is_damaged())
+        );
+        assert_eq!(formation.count_alive(), 2);
+
+        assert!(formation.damage(armored).died());
+        assert!(formation.get_enemy(armored).is_none());
+        assert_eq!(formation.count_alive(), 1);
+    }
+
+    #[test]
+    fn restored_damage_carries_over_to_the_enemies() {
+        let layout = FormationLayout::parse(&["AC"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout).with_damage(&[vec![2, 0]]);
+        let armored = EnemyId::new(1);
+
+        assert_eq!(
+            formation.get_enemy(armored).map(Enemy::get_hit_points),
+            Some(ARMORED_HIT_POINTS - 2)
+        );
+        assert!(formation.damage(armored).died());
+    }
+
     #[test]
     fn killing_invalid_enemy_id_does_nothing() {
         let mut formation = create_formation();
 
-        formation.kill(EnemyId::new(999));
+        assert!(!formation.kill(EnemyId::new(999)));
 
         let enemies = formation.get_enemies();
         let alive_count = enemies
@@ -306,4 +660,278 @@ mod tests {
             .count();
         assert_eq!(alive_count, 55);
     }
+
+    #[test]
+    fn restoring_formation_keeps_only_the_alive_enemies() {
+        let mut alive = vec![vec![true; COLUMNS]; ROWS];
+        alive[0][0] = false;
+        alive[4][10] = false;
+
+        let formation = EnemyFormation::restore(
+            &FormationLayout::classic(),
+            &alive,
+            (7, 3),
+            MovingDirection::ToLeft,
+            FormationStatus::Advancing,
+        );
+
+        assert!(formation.get_enemies()[0][0].is_none());
+        assert!(formation.get_enemies()[4][10].is_none());
+        assert!(formation.get_enemies()[2][5].is_some());
+        assert_eq!(formation.get_position(), (7, 3));
+        assert_eq!(formation.get_direction(), MovingDirection::ToLeft);
+        assert_eq!(formation.get_status(), FormationStatus::Advancing);
+    }
+
+    #[test]
+    fn restoring_an_empty_formation_annihilates_it() {
+        let alive = vec![vec![false; COLUMNS]; ROWS];
+
+        let formation = EnemyFormation::restore(
+            &FormationLayout::classic(),
+            &alive,
+            (0, 0),
+            MovingDirection::ToRight,
+            FormationStatus::Advancing,
+        );
+
+        assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn formation_survives_a_serde_round_trip() {
+        let mut formation = create_formation();
+        formation.kill(EnemyId::new(12));
+        advance_formation_n_times(&mut formation, 35);
+
+        let restored: EnemyFormation =
+            ron::from_str(&ron::to_string(&formation).expect("Formation should serialize"))
+                .expect("Formation should deserialize");
+
+        assert_eq!(restored, formation);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn direction_and_status_have_stable_names() {
+        assert_eq!(
+            ron::to_string(&MovingDirection::ToLeft).ok(),
+            Some("to_left".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&MovingDirection::ToRight).ok(),
+            Some("to_right".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&FormationStatus::Assembled).ok(),
+            Some("assembled".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&FormationStatus::Advancing).ok(),
+            Some("advancing".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&FormationStatus::Breached).ok(),
+            Some("breached".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&FormationStatus::Annihilated).ok(),
+            Some("annihilated".to_string())
+        );
+    }
+
+    #[test]
+    fn bottom_enemy_is_the_lowest_survivor_of_a_column() {
+        let mut formation = create_formation();
+
+        assert_eq!(formation.bottom_enemy(2), Some(EnemyId::new(47)));
+
+        formation.kill(EnemyId::new(47));
+        formation.kill(EnemyId::new(36));
+
+        assert_eq!(formation.bottom_enemy(2), Some(EnemyId::new(25)));
+        assert_eq!(formation.bottom_enemy(COLUMNS), None);
+    }
+
+    #[test]
+    fn a_cleared_formation_has_nobody_left() {
+        let formation = EnemyFormation::cleared();
+
+        assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+        assert_eq!(formation.count_alive(), 0);
+        assert_eq!(formation.in_formation().count(), 0);
+    }
+
+    #[test]
+    fn divers_are_away_but_still_alive() {
+        let mut formation = create_formation();
+
+        assert!(formation.dive(EnemyId::new(47)));
+        assert!(!formation.dive(EnemyId::new(47)));
+
+        assert!(formation.is_away(EnemyId::new(47)));
+        assert_eq!(formation.count_away(), 1);
+        assert_eq!(formation.count_alive(), 55);
+        assert_eq!(formation.in_formation().count(), 54);
+        assert_eq!(formation.bottom_enemy(2), Some(EnemyId::new(36)));
+    }
+
+    #[test]
+    fn returning_divers_take_their_slot_back() {
+        let mut formation = create_formation();
+        formation.dive(EnemyId::new(47));
+
+        assert!(formation.return_to_slot(EnemyId::new(47)));
+        assert!(!formation.return_to_slot(EnemyId::new(47)));
+
+        assert!(!formation.is_away(EnemyId::new(47)));
+        assert_eq!(formation.bottom_enemy(2), Some(EnemyId::new(47)));
+    }
+
+    #[test]
+    fn divers_leaving_the_screen_are_gone_for_the_wave() {
+        let layout = FormationLayout::parse(&["S"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+        let diver = EnemyId::new(1);
+
+        assert!(!formation.leave(diver));
+        formation.dive(diver);
+
+        assert!(formation.leave(diver));
+        assert_eq!(formation.count_away(), 0);
+        assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+    }
+
+    #[test]
+    fn killed_divers_are_no_longer_away() {
+        let mut formation = create_formation();
+        formation.dive(EnemyId::new(3));
+
+        formation.kill(EnemyId::new(3));
+
+        assert!(!formation.is_away(EnemyId::new(3)));
+        assert!(!formation.dive(EnemyId::new(3)));
+    }
+
+    #[test]
+    fn a_breached_formation_sends_no_divers() {
+        let mut formation = create_formation();
+        advance_until_breached(&mut formation);
+
+        assert!(!formation.dive(EnemyId::new(1)));
+    }
+
+    #[test]
+    fn cleared_rows_count_only_empty_rows() {
+        let mut formation = create_formation();
+
+        assert_eq!(formation.cleared_rows(), 0);
+
+        for id in 45..=55 {
+            formation.kill(EnemyId::new(id));
+        }
+        formation.kill(EnemyId::new(1));
+
+        assert_eq!(formation.cleared_rows(), 1);
+    }
+
+    #[test]
+    fn reinforcing_refills_the_lowest_cleared_row() {
+        let mut formation = create_formation();
+        for id in 34..=55 {
+            formation.kill(EnemyId::new(id));
+        }
+
+        assert!(formation.reinforce());
+
+        assert_eq!(formation.cleared_rows(), 1);
+        assert_eq!(formation.bottom_enemy(0), Some(EnemyId::new(45)));
+
+        formation.kill(EnemyId::new(45));
+
+        assert_eq!(formation.bottom_enemy(0), Some(EnemyId::new(23)));
+    }
+
+    #[test]
+    fn full_or_annihilated_formations_cannot_be_reinforced() {
+        let mut formation = create_formation();
+
+        assert!(!formation.reinforce());
+
+        for id in 1..=COLUMNS * ROWS {
+            formation.kill(EnemyId::new(id));
+        }
+
+        assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+        assert!(!formation.reinforce());
+    }
+
+    #[test]
+    fn custom_layouts_only_spawn_their_cells() {
+        let layout = FormationLayout::parse(&[".S.", "COC"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+
+        assert_eq!(formation.get_rows(), 2);
+        assert_eq!(formation.get_columns(), 3);
+        assert_eq!(formation.bottom_enemy(1), Some(EnemyId::new(5)));
+        assert_eq!(
+            formation.get_enemies()[0][1].map(|enemy| enemy.get_kind()),
+            Some(EnemyKind::Squid)
+        );
+        assert_eq!(formation.get_enemies()[0][0], None);
+
+        for id in [2, 4, 5, 6] {
+            formation.kill(EnemyId::new(id));
+        }
+
+        assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+    }
+
+    #[test]
+    fn narrow_layouts_travel_further_before_dropping() {
+        let layout = FormationLayout::parse(&["CCC"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+
+        for _ in 0..NUMBER_OF_STEPS_ON_X_AXE - 3 {
+            formation.advance();
+        }
+
+        assert_eq!(formation.get_position(), (NUMBER_OF_STEPS_ON_X_AXE - 3, 0));
+
+        formation.advance();
+
+        assert_eq!(formation.get_position(), (NUMBER_OF_STEPS_ON_X_AXE - 3, 1));
+    }
+
+    #[test]
+    fn taller_layouts_breach_sooner() {
+        let layout = FormationLayout::parse(&["C"; MAX_LAYOUT_ROWS]).expect("Layout should parse");
+        let mut formation =
+            EnemyFormation::from_layout_at_row(&layout, BREACH_ROW - MAX_LAYOUT_ROWS);
+        formation.march.direction = MovingDirection::ToLeft;
+
+        formation.advance();
+
+        assert_eq!(formation.get_status(), FormationStatus::Breached);
+    }
+
+    #[test]
+    fn empty_rows_of_a_layout_are_never_cleared_rows() {
+        let layout = FormationLayout::parse(&["CC", "..", "OO"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+
+        assert_eq!(formation.populated_rows(), 2);
+        assert_eq!(formation.cleared_rows(), 0);
+
+        formation.kill(EnemyId::new(5));
+        formation.kill(EnemyId::new(6));
+
+        assert_eq!(formation.cleared_rows(), 1);
+        assert!(formation.reinforce());
+        assert_eq!(
+            formation.get_enemies()[2][0].map(|enemy| enemy.get_kind()),
+            Some(EnemyKind::Octopus)
+        );
+    }
 }
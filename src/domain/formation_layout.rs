@@ -0,0 +1,316 @@
+use crate::domain::enemy::EnemyKind;
+use crate::domain::enemy_formation::{COLUMNS, NUMBER_OF_STEPS_ON_X_AXE, ROWS};
+use crate::domain::wave::{FIRST_WAVE, Wave};
+use std::fmt::{Display, Formatter};
+
+pub const EMPTY_CELL: char = '.';
+pub const MAX_LAYOUT_ROWS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormationLayout {
+    cells: Vec<Vec<Option<EnemyKind>>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormationLayouts {
+    layouts: Vec<FormationLayout>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FormationLayoutError {
+    Empty,
+    NoEnemies,
+    TooManyRows(usize),
+    TooManyColumns(usize),
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    UnknownCell {
+        row: usize,
+        column: usize,
+        symbol: char,
+    },
+}
+
+impl Display for FormationLayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormationLayoutError::Empty => write!(f, "the layout has no rows"),
+            FormationLayoutError::NoEnemies => write!(f, "the layout has no enemies"),
+            FormationLayoutError::TooManyRows(rows) => write!(
+                f,
+                "the layout has {} rows, at most {} fit on screen",
+                rows, MAX_LAYOUT_ROWS
+            ),
+            FormationLayoutError::TooManyColumns(columns) => write!(
+                f,
+                "the layout has {} columns, at most {} fit on screen",
+                columns,
+                NUMBER_OF_STEPS_ON_X_AXE - 1
+            ),
+            FormationLayoutError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} cells but the first row has {}",
+                row + 1,
+                found,
+                expected
+            ),
+            FormationLayoutError::UnknownCell {
+                row,
+                column,
+                symbol,
+            } => write!(
+                f,
+                "unknown cell '{}' at row {}, column {}",
+                symbol,
+                row + 1,
+                column + 1
+            ),
+        }
+    }
+}
+
+impl Default for FormationLayout {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl FormationLayout {
+    pub fn classic() -> Self {
+        let kind_of_row = |row: usize| match row {
+            0 => EnemyKind::Squid,
+            1 | 2 => EnemyKind::Crab,
+            _ => EnemyKind::Octopus,
+        };
+
+        Self {
+            cells: (0..ROWS)
+                .map(|row| vec![Some(kind_of_row(row)); COLUMNS])
+                .collect(),
+        }
+    }
+
+    pub fn parse<S: AsRef<str>>(rows: &[S]) -> Result<Self, FormationLayoutError> {
+        if rows.is_empty() {
+            return Err(FormationLayoutError::Empty);
+        }
+        if rows.len() > MAX_LAYOUT_ROWS {
+            return Err(FormationLayoutError::TooManyRows(rows.len()));
+        }
+
+        let columns = rows[0].as_ref().chars().count();
+        if columns >= NUMBER_OF_STEPS_ON_X_AXE {
+            return Err(FormationLayoutError::TooManyColumns(columns));
+        }
+
+        let cells = rows
+            .iter()
+            .enumerate()
+            .map(|(row, text)| {
+                let found = text.as_ref().chars().count();
+                if found != columns {
+                    return Err(FormationLayoutError::Ragged {
+                        row,
+                        expected: columns,
+                        found,
+                    });
+                }
+
+                text.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(column, symbol)| match symbol {
+                        EMPTY_CELL => Ok(None),
+                        _ => EnemyKind::from_symbol(symbol).map(Some).ok_or(
+                            FormationLayoutError::UnknownCell {
+                                row,
+                                column,
+                                symbol,
+                            },
+                        ),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let layout = Self { cells };
+        if layout.count_enemies() == 0 {
+            return Err(FormationLayoutError::NoEnemies);
+        }
+
+        Ok(layout)
+    }
+
+    pub fn to_rows(&self) -> Vec<String> {
+        self.cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| cell.map_or(EMPTY_CELL, |kind| kind.symbol()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn get_rows(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn get_columns(&self) -> usize {
+        self.cells.first().map_or(0, Vec::len)
+    }
+
+    pub fn get_cell(&self, row: usize, column: usize) -> Option<EnemyKind> {
+        self.cells
+            .get(row)
+            .and_then(|cells| cells.get(column))
+            .copied()
+            .flatten()
+    }
+
+    pub fn is_row_populated(&self, row: usize) -> bool {
+        self.cells
+            .get(row)
+            .is_some_and(|cells| cells.iter().any(Option::is_some))
+    }
+
+    pub fn count_enemies(&self) -> usize {
+        self.cells
+            .iter()
+            .flatten()
+            .filter(|cell| cell.is_some())
+            .count()
+    }
+}
+
+impl Default for FormationLayouts {
+    fn default() -> Self {
+        Self {
+            layouts: vec![FormationLayout::classic()],
+        }
+    }
+}
+
+impl FormationLayouts {
+    pub fn new(layouts: Vec<FormationLayout>) -> Self {
+        if layouts.is_empty() {
+            Self::default()
+        } else {
+            Self { layouts }
+        }
+    }
+
+    pub fn for_wave(&self, wave: Wave) -> &FormationLayout {
+        let index = (wave.get_number() - FIRST_WAVE) as usize % self.layouts.len();
+
+        &self.layouts[index]
+    }
+
+    pub fn count(&self) -> usize {
+        self.layouts.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_layout_is_the_arcade_rectangle() {
+        let layout = FormationLayout::classic();
+
+        assert_eq!(layout.get_rows(), ROWS);
+        assert_eq!(layout.get_columns(), COLUMNS);
+        assert_eq!(layout.count_enemies(), ROWS * COLUMNS);
+        assert_eq!(layout.get_cell(0, 0), Some(EnemyKind::Squid));
+        assert_eq!(layout.get_cell(2, 5), Some(EnemyKind::Crab));
+        assert_eq!(layout.get_cell(4, 10), Some(EnemyKind::Octopus));
+    }
+
+    #[test]
+    fn layouts_are_parsed_from_text_rows() {
+        let layout =
+            FormationLayout::parse(&["..S..", ".CCC.", "OO.OO"]).expect("Layout should parse");
+
+        assert_eq!(layout.get_rows(), 3);
+        assert_eq!(layout.get_columns(), 5);
+        assert_eq!(layout.get_cell(0, 2), Some(EnemyKind::Squid));
+        assert_eq!(layout.get_cell(0, 0), None);
+        assert_eq!(layout.get_cell(2, 4), Some(EnemyKind::Octopus));
+        assert_eq!(layout.count_enemies(), 8);
+        assert_eq!(layout.to_rows(), vec!["..S..", ".CCC.", "OO.OO"]);
+    }
+
+    #[test]
+    fn invalid_layouts_are_explained() {
+        assert_eq!(
+            FormationLayout::parse::<&str>(&[]),
+            Err(FormationLayoutError::Empty)
+        );
+        assert_eq!(
+            FormationLayout::parse(&["...", "..."]),
+            Err(FormationLayoutError::NoEnemies)
+        );
+        assert_eq!(
+            FormationLayout::parse(&["CCC", "CC"]),
+            Err(FormationLayoutError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            FormationLayout::parse(&["CxC"]),
+            Err(FormationLayoutError::UnknownCell {
+                row: 0,
+                column: 1,
+                symbol: 'x'
+            })
+        );
+        assert_eq!(
+            FormationLayout::parse(&["C".repeat(NUMBER_OF_STEPS_ON_X_AXE)]),
+            Err(FormationLayoutError::TooManyColumns(
+                NUMBER_OF_STEPS_ON_X_AXE
+            ))
+        );
+        assert_eq!(
+            FormationLayout::parse(&["C"; MAX_LAYOUT_ROWS + 1]),
+            Err(FormationLayoutError::TooManyRows(MAX_LAYOUT_ROWS + 1))
+        );
+        assert_eq!(
+            FormationLayoutError::UnknownCell {
+                row: 0,
+                column: 1,
+                symbol: 'x'
+            }
+            .to_string(),
+            "unknown cell 'x' at row 1, column 2"
+        );
+    }
+
+    #[test]
+    fn waves_cycle_through_the_layouts() {
+        let diamond = FormationLayout::parse(&[".C.", "CCC", ".C."]).expect("Layout should parse");
+        let layouts = FormationLayouts::new(vec![FormationLayout::classic(), diamond.clone()]);
+
+        assert_eq!(layouts.for_wave(Wave::new(1)), &FormationLayout::classic());
+        assert_eq!(layouts.for_wave(Wave::new(2)), &diamond);
+        assert_eq!(layouts.for_wave(Wave::new(3)), &FormationLayout::classic());
+    }
+
+    #[test]
+    fn there_is_always_a_layout_to_play() {
+        let layouts = FormationLayouts::new(vec![]);
+
+        assert_eq!(layouts.count(), 1);
+        assert_eq!(layouts.for_wave(Wave::new(9)), &FormationLayout::classic());
+    }
+}
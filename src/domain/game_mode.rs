@@ -0,0 +1,212 @@
+use crate::domain::named_enum::named_enum;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    #[default]
+    Single,
+    Alternating,
+    Coop,
+    Versus,
+    Battle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum PlayerSlot {
+    #[default]
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TurnChange {
+    Continue,
+    Swap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turns {
+    mode: GameMode,
+    current: PlayerSlot,
+}
+
+named_enum!(GameMode, "game mode", {
+    Single => "single",
+    Alternating => "alternating",
+    Coop => "coop",
+    Versus => "versus",
+    Battle => "battle",
+});
+
+impl GameMode {
+    pub fn player_count(&self) -> u8 {
+        match self {
+            GameMode::Single => 1,
+            GameMode::Alternating | GameMode::Coop | GameMode::Versus | GameMode::Battle => 2,
+        }
+    }
+
+    pub fn active_slots(&self) -> &'static [PlayerSlot] {
+        match self {
+            GameMode::Single | GameMode::Alternating | GameMode::Versus | GameMode::Battle => {
+                &[PlayerSlot::One]
+            }
+            GameMode::Coop => &[PlayerSlot::One, PlayerSlot::Two],
+        }
+    }
+}
+
+impl PlayerSlot {
+    pub const ALL: [PlayerSlot; 2] = [PlayerSlot::One, PlayerSlot::Two];
+
+    pub fn index(&self) -> usize {
+        match self {
+            PlayerSlot::One => 0,
+            PlayerSlot::Two => 1,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            PlayerSlot::One => 1,
+            PlayerSlot::Two => 2,
+        }
+    }
+
+    pub fn other(&self) -> PlayerSlot {
+        match self {
+            PlayerSlot::One => PlayerSlot::Two,
+            PlayerSlot::Two => PlayerSlot::One,
+        }
+    }
+}
+
+impl Default for Turns {
+    fn default() -> Self {
+        Self::new(GameMode::default())
+    }
+}
+
+impl Turns {
+    pub fn new(mode: GameMode) -> Self {
+        Self {
+            mode,
+            current: PlayerSlot::One,
+        }
+    }
+
+    pub fn get_mode(&self) -> GameMode {
+        self.mode
+    }
+
+    pub fn get_current(&self) -> PlayerSlot {
+        self.current
+    }
+
+    pub fn after_death(&self, waiting_lives: Option<u8>) -> TurnChange {
+        if self.mode == GameMode::Alternating && waiting_lives.is_some_and(|lives| lives > 0) {
+            TurnChange::Swap
+        } else {
+            TurnChange::Continue
+        }
+    }
+
+    pub fn swap(&mut self) {
+        if self.mode == GameMode::Alternating {
+            self.current = self.current.other();
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.current = PlayerSlot::One;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_player_never_swaps() {
+        let mut turns = Turns::new(GameMode::Single);
+
+        assert_eq!(turns.after_death(Some(3)), TurnChange::Continue);
+
+        turns.swap();
+
+        assert_eq!(turns.get_current(), PlayerSlot::One);
+    }
+
+    #[test]
+    fn alternating_players_swap_while_the_other_has_lives() {
+        let turns = Turns::new(GameMode::Alternating);
+
+        assert_eq!(turns.after_death(Some(1)), TurnChange::Swap);
+        assert_eq!(turns.after_death(Some(0)), TurnChange::Continue);
+        assert_eq!(turns.after_death(None), TurnChange::Continue);
+    }
+
+    #[test]
+    fn swapping_alternates_between_the_two_players() {
+        let mut turns = Turns::new(GameMode::Alternating);
+
+        turns.swap();
+        assert_eq!(turns.get_current(), PlayerSlot::Two);
+
+        turns.swap();
+        assert_eq!(turns.get_current(), PlayerSlot::One);
+    }
+
+    #[test]
+    fn reset_gives_the_turn_back_to_player_one() {
+        let mut turns = Turns::new(GameMode::Alternating);
+        turns.swap();
+
+        turns.reset();
+
+        assert_eq!(turns.get_current(), PlayerSlot::One);
+    }
+
+    #[test]
+    fn should_parse_game_modes_ignoring_case() {
+        assert_eq!("Alternating".parse::<GameMode>(), Ok(GameMode::Alternating));
+        assert!("duel".parse::<GameMode>().is_err());
+        assert_eq!(GameMode::Alternating.player_count(), 2);
+    }
+
+    #[test]
+    fn coop_keeps_both_players_on_the_field() {
+        assert_eq!("coop".parse::<GameMode>(), Ok(GameMode::Coop));
+        assert_eq!(
+            GameMode::Coop.active_slots(),
+            &[PlayerSlot::One, PlayerSlot::Two]
+        );
+        assert_eq!(GameMode::Alternating.active_slots(), &[PlayerSlot::One]);
+    }
+
+    #[test]
+    fn coop_players_never_swap() {
+        let mut turns = Turns::new(GameMode::Coop);
+
+        assert_eq!(turns.after_death(Some(3)), TurnChange::Continue);
+
+        turns.swap();
+
+        assert_eq!(turns.get_current(), PlayerSlot::One);
+    }
+
+    #[test]
+    fn versus_leaves_only_player_one_on_the_field() {
+        assert_eq!("Versus".parse::<GameMode>(), Ok(GameMode::Versus));
+        assert_eq!(GameMode::Versus.player_count(), 2);
+        assert_eq!(GameMode::Versus.active_slots(), &[PlayerSlot::One]);
+    }
+
+    #[test]
+    fn battle_gives_each_player_their_own_arena() {
+        assert_eq!("battle".parse::<GameMode>(), Ok(GameMode::Battle));
+        assert_eq!(GameMode::Battle.player_count(), 2);
+        assert_eq!(GameMode::Battle.active_slots(), &[PlayerSlot::One]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::domain::enemy::EnemyId;
+use crate::domain::enemy_formation::{COLUMNS, EnemyFormation, MovingDirection};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gunner {
+    column: usize,
+}
+
+impl Default for Gunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Gunner {
+    pub fn new() -> Self {
+        Gunner {
+            column: COLUMNS / 2,
+        }
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn aim(&mut self, formation: &EnemyFormation, direction: MovingDirection) {
+        let next = match direction {
+            MovingDirection::ToLeft => (0..self.column)
+                .rev()
+                .find(|column| formation.bottom_enemy(*column).is_some()),
+            MovingDirection::ToRight => (self.column + 1..formation.get_columns())
+                .find(|column| formation.bottom_enemy(*column).is_some()),
+        };
+
+        if let Some(column) = next {
+            self.column = column;
+        }
+    }
+
+    pub fn settle(&mut self, formation: &EnemyFormation) {
+        if formation.bottom_enemy(self.column).is_some() {
+            return;
+        }
+
+        if let Some(column) = (0..formation.get_columns())
+            .filter(|column| formation.bottom_enemy(*column).is_some())
+            .min_by_key(|column| column.abs_diff(self.column))
+        {
+            self.column = column;
+        }
+    }
+
+    pub fn target(&self, formation: &EnemyFormation) -> Option<EnemyId> {
+        formation.bottom_enemy(self.column)
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clear_column(formation: &mut EnemyFormation, column: usize) {
+        while let Some(id) = formation.bottom_enemy(column) {
+            formation.kill(id);
+        }
+    }
+
+    #[test]
+    fn gunner_starts_in_the_middle_column() {
+        let gunner = Gunner::new();
+
+        assert_eq!(gunner.get_column(), 5);
+        assert_eq!(
+            gunner.target(&EnemyFormation::new()),
+            Some(EnemyId::new(50))
+        );
+    }
+
+    #[test]
+    fn aiming_skips_empty_columns() {
+        let mut formation = EnemyFormation::new();
+        clear_column(&mut formation, 4);
+        clear_column(&mut formation, 3);
+        let mut gunner = Gunner::new();
+
+        gunner.aim(&formation, MovingDirection::ToLeft);
+
+        assert_eq!(gunner.get_column(), 2);
+
+        gunner.aim(&formation, MovingDirection::ToRight);
+
+        assert_eq!(gunner.get_column(), 5);
+    }
+
+    #[test]
+    fn aiming_stops_at_the_last_column() {
+        let formation = EnemyFormation::new();
+        let mut gunner = Gunner::new();
+
+        for _ in 0..COLUMNS {
+            gunner.aim(&formation, MovingDirection::ToRight);
+        }
+
+        assert_eq!(gunner.get_column(), COLUMNS - 1);
+    }
+
+    #[test]
+    fn settling_moves_to_the_nearest_column_with_invaders() {
+        let mut formation = EnemyFormation::new();
+        for column in 3..COLUMNS {
+            clear_column(&mut formation, column);
+        }
+        let mut gunner = Gunner::new();
+
+        assert_eq!(gunner.target(&formation), None);
+
+        gunner.settle(&formation);
+
+        assert_eq!(gunner.get_column(), 2);
+        assert_eq!(gunner.target(&formation), Some(EnemyId::new(47)));
+    }
+}
@@ -0,0 +1,319 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub const MAX_HIGH_SCORES: usize = 10;
+pub const INITIALS_LENGTH: usize = 3;
+const FIRST_LETTER: char = 'A';
+const LAST_LETTER: char = 'Z';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Initials {
+    letters: [char; INITIALS_LENGTH],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HighScoreEntry {
+    pub initials: Initials,
+    pub score: u32,
+    pub wave: u32,
+    pub date: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct HighScoreTable {
+    entries: Vec<HighScoreEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitialsEntry {
+    letters: [char; INITIALS_LENGTH],
+    cursor: usize,
+}
+
+impl Default for Initials {
+    fn default() -> Self {
+        Self {
+            letters: [FIRST_LETTER; INITIALS_LENGTH],
+        }
+    }
+}
+
+impl Initials {
+    pub fn get_letters(&self) -> [char; INITIALS_LENGTH] {
+        self.letters
+    }
+}
+
+impl Display for Initials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.letters
+            .iter()
+            .try_for_each(|letter| write!(f, "{}", letter))
+    }
+}
+
+impl FromStr for Initials {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let letters: Vec<char> = value.chars().collect();
+
+        match letters.as_slice() {
+            [a, b, c] if letters.iter().all(|letter| letter.is_ascii_uppercase()) => Ok(Self {
+                letters: [*a, *b, *c],
+            }),
+            _ => Err(format!(
+                "initials must be {} uppercase letters, got '{}'",
+                INITIALS_LENGTH, value
+            )),
+        }
+    }
+}
+
+impl HighScoreTable {
+    pub fn new(mut entries: Vec<HighScoreEntry>) -> Self {
+        entries.sort_by(|a, b| b.score.cmp(&a.score));
+        entries.truncate(MAX_HIGH_SCORES);
+        Self { entries }
+    }
+
+    pub fn get_entries(&self) -> &[HighScoreEntry] {
+        &self.entries
+    }
+
+    pub fn best(&self) -> u32 {
+        self.entries.first().map(|entry| entry.score).unwrap_or(0)
+    }
+
+    pub fn qualifies(&self, score: u32) -> bool {
+        if score == 0 {
+            return false;
+        }
+
+        self.entries.len() < MAX_HIGH_SCORES
+            || self
+                .entries
+                .last()
+                .is_some_and(|lowest| score > lowest.score)
+    }
+
+    pub fn insert(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        if !self.qualifies(entry.score) {
+            return None;
+        }
+
+        let rank = self
+            .entries
+            .iter()
+            .position(|existing| entry.score > existing.score)
+            .unwrap_or(self.entries.len());
+
+        self.entries.insert(rank, entry);
+        self.entries.truncate(MAX_HIGH_SCORES);
+
+        Some(rank)
+    }
+}
+
+impl Default for InitialsEntry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InitialsEntry {
+    pub fn new() -> Self {
+        Self {
+            letters: [FIRST_LETTER; INITIALS_LENGTH],
+            cursor: 0,
+        }
+    }
+
+    pub fn get_letters(&self) -> [char; INITIALS_LENGTH] {
+        self.letters
+    }
+
+    pub fn get_cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.cursor >= INITIALS_LENGTH
+    }
+
+    pub fn next_letter(&mut self) {
+        self.cycle_letter(|letter| match letter {
+            LAST_LETTER => FIRST_LETTER,
+            _ => (letter as u8 + 1) as char,
+        });
+    }
+
+    pub fn previous_letter(&mut self) {
+        self.cycle_letter(|letter| match letter {
+            FIRST_LETTER => LAST_LETTER,
+            _ => (letter as u8 - 1) as char,
+        });
+    }
+
+    pub fn confirm(&mut self) {
+        if !self.is_complete() {
+            self.cursor += 1;
+        }
+    }
+
+    pub fn back(&mut self) {
+        self.cursor = self.cursor.saturating_sub(1);
+    }
+
+    pub fn to_initials(&self) -> Initials {
+        Initials {
+            letters: self.letters,
+        }
+    }
+
+    fn cycle_letter(&mut self, change: impl Fn(char) -> char) {
+        if let Some(letter) = self.letters.get_mut(self.cursor) {
+            *letter = change(*letter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_entry(score: u32) -> HighScoreEntry {
+        HighScoreEntry {
+            initials: "ABC".parse().expect("Initials should parse"),
+            score,
+            wave: 1,
+            date: "2024-01-01".to_string(),
+        }
+    }
+
+    fn create_full_table() -> HighScoreTable {
+        HighScoreTable::new(
+            (1..=MAX_HIGH_SCORES as u32)
+                .map(|n| create_entry(n * 100))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn new_table_is_sorted_and_capped() {
+        let table = HighScoreTable::new((1..=12).map(|n| create_entry(n * 10)).collect());
+
+        assert_eq!(table.get_entries().len(), MAX_HIGH_SCORES);
+        assert_eq!(table.best(), 120);
+        assert_eq!(
+            table.get_entries().last().map(|entry| entry.score),
+            Some(30)
+        );
+    }
+
+    #[test]
+    fn empty_table_accepts_any_positive_score() {
+        let table = HighScoreTable::default();
+
+        assert_eq!(table.best(), 0);
+        assert!(table.qualifies(10));
+        assert!(!table.qualifies(0));
+    }
+
+    #[test]
+    fn full_table_only_accepts_scores_above_the_lowest() {
+        let table = create_full_table();
+
+        assert!(!table.qualifies(100));
+        assert!(table.qualifies(101));
+    }
+
+    #[test]
+    fn inserting_returns_the_rank_and_drops_the_lowest() {
+        let mut table = create_full_table();
+
+        let rank = table.insert(create_entry(550));
+
+        assert_eq!(rank, Some(5));
+        assert_eq!(table.get_entries().len(), MAX_HIGH_SCORES);
+        assert_eq!(
+            table.get_entries().last().map(|entry| entry.score),
+            Some(200)
+        );
+    }
+
+    #[test]
+    fn equal_scores_rank_below_older_entries() {
+        let mut table = HighScoreTable::new(vec![create_entry(300)]);
+
+        assert_eq!(table.insert(create_entry(300)), Some(1));
+    }
+
+    #[test]
+    fn inserting_a_low_score_does_nothing() {
+        let mut table = create_full_table();
+
+        assert_eq!(table.insert(create_entry(50)), None);
+        assert_eq!(table, create_full_table());
+    }
+
+    #[test]
+    fn initials_must_be_three_uppercase_letters() {
+        assert_eq!(
+            "XYZ"
+                .parse::<Initials>()
+                .map(|initials| initials.to_string()),
+            Ok("XYZ".to_string())
+        );
+        assert!("xyz".parse::<Initials>().is_err());
+        assert!("AB".parse::<Initials>().is_err());
+        assert!("A1C".parse::<Initials>().is_err());
+    }
+
+    #[test]
+    fn initials_entry_starts_on_the_first_letter() {
+        let entry = InitialsEntry::new();
+
+        assert_eq!(entry.get_letters(), ['A', 'A', 'A']);
+        assert_eq!(entry.get_cursor(), 0);
+        assert!(!entry.is_complete());
+    }
+
+    #[test]
+    fn letters_wrap_around_the_alphabet() {
+        let mut entry = InitialsEntry::new();
+
+        entry.previous_letter();
+        assert_eq!(entry.get_letters()[0], 'Z');
+
+        entry.next_letter();
+        entry.next_letter();
+        assert_eq!(entry.get_letters()[0], 'B');
+    }
+
+    #[test]
+    fn confirming_every_letter_completes_the_entry() {
+        let mut entry = InitialsEntry::new();
+
+        entry.next_letter();
+        entry.confirm();
+        entry.confirm();
+        entry.previous_letter();
+        entry.confirm();
+
+        assert!(entry.is_complete());
+        assert_eq!(entry.to_initials().to_string(), "BAZ");
+    }
+
+    #[test]
+    fn going_back_moves_to_the_previous_letter() {
+        let mut entry = InitialsEntry::new();
+
+        entry.back();
+        assert_eq!(entry.get_cursor(), 0);
+
+        entry.confirm();
+        entry.back();
+        assert_eq!(entry.get_cursor(), 0);
+    }
+}
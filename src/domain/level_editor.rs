@@ -0,0 +1,553 @@
+use crate::domain::enemy::EnemyKind;
+use crate::domain::formation_layout::{EMPTY_CELL, FormationLayout};
+use crate::domain::shield_layout::{
+    EMPTY_PIXEL, MAX_SHIELDS, SOLID_PIXEL, ShieldLayout, ShieldShape,
+};
+use crate::domain::wave_script::{ShieldRebuild, ShotType, WaveScript, WaveScriptError};
+
+pub const EDITOR_COLUMNS: usize = 16;
+pub const EDITOR_ROWS: usize = 8;
+pub const EDITOR_SHIELD_WIDTH: usize = 32;
+pub const EDITOR_SHIELD_HEIGHT: usize = 24;
+
+const SPEED_STEP: f32 = 0.05;
+const MIN_SPEED: f32 = 0.05;
+const MAX_SPEED: f32 = 2.0;
+const PROBABILITY_STEP: f64 = 0.05;
+const SPREAD_STEP: f32 = 0.05;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorParameter {
+    SpeedStart,
+    SpeedEnd,
+    FireProbability,
+    RollingShots,
+    PlungerShots,
+    SquigglyShots,
+    Movement,
+    ShieldCount,
+    ShieldSpread,
+    ShieldRebuild,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LevelEditor {
+    cells: Vec<Vec<Option<EnemyKind>>>,
+    pixels: Vec<Vec<bool>>,
+    script: WaveScript,
+    brush: EnemyKind,
+    parameter: EditorParameter,
+}
+
+impl EditorParameter {
+    pub const ALL: [EditorParameter; 10] = [
+        EditorParameter::SpeedStart,
+        EditorParameter::SpeedEnd,
+        EditorParameter::FireProbability,
+        EditorParameter::RollingShots,
+        EditorParameter::PlungerShots,
+        EditorParameter::SquigglyShots,
+        EditorParameter::Movement,
+        EditorParameter::ShieldCount,
+        EditorParameter::ShieldSpread,
+        EditorParameter::ShieldRebuild,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            EditorParameter::SpeedStart => "STEP START",
+            EditorParameter::SpeedEnd => "STEP END",
+            EditorParameter::FireProbability => "FIRE CHANCE",
+            EditorParameter::Movement => "MOVEMENT",
+            EditorParameter::RollingShots => "ROLLING SHOTS",
+            EditorParameter::PlungerShots => "PLUNGER SHOTS",
+            EditorParameter::SquigglyShots => "SQUIGGLY SHOTS",
+            EditorParameter::ShieldCount => "SHIELDS",
+            EditorParameter::ShieldSpread => "SHIELD SPREAD",
+            EditorParameter::ShieldRebuild => "REBUILD SHIELDS",
+        }
+    }
+
+    fn shot_type(&self) -> Option<ShotType> {
+        match self {
+            EditorParameter::RollingShots => Some(ShotType::Rolling),
+            EditorParameter::PlungerShots => Some(ShotType::Plunger),
+            EditorParameter::SquigglyShots => Some(ShotType::Squiggly),
+            _ => None,
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL
+            .iter()
+            .position(|parameter| parameter == self)
+            .unwrap_or(0)
+    }
+}
+
+impl Default for LevelEditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LevelEditor {
+    pub fn new() -> Self {
+        Self::from_script(WaveScript::classic(0.6, 0.2))
+    }
+
+    pub fn from_script(script: WaveScript) -> Self {
+        let formation = &script.formation;
+        let rows = formation.get_rows().max(EDITOR_ROWS);
+        let columns = formation.get_columns().max(EDITOR_COLUMNS);
+        let cells = (0..rows)
+            .map(|row| {
+                (0..columns)
+                    .map(|column| formation.get_cell(row, column))
+                    .collect()
+            })
+            .collect();
+
+        let shape = script.shields.get_shape();
+        let height = shape.get_height().max(EDITOR_SHIELD_HEIGHT);
+        let width = shape.get_width().max(EDITOR_SHIELD_WIDTH);
+        let pixels = (0..height)
+            .map(|row| {
+                (0..width)
+                    .map(|column| shape.is_solid(row, column))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            cells,
+            pixels,
+            script,
+            brush: EnemyKind::Squid,
+            parameter: EditorParameter::SpeedStart,
+        }
+    }
+
+    pub fn get_cells(&self) -> &Vec<Vec<Option<EnemyKind>>> {
+        &self.cells
+    }
+
+    pub fn get_pixels(&self) -> &Vec<Vec<bool>> {
+        &self.pixels
+    }
+
+    pub fn get_brush(&self) -> EnemyKind {
+        self.brush
+    }
+
+    pub fn get_parameter(&self) -> EditorParameter {
+        self.parameter
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.script.name
+    }
+
+    pub fn set_brush(&mut self, kind: EnemyKind) {
+        self.brush = kind;
+    }
+
+    pub fn toggle_cell(&mut self, row: usize, column: usize) {
+        let brush = self.brush;
+
+        if let Some(cell) = self
+            .cells
+            .get_mut(row)
+            .and_then(|cells| cells.get_mut(column))
+        {
+            *cell = if *cell == Some(brush) {
+                None
+            } else {
+                Some(brush)
+            };
+        }
+    }
+
+    pub fn toggle_pixel(&mut self, row: usize, column: usize) {
+        if let Some(pixel) = self
+            .pixels
+            .get_mut(row)
+            .and_then(|pixels| pixels.get_mut(column))
+        {
+            *pixel = !*pixel;
+        }
+    }
+
+    pub fn next_parameter(&mut self) {
+        let index = (self.parameter.index() + 1) % EditorParameter::ALL.len();
+        self.parameter = EditorParameter::ALL[index];
+    }
+
+    pub fn previous_parameter(&mut self) {
+        let count = EditorParameter::ALL.len();
+        let index = (self.parameter.index() + count - 1) % count;
+        self.parameter = EditorParameter::ALL[index];
+    }
+
+    pub fn increase(&mut self) {
+        self.adjust(1.0);
+    }
+
+    pub fn decrease(&mut self) {
+        self.adjust(-1.0);
+    }
+
+    fn adjust(&mut self, direction: f32) {
+        let script = &mut self.script;
+
+        match self.parameter {
+            EditorParameter::SpeedStart => {
+                script.speed.start = step_speed(script.speed.start, direction);
+            }
+            EditorParameter::SpeedEnd => {
+                script.speed.end = step_speed(script.speed.end, direction);
+            }
+            EditorParameter::FireProbability => {
+                script.fire_probability =
+                    round_hundredths(script.fire_probability + PROBABILITY_STEP * direction as f64)
+                        .clamp(0.0, 1.0);
+            }
+            EditorParameter::Movement => {
+                script.movement = if direction > 0.0 {
+                    script.movement.next()
+                } else {
+                    script.movement.previous()
+                };
+            }
+            EditorParameter::RollingShots
+            | EditorParameter::PlungerShots
+            | EditorParameter::SquigglyShots => {
+                if let Some(shot_type) = self.parameter.shot_type() {
+                    toggle_shot_type(&mut script.shot_types, shot_type);
+                }
+            }
+            EditorParameter::ShieldCount => {
+                let count = script.shields.get_count() as i64 + direction as i64;
+                self.set_shields(count.clamp(0, MAX_SHIELDS as i64) as usize, None);
+            }
+            EditorParameter::ShieldSpread => {
+                let spread = (script.shields.get_spread() + SPREAD_STEP * direction) * 100.0;
+                self.set_shields(
+                    self.script.shields.get_count(),
+                    Some((spread.round() / 100.0).clamp(0.0, 1.0)),
+                );
+            }
+            EditorParameter::ShieldRebuild => {
+                script.shield_rebuild = match script.shield_rebuild {
+                    ShieldRebuild::Keep => ShieldRebuild::Rebuild,
+                    ShieldRebuild::Rebuild => ShieldRebuild::Keep,
+                };
+            }
+        }
+    }
+
+    fn set_shields(&mut self, count: usize, spread: Option<f32>) {
+        let shields = &self.script.shields;
+        let spread = spread.unwrap_or(shields.get_spread());
+
+        if let Ok(layout) = ShieldLayout::new(shields.get_shape().clone(), count, spread) {
+            self.script.shields = layout;
+        }
+    }
+
+    pub fn parameter_value(&self, parameter: EditorParameter) -> String {
+        let script = &self.script;
+
+        match parameter {
+            EditorParameter::SpeedStart => format!("{:.2}s", script.speed.start),
+            EditorParameter::SpeedEnd => format!("{:.2}s", script.speed.end),
+            EditorParameter::FireProbability => format!("{:.2}", script.fire_probability),
+            EditorParameter::Movement => script.movement.label().to_string(),
+            EditorParameter::RollingShots
+            | EditorParameter::PlungerShots
+            | EditorParameter::SquigglyShots => {
+                let allowed = parameter
+                    .shot_type()
+                    .is_some_and(|shot_type| script.shot_types.contains(&shot_type));
+
+                if allowed { "ON" } else { "OFF" }.to_string()
+            }
+            EditorParameter::ShieldCount => script.shields.get_count().to_string(),
+            EditorParameter::ShieldSpread => format!("{:.2}", script.shields.get_spread()),
+            EditorParameter::ShieldRebuild => match script.shield_rebuild {
+                ShieldRebuild::Keep => "NO".to_string(),
+                ShieldRebuild::Rebuild => "YES".to_string(),
+            },
+        }
+    }
+
+    pub fn to_script(&self) -> Result<WaveScript, WaveScriptError> {
+        let formation_rows = trimmed_rows(&self.cells, Option::is_some, |cell| {
+            cell.map_or(EMPTY_CELL, |kind| kind.symbol())
+        });
+        let formation =
+            FormationLayout::parse(&formation_rows).map_err(WaveScriptError::Formation)?;
+
+        let shield_rows = trimmed_rows(
+            &self.pixels,
+            |pixel| *pixel,
+            |pixel| {
+                if *pixel { SOLID_PIXEL } else { EMPTY_PIXEL }
+            },
+        );
+        let shields = ShieldShape::parse(&shield_rows)
+            .and_then(|shape| {
+                ShieldLayout::new(
+                    shape,
+                    self.script.shields.get_count(),
+                    self.script.shields.get_spread(),
+                )
+            })
+            .map_err(WaveScriptError::Shields)?;
+
+        let script = WaveScript {
+            formation,
+            shields,
+            ..self.script.clone()
+        };
+        script.validate()?;
+
+        Ok(script)
+    }
+}
+
+fn step_speed(value: f32, direction: f32) -> f32 {
+    ((value + SPEED_STEP * direction) * 100.0)
+        .round()
+        .clamp(MIN_SPEED * 100.0, MAX_SPEED * 100.0)
+        / 100.0
+}
+
+fn round_hundredths(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+fn toggle_shot_type(shot_types: &mut Vec<ShotType>, shot_type: ShotType) {
+    if let Some(index) = shot_types.iter().position(|allowed| *allowed == shot_type) {
+        shot_types.remove(index);
+    } else {
+        shot_types.push(shot_type);
+        shot_types.sort_by_key(|allowed| {
+            [ShotType::Rolling, ShotType::Plunger, ShotType::Squiggly]
+                .iter()
+                .position(|known| known == allowed)
+        });
+    }
+}
+
+fn trimmed_rows<T>(
+    grid: &[Vec<T>],
+    is_filled: impl Fn(&T) -> bool,
+    symbol: impl Fn(&T) -> char,
+) -> Vec<String> {
+    let filled = |row: &Vec<T>| row.iter().any(&is_filled);
+    let Some(first_row) = grid.iter().position(filled) else {
+        return grid
+            .iter()
+            .map(|row| row.iter().map(&symbol).collect())
+            .collect();
+    };
+    let last_row = grid.iter().rposition(filled).unwrap_or(first_row);
+    let rows = &grid[first_row..=last_row];
+
+    let filled_columns = rows
+        .iter()
+        .flat_map(|row| row.iter().enumerate().filter(|(_, cell)| is_filled(cell)))
+        .map(|(column, _)| column);
+    let first_column = filled_columns.clone().min().unwrap_or(0);
+    let last_column = filled_columns.max().unwrap_or(first_column);
+
+    rows.iter()
+        .map(|row| {
+            row[first_column..=last_column]
+                .iter()
+                .map(&symbol)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::formation_layout::FormationLayoutError;
+    use crate::domain::movement_pattern::FormationMovement;
+    use crate::domain::shield_layout::ShieldLayoutError;
+
+    #[test]
+    fn new_editor_starts_from_the_classic_wave() {
+        let editor = LevelEditor::new();
+
+        assert_eq!(editor.get_cells().len(), EDITOR_ROWS);
+        assert_eq!(editor.get_cells()[0].len(), EDITOR_COLUMNS);
+        assert_eq!(editor.get_pixels().len(), EDITOR_SHIELD_HEIGHT);
+        assert_eq!(
+            editor.to_script().expect("Classic wave is valid"),
+            WaveScript::classic(0.6, 0.2)
+        );
+    }
+
+    #[test]
+    fn clicking_a_cell_places_the_brush_or_clears_it() {
+        let mut editor = LevelEditor::new();
+
+        editor.set_brush(EnemyKind::Octopus);
+        editor.toggle_cell(0, 0);
+        assert_eq!(editor.get_cells()[0][0], Some(EnemyKind::Octopus));
+
+        editor.toggle_cell(0, 0);
+        assert_eq!(editor.get_cells()[0][0], None);
+
+        editor.toggle_cell(99, 99);
+    }
+
+    #[test]
+    fn saved_layouts_are_trimmed_to_the_painted_area() {
+        let mut editor = LevelEditor::new();
+        for row in 0..EDITOR_ROWS {
+            for column in 0..EDITOR_COLUMNS {
+                if editor.get_cells()[row][column].is_some() {
+                    editor.set_brush(editor.get_cells()[row][column].unwrap_or_default());
+                    editor.toggle_cell(row, column);
+                }
+            }
+        }
+        editor.set_brush(EnemyKind::Crab);
+        editor.toggle_cell(2, 3);
+        editor.toggle_cell(3, 5);
+
+        let script = editor.to_script().expect("Layout is valid");
+
+        assert_eq!(script.formation.to_rows(), vec!["C..", "..C"]);
+    }
+
+    #[test]
+    fn painting_pixels_reshapes_the_shields() {
+        let mut editor = LevelEditor::new();
+        for row in 0..EDITOR_SHIELD_HEIGHT {
+            for column in 0..EDITOR_SHIELD_WIDTH {
+                if editor.get_pixels()[row][column] {
+                    editor.toggle_pixel(row, column);
+                }
+            }
+        }
+
+        assert_eq!(
+            editor.to_script(),
+            Err(WaveScriptError::Shields(ShieldLayoutError::NoPixels))
+        );
+
+        editor.toggle_pixel(1, 1);
+        editor.toggle_pixel(1, 2);
+
+        let script = editor.to_script().expect("Shape is valid");
+        assert_eq!(script.shields.get_shape().to_rows(), vec!["##"]);
+    }
+
+    #[test]
+    fn an_empty_formation_cannot_be_played() {
+        let mut editor = LevelEditor::from_script(WaveScript {
+            formation: FormationLayout::parse(&["S"]).expect("Layout is valid"),
+            ..WaveScript::classic(0.6, 0.2)
+        });
+        editor.set_brush(EnemyKind::Squid);
+        editor.toggle_cell(0, 0);
+
+        assert_eq!(
+            editor.to_script(),
+            Err(WaveScriptError::Formation(FormationLayoutError::NoEnemies))
+        );
+    }
+
+    #[test]
+    fn parameters_are_adjusted_within_their_range() {
+        let mut editor = LevelEditor::new();
+
+        editor.increase();
+        assert_eq!(editor.parameter_value(EditorParameter::SpeedStart), "0.65s");
+
+        editor.next_parameter();
+        editor.next_parameter();
+        assert_eq!(editor.get_parameter(), EditorParameter::FireProbability);
+        for _ in 0..30 {
+            editor.increase();
+        }
+        assert_eq!(
+            editor.parameter_value(EditorParameter::FireProbability),
+            "1.00"
+        );
+
+        editor.previous_parameter();
+        editor.previous_parameter();
+        editor.previous_parameter();
+        assert_eq!(editor.get_parameter(), EditorParameter::ShieldRebuild);
+        editor.increase();
+        assert_eq!(
+            editor.parameter_value(EditorParameter::ShieldRebuild),
+            "YES"
+        );
+
+        editor.previous_parameter();
+        editor.previous_parameter();
+        for _ in 0..20 {
+            editor.increase();
+        }
+        assert_eq!(
+            editor.parameter_value(EditorParameter::ShieldCount),
+            MAX_SHIELDS.to_string()
+        );
+    }
+
+    #[test]
+    fn movement_cycles_through_the_patterns() {
+        let mut editor = LevelEditor::new();
+        while editor.get_parameter() != EditorParameter::Movement {
+            editor.next_parameter();
+        }
+
+        assert_eq!(editor.parameter_value(EditorParameter::Movement), "CLASSIC");
+        editor.increase();
+        assert_eq!(
+            editor.parameter_value(EditorParameter::Movement),
+            "SINE WAVE"
+        );
+        editor.decrease();
+        editor.decrease();
+        assert_eq!(
+            editor.to_script().expect("Wave is valid").movement,
+            FormationMovement::Circling
+        );
+    }
+
+    #[test]
+    fn shot_types_are_toggled_in_a_stable_order() {
+        let mut editor = LevelEditor::new();
+        while editor.get_parameter() != EditorParameter::SquigglyShots {
+            editor.next_parameter();
+        }
+        editor.increase();
+        editor.previous_parameter();
+        editor.increase();
+
+        assert_eq!(
+            editor.to_script().expect("Wave is valid").shot_types,
+            vec![ShotType::Rolling, ShotType::Plunger, ShotType::Squiggly]
+        );
+
+        while editor.get_parameter() != EditorParameter::RollingShots {
+            editor.previous_parameter();
+        }
+        editor.increase();
+        editor.next_parameter();
+        editor.decrease();
+        editor.next_parameter();
+        editor.decrease();
+
+        assert_eq!(editor.to_script(), Err(WaveScriptError::NoShotTypes));
+    }
+}
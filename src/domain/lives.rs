@@ -1,5 +1,8 @@
 pub const DEFAULT_LIVES: u8 = 3;
+pub const MAX_LIVES: u8 = 9;
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Lives {
     current: u8,
 }
@@ -17,6 +20,16 @@ impl Lives {
         }
     }
 
+    pub fn with_count(count: u8) -> Self {
+        Lives { current: count }
+    }
+
+    pub fn increment(&mut self) {
+        if self.current < MAX_LIVES {
+            self.current += 1;
+        }
+    }
+
     pub fn decrement(&mut self) {
         if self.current > 0 {
             self.current -= 1;
@@ -67,6 +80,17 @@ mod tests {
         assert_eq!(lives.get_current(), 3);
     }
 
+    #[test]
+    fn incrementing_lives_stops_at_the_maximum() {
+        let mut lives = create_lives();
+        lives.increment();
+        assert_eq!(lives.get_current(), 4);
+
+        let mut lives = Lives::with_count(MAX_LIVES);
+        lives.increment();
+        assert_eq!(lives.get_current(), MAX_LIVES);
+    }
+
     #[test]
     fn exhausted_lives_cannot_be_decremented_below_zero() {
         let mut lives = create_lives();
@@ -74,4 +98,24 @@ mod tests {
 
         assert_eq!(lives.get_current(), 0);
     }
+
+    #[test]
+    fn lives_can_start_with_a_given_count() {
+        let lives = Lives::with_count(1);
+
+        assert_eq!(lives.get_current(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn lives_survive_a_serde_round_trip() {
+        let mut lives = create_lives();
+        lives.decrement();
+
+        let restored: Lives =
+            ron::from_str(&ron::to_string(&lives).expect("Lives should serialize"))
+                .expect("Lives should deserialize");
+
+        assert_eq!(restored, lives);
+    }
 }
@@ -0,0 +1,188 @@
+pub const DEFAULT_VOLUME: f32 = 0.8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MixerChannel {
+    Master,
+    Music,
+    Effects,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChannelLevel {
+    volume: f32,
+    muted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AudioMixer {
+    master: ChannelLevel,
+    music: ChannelLevel,
+    effects: ChannelLevel,
+}
+
+impl Default for ChannelLevel {
+    fn default() -> Self {
+        Self::new(DEFAULT_VOLUME, false)
+    }
+}
+
+impl ChannelLevel {
+    pub fn new(volume: f32, muted: bool) -> Self {
+        Self {
+            volume: clamp_volume(volume),
+            muted,
+        }
+    }
+
+    pub fn get_volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    fn audible_volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.volume }
+    }
+}
+
+impl Default for AudioMixer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioMixer {
+    pub fn new() -> Self {
+        Self {
+            master: ChannelLevel::default(),
+            music: ChannelLevel::default(),
+            effects: ChannelLevel::default(),
+        }
+    }
+
+    pub fn get_level(&self, channel: MixerChannel) -> ChannelLevel {
+        match channel {
+            MixerChannel::Master => self.master,
+            MixerChannel::Music => self.music,
+            MixerChannel::Effects => self.effects,
+        }
+    }
+
+    pub fn set_level(&mut self, channel: MixerChannel, level: ChannelLevel) {
+        *self.level_mut(channel) = level;
+    }
+
+    pub fn set_volume(&mut self, channel: MixerChannel, volume: f32) {
+        self.level_mut(channel).volume = clamp_volume(volume);
+    }
+
+    pub fn set_muted(&mut self, channel: MixerChannel, muted: bool) {
+        self.level_mut(channel).muted = muted;
+    }
+
+    pub fn toggle_mute(&mut self, channel: MixerChannel) {
+        let level = self.level_mut(channel);
+        level.muted = !level.muted;
+    }
+
+    pub fn effective_volume(&self, channel: MixerChannel) -> f32 {
+        match channel {
+            MixerChannel::Master => self.master.audible_volume(),
+            _ => self.master.audible_volume() * self.get_level(channel).audible_volume(),
+        }
+    }
+
+    fn level_mut(&mut self, channel: MixerChannel) -> &mut ChannelLevel {
+        match channel {
+            MixerChannel::Master => &mut self.master,
+            MixerChannel::Music => &mut self.music,
+            MixerChannel::Effects => &mut self.effects,
+        }
+    }
+}
+
+fn clamp_volume(volume: f32) -> f32 {
+    if volume.is_nan() {
+        0.0
+    } else {
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_mixer_plays_every_channel_at_the_default_volume() {
+        let mixer = AudioMixer::new();
+
+        assert_eq!(mixer.effective_volume(MixerChannel::Master), DEFAULT_VOLUME);
+        assert_eq!(
+            mixer.effective_volume(MixerChannel::Effects),
+            DEFAULT_VOLUME * DEFAULT_VOLUME
+        );
+    }
+
+    #[test]
+    fn channel_volume_is_scaled_by_the_master_volume() {
+        let mut mixer = AudioMixer::new();
+        mixer.set_volume(MixerChannel::Master, 0.5);
+        mixer.set_volume(MixerChannel::Music, 0.5);
+
+        assert_eq!(mixer.effective_volume(MixerChannel::Music), 0.25);
+    }
+
+    #[test]
+    fn volume_is_clamped_between_silent_and_full() {
+        let mut mixer = AudioMixer::new();
+        mixer.set_volume(MixerChannel::Music, 3.0);
+        mixer.set_volume(MixerChannel::Effects, -1.0);
+
+        assert_eq!(mixer.get_level(MixerChannel::Music).get_volume(), 1.0);
+        assert_eq!(mixer.get_level(MixerChannel::Effects).get_volume(), 0.0);
+        assert_eq!(ChannelLevel::new(f32::NAN, false).get_volume(), 0.0);
+    }
+
+    #[test]
+    fn muting_a_channel_silences_only_that_channel() {
+        let mut mixer = AudioMixer::new();
+        mixer.toggle_mute(MixerChannel::Music);
+
+        assert_eq!(mixer.effective_volume(MixerChannel::Music), 0.0);
+        assert!(mixer.effective_volume(MixerChannel::Effects) > 0.0);
+    }
+
+    #[test]
+    fn muting_the_master_silences_every_channel_and_keeps_the_volumes() {
+        let mut mixer = AudioMixer::new();
+        mixer.toggle_mute(MixerChannel::Master);
+
+        assert_eq!(mixer.effective_volume(MixerChannel::Effects), 0.0);
+        assert_eq!(mixer.effective_volume(MixerChannel::Music), 0.0);
+
+        mixer.toggle_mute(MixerChannel::Master);
+
+        assert_eq!(mixer, AudioMixer::new());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn mixer_survives_a_serde_round_trip() {
+        let mut mixer = AudioMixer::new();
+        mixer.set_volume(MixerChannel::Music, 0.3);
+        mixer.set_muted(MixerChannel::Effects, true);
+
+        let restored: AudioMixer =
+            ron::from_str(&ron::to_string(&mixer).expect("Mixer should serialize"))
+                .expect("Mixer should deserialize");
+
+        assert_eq!(restored, mixer);
+    }
+}
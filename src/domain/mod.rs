@@ -1,9 +1,27 @@
+pub mod battle;
+pub mod boss;
 pub mod collision;
+pub mod difficulty;
+pub mod dive;
 pub mod enemy;
 pub mod enemy_formation;
+pub mod formation_layout;
+pub mod game_mode;
+pub mod gunner;
+pub mod high_scores;
+pub mod level_editor;
 pub mod lives;
+pub mod mixer;
+pub mod movement_pattern;
+pub mod named_enum;
+pub mod netplay;
 pub mod player;
+pub mod power_up;
+pub mod ruleset;
 pub mod score;
 pub mod shield;
 pub mod shield_formation;
+pub mod shield_layout;
+pub mod wave;
+pub mod wave_script;
 pub mod weapons;
@@ -0,0 +1,360 @@
+use crate::domain::enemy_formation::MovingDirection;
+use crate::domain::wave::{FIRST_WAVE, Wave};
+use std::f32::consts::TAU;
+
+pub const SINE_PERIOD: usize = 8;
+pub const SINE_AMPLITUDE: usize = 2;
+pub const ZIG_ZAG_DEPTH: usize = 1;
+pub const CIRCLE_PERIOD: usize = 12;
+pub const CIRCLE_RADIUS: (usize, usize) = (3, 1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bounds {
+    pub right: usize,
+    pub bottom: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct March {
+    pub anchor: (usize, usize),
+    pub direction: MovingDirection,
+    pub step: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum FormationMovement {
+    #[default]
+    ClassicMarch,
+    SineWave,
+    ZigZag,
+    Circling,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormationMovements {
+    movements: Vec<FormationMovement>,
+}
+
+pub struct ClassicMarch;
+pub struct SineWave;
+pub struct ZigZag;
+pub struct Circling;
+
+pub trait MovementPattern {
+    fn reach(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    fn offset(&self, _step: usize) -> (usize, usize) {
+        (0, 0)
+    }
+
+    fn position(&self, march: &March) -> (usize, usize) {
+        let (x, y) = self.offset(march.step);
+
+        (march.anchor.0 + x, march.anchor.1 + y)
+    }
+
+    fn next(&self, march: &March, bounds: Bounds) -> March {
+        let right = bounds.right.saturating_sub(self.reach().0);
+        let (x, y) = march.anchor;
+        let mut next = March {
+            step: march.step + 1,
+            ..*march
+        };
+
+        match march.direction {
+            MovingDirection::ToRight if x < right => next.anchor.0 += 1,
+            MovingDirection::ToLeft if x > 0 => next.anchor.0 -= 1,
+            MovingDirection::ToRight => {
+                next.anchor = (right, y + 1);
+                next.direction = MovingDirection::ToLeft;
+            }
+            MovingDirection::ToLeft => {
+                next.anchor.1 = y + 1;
+                next.direction = MovingDirection::ToRight;
+            }
+        }
+
+        next
+    }
+
+    fn advance(&self, march: &mut March, bounds: Bounds) -> bool {
+        let next = self.next(march, bounds);
+
+        if self.position(&next).1 > bounds.bottom {
+            return false;
+        }
+
+        *march = next;
+        true
+    }
+}
+
+impl MovementPattern for ClassicMarch {}
+
+impl MovementPattern for SineWave {
+    fn reach(&self) -> (usize, usize) {
+        (0, SINE_AMPLITUDE)
+    }
+
+    fn offset(&self, step: usize) -> (usize, usize) {
+        let phase = (step % SINE_PERIOD) as f32 / SINE_PERIOD as f32 * TAU;
+        let swell = (1.0 - phase.cos()) / 2.0 * SINE_AMPLITUDE as f32;
+
+        (0, swell.round() as usize)
+    }
+}
+
+impl MovementPattern for ZigZag {
+    fn reach(&self) -> (usize, usize) {
+        (0, ZIG_ZAG_DEPTH)
+    }
+
+    fn offset(&self, step: usize) -> (usize, usize) {
+        (0, (step % 2) * ZIG_ZAG_DEPTH)
+    }
+}
+
+impl MovementPattern for Circling {
+    fn reach(&self) -> (usize, usize) {
+        (CIRCLE_RADIUS.0 * 2, CIRCLE_RADIUS.1 * 2)
+    }
+
+    fn offset(&self, step: usize) -> (usize, usize) {
+        let phase = (step % CIRCLE_PERIOD) as f32 / CIRCLE_PERIOD as f32 * TAU;
+        let x = (1.0 + phase.sin()) * CIRCLE_RADIUS.0 as f32;
+        let y = (1.0 - phase.cos()) * CIRCLE_RADIUS.1 as f32;
+
+        (x.round() as usize, y.round() as usize)
+    }
+}
+
+impl March {
+    pub fn new(anchor: (usize, usize), direction: MovingDirection) -> Self {
+        Self {
+            anchor,
+            direction,
+            step: 0,
+        }
+    }
+}
+
+impl FormationMovement {
+    pub const ALL: [FormationMovement; 4] = [
+        FormationMovement::ClassicMarch,
+        FormationMovement::SineWave,
+        FormationMovement::ZigZag,
+        FormationMovement::Circling,
+    ];
+
+    pub fn pattern(&self) -> &'static dyn MovementPattern {
+        match self {
+            FormationMovement::ClassicMarch => &ClassicMarch,
+            FormationMovement::SineWave => &SineWave,
+            FormationMovement::ZigZag => &ZigZag,
+            FormationMovement::Circling => &Circling,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            FormationMovement::ClassicMarch => "CLASSIC",
+            FormationMovement::SineWave => "SINE WAVE",
+            FormationMovement::ZigZag => "ZIG-ZAG",
+            FormationMovement::Circling => "CIRCLING",
+        }
+    }
+
+    pub fn next(&self) -> FormationMovement {
+        FormationMovement::ALL[(self.index() + 1) % FormationMovement::ALL.len()]
+    }
+
+    pub fn previous(&self) -> FormationMovement {
+        let count = FormationMovement::ALL.len();
+
+        FormationMovement::ALL[(self.index() + count - 1) % count]
+    }
+
+    fn index(&self) -> usize {
+        FormationMovement::ALL
+            .iter()
+            .position(|movement| movement == self)
+            .unwrap_or_default()
+    }
+}
+
+impl Default for FormationMovements {
+    fn default() -> Self {
+        Self {
+            movements: vec![FormationMovement::ClassicMarch],
+        }
+    }
+}
+
+impl FormationMovements {
+    pub fn new(movements: Vec<FormationMovement>) -> Self {
+        if movements.is_empty() {
+            Self::default()
+        } else {
+            Self { movements }
+        }
+    }
+
+    pub fn for_wave(&self, wave: Wave) -> FormationMovement {
+        let index = (wave.get_number() - FIRST_WAVE) as usize % self.movements.len();
+
+        self.movements[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Bounds = Bounds {
+        right: 30,
+        bottom: 13,
+    };
+
+    fn march_until_breached(pattern: &dyn MovementPattern, bounds: Bounds) -> (March, usize) {
+        let mut march = March::new((0, 0), MovingDirection::ToRight);
+        let mut steps = 0;
+
+        while pattern.advance(&mut march, bounds) {
+            let (x, y) = pattern.position(&march);
+            assert!(x <= bounds.right, "x {} left the screen", x);
+            assert!(y <= bounds.bottom, "y {} went past the bottom", y);
+            steps += 1;
+        }
+
+        (march, steps)
+    }
+
+    #[test]
+    fn classic_march_sweeps_and_drops_at_the_edges() {
+        let mut march = March::new((0, 0), MovingDirection::ToRight);
+
+        for _ in 0..BOUNDS.right {
+            ClassicMarch.advance(&mut march, BOUNDS);
+        }
+        assert_eq!(ClassicMarch.position(&march), (30, 0));
+
+        ClassicMarch.advance(&mut march, BOUNDS);
+        assert_eq!(ClassicMarch.position(&march), (30, 1));
+        assert_eq!(march.direction, MovingDirection::ToLeft);
+    }
+
+    #[test]
+    fn classic_march_breaches_when_dropping_past_the_bottom() {
+        let (march, steps) = march_until_breached(&ClassicMarch, BOUNDS);
+
+        assert_eq!(ClassicMarch.position(&march).1, BOUNDS.bottom);
+        assert_eq!(steps, (BOUNDS.right + 1) * BOUNDS.bottom + BOUNDS.right);
+    }
+
+    #[test]
+    fn sine_wave_bobs_within_its_amplitude() {
+        let offsets: Vec<usize> = (0..SINE_PERIOD)
+            .map(|step| SineWave.offset(step).1)
+            .collect();
+
+        assert_eq!(offsets, vec![0, 0, 1, 2, 2, 2, 1, 0]);
+        assert_eq!(SineWave.offset(SINE_PERIOD), SineWave.offset(0));
+    }
+
+    #[test]
+    fn sine_wave_stays_in_bounds_until_it_breaches() {
+        let (march, _) = march_until_breached(&SineWave, BOUNDS);
+
+        assert!(SineWave.position(&SineWave.next(&march, BOUNDS)).1 > BOUNDS.bottom);
+        assert!(march.anchor.1 + SINE_AMPLITUDE >= BOUNDS.bottom);
+    }
+
+    #[test]
+    fn zig_zag_alternates_rows_on_every_step() {
+        let mut march = March::new((0, 0), MovingDirection::ToRight);
+
+        ZigZag.advance(&mut march, BOUNDS);
+        assert_eq!(ZigZag.position(&march), (1, ZIG_ZAG_DEPTH));
+
+        ZigZag.advance(&mut march, BOUNDS);
+        assert_eq!(ZigZag.position(&march), (2, 0));
+    }
+
+    #[test]
+    fn zig_zag_stays_in_bounds_until_it_breaches() {
+        let (march, _) = march_until_breached(&ZigZag, BOUNDS);
+
+        assert!(ZigZag.position(&ZigZag.next(&march, BOUNDS)).1 > BOUNDS.bottom);
+    }
+
+    #[test]
+    fn circling_loops_back_to_where_it_started() {
+        assert_eq!(Circling.offset(0), (CIRCLE_RADIUS.0, 0));
+        assert_eq!(
+            Circling.offset(CIRCLE_PERIOD / 4),
+            (CIRCLE_RADIUS.0 * 2, CIRCLE_RADIUS.1)
+        );
+        assert_eq!(
+            Circling.offset(CIRCLE_PERIOD / 2),
+            (CIRCLE_RADIUS.0, CIRCLE_RADIUS.1 * 2)
+        );
+        assert_eq!(Circling.offset(CIRCLE_PERIOD * 3 / 4), (0, CIRCLE_RADIUS.1));
+        assert_eq!(Circling.offset(CIRCLE_PERIOD), Circling.offset(0));
+    }
+
+    #[test]
+    fn circling_stays_in_bounds_until_it_breaches() {
+        let (march, _) = march_until_breached(&Circling, BOUNDS);
+
+        assert!(Circling.position(&Circling.next(&march, BOUNDS)).1 > BOUNDS.bottom);
+    }
+
+    #[test]
+    fn patterns_wider_than_the_screen_only_drop() {
+        let bounds = Bounds {
+            right: 2,
+            bottom: 5,
+        };
+        let mut march = March::new((0, 0), MovingDirection::ToRight);
+
+        Circling.advance(&mut march, bounds);
+
+        assert_eq!(march.anchor, (0, 1));
+        assert!(Circling.position(&march).0 <= Circling.reach().0);
+    }
+
+    #[test]
+    fn movements_cycle_with_the_waves() {
+        let movements = FormationMovements::new(vec![
+            FormationMovement::ClassicMarch,
+            FormationMovement::ZigZag,
+        ]);
+
+        assert_eq!(
+            movements.for_wave(Wave::new(1)),
+            FormationMovement::ClassicMarch
+        );
+        assert_eq!(movements.for_wave(Wave::new(2)), FormationMovement::ZigZag);
+        assert_eq!(
+            movements.for_wave(Wave::new(3)),
+            FormationMovement::ClassicMarch
+        );
+        assert_eq!(
+            FormationMovements::new(vec![]).for_wave(Wave::new(4)),
+            FormationMovement::ClassicMarch
+        );
+    }
+
+    #[test]
+    fn movements_cycle_through_every_pattern() {
+        let movement = FormationMovement::Circling;
+
+        assert_eq!(movement.next(), FormationMovement::ClassicMarch);
+        assert_eq!(movement.previous(), FormationMovement::ZigZag);
+    }
+}
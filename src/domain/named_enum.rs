@@ -0,0 +1,68 @@
+/// Gives a fieldless enum its list of variants, a lowercase name per variant,
+/// and the `Display` and case-insensitive `FromStr` impls built on those names.
+/// `$label` names the enum in the parse error, as in "unknown difficulty 'x'".
+macro_rules! named_enum {
+    ($name:ident, $label:literal, { $($variant:ident => $text:literal),+ $(,)? }) => {
+        impl $name {
+            pub const ALL: [$name; [$($text),+].len()] = [$($name::$variant),+];
+
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($name::$variant => $text),+
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = String;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                $name::ALL
+                    .into_iter()
+                    .find(|variant| variant.as_str().eq_ignore_ascii_case(value))
+                    .ok_or_else(|| format!("unknown {} '{}'", $label, value))
+            }
+        }
+    };
+}
+
+pub(crate) use named_enum;
+
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Speed {
+        Slow,
+        Fast,
+    }
+
+    named_enum!(Speed, "speed", {
+        Slow => "slow",
+        Fast => "fast",
+    });
+
+    #[test]
+    fn should_list_the_variants_in_declaration_order() {
+        assert_eq!(Speed::ALL, [Speed::Slow, Speed::Fast]);
+    }
+
+    #[test]
+    fn should_display_the_variant_name() {
+        assert_eq!(Speed::Fast.to_string(), "fast");
+    }
+
+    #[test]
+    fn should_parse_names_ignoring_case() {
+        assert_eq!("SLOW".parse::<Speed>(), Ok(Speed::Slow));
+        assert_eq!(
+            "warp".parse::<Speed>(),
+            Err("unknown speed 'warp'".to_string())
+        );
+    }
+}
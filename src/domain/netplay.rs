@@ -0,0 +1,645 @@
+use crate::domain::game_mode::PlayerSlot;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::hash::Hasher;
+
+pub type Frame = u32;
+
+const CHECKSUM_HISTORY: usize = 256;
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ActionInput(u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Desync {
+    pub frame: Frame,
+    pub local: u64,
+    pub remote: u64,
+}
+
+pub trait RollbackGame {
+    type State;
+
+    fn advance(&mut self, inputs: [ActionInput; 2]);
+    fn save_state(&mut self) -> Self::State;
+    fn load_state(&mut self, state: &Self::State);
+    fn checksum(&self, state: &Self::State) -> u64;
+}
+
+pub struct StateHasher {
+    hash: u64,
+}
+
+pub struct RollbackSession<S> {
+    local: PlayerSlot,
+    max_prediction: u32,
+    frame: Frame,
+    first_input_frame: Frame,
+    local_inputs: Vec<ActionInput>,
+    remote_inputs: Vec<ActionInput>,
+    pending_remote_inputs: BTreeMap<Frame, ActionInput>,
+    used_remote_inputs: Vec<ActionInput>,
+    last_remote_input: ActionInput,
+    states: VecDeque<S>,
+    first_state_frame: Frame,
+    rollback_from: Option<Frame>,
+    remote_ack: Frame,
+    checksummed_frame: Frame,
+    local_checksums: BTreeMap<Frame, u64>,
+    remote_checksums: BTreeMap<Frame, u64>,
+    rollbacks: u32,
+}
+
+impl ActionInput {
+    pub const NONE: ActionInput = ActionInput(0);
+    pub const LEFT: ActionInput = ActionInput(0b001);
+    pub const RIGHT: ActionInput = ActionInput(0b010);
+    pub const FIRE: ActionInput = ActionInput(0b100);
+
+    pub fn from_bits(bits: u8) -> Self {
+        Self(bits & (Self::LEFT.0 | Self::RIGHT.0 | Self::FIRE.0))
+    }
+
+    pub fn bits(&self) -> u8 {
+        self.0
+    }
+
+    pub fn with(self, other: ActionInput) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn contains(&self, other: ActionInput) -> bool {
+        other.0 != 0 && self.0 & other.0 == other.0
+    }
+}
+
+impl Display for Desync {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "desync at frame {}: local state {:016x}, remote state {:016x}",
+            self.frame, self.local, self.remote
+        )
+    }
+}
+
+impl Default for StateHasher {
+    fn default() -> Self {
+        Self {
+            hash: FNV_OFFSET_BASIS,
+        }
+    }
+}
+
+impl Hasher for StateHasher {
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.hash ^= *byte as u64;
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+impl<S> RollbackSession<S> {
+    pub fn new(local: PlayerSlot, max_prediction: u32) -> Self {
+        Self {
+            local,
+            max_prediction,
+            frame: 0,
+            first_input_frame: 0,
+            local_inputs: vec![],
+            remote_inputs: vec![],
+            pending_remote_inputs: BTreeMap::new(),
+            used_remote_inputs: vec![],
+            last_remote_input: ActionInput::NONE,
+            states: VecDeque::new(),
+            first_state_frame: 0,
+            rollback_from: None,
+            remote_ack: 0,
+            checksummed_frame: 0,
+            local_checksums: BTreeMap::new(),
+            remote_checksums: BTreeMap::new(),
+            rollbacks: 0,
+        }
+    }
+
+    pub fn get_local(&self) -> PlayerSlot {
+        self.local
+    }
+
+    pub fn current_frame(&self) -> Frame {
+        self.frame
+    }
+
+    pub fn confirmed_frame(&self) -> Frame {
+        self.received_frames().min(self.frame)
+    }
+
+    pub fn received_frames(&self) -> Frame {
+        self.first_input_frame + self.remote_inputs.len() as Frame
+    }
+
+    pub fn get_rollbacks(&self) -> u32 {
+        self.rollbacks
+    }
+
+    pub fn can_advance(&self) -> bool {
+        self.frame < self.received_frames() + self.max_prediction
+    }
+
+    pub fn frame_advantage(&self) -> i64 {
+        let local_advantage = self.frame as i64 - self.received_frames() as i64;
+        let remote_advantage = self.received_frames() as i64 - self.remote_ack as i64;
+
+        (local_advantage - remote_advantage) / 2
+    }
+
+    pub fn is_synchronized(&self) -> bool {
+        self.rollback_from.is_none() && self.confirmed_frame() == self.frame
+    }
+
+    pub fn add_remote_input(&mut self, frame: Frame, input: ActionInput) {
+        if frame < self.received_frames() || frame >= self.frame + self.max_prediction {
+            return;
+        }
+
+        self.pending_remote_inputs.insert(frame, input);
+
+        while let Some(input) = self.pending_remote_inputs.remove(&self.received_frames()) {
+            let frame = self.received_frames();
+
+            if frame < self.frame && self.used_remote_inputs[self.input_index(frame)] != input {
+                self.rollback_from = Some(self.rollback_from.map_or(frame, |from| from.min(frame)));
+            }
+
+            self.remote_inputs.push(input);
+            self.last_remote_input = input;
+        }
+    }
+
+    pub fn set_remote_ack(&mut self, ack: Frame) {
+        self.remote_ack = self.remote_ack.max(ack.min(self.frame));
+    }
+
+    pub fn unacknowledged_inputs(&self) -> (Frame, &[ActionInput]) {
+        (
+            self.remote_ack,
+            &self.local_inputs[self.input_index(self.remote_ack)..],
+        )
+    }
+
+    pub fn advance<G: RollbackGame<State = S>>(&mut self, game: &mut G, local_input: ActionInput) {
+        if self.states.is_empty() {
+            self.first_state_frame = self.frame;
+            self.states.push_back(game.save_state());
+        }
+
+        if let Some(from) = self.rollback_from.take() {
+            game.load_state(&self.states[(from - self.first_state_frame) as usize]);
+
+            for frame in from..self.frame {
+                self.simulate(game, frame);
+            }
+
+            self.rollbacks += 1;
+        }
+
+        self.local_inputs.push(local_input);
+        self.simulate(game, self.frame);
+        self.frame += 1;
+
+        self.record_checksums(game);
+        self.forget_confirmed_states();
+        self.forget_confirmed_inputs();
+    }
+
+    pub fn latest_checksum(&self) -> Option<(Frame, u64)> {
+        self.local_checksums
+            .last_key_value()
+            .map(|(frame, checksum)| (*frame, *checksum))
+    }
+
+    pub fn add_remote_checksum(&mut self, frame: Frame, checksum: u64) {
+        self.remote_checksums.insert(frame, checksum);
+    }
+
+    pub fn check_desync(&mut self) -> Result<(), Desync> {
+        let oldest_local = self.local_checksums.keys().next().copied();
+        let compared: Vec<Frame> = self
+            .remote_checksums
+            .keys()
+            .copied()
+            .take_while(|frame| *frame < self.checksummed_frame)
+            .collect();
+
+        for frame in compared {
+            let Some(remote) = self.remote_checksums.remove(&frame) else {
+                continue;
+            };
+
+            if oldest_local.is_some_and(|oldest| frame < oldest) {
+                continue;
+            }
+
+            if let Some(local) = self.local_checksums.get(&frame).copied()
+                && local != remote
+            {
+                return Err(Desync {
+                    frame,
+                    local,
+                    remote,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn simulate<G: RollbackGame<State = S>>(&mut self, game: &mut G, frame: Frame) {
+        let index = self.input_index(frame);
+        let remote_input = self
+            .remote_inputs
+            .get(index)
+            .copied()
+            .unwrap_or(self.last_remote_input);
+        let local_input = self.local_inputs[index];
+
+        match self.used_remote_inputs.get_mut(index) {
+            Some(used) => *used = remote_input,
+            None => self.used_remote_inputs.push(remote_input),
+        }
+
+        let mut inputs = [remote_input; 2];
+        inputs[self.local.index()] = local_input;
+        game.advance(inputs);
+
+        let state = game.save_state();
+        let index = (frame + 1 - self.first_state_frame) as usize;
+        match self.states.get_mut(index) {
+            Some(saved) => *saved = state,
+            None => self.states.push_back(state),
+        }
+    }
+
+    fn input_index(&self, frame: Frame) -> usize {
+        (frame - self.first_input_frame) as usize
+    }
+
+    fn record_checksums<G: RollbackGame<State = S>>(&mut self, game: &G) {
+        while self.checksummed_frame < self.confirmed_frame() {
+            let index = (self.checksummed_frame + 1 - self.first_state_frame) as usize;
+            let checksum = game.checksum(&self.states[index]);
+
+            self.local_checksums
+                .insert(self.checksummed_frame, checksum);
+            self.checksummed_frame += 1;
+        }
+
+        while self.local_checksums.len() > CHECKSUM_HISTORY {
+            self.local_checksums.pop_first();
+        }
+    }
+
+    fn forget_confirmed_states(&mut self) {
+        while self.first_state_frame < self.confirmed_frame() {
+            self.states.pop_front();
+            self.first_state_frame += 1;
+        }
+    }
+
+    fn forget_confirmed_inputs(&mut self) {
+        let forgotten = self.input_index(self.confirmed_frame().min(self.remote_ack));
+
+        self.local_inputs.drain(..forgotten);
+        self.remote_inputs.drain(..forgotten);
+        self.used_remote_inputs.drain(..forgotten);
+        self.first_input_frame += forgotten as Frame;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hash::Hash;
+
+    const MAX_PREDICTION: u32 = 8;
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Walkers {
+        positions: [i32; 2],
+        shots: [u32; 2],
+        steps: u32,
+    }
+
+    impl RollbackGame for Walkers {
+        type State = Walkers;
+
+        fn advance(&mut self, inputs: [ActionInput; 2]) {
+            for (index, input) in inputs.iter().enumerate() {
+                if input.contains(ActionInput::LEFT) {
+                    self.positions[index] -= 1;
+                }
+                if input.contains(ActionInput::RIGHT) {
+                    self.positions[index] += 1;
+                }
+                if input.contains(ActionInput::FIRE) {
+                    self.shots[index] += self.steps;
+                }
+            }
+            self.steps += 1;
+        }
+
+        fn save_state(&mut self) -> Walkers {
+            self.clone()
+        }
+
+        fn load_state(&mut self, state: &Walkers) {
+            *self = state.clone();
+        }
+
+        fn checksum(&self, state: &Walkers) -> u64 {
+            let mut hasher = StateHasher::default();
+            state.positions.hash(&mut hasher);
+            state.shots.hash(&mut hasher);
+            state.steps.hash(&mut hasher);
+            hasher.finish()
+        }
+    }
+
+    fn reference_run(inputs: &[[ActionInput; 2]]) -> Walkers {
+        let mut game = Walkers::default();
+        inputs.iter().for_each(|frame| game.advance(*frame));
+        game
+    }
+
+    #[test]
+    fn action_inputs_keep_only_known_bits() {
+        let input = ActionInput::LEFT.with(ActionInput::FIRE);
+
+        assert_eq!(ActionInput::from_bits(input.bits()), input);
+        assert_eq!(ActionInput::from_bits(0xff).bits(), 0b111);
+        assert!(input.contains(ActionInput::FIRE));
+        assert!(!input.contains(ActionInput::RIGHT));
+        assert!(!input.contains(ActionInput::NONE));
+    }
+
+    #[test]
+    fn state_hasher_is_the_stable_fnv_1a_hash() {
+        let mut hasher = StateHasher::default();
+        hasher.write(b"a");
+
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn should_advance_on_predicted_remote_input() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.advance(&mut game, ActionInput::RIGHT);
+        session.advance(&mut game, ActionInput::RIGHT);
+
+        assert_eq!(session.current_frame(), 2);
+        assert_eq!(session.confirmed_frame(), 0);
+        assert_eq!(game.positions, [2, 0]);
+    }
+
+    #[test]
+    fn should_stall_when_the_prediction_window_is_full() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, 2);
+
+        session.advance(&mut game, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+
+        assert!(!session.can_advance());
+
+        session.add_remote_input(0, ActionInput::NONE);
+
+        assert!(session.can_advance());
+    }
+
+    #[test]
+    fn should_measure_how_far_ahead_of_the_peer_it_runs() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        for _ in 0..4 {
+            session.advance(&mut game, ActionInput::NONE);
+        }
+        session.add_remote_input(0, ActionInput::NONE);
+        session.add_remote_input(1, ActionInput::NONE);
+        session.set_remote_ack(4);
+
+        assert_eq!(session.frame_advantage(), 2);
+
+        session.add_remote_input(2, ActionInput::NONE);
+        session.add_remote_input(3, ActionInput::NONE);
+
+        assert_eq!(session.frame_advantage(), 0);
+    }
+
+    #[test]
+    fn should_roll_back_and_resimulate_a_wrong_prediction() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::Two, MAX_PREDICTION);
+
+        for _ in 0..3 {
+            session.advance(&mut game, ActionInput::LEFT);
+        }
+        for frame in 0..3 {
+            session.add_remote_input(frame, ActionInput::FIRE);
+        }
+        session.advance(&mut game, ActionInput::LEFT);
+
+        let expected = reference_run(&[
+            [ActionInput::FIRE, ActionInput::LEFT],
+            [ActionInput::FIRE, ActionInput::LEFT],
+            [ActionInput::FIRE, ActionInput::LEFT],
+            [ActionInput::FIRE, ActionInput::LEFT],
+        ]);
+
+        assert_eq!(game, expected);
+        assert_eq!(session.get_rollbacks(), 1);
+        assert_eq!(session.confirmed_frame(), 3);
+    }
+
+    #[test]
+    fn should_not_roll_back_a_right_prediction() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.add_remote_input(0, ActionInput::RIGHT);
+        session.advance(&mut game, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+        session.add_remote_input(1, ActionInput::RIGHT);
+        session.advance(&mut game, ActionInput::NONE);
+
+        assert_eq!(session.get_rollbacks(), 0);
+        assert_eq!(game.positions, [0, 3]);
+    }
+
+    #[test]
+    fn should_forget_inputs_once_confirmed_and_acknowledged() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+        let mut inputs = vec![];
+
+        for frame in 0..100 {
+            let remote = if frame % 3 == 0 {
+                ActionInput::FIRE
+            } else {
+                ActionInput::LEFT
+            };
+            session.advance(&mut game, ActionInput::RIGHT);
+            if frame >= 2 {
+                session.add_remote_input(frame - 2, inputs[(frame - 2) as usize]);
+                session.set_remote_ack(frame - 1);
+            }
+            inputs.push(remote);
+        }
+        session.advance(&mut game, ActionInput::RIGHT);
+
+        let expected: Vec<[ActionInput; 2]> = (0..101)
+            .map(|frame| [ActionInput::RIGHT, inputs[frame.min(97)]])
+            .collect();
+
+        assert_eq!(game, reference_run(&expected));
+        assert_eq!(session.confirmed_frame(), 98);
+        assert!(session.local_inputs.len() <= MAX_PREDICTION as usize);
+        assert!(session.remote_inputs.len() <= MAX_PREDICTION as usize);
+        assert!(session.used_remote_inputs.len() <= MAX_PREDICTION as usize);
+        assert_eq!(session.unacknowledged_inputs().0, 98);
+        assert_eq!(session.unacknowledged_inputs().1.len(), 3);
+    }
+
+    #[test]
+    fn should_accept_remote_inputs_out_of_order() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.add_remote_input(1, ActionInput::RIGHT);
+
+        assert_eq!(session.received_frames(), 0);
+
+        session.add_remote_input(0, ActionInput::LEFT);
+        session.add_remote_input(0, ActionInput::FIRE);
+
+        assert_eq!(session.received_frames(), 2);
+
+        session.advance(&mut game, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+
+        assert_eq!(game.positions, [0, 0]);
+        assert!(session.is_synchronized());
+    }
+
+    #[test]
+    fn should_ignore_remote_inputs_beyond_the_prediction_window() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.add_remote_input(MAX_PREDICTION, ActionInput::RIGHT);
+
+        for frame in 0..MAX_PREDICTION {
+            session.add_remote_input(frame, ActionInput::NONE);
+        }
+
+        assert_eq!(session.received_frames(), MAX_PREDICTION);
+
+        for _ in 0..=MAX_PREDICTION {
+            session.advance(&mut game, ActionInput::NONE);
+        }
+
+        assert_eq!(session.received_frames(), MAX_PREDICTION);
+        assert_eq!(game.positions, [0, 0]);
+    }
+
+    #[test]
+    fn should_only_checksum_confirmed_frames() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.advance(&mut game, ActionInput::FIRE);
+        session.advance(&mut game, ActionInput::FIRE);
+
+        assert_eq!(session.latest_checksum(), None);
+
+        session.add_remote_input(0, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::FIRE);
+
+        let after_first_frame = reference_run(&[[ActionInput::FIRE, ActionInput::NONE]]);
+        assert_eq!(
+            session.latest_checksum(),
+            Some((0, game.checksum(&after_first_frame)))
+        );
+    }
+
+    #[test]
+    fn should_offer_inputs_until_the_peer_acknowledges_them() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.advance(&mut game, ActionInput::LEFT);
+        session.advance(&mut game, ActionInput::RIGHT);
+
+        assert_eq!(
+            session.unacknowledged_inputs(),
+            (0, &[ActionInput::LEFT, ActionInput::RIGHT][..])
+        );
+
+        session.set_remote_ack(1);
+
+        assert_eq!(
+            session.unacknowledged_inputs(),
+            (1, &[ActionInput::RIGHT][..])
+        );
+    }
+
+    #[test]
+    fn should_detect_a_desync_from_remote_checksums() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.add_remote_input(0, ActionInput::NONE);
+        session.add_remote_input(1, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+
+        let (frame, checksum) = session
+            .latest_checksum()
+            .expect("Frame 1 should be confirmed");
+        session.add_remote_checksum(frame, checksum);
+
+        assert_eq!(session.check_desync(), Ok(()));
+
+        session.add_remote_checksum(0, 42);
+
+        assert_eq!(
+            session.check_desync().map_err(|desync| desync.frame),
+            Err(0)
+        );
+    }
+
+    #[test]
+    fn should_keep_remote_checksums_until_the_frame_is_confirmed() {
+        let mut game = Walkers::default();
+        let mut session = RollbackSession::new(PlayerSlot::One, MAX_PREDICTION);
+
+        session.add_remote_checksum(0, 42);
+        session.advance(&mut game, ActionInput::NONE);
+
+        assert_eq!(session.check_desync(), Ok(()));
+
+        session.add_remote_input(0, ActionInput::NONE);
+        session.advance(&mut game, ActionInput::NONE);
+
+        assert!(session.check_desync().is_err());
+    }
+}
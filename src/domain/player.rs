@@ -1,7 +1,13 @@
-use crate::domain::weapons::{Fireable, WeaponState};
+use crate::domain::weapons::{Armed, Weapon, WeaponUpgrade};
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Player {
-    weapon_state: WeaponState,
+    weapon: Weapon,
+    #[cfg_attr(feature = "serde", serde(default))]
+    upgrade: WeaponUpgrade,
+    #[cfg_attr(feature = "serde", serde(default))]
+    kills: u32,
 }
 
 impl Default for Player {
@@ -13,39 +19,53 @@ impl Default for Player {
 impl Player {
     pub fn new() -> Self {
         Player {
-            weapon_state: WeaponState::Ready,
+            weapon: Weapon::single_shot(),
+            upgrade: WeaponUpgrade::Standard,
+            kills: 0,
         }
     }
-}
 
-impl Fireable for Player {
-    fn start_firing(&mut self) {
-        self.weapon_state = WeaponState::Firing;
+    pub fn get_upgrade(&self) -> WeaponUpgrade {
+        self.upgrade
+    }
+
+    pub fn equip(&mut self, upgrade: WeaponUpgrade) {
+        self.upgrade = upgrade;
+        self.weapon.refit(upgrade.weapon());
     }
 
-    fn reload(&mut self) {
-        self.weapon_state = WeaponState::Ready;
+    pub fn record_kill(&mut self, kills_per_upgrade: u32) -> bool {
+        self.kills += 1;
+
+        if self.kills < kills_per_upgrade || self.upgrade.next() == self.upgrade {
+            return false;
+        }
+
+        self.kills = 0;
+        self.equip(self.upgrade.next());
+        true
     }
 
-    fn can_fire(&self) -> bool {
-        self.weapon_state == WeaponState::Ready
+    pub fn downgrade(&mut self) {
+        self.kills = 0;
+        self.equip(self.upgrade.previous());
     }
+}
 
-    fn is_firing(&self) -> bool {
-        self.weapon_state == WeaponState::Firing
+impl Armed for Player {
+    fn weapon(&self) -> &Weapon {
+        &self.weapon
     }
 
-    fn toggle_fire(&mut self) {
-        self.weapon_state = match self.weapon_state {
-            WeaponState::Ready => WeaponState::Firing,
-            WeaponState::Firing => WeaponState::Ready,
-        };
+    fn weapon_mut(&mut self) -> &mut Weapon {
+        &mut self.weapon
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::weapons::WeaponEvent;
 
     fn create_player() -> Player {
         Player::new()
@@ -54,57 +74,91 @@ mod tests {
     #[test]
     fn new_player_is_not_firing() {
         let player = create_player();
-        assert!(!player.is_firing());
+        assert!(!player.weapon().is_firing());
+    }
+
+    #[test]
+    fn new_player_can_fire() {
+        let player = create_player();
+        assert!(player.weapon().can_fire());
     }
 
     #[test]
-    fn toggling_fire_starts_firing() {
+    fn player_fires_one_shot_at_a_time() {
         let mut player = create_player();
-        player.toggle_fire();
-        assert!(player.is_firing());
+        assert_eq!(player.weapon_mut().fire(), Some(WeaponEvent::Fired));
+        assert!(player.weapon().is_firing());
+        assert!(!player.weapon().can_fire());
+        assert_eq!(player.weapon_mut().fire(), None);
     }
 
     #[test]
-    fn toggling_fire_twice_stops_firing() {
+    fn ending_the_shot_reloads_the_player() {
         let mut player = create_player();
-        player.toggle_fire();
-        player.toggle_fire();
-        assert!(!player.is_firing());
+        player.weapon_mut().fire();
+        assert_eq!(
+            player.weapon_mut().shot_ended(),
+            Some(WeaponEvent::Reloaded)
+        );
+        assert!(!player.weapon().is_firing());
+        assert!(player.weapon().can_fire());
     }
 
     #[test]
-    fn new_player_can_fire() {
-        let player = create_player();
-        assert!(player.can_fire());
+    fn ending_a_shot_twice_keeps_the_player_ready() {
+        let mut player = create_player();
+        player.weapon_mut().fire();
+        player.weapon_mut().shot_ended();
+        assert_eq!(player.weapon_mut().shot_ended(), None);
+        assert!(player.weapon().can_fire());
+    }
+
+    #[test]
+    fn new_player_has_the_standard_weapon() {
+        assert_eq!(create_player().get_upgrade(), WeaponUpgrade::Standard);
     }
 
     #[test]
-    fn player_cannot_fire_while_firing() {
+    fn kills_earn_the_next_upgrade() {
         let mut player = create_player();
-        player.start_firing();
-        assert!(!player.can_fire());
+
+        assert!(!player.record_kill(2));
+        assert!(player.record_kill(2));
+        assert_eq!(player.get_upgrade(), WeaponUpgrade::DoubleShot);
+        assert_eq!(*player.weapon(), WeaponUpgrade::DoubleShot.weapon());
     }
 
     #[test]
-    fn start_firing_changes_state_to_firing() {
+    fn kills_stop_upgrading_at_the_last_weapon() {
         let mut player = create_player();
-        player.start_firing();
-        assert!(player.is_firing());
+        player.equip(WeaponUpgrade::PiercingLaser);
+
+        assert!(!player.record_kill(1));
+        assert_eq!(player.get_upgrade(), WeaponUpgrade::PiercingLaser);
     }
 
     #[test]
-    fn reload_changes_state_to_ready() {
+    fn downgrading_drops_one_level_and_the_progress() {
         let mut player = create_player();
-        player.start_firing();
-        player.reload();
-        assert!(!player.is_firing());
-        assert!(player.can_fire());
+        player.equip(WeaponUpgrade::Spread);
+        player.record_kill(3);
+        player.downgrade();
+
+        assert_eq!(player.get_upgrade(), WeaponUpgrade::DoubleShot);
+        assert!(!player.record_kill(2));
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn reloading_when_ready_keeps_state_ready() {
+    fn player_survives_a_serde_round_trip() {
         let mut player = create_player();
-        player.reload();
-        assert!(player.can_fire());
+        player.equip(WeaponUpgrade::Spread);
+        player.weapon_mut().fire();
+
+        let restored: Player =
+            ron::from_str(&ron::to_string(&player).expect("Player should serialize"))
+                .expect("Player should deserialize");
+
+        assert_eq!(restored, player);
     }
 }
@@ -0,0 +1,196 @@
+pub const POWER_UP_DROP_CHANCE: f64 = 0.08;
+pub const RAPID_FIRE_DURATION: f32 = 10.0;
+pub const RAPID_FIRE_SCALE: f32 = 2.0;
+pub const TIME_SLOW_DURATION: f32 = 6.0;
+pub const TIME_SLOW_SCALE: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpKind {
+    RapidFire,
+    ShieldRepair,
+    ExtraLife,
+    TimeSlow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ActivePowerUp {
+    kind: PowerUpKind,
+    remaining: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ActivePowerUps {
+    active: Vec<ActivePowerUp>,
+}
+
+impl PowerUpKind {
+    pub const ALL: [PowerUpKind; 4] = [
+        PowerUpKind::RapidFire,
+        PowerUpKind::ShieldRepair,
+        PowerUpKind::ExtraLife,
+        PowerUpKind::TimeSlow,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PowerUpKind::RapidFire => "rapid fire",
+            PowerUpKind::ShieldRepair => "shield repair",
+            PowerUpKind::ExtraLife => "extra life",
+            PowerUpKind::TimeSlow => "time slow",
+        }
+    }
+
+    pub fn duration(&self) -> Option<f32> {
+        match self {
+            PowerUpKind::RapidFire => Some(RAPID_FIRE_DURATION),
+            PowerUpKind::TimeSlow => Some(TIME_SLOW_DURATION),
+            PowerUpKind::ShieldRepair | PowerUpKind::ExtraLife => None,
+        }
+    }
+}
+
+impl ActivePowerUp {
+    pub fn get_kind(&self) -> PowerUpKind {
+        self.kind
+    }
+
+    pub fn get_remaining(&self) -> f32 {
+        self.remaining
+    }
+}
+
+impl ActivePowerUps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn activate(&mut self, kind: PowerUpKind) -> bool {
+        let Some(duration) = kind.duration() else {
+            return false;
+        };
+
+        match self.active.iter_mut().find(|active| active.kind == kind) {
+            Some(active) => active.remaining = duration,
+            None => self.active.push(ActivePowerUp {
+                kind,
+                remaining: duration,
+            }),
+        }
+
+        true
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) -> Vec<PowerUpKind> {
+        let mut expired = Vec::new();
+
+        self.active.retain_mut(|active| {
+            active.remaining -= delta_secs;
+
+            if active.remaining <= 0.0 {
+                expired.push(active.kind);
+                false
+            } else {
+                true
+            }
+        });
+
+        expired
+    }
+
+    pub fn is_active(&self, kind: PowerUpKind) -> bool {
+        self.remaining(kind).is_some()
+    }
+
+    pub fn remaining(&self, kind: PowerUpKind) -> Option<f32> {
+        self.active
+            .iter()
+            .find(|active| active.kind == kind)
+            .map(|active| active.remaining)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ActivePowerUp> {
+        self.active.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn fire_rate_scale(&self) -> f32 {
+        if self.is_active(PowerUpKind::RapidFire) {
+            RAPID_FIRE_SCALE
+        } else {
+            1.0
+        }
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        if self.is_active(PowerUpKind::TimeSlow) {
+            TIME_SLOW_SCALE
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_timed_power_ups_have_a_duration() {
+        assert_eq!(PowerUpKind::RapidFire.duration(), Some(RAPID_FIRE_DURATION));
+        assert_eq!(PowerUpKind::TimeSlow.duration(), Some(TIME_SLOW_DURATION));
+        assert_eq!(PowerUpKind::ShieldRepair.duration(), None);
+        assert_eq!(PowerUpKind::ExtraLife.duration(), None);
+    }
+
+    #[test]
+    fn instant_power_ups_are_never_active() {
+        let mut power_ups = ActivePowerUps::new();
+
+        assert!(!power_ups.activate(PowerUpKind::ExtraLife));
+        assert!(power_ups.is_empty());
+    }
+
+    #[test]
+    fn activating_again_refreshes_the_duration() {
+        let mut power_ups = ActivePowerUps::new();
+        power_ups.activate(PowerUpKind::RapidFire);
+        power_ups.tick(4.0);
+
+        assert!(power_ups.activate(PowerUpKind::RapidFire));
+
+        assert_eq!(
+            power_ups.remaining(PowerUpKind::RapidFire),
+            Some(RAPID_FIRE_DURATION)
+        );
+        assert_eq!(power_ups.iter().count(), 1);
+    }
+
+    #[test]
+    fn tick_should_report_expired_power_ups() {
+        let mut power_ups = ActivePowerUps::new();
+        power_ups.activate(PowerUpKind::RapidFire);
+        power_ups.activate(PowerUpKind::TimeSlow);
+
+        let expired = power_ups.tick(TIME_SLOW_DURATION);
+
+        assert_eq!(expired, vec![PowerUpKind::TimeSlow]);
+        assert!(power_ups.is_active(PowerUpKind::RapidFire));
+        assert!(!power_ups.is_active(PowerUpKind::TimeSlow));
+    }
+
+    #[test]
+    fn scales_follow_the_active_power_ups() {
+        let mut power_ups = ActivePowerUps::new();
+        assert_eq!(power_ups.fire_rate_scale(), 1.0);
+        assert_eq!(power_ups.time_scale(), 1.0);
+
+        power_ups.activate(PowerUpKind::RapidFire);
+        power_ups.activate(PowerUpKind::TimeSlow);
+
+        assert_eq!(power_ups.fire_rate_scale(), RAPID_FIRE_SCALE);
+        assert_eq!(power_ups.time_scale(), TIME_SLOW_SCALE);
+    }
+}
@@ -0,0 +1,59 @@
+use crate::domain::named_enum::named_enum;
+
+pub const MODERN_KILLS_PER_UPGRADE: u32 = 15;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ruleset {
+    #[default]
+    Classic,
+    Modern,
+}
+
+named_enum!(Ruleset, "ruleset", {
+    Classic => "classic",
+    Modern => "modern",
+});
+
+impl Ruleset {
+    pub fn kills_per_upgrade(&self) -> Option<u32> {
+        match self {
+            Ruleset::Classic => None,
+            Ruleset::Modern => Some(MODERN_KILLS_PER_UPGRADE),
+        }
+    }
+
+    pub fn drops_power_ups(&self) -> bool {
+        *self == Ruleset::Modern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_ruleset_is_classic() {
+        assert_eq!(Ruleset::default(), Ruleset::Classic);
+    }
+
+    #[test]
+    fn only_the_modern_ruleset_earns_upgrades() {
+        assert_eq!(Ruleset::Classic.kills_per_upgrade(), None);
+        assert_eq!(
+            Ruleset::Modern.kills_per_upgrade(),
+            Some(MODERN_KILLS_PER_UPGRADE)
+        );
+    }
+
+    #[test]
+    fn only_the_modern_ruleset_drops_power_ups() {
+        assert!(!Ruleset::Classic.drops_power_ups());
+        assert!(Ruleset::Modern.drops_power_ups());
+    }
+
+    #[test]
+    fn should_parse_names_ignoring_case() {
+        assert_eq!("Modern".parse::<Ruleset>(), Ok(Ruleset::Modern));
+        assert!("retro".parse::<Ruleset>().is_err());
+    }
+}
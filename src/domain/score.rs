@@ -1,3 +1,5 @@
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Score {
     current: u32,
 }
@@ -13,6 +15,10 @@ impl Score {
         Score { current: 0 }
     }
 
+    pub fn with_points(points: u32) -> Self {
+        Score { current: points }
+    }
+
     pub fn increment(&mut self, points: u32) {
         self.current = self.current.saturating_add(points);
     }
@@ -81,4 +87,23 @@ mod tests {
         score.increment(u32::MAX);
         assert_eq!(score.get_current(), u32::MAX);
     }
+
+    #[test]
+    fn score_can_start_with_points() {
+        let score = Score::with_points(120);
+
+        assert_eq!(score.get_current(), 120);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn score_survives_a_serde_round_trip() {
+        let score = create_score_with_value(340);
+
+        let restored: Score =
+            ron::from_str(&ron::to_string(&score).expect("Score should serialize"))
+                .expect("Score should deserialize");
+
+        assert_eq!(restored, score);
+    }
 }
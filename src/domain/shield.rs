@@ -2,6 +2,7 @@ pub const DEFAULT_SHIELD_HEALTH: u8 = 100;
 pub const MIN_SHIELD_HEALTH: u8 = 0;
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shield {
     health: u8,
 }
@@ -95,4 +96,16 @@ mod tests {
         assert!(!shield.is_destroyed());
         assert_eq!(shield.get_health(), 50);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn shield_survives_a_serde_round_trip() {
+        let shield = create_damaged_shield(40);
+
+        let restored: Shield =
+            ron::from_str(&ron::to_string(&shield).expect("Shield should serialize"))
+                .expect("Shield should deserialize");
+
+        assert_eq!(restored, shield);
+    }
 }
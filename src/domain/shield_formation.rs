@@ -1,8 +1,11 @@
 use crate::domain::shield::Shield;
+use crate::domain::shield_layout::ShieldLayout;
 
-const NUMBER_OF_SHIELDS: usize = 4;
-
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ShieldFormation {
+    #[cfg_attr(feature = "serde", serde(default))]
+    layout: ShieldLayout,
     shields: Vec<Shield>,
 }
 
@@ -14,11 +17,20 @@ impl Default for ShieldFormation {
 
 impl ShieldFormation {
     pub fn new() -> Self {
+        Self::from_layout(&ShieldLayout::classic())
+    }
+
+    pub fn from_layout(layout: &ShieldLayout) -> Self {
         Self {
-            shields: (0..NUMBER_OF_SHIELDS).map(|_| Shield::new()).collect(),
+            layout: layout.clone(),
+            shields: (0..layout.get_count()).map(|_| Shield::new()).collect(),
         }
     }
 
+    pub fn get_layout(&self) -> &ShieldLayout {
+        &self.layout
+    }
+
     pub(crate) fn get_shields(&self) -> Vec<Shield> {
         self.shields.clone()
     }
@@ -27,6 +39,7 @@ impl ShieldFormation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::shield_layout::ShieldShape;
 
     fn create_shield_formation() -> ShieldFormation {
         ShieldFormation::new()
@@ -37,4 +50,25 @@ mod tests {
         let formation = create_shield_formation();
         assert_eq!(formation.get_shields().len(), 4);
     }
+
+    #[test]
+    fn shield_formation_follows_its_layout() {
+        let layout = ShieldLayout::new(ShieldShape::classic(), 2, 0.4).expect("Layout is valid");
+        let formation = ShieldFormation::from_layout(&layout);
+
+        assert_eq!(formation.get_shields().len(), 2);
+        assert_eq!(formation.get_layout(), &layout);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn shield_formation_survives_a_serde_round_trip() {
+        let formation = create_shield_formation();
+
+        let restored: ShieldFormation =
+            ron::from_str(&ron::to_string(&formation).expect("Formation should serialize"))
+                .expect("Formation should deserialize");
+
+        assert_eq!(restored, formation);
+    }
 }
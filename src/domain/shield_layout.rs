@@ -0,0 +1,376 @@
+use crate::domain::wave::{FIRST_WAVE, Wave};
+use std::fmt::{Display, Formatter};
+
+pub const SOLID_PIXEL: char = '#';
+pub const EMPTY_PIXEL: char = '.';
+pub const MAX_SHIELD_PIXELS: usize = 64;
+pub const MAX_SHIELDS: usize = 8;
+pub const DEFAULT_SHIELD_COUNT: usize = 4;
+pub const DEFAULT_SHIELD_SPREAD: f32 = 0.68;
+
+const CLASSIC_SHAPE: [&str; 18] = [
+    "...##################...",
+    "..####################..",
+    ".######################.",
+    "########################",
+    "########################",
+    "########################",
+    "########################",
+    "########################",
+    "##########....##########",
+    "#########......#########",
+    "########........########",
+    "#######..........#######",
+    "######............######",
+    "######............######",
+    "######............######",
+    "######............######",
+    "######............######",
+    "######............######",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShieldShape {
+    pixels: Vec<Vec<bool>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShieldLayout {
+    shape: ShieldShape,
+    count: usize,
+    spread: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShieldLayouts {
+    layouts: Vec<ShieldLayout>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShieldLayoutError {
+    Empty,
+    NoPixels,
+    TooLarge {
+        width: usize,
+        height: usize,
+    },
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    UnknownPixel {
+        row: usize,
+        column: usize,
+        symbol: char,
+    },
+    TooManyShields(usize),
+    InvalidSpread(f32),
+}
+
+impl Display for ShieldLayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShieldLayoutError::Empty => write!(f, "the shape has no rows"),
+            ShieldLayoutError::NoPixels => write!(f, "the shape has no solid pixels"),
+            ShieldLayoutError::TooLarge { width, height } => write!(
+                f,
+                "the shape is {}x{} pixels, at most {}x{} are allowed",
+                width, height, MAX_SHIELD_PIXELS, MAX_SHIELD_PIXELS
+            ),
+            ShieldLayoutError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} pixels but the first row has {}",
+                row + 1,
+                found,
+                expected
+            ),
+            ShieldLayoutError::UnknownPixel {
+                row,
+                column,
+                symbol,
+            } => write!(
+                f,
+                "unknown pixel '{}' at row {}, column {}",
+                symbol,
+                row + 1,
+                column + 1
+            ),
+            ShieldLayoutError::TooManyShields(count) => write!(
+                f,
+                "{} shields requested, at most {} fit on screen",
+                count, MAX_SHIELDS
+            ),
+            ShieldLayoutError::InvalidSpread(spread) => {
+                write!(f, "spread {} is outside 0.0..=1.0", spread)
+            }
+        }
+    }
+}
+
+impl Default for ShieldShape {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl ShieldShape {
+    pub fn classic() -> Self {
+        Self::from_pixels(
+            CLASSIC_SHAPE
+                .iter()
+                .map(|row| row.chars().map(|pixel| pixel == SOLID_PIXEL).collect())
+                .collect(),
+        )
+    }
+
+    pub fn from_pixels(pixels: Vec<Vec<bool>>) -> Self {
+        Self { pixels }
+    }
+
+    pub fn parse<S: AsRef<str>>(rows: &[S]) -> Result<Self, ShieldLayoutError> {
+        if rows.is_empty() {
+            return Err(ShieldLayoutError::Empty);
+        }
+
+        let width = rows[0].as_ref().chars().count();
+        if width > MAX_SHIELD_PIXELS || rows.len() > MAX_SHIELD_PIXELS {
+            return Err(ShieldLayoutError::TooLarge {
+                width,
+                height: rows.len(),
+            });
+        }
+
+        let pixels = rows
+            .iter()
+            .enumerate()
+            .map(|(row, text)| {
+                let found = text.as_ref().chars().count();
+                if found != width {
+                    return Err(ShieldLayoutError::Ragged {
+                        row,
+                        expected: width,
+                        found,
+                    });
+                }
+
+                text.as_ref()
+                    .chars()
+                    .enumerate()
+                    .map(|(column, symbol)| match symbol {
+                        SOLID_PIXEL => Ok(true),
+                        EMPTY_PIXEL => Ok(false),
+                        _ => Err(ShieldLayoutError::UnknownPixel {
+                            row,
+                            column,
+                            symbol,
+                        }),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !pixels.iter().flatten().any(|solid| *solid) {
+            return Err(ShieldLayoutError::NoPixels);
+        }
+
+        Ok(Self { pixels })
+    }
+
+    pub fn to_rows(&self) -> Vec<String> {
+        self.pixels
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|solid| if *solid { SOLID_PIXEL } else { EMPTY_PIXEL })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn get_pixels(&self) -> &Vec<Vec<bool>> {
+        &self.pixels
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.pixels.first().map_or(0, Vec::len)
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.pixels.len()
+    }
+
+    pub fn is_solid(&self, row: usize, column: usize) -> bool {
+        self.pixels
+            .get(row)
+            .and_then(|pixels| pixels.get(column))
+            .is_some_and(|solid| *solid)
+    }
+}
+
+impl Default for ShieldLayout {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl ShieldLayout {
+    pub fn classic() -> Self {
+        Self {
+            shape: ShieldShape::classic(),
+            count: DEFAULT_SHIELD_COUNT,
+            spread: DEFAULT_SHIELD_SPREAD,
+        }
+    }
+
+    pub fn new(shape: ShieldShape, count: usize, spread: f32) -> Result<Self, ShieldLayoutError> {
+        if count > MAX_SHIELDS {
+            return Err(ShieldLayoutError::TooManyShields(count));
+        }
+        if !(0.0..=1.0).contains(&spread) {
+            return Err(ShieldLayoutError::InvalidSpread(spread));
+        }
+
+        Ok(Self {
+            shape,
+            count,
+            spread,
+        })
+    }
+
+    pub fn get_shape(&self) -> &ShieldShape {
+        &self.shape
+    }
+
+    pub fn get_count(&self) -> usize {
+        self.count
+    }
+
+    pub fn get_spread(&self) -> f32 {
+        self.spread
+    }
+}
+
+impl Default for ShieldLayouts {
+    fn default() -> Self {
+        Self {
+            layouts: vec![ShieldLayout::classic()],
+        }
+    }
+}
+
+impl ShieldLayouts {
+    pub fn new(layouts: Vec<ShieldLayout>) -> Self {
+        if layouts.is_empty() {
+            Self::default()
+        } else {
+            Self { layouts }
+        }
+    }
+
+    pub fn for_wave(&self, wave: Wave) -> &ShieldLayout {
+        let index = (wave.get_number() - FIRST_WAVE) as usize % self.layouts.len();
+
+        &self.layouts[index]
+    }
+
+    pub fn count(&self) -> usize {
+        self.layouts.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_shape_is_the_arcade_bunker() {
+        let shape = ShieldShape::classic();
+
+        assert_eq!(shape.get_width(), 24);
+        assert_eq!(shape.get_height(), 18);
+        assert!(!shape.is_solid(0, 0));
+        assert!(shape.is_solid(0, 3));
+        assert!(!shape.is_solid(17, 12));
+        assert_eq!(shape.to_rows(), CLASSIC_SHAPE);
+    }
+
+    #[test]
+    fn shapes_are_parsed_from_text_rows() {
+        let shape = ShieldShape::parse(&[".#.", "###"]).expect("Shape should parse");
+
+        assert_eq!(shape.get_width(), 3);
+        assert_eq!(shape.get_height(), 2);
+        assert!(shape.is_solid(0, 1));
+        assert!(!shape.is_solid(0, 0));
+        assert!(!shape.is_solid(5, 5));
+    }
+
+    #[test]
+    fn invalid_shapes_are_explained() {
+        assert_eq!(
+            ShieldShape::parse::<&str>(&[]),
+            Err(ShieldLayoutError::Empty)
+        );
+        assert_eq!(
+            ShieldShape::parse(&["...", "..."]),
+            Err(ShieldLayoutError::NoPixels)
+        );
+        assert_eq!(
+            ShieldShape::parse(&["###", "#"]),
+            Err(ShieldLayoutError::Ragged {
+                row: 1,
+                expected: 3,
+                found: 1
+            })
+        );
+        assert_eq!(
+            ShieldShape::parse(&["#x#"]),
+            Err(ShieldLayoutError::UnknownPixel {
+                row: 0,
+                column: 1,
+                symbol: 'x'
+            })
+        );
+        assert_eq!(
+            ShieldShape::parse(&["#".repeat(MAX_SHIELD_PIXELS + 1)]),
+            Err(ShieldLayoutError::TooLarge {
+                width: MAX_SHIELD_PIXELS + 1,
+                height: 1
+            })
+        );
+    }
+
+    #[test]
+    fn layouts_reject_impossible_counts_and_spreads() {
+        assert_eq!(
+            ShieldLayout::new(ShieldShape::classic(), MAX_SHIELDS + 1, 0.5),
+            Err(ShieldLayoutError::TooManyShields(MAX_SHIELDS + 1))
+        );
+        assert_eq!(
+            ShieldLayout::new(ShieldShape::classic(), 2, 1.5),
+            Err(ShieldLayoutError::InvalidSpread(1.5))
+        );
+        assert_eq!(
+            ShieldLayoutError::InvalidSpread(1.5).to_string(),
+            "spread 1.5 is outside 0.0..=1.0"
+        );
+    }
+
+    #[test]
+    fn waves_cycle_through_the_layouts() {
+        let pair = ShieldLayout::new(ShieldShape::classic(), 2, 0.3).expect("Layout is valid");
+        let layouts = ShieldLayouts::new(vec![ShieldLayout::classic(), pair.clone()]);
+
+        assert_eq!(layouts.for_wave(Wave::new(1)), &ShieldLayout::classic());
+        assert_eq!(layouts.for_wave(Wave::new(2)), &pair);
+        assert_eq!(layouts.for_wave(Wave::new(3)), &ShieldLayout::classic());
+        assert_eq!(ShieldLayouts::new(vec![]).count(), 1);
+    }
+}
@@ -0,0 +1,76 @@
+pub const FIRST_WAVE: u32 = 1;
+const STARTING_ROWS_CYCLE: u32 = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wave {
+    number: u32,
+}
+
+impl Default for Wave {
+    fn default() -> Self {
+        Self::new(FIRST_WAVE)
+    }
+}
+
+impl Wave {
+    pub fn new(number: u32) -> Self {
+        Wave {
+            number: number.max(FIRST_WAVE),
+        }
+    }
+
+    pub fn get_number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn next(&self) -> Wave {
+        Wave::new(self.number.saturating_add(1))
+    }
+
+    pub fn starting_row(&self) -> usize {
+        ((self.number - FIRST_WAVE) % STARTING_ROWS_CYCLE) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_wave() -> Wave {
+        Wave::default()
+    }
+
+    #[test]
+    fn default_wave_is_the_first_one() {
+        let wave = create_wave();
+
+        assert_eq!(wave.get_number(), FIRST_WAVE);
+        assert_eq!(wave.starting_row(), 0);
+    }
+
+    #[test]
+    fn wave_zero_is_clamped_to_the_first_wave() {
+        let wave = Wave::new(0);
+
+        assert_eq!(wave.get_number(), FIRST_WAVE);
+    }
+
+    #[test]
+    fn next_wave_increments_the_number() {
+        let wave = create_wave().next();
+
+        assert_eq!(wave.get_number(), 2);
+    }
+
+    #[test]
+    fn each_wave_starts_one_row_lower() {
+        assert_eq!(Wave::new(2).starting_row(), 1);
+        assert_eq!(Wave::new(5).starting_row(), 4);
+    }
+
+    #[test]
+    fn starting_rows_cycle_back_to_the_top() {
+        assert_eq!(Wave::new(9).starting_row(), 0);
+        assert_eq!(Wave::new(10).starting_row(), 1);
+    }
+}
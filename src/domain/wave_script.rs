@@ -0,0 +1,393 @@
+use crate::domain::formation_layout::{FormationLayout, FormationLayoutError, FormationLayouts};
+use crate::domain::movement_pattern::{FormationMovement, FormationMovements};
+use crate::domain::shield_layout::{ShieldLayout, ShieldLayoutError, ShieldLayouts};
+use crate::domain::wave::{FIRST_WAVE, Wave};
+use std::fmt::{Display, Formatter};
+
+pub const WAVE_SCRIPT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShotType {
+    #[default]
+    Rolling,
+    Plunger,
+    Squiggly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedCurve {
+    pub start: f32,
+    pub end: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UfoSchedule {
+    pub first: f32,
+    pub interval: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShieldRebuild {
+    #[default]
+    Keep,
+    Rebuild,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WaveAction {
+    Announce(String),
+    Reinforce,
+    FireProbability(f64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaveEvent {
+    pub enemies_left: usize,
+    pub action: WaveAction,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WaveScript {
+    pub name: String,
+    pub formation: FormationLayout,
+    pub movement: FormationMovement,
+    pub speed: SpeedCurve,
+    pub fire_probability: f64,
+    pub shot_types: Vec<ShotType>,
+    pub ufo: Option<UfoSchedule>,
+    pub shields: ShieldLayout,
+    pub shield_rebuild: ShieldRebuild,
+    pub events: Vec<WaveEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Campaign {
+    name: String,
+    waves: Vec<WaveScript>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WaveScriptError {
+    Formation(FormationLayoutError),
+    Shields(ShieldLayoutError),
+    OutOfRange {
+        name: &'static str,
+        value: f64,
+    },
+    NoShotTypes,
+    UnreachableEvent {
+        index: usize,
+        enemies_left: usize,
+        enemies: usize,
+    },
+    NoWaves,
+}
+
+impl Display for WaveScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WaveScriptError::Formation(error) => write!(f, "formation: {}", error),
+            WaveScriptError::Shields(error) => write!(f, "shields: {}", error),
+            WaveScriptError::OutOfRange { name, value } => {
+                write!(f, "{} has an invalid value: {}", name, value)
+            }
+            WaveScriptError::NoShotTypes => write!(f, "shot_types must allow at least one shot"),
+            WaveScriptError::UnreachableEvent {
+                index,
+                enemies_left,
+                enemies,
+            } => write!(
+                f,
+                "event {} waits for {} enemies left but the formation only has {}",
+                index + 1,
+                enemies_left,
+                enemies
+            ),
+            WaveScriptError::NoWaves => write!(f, "the campaign lists no waves"),
+        }
+    }
+}
+
+impl ShotType {
+    pub fn speed_scale(self) -> f32 {
+        match self {
+            ShotType::Rolling => 1.0,
+            ShotType::Plunger => 1.25,
+            ShotType::Squiggly => 0.8,
+        }
+    }
+}
+
+impl SpeedCurve {
+    pub fn constant(duration: f32) -> Self {
+        Self {
+            start: duration,
+            end: duration,
+        }
+    }
+
+    pub fn step_duration(&self, enemies_alive: usize, enemies: usize) -> f32 {
+        if enemies == 0 {
+            return self.start;
+        }
+
+        let alive = (enemies_alive as f32 / enemies as f32).clamp(0.0, 1.0);
+
+        self.end + (self.start - self.end) * alive
+    }
+}
+
+impl WaveScript {
+    pub fn classic(step_duration: f32, fire_probability: f64) -> Self {
+        Self {
+            name: "classic".to_string(),
+            formation: FormationLayout::classic(),
+            movement: FormationMovement::ClassicMarch,
+            speed: SpeedCurve::constant(step_duration),
+            fire_probability,
+            shot_types: vec![ShotType::Rolling],
+            ufo: None,
+            shields: ShieldLayout::classic(),
+            shield_rebuild: ShieldRebuild::Keep,
+            events: vec![],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), WaveScriptError> {
+        if !(0.0..=1.0).contains(&self.fire_probability) {
+            return Err(WaveScriptError::OutOfRange {
+                name: "fire_probability",
+                value: self.fire_probability,
+            });
+        }
+
+        let mut durations = vec![
+            ("speed.start", self.speed.start),
+            ("speed.end", self.speed.end),
+        ];
+        if let Some(ufo) = self.ufo {
+            durations.push(("ufo.interval", ufo.interval));
+        }
+
+        for (name, value) in durations {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(WaveScriptError::OutOfRange {
+                    name,
+                    value: value as f64,
+                });
+            }
+        }
+
+        if let Some(ufo) = self.ufo
+            && (!ufo.first.is_finite() || ufo.first < 0.0)
+        {
+            return Err(WaveScriptError::OutOfRange {
+                name: "ufo.first",
+                value: ufo.first as f64,
+            });
+        }
+
+        if self.shot_types.is_empty() {
+            return Err(WaveScriptError::NoShotTypes);
+        }
+
+        let enemies = self.formation.count_enemies();
+        for (index, event) in self.events.iter().enumerate() {
+            if event.enemies_left > enemies {
+                return Err(WaveScriptError::UnreachableEvent {
+                    index,
+                    enemies_left: event.enemies_left,
+                    enemies,
+                });
+            }
+
+            if let WaveAction::FireProbability(probability) = event.action
+                && !(0.0..=1.0).contains(&probability)
+            {
+                return Err(WaveScriptError::OutOfRange {
+                    name: "events.fire_probability",
+                    value: probability,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Campaign {
+    pub fn new(name: String, waves: Vec<WaveScript>) -> Result<Self, WaveScriptError> {
+        if waves.is_empty() {
+            return Err(WaveScriptError::NoWaves);
+        }
+
+        Ok(Self { name, waves })
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_waves(&self) -> &Vec<WaveScript> {
+        &self.waves
+    }
+
+    pub fn for_wave(&self, wave: Wave) -> &WaveScript {
+        let index = (wave.get_number() - FIRST_WAVE) as usize % self.waves.len();
+
+        &self.waves[index]
+    }
+
+    pub fn formation_layouts(&self) -> FormationLayouts {
+        FormationLayouts::new(
+            self.waves
+                .iter()
+                .map(|script| script.formation.clone())
+                .collect(),
+        )
+    }
+
+    pub fn formation_movements(&self) -> FormationMovements {
+        FormationMovements::new(self.waves.iter().map(|script| script.movement).collect())
+    }
+
+    pub fn shield_layouts(&self) -> ShieldLayouts {
+        ShieldLayouts::new(
+            self.waves
+                .iter()
+                .map(|script| script.shields.clone())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn script() -> WaveScript {
+        WaveScript::classic(0.6, 0.2)
+    }
+
+    #[test]
+    fn classic_script_is_valid() {
+        assert_eq!(script().validate(), Ok(()));
+    }
+
+    #[test]
+    fn speed_curve_interpolates_by_enemies_alive() {
+        let curve = SpeedCurve {
+            start: 1.0,
+            end: 0.2,
+        };
+
+        assert_eq!(curve.step_duration(10, 10), 1.0);
+        assert_eq!(curve.step_duration(0, 10), 0.2);
+        assert!((curve.step_duration(5, 10) - 0.6).abs() < f32::EPSILON);
+        assert_eq!(curve.step_duration(0, 0), 1.0);
+    }
+
+    #[test]
+    fn shot_types_change_projectile_speed() {
+        assert_eq!(ShotType::Rolling.speed_scale(), 1.0);
+        assert!(ShotType::Plunger.speed_scale() > ShotType::Squiggly.speed_scale());
+    }
+
+    #[test]
+    fn validation_rejects_out_of_range_values() {
+        let mut wave = script();
+        wave.fire_probability = 1.5;
+        assert_eq!(
+            wave.validate(),
+            Err(WaveScriptError::OutOfRange {
+                name: "fire_probability",
+                value: 1.5
+            })
+        );
+
+        let mut wave = script();
+        wave.speed.end = 0.0;
+        assert_eq!(
+            wave.validate(),
+            Err(WaveScriptError::OutOfRange {
+                name: "speed.end",
+                value: 0.0
+            })
+        );
+
+        let mut wave = script();
+        wave.ufo = Some(UfoSchedule {
+            first: -1.0,
+            interval: 20.0,
+        });
+        assert_eq!(
+            wave.validate(),
+            Err(WaveScriptError::OutOfRange {
+                name: "ufo.first",
+                value: -1.0
+            })
+        );
+    }
+
+    #[test]
+    fn validation_requires_a_shot_type() {
+        let mut wave = script();
+        wave.shot_types.clear();
+
+        assert_eq!(wave.validate(), Err(WaveScriptError::NoShotTypes));
+    }
+
+    #[test]
+    fn validation_rejects_events_that_can_never_trigger() {
+        let mut wave = script();
+        wave.events.push(WaveEvent {
+            enemies_left: 100,
+            action: WaveAction::Reinforce,
+        });
+
+        let error = wave.validate().expect_err("Event should be rejected");
+
+        assert_eq!(
+            error,
+            WaveScriptError::UnreachableEvent {
+                index: 0,
+                enemies_left: 100,
+                enemies: 55
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "event 1 waits for 100 enemies left but the formation only has 55"
+        );
+    }
+
+    #[test]
+    fn campaigns_cycle_through_their_waves() {
+        let mut second = script();
+        second.name = "second".to_string();
+        second.movement = FormationMovement::SineWave;
+        let campaign =
+            Campaign::new("test".to_string(), vec![script(), second]).expect("Campaign is valid");
+
+        assert_eq!(campaign.for_wave(Wave::new(1)).name, "classic");
+        assert_eq!(campaign.for_wave(Wave::new(2)).name, "second");
+        assert_eq!(campaign.for_wave(Wave::new(3)).name, "classic");
+        assert_eq!(campaign.formation_layouts().count(), 2);
+        assert_eq!(campaign.shield_layouts().count(), 2);
+        assert_eq!(
+            campaign.formation_movements().for_wave(Wave::new(2)),
+            FormationMovement::SineWave
+        );
+        assert_eq!(
+            Campaign::new("empty".to_string(), vec![]),
+            Err(WaveScriptError::NoWaves)
+        );
+    }
+}
@@ -1,13 +1,418 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum WeaponState {
     Ready,
     Firing,
+    CoolingDown,
 }
 
-pub trait Fireable {
-    fn start_firing(&mut self);
-    fn reload(&mut self);
-    fn can_fire(&self) -> bool;
-    fn is_firing(&self) -> bool;
-    fn toggle_fire(&mut self);
+pub const DOUBLE_SHOT_OFFSET: f32 = 10.0;
+pub const SPREAD_DRIFT: f32 = 0.25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponEvent {
+    Fired,
+    Reloaded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Weapon {
+    max_shots: u32,
+    volley: u32,
+    cooldown: f32,
+    shots_in_flight: u32,
+    cooldown_remaining: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum WeaponUpgrade {
+    #[default]
+    Standard,
+    DoubleShot,
+    Spread,
+    PiercingLaser,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Shot {
+    pub offset: f32,
+    pub drift: f32,
+    pub piercing: bool,
+}
+
+const STANDARD_SHOTS: [Shot; 1] = [Shot::straight(0.0)];
+const DOUBLE_SHOTS: [Shot; 2] = [
+    Shot::straight(-DOUBLE_SHOT_OFFSET),
+    Shot::straight(DOUBLE_SHOT_OFFSET),
+];
+const SPREAD_SHOTS: [Shot; 3] = [
+    Shot::angled(-SPREAD_DRIFT),
+    Shot::straight(0.0),
+    Shot::angled(SPREAD_DRIFT),
+];
+const LASER_SHOTS: [Shot; 1] = [Shot {
+    offset: 0.0,
+    drift: 0.0,
+    piercing: true,
+}];
+
+impl Default for Weapon {
+    fn default() -> Self {
+        Self::single_shot()
+    }
+}
+
+impl Weapon {
+    pub fn new(max_shots: u32, cooldown: f32) -> Self {
+        Weapon {
+            max_shots: max_shots.max(1),
+            volley: 1,
+            cooldown: cooldown.max(0.0),
+            shots_in_flight: 0,
+            cooldown_remaining: 0.0,
+        }
+    }
+
+    pub fn single_shot() -> Self {
+        Self::new(1, 0.0)
+    }
+
+    pub fn with_volley(self, volley: u32) -> Self {
+        Weapon {
+            volley: volley.clamp(1, self.max_shots),
+            ..self
+        }
+    }
+
+    pub fn refit(&mut self, weapon: Weapon) {
+        self.max_shots = weapon.max_shots;
+        self.volley = weapon.volley;
+        self.cooldown = weapon.cooldown;
+        self.cooldown_remaining = self.cooldown_remaining.min(weapon.cooldown);
+    }
+
+    pub fn state(&self) -> WeaponState {
+        if self.shots_in_flight + self.volley > self.max_shots {
+            WeaponState::Firing
+        } else if self.cooldown_remaining > 0.0 {
+            WeaponState::CoolingDown
+        } else {
+            WeaponState::Ready
+        }
+    }
+
+    pub fn can_fire(&self) -> bool {
+        self.state() == WeaponState::Ready
+    }
+
+    pub fn is_firing(&self) -> bool {
+        self.shots_in_flight > 0
+    }
+
+    pub fn shots_in_flight(&self) -> u32 {
+        self.shots_in_flight
+    }
+
+    pub fn fire(&mut self) -> Option<WeaponEvent> {
+        if !self.can_fire() {
+            return None;
+        }
+
+        self.shots_in_flight += self.volley;
+        self.cooldown_remaining = self.cooldown;
+        Some(WeaponEvent::Fired)
+    }
+
+    pub fn shot_ended(&mut self) -> Option<WeaponEvent> {
+        if self.shots_in_flight == 0 {
+            return None;
+        }
+
+        self.transition(|weapon| weapon.shots_in_flight -= 1)
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) -> Option<WeaponEvent> {
+        if self.cooldown_remaining <= 0.0 {
+            return None;
+        }
+
+        self.transition(|weapon| {
+            weapon.cooldown_remaining = (weapon.cooldown_remaining - delta_secs).max(0.0)
+        })
+    }
+
+    fn transition(&mut self, change: impl FnOnce(&mut Self)) -> Option<WeaponEvent> {
+        let could_fire = self.can_fire();
+        change(self);
+
+        (!could_fire && self.can_fire()).then_some(WeaponEvent::Reloaded)
+    }
+}
+
+impl Shot {
+    const fn straight(offset: f32) -> Self {
+        Shot {
+            offset,
+            drift: 0.0,
+            piercing: false,
+        }
+    }
+
+    const fn angled(drift: f32) -> Self {
+        Shot {
+            offset: 0.0,
+            drift,
+            piercing: false,
+        }
+    }
+}
+
+impl WeaponUpgrade {
+    pub const ALL: [WeaponUpgrade; 4] = [
+        WeaponUpgrade::Standard,
+        WeaponUpgrade::DoubleShot,
+        WeaponUpgrade::Spread,
+        WeaponUpgrade::PiercingLaser,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WeaponUpgrade::Standard => "standard",
+            WeaponUpgrade::DoubleShot => "double shot",
+            WeaponUpgrade::Spread => "spread",
+            WeaponUpgrade::PiercingLaser => "piercing laser",
+        }
+    }
+
+    pub fn shots(&self) -> &'static [Shot] {
+        match self {
+            WeaponUpgrade::Standard => &STANDARD_SHOTS,
+            WeaponUpgrade::DoubleShot => &DOUBLE_SHOTS,
+            WeaponUpgrade::Spread => &SPREAD_SHOTS,
+            WeaponUpgrade::PiercingLaser => &LASER_SHOTS,
+        }
+    }
+
+    pub fn weapon(&self) -> Weapon {
+        let volley = self.shots().len() as u32;
+
+        match self {
+            WeaponUpgrade::Standard => Weapon::single_shot(),
+            WeaponUpgrade::DoubleShot => Weapon::new(volley * 2, 0.25).with_volley(volley),
+            WeaponUpgrade::Spread => Weapon::new(volley * 2, 0.35).with_volley(volley),
+            WeaponUpgrade::PiercingLaser => Weapon::new(2, 0.4),
+        }
+    }
+
+    pub fn next(&self) -> WeaponUpgrade {
+        let index = self.index();
+        WeaponUpgrade::ALL[(index + 1).min(WeaponUpgrade::ALL.len() - 1)]
+    }
+
+    pub fn previous(&self) -> WeaponUpgrade {
+        WeaponUpgrade::ALL[self.index().saturating_sub(1)]
+    }
+
+    fn index(&self) -> usize {
+        WeaponUpgrade::ALL
+            .iter()
+            .position(|upgrade| upgrade == self)
+            .unwrap_or_default()
+    }
+}
+
+pub trait Armed {
+    fn weapon(&self) -> &Weapon;
+    fn weapon_mut(&mut self) -> &mut Weapon;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_weapon_is_ready() {
+        let weapon = Weapon::single_shot();
+
+        assert_eq!(weapon.state(), WeaponState::Ready);
+        assert!(weapon.can_fire());
+        assert!(!weapon.is_firing());
+    }
+
+    #[test]
+    fn firing_the_last_shot_blocks_the_weapon() {
+        let mut weapon = Weapon::single_shot();
+
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+        assert_eq!(weapon.state(), WeaponState::Firing);
+        assert_eq!(weapon.fire(), None);
+        assert_eq!(weapon.shots_in_flight(), 1);
+    }
+
+    #[test]
+    fn ending_the_shot_reloads_the_weapon() {
+        let mut weapon = Weapon::single_shot();
+        weapon.fire();
+
+        assert_eq!(weapon.shot_ended(), Some(WeaponEvent::Reloaded));
+        assert!(weapon.can_fire());
+    }
+
+    #[test]
+    fn stray_shot_ends_are_ignored() {
+        let mut weapon = Weapon::single_shot();
+
+        assert_eq!(weapon.shot_ended(), None);
+        assert!(weapon.can_fire());
+
+        weapon.fire();
+        weapon.shot_ended();
+
+        assert_eq!(weapon.shot_ended(), None);
+        assert_eq!(weapon.shots_in_flight(), 0);
+        assert!(weapon.can_fire());
+    }
+
+    #[test]
+    fn shots_in_flight_are_limited() {
+        let mut weapon = Weapon::new(2, 0.0);
+
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+        assert_eq!(weapon.fire(), None);
+        assert_eq!(weapon.shot_ended(), Some(WeaponEvent::Reloaded));
+        assert_eq!(weapon.shot_ended(), None);
+        assert_eq!(weapon.shots_in_flight(), 0);
+    }
+
+    #[test]
+    fn cooldown_delays_the_next_shot() {
+        let mut weapon = Weapon::new(3, 0.5);
+        weapon.fire();
+
+        assert_eq!(weapon.state(), WeaponState::CoolingDown);
+        assert_eq!(weapon.fire(), None);
+        assert_eq!(weapon.tick(0.3), None);
+        assert_eq!(weapon.tick(0.3), Some(WeaponEvent::Reloaded));
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+    }
+
+    #[test]
+    fn cooling_down_does_not_reload_a_full_weapon() {
+        let mut weapon = Weapon::new(1, 0.5);
+        weapon.fire();
+
+        assert_eq!(weapon.tick(1.0), None);
+        assert_eq!(weapon.state(), WeaponState::Firing);
+        assert_eq!(weapon.shot_ended(), Some(WeaponEvent::Reloaded));
+    }
+
+    #[test]
+    fn volleys_count_every_projectile() {
+        let mut weapon = Weapon::new(4, 0.0).with_volley(2);
+
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+        assert_eq!(weapon.shots_in_flight(), 2);
+        assert_eq!(weapon.fire(), Some(WeaponEvent::Fired));
+        assert_eq!(weapon.fire(), None);
+        assert_eq!(weapon.shot_ended(), None);
+        assert_eq!(weapon.shot_ended(), Some(WeaponEvent::Reloaded));
+    }
+
+    #[test]
+    fn refitting_keeps_the_shots_in_flight() {
+        let mut weapon = Weapon::single_shot();
+        weapon.fire();
+        weapon.refit(WeaponUpgrade::DoubleShot.weapon());
+
+        assert_eq!(weapon.shots_in_flight(), 1);
+        assert!(weapon.can_fire());
+    }
+
+    #[test]
+    fn upgrades_fire_their_whole_volley() {
+        for upgrade in WeaponUpgrade::ALL {
+            let mut weapon = upgrade.weapon();
+
+            weapon.fire();
+
+            assert_eq!(weapon.shots_in_flight(), upgrade.shots().len() as u32);
+        }
+    }
+
+    #[test]
+    fn only_the_laser_pierces() {
+        for upgrade in WeaponUpgrade::ALL {
+            assert_eq!(
+                upgrade.shots().iter().any(|shot| shot.piercing),
+                upgrade == WeaponUpgrade::PiercingLaser
+            );
+        }
+    }
+
+    #[test]
+    fn upgrades_step_up_and_down_within_bounds() {
+        assert_eq!(WeaponUpgrade::Standard.next(), WeaponUpgrade::DoubleShot);
+        assert_eq!(
+            WeaponUpgrade::PiercingLaser.next(),
+            WeaponUpgrade::PiercingLaser
+        );
+        assert_eq!(WeaponUpgrade::Spread.previous(), WeaponUpgrade::DoubleShot);
+        assert_eq!(WeaponUpgrade::Standard.previous(), WeaponUpgrade::Standard);
+    }
+
+    #[test]
+    fn weapons_fire_at_least_one_shot() {
+        assert_eq!(Weapon::new(0, -1.0), Weapon::single_shot());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn weapon_state_survives_a_serde_round_trip() {
+        for state in [
+            WeaponState::Ready,
+            WeaponState::Firing,
+            WeaponState::CoolingDown,
+        ] {
+            let restored: WeaponState =
+                ron::from_str(&ron::to_string(&state).expect("State should serialize"))
+                    .expect("State should deserialize");
+
+            assert_eq!(restored, state);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn weapon_state_has_stable_names() {
+        assert_eq!(
+            ron::to_string(&WeaponState::Ready).ok(),
+            Some("ready".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&WeaponState::Firing).ok(),
+            Some("firing".to_string())
+        );
+        assert_eq!(
+            ron::to_string(&WeaponState::CoolingDown).ok(),
+            Some("cooling_down".to_string())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn weapon_survives_a_serde_round_trip() {
+        let mut weapon = Weapon::new(2, 0.25);
+        weapon.fire();
+
+        let restored: Weapon =
+            ron::from_str(&ron::to_string(&weapon).expect("Weapon should serialize"))
+                .expect("Weapon should deserialize");
+
+        assert_eq!(restored, weapon);
+    }
 }
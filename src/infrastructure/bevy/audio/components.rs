@@ -0,0 +1,14 @@
+use crate::infrastructure::bevy::audio::resources::SoundEffect;
+use bevy::prelude::{Component, Message, Timer};
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PlaySoundMessage(pub SoundEffect);
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct StopSoundMessage(pub SoundEffect);
+
+#[derive(Component, Debug, PartialEq)]
+pub struct SoundEffectComponent(pub SoundEffect);
+
+#[derive(Component, Debug)]
+pub struct SoundLifetimeComponent(pub Timer);
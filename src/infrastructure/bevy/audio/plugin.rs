@@ -0,0 +1,91 @@
+use crate::infrastructure::bevy::audio::components::{PlaySoundMessage, StopSoundMessage};
+use crate::infrastructure::bevy::audio::resources::{
+    AudioMixerResource, MarchResource, SettingsStorageResource, SynthSound,
+};
+use crate::infrastructure::bevy::audio::systems::{
+    apply_mixer_volume_system, load_audio_settings_system, march_system, mute_hotkey_system,
+    play_sound_system, reset_march_system, save_audio_settings_system, sound_lifetime_system,
+    sound_triggers_system, stop_sound_system, synthesize_sound_effects_system, ufo_sound_system,
+};
+use crate::infrastructure::bevy::game_state::resources::SoundSystems;
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::audio::{AddAudioSource, AudioPlugin};
+use bevy::log::info;
+use bevy::prelude::IntoScheduleConfigs;
+
+pub struct SoundPlugin;
+
+impl Plugin for SoundPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MarchResource>()
+            .init_resource::<AudioMixerResource>()
+            .init_resource::<SettingsStorageResource>()
+            .add_message::<PlaySoundMessage>()
+            .add_message::<StopSoundMessage>()
+            .add_message::<UfoShotMessage>()
+            .add_systems(Startup, load_audio_settings_system)
+            .add_systems(
+                Update,
+                (
+                    sound_triggers_system,
+                    ufo_sound_system,
+                    march_system,
+                    reset_march_system,
+                    (mute_hotkey_system, save_audio_settings_system).chain(),
+                )
+                    .in_set(SoundSystems),
+            );
+
+        if !app.is_plugin_added::<AudioPlugin>() {
+            info!("Audio is not available, sound effects are disabled");
+            return;
+        }
+
+        app.add_audio_source::<SynthSound>()
+            .add_systems(Startup, synthesize_sound_effects_system)
+            .add_systems(
+                Update,
+                (
+                    play_sound_system,
+                    stop_sound_system,
+                    sound_lifetime_system,
+                    apply_mixer_volume_system,
+                )
+                    .after(sound_triggers_system)
+                    .after(ufo_sound_system)
+                    .after(march_system)
+                    .after(mute_hotkey_system)
+                    .in_set(SoundSystems),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::enemy_formation::components::FormationAdvancedMessage;
+    use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::player::components::{
+        PlayerFiredMessage, PlayerReloadedMessage,
+    };
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = bevy_test::TestAppBuilder::new()
+            .with_input()
+            .with_setup(|app| {
+                app.insert_resource(SettingsStorageResource { path: None });
+            })
+            .with_plugin(SoundPlugin)
+            .with_message::<PlayerFiredMessage>()
+            .with_message::<PlayerReloadedMessage>()
+            .with_message::<EnemyKilledMessage>()
+            .with_message::<PlayerKilledMessage>()
+            .with_message::<FormationAdvancedMessage>()
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
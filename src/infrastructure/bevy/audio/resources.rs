@@ -0,0 +1,476 @@
+use crate::domain::mixer::{AudioMixer, ChannelLevel, MixerChannel};
+use crate::infrastructure::storage::{data_file, from_ron, to_pretty_ron, write_atomically};
+use bevy::asset::{Asset, Handle};
+use bevy::audio::{Decodable, Source};
+use bevy::prelude::{KeyCode, Resource, Timer, TimerMode};
+use bevy::reflect::TypePath;
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+pub const SETTINGS_FILE: &str = "settings.ron";
+pub const SETTINGS_VERSION: u32 = 1;
+pub const MUTE_KEY: KeyCode = KeyCode::KeyM;
+
+pub const SAMPLE_RATE: u32 = 22_050;
+pub const PLAYER_FIRE_DURATION: f32 = 0.18;
+pub const PLAYER_RELOAD_DURATION: f32 = 0.05;
+pub const INVADER_DEATH_DURATION: f32 = 0.25;
+pub const PLAYER_DEATH_DURATION: f32 = 0.9;
+pub const UFO_LOOP_DURATION: f32 = 0.4;
+pub const UFO_HIT_DURATION: f32 = 0.6;
+pub const MARCH_NOTE_DURATION: f32 = 0.12;
+pub const MARCH_NOTE_FREQUENCIES: [f32; 4] = [110.0, 98.0, 87.3, 82.4];
+pub const MARCH_MUTE_AFTER_DEATH: f32 = PLAYER_DEATH_DURATION;
+const NOISE_SEED: u32 = 0x2545_f491;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    PlayerFire,
+    PlayerReload,
+    InvaderDeath,
+    PlayerDeath,
+    UfoLoop,
+    UfoHit,
+    MarchNote(usize),
+}
+
+impl SoundEffect {
+    pub const ALL: [SoundEffect; 10] = [
+        SoundEffect::PlayerFire,
+        SoundEffect::PlayerReload,
+        SoundEffect::InvaderDeath,
+        SoundEffect::PlayerDeath,
+        SoundEffect::UfoLoop,
+        SoundEffect::UfoHit,
+        SoundEffect::MarchNote(0),
+        SoundEffect::MarchNote(1),
+        SoundEffect::MarchNote(2),
+        SoundEffect::MarchNote(3),
+    ];
+
+    pub fn is_looping(&self) -> bool {
+        *self == SoundEffect::UfoLoop
+    }
+
+    pub fn channel(&self) -> MixerChannel {
+        match self {
+            SoundEffect::MarchNote(_) => MixerChannel::Music,
+            _ => MixerChannel::Effects,
+        }
+    }
+}
+
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct SynthSound {
+    samples: Arc<[f32]>,
+}
+
+pub struct SynthDecoder {
+    samples: Arc<[f32]>,
+    position: usize,
+}
+
+#[derive(Resource, Default)]
+pub struct SoundEffectsResource(pub HashMap<SoundEffect, Handle<SynthSound>>);
+
+#[derive(Resource, Default)]
+pub struct AudioMixerResource(pub AudioMixer);
+
+#[derive(Resource)]
+pub struct SettingsStorageResource {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for SettingsStorageResource {
+    fn default() -> Self {
+        Self {
+            path: data_file(SETTINGS_FILE),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SettingsError {
+    Io(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+}
+
+impl Display for SettingsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingsError::Io(reason) => write!(f, "{}", reason),
+            SettingsError::Parse(reason) => write!(f, "invalid settings file: {}", reason),
+            SettingsError::UnsupportedVersion(version) => write!(
+                f,
+                "settings file version {} is not supported (expected {})",
+                version, SETTINGS_VERSION
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct SettingsFile {
+    version: u32,
+    audio: AudioSettings,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct AudioSettings {
+    master: ChannelRecord,
+    music: ChannelRecord,
+    effects: ChannelRecord,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ChannelRecord {
+    volume: f32,
+    muted: bool,
+}
+
+#[derive(Resource)]
+pub struct MarchResource {
+    pub next_note: usize,
+    pub mute_timer: Timer,
+}
+
+impl Default for MarchResource {
+    fn default() -> Self {
+        let mut mute_timer = Timer::from_seconds(MARCH_MUTE_AFTER_DEATH, TimerMode::Once);
+        mute_timer.finish();
+
+        Self {
+            next_note: 0,
+            mute_timer,
+        }
+    }
+}
+
+impl SynthSound {
+    pub fn new(samples: Vec<f32>) -> Self {
+        Self {
+            samples: samples.into(),
+        }
+    }
+
+    pub fn get_samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    pub fn get_duration(&self) -> Duration {
+        Duration::from_secs_f64(self.samples.len() as f64 / SAMPLE_RATE as f64)
+    }
+}
+
+impl Decodable for SynthSound {
+    type DecoderItem = f32;
+    type Decoder = SynthDecoder;
+
+    fn decoder(&self) -> Self::Decoder {
+        SynthDecoder {
+            samples: self.samples.clone(),
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for SynthDecoder {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sample = self.samples.get(self.position).copied();
+        self.position += 1;
+        sample
+    }
+}
+
+impl Source for SynthDecoder {
+    fn current_frame_len(&self) -> Option<usize> {
+        Some(self.samples.len().saturating_sub(self.position))
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        Some(Duration::from_secs_f64(
+            self.samples.len() as f64 / SAMPLE_RATE as f64,
+        ))
+    }
+}
+
+impl From<ChannelLevel> for ChannelRecord {
+    fn from(level: ChannelLevel) -> Self {
+        Self {
+            volume: level.get_volume(),
+            muted: level.is_muted(),
+        }
+    }
+}
+
+impl From<&ChannelRecord> for ChannelLevel {
+    fn from(record: &ChannelRecord) -> Self {
+        ChannelLevel::new(record.volume, record.muted)
+    }
+}
+
+pub fn parse_settings(source: &str) -> Result<AudioMixer, SettingsError> {
+    let file: SettingsFile = from_ron(source).map_err(SettingsError::Parse)?;
+
+    if file.version != SETTINGS_VERSION {
+        return Err(SettingsError::UnsupportedVersion(file.version));
+    }
+
+    let mut mixer = AudioMixer::new();
+    mixer.set_level(MixerChannel::Master, (&file.audio.master).into());
+    mixer.set_level(MixerChannel::Music, (&file.audio.music).into());
+    mixer.set_level(MixerChannel::Effects, (&file.audio.effects).into());
+
+    Ok(mixer)
+}
+
+pub fn load_settings(path: &Path) -> Result<AudioMixer, SettingsError> {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_settings(&source),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(AudioMixer::default()),
+        Err(error) => Err(SettingsError::Io(error.to_string())),
+    }
+}
+
+pub fn save_settings(path: &Path, mixer: &AudioMixer) -> Result<(), SettingsError> {
+    let file = SettingsFile {
+        version: SETTINGS_VERSION,
+        audio: AudioSettings {
+            master: mixer.get_level(MixerChannel::Master).into(),
+            music: mixer.get_level(MixerChannel::Music).into(),
+            effects: mixer.get_level(MixerChannel::Effects).into(),
+        },
+    };
+
+    let source = to_pretty_ron(&file).map_err(SettingsError::Parse)?;
+
+    write_atomically(path, source.as_bytes()).map_err(|error| SettingsError::Io(error.to_string()))
+}
+
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+}
+
+fn square(phase: f32) -> f32 {
+    if phase.fract() < 0.5 { 1.0 } else { -1.0 }
+}
+
+fn render(duration: f32, mut sample: impl FnMut(f32, f32) -> f32) -> Vec<f32> {
+    let length = (duration * SAMPLE_RATE as f32) as usize;
+
+    (0..length)
+        .map(|index| {
+            let time = index as f32 / SAMPLE_RATE as f32;
+            let progress = index as f32 / length as f32;
+            sample(time, progress).clamp(-1.0, 1.0)
+        })
+        .collect()
+}
+
+fn sweep(duration: f32, from: f32, to: f32, mut shape: impl FnMut(f32, f32) -> f32) -> Vec<f32> {
+    let mut phase = 0.0;
+
+    render(duration, |_, progress| {
+        let frequency = from * (to / from).powf(progress);
+        phase += frequency / SAMPLE_RATE as f32;
+        shape(phase, progress)
+    })
+}
+
+pub fn synthesize(effect: SoundEffect) -> SynthSound {
+    let mut noise = Noise(NOISE_SEED);
+
+    let samples = match effect {
+        SoundEffect::PlayerFire => sweep(PLAYER_FIRE_DURATION, 1400.0, 180.0, |phase, progress| {
+            square(phase) * 0.35 * (1.0 - progress).powi(2)
+        }),
+        SoundEffect::PlayerReload => {
+            sweep(PLAYER_RELOAD_DURATION, 500.0, 900.0, |phase, progress| {
+                square(phase) * 0.12 * (1.0 - progress)
+            })
+        }
+        SoundEffect::InvaderDeath => {
+            sweep(INVADER_DEATH_DURATION, 220.0, 60.0, |phase, progress| {
+                (square(phase) * 0.5 + noise.next() * 0.5) * 0.45 * (1.0 - progress)
+            })
+        }
+        SoundEffect::PlayerDeath => {
+            let mut filtered = 0.0;
+
+            render(PLAYER_DEATH_DURATION, |time, progress| {
+                filtered += (noise.next() - filtered) * 0.15;
+                let wobble = 0.6 + 0.4 * (TAU * 9.0 * time).sin();
+                filtered * 1.8 * wobble * (1.0 - progress)
+            })
+        }
+        SoundEffect::UfoLoop => {
+            let mut phase = 0.0;
+
+            render(UFO_LOOP_DURATION, |_, progress| {
+                let frequency = 650.0 + 180.0 * (TAU * progress).sin();
+                phase += frequency / SAMPLE_RATE as f32;
+                (TAU * phase).sin() * 0.25
+            })
+        }
+        SoundEffect::UfoHit => sweep(UFO_HIT_DURATION, 300.0, 1200.0, |phase, progress| {
+            let tremolo = 0.5 + 0.5 * square(progress * 12.0);
+            square(phase) * 0.35 * tremolo * (1.0 - progress)
+        }),
+        SoundEffect::MarchNote(note) => {
+            let frequency = MARCH_NOTE_FREQUENCIES[note % MARCH_NOTE_FREQUENCIES.len()];
+
+            render(MARCH_NOTE_DURATION, |time, progress| {
+                square(frequency * time) * 0.5 * (1.0 - progress).powi(3)
+            })
+        }
+    };
+
+    SynthSound::new(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::temporary_directory;
+
+    #[test]
+    fn every_effect_is_synthesized_within_range() {
+        for effect in SoundEffect::ALL {
+            let sound = synthesize(effect);
+
+            assert!(!sound.get_samples().is_empty());
+            assert!(
+                sound
+                    .get_samples()
+                    .iter()
+                    .all(|sample| (-1.0..=1.0).contains(sample))
+            );
+            assert!(sound.get_samples().iter().any(|sample| *sample != 0.0));
+        }
+    }
+
+    #[test]
+    fn effects_last_their_configured_duration() {
+        let sound = synthesize(SoundEffect::PlayerDeath);
+
+        let difference = sound.get_duration().as_secs_f32() - PLAYER_DEATH_DURATION;
+
+        assert!(difference.abs() < 0.001);
+    }
+
+    #[test]
+    fn synthesis_is_deterministic() {
+        assert_eq!(
+            synthesize(SoundEffect::InvaderDeath).get_samples(),
+            synthesize(SoundEffect::InvaderDeath).get_samples()
+        );
+    }
+
+    #[test]
+    fn only_the_ufo_loop_repeats() {
+        let looping: Vec<SoundEffect> = SoundEffect::ALL
+            .into_iter()
+            .filter(SoundEffect::is_looping)
+            .collect();
+
+        assert_eq!(looping, vec![SoundEffect::UfoLoop]);
+    }
+
+    #[test]
+    fn march_notes_descend() {
+        let periods: Vec<usize> = (0..MARCH_NOTE_FREQUENCIES.len())
+            .map(|note| {
+                let sound = synthesize(SoundEffect::MarchNote(note));
+                let samples = sound.get_samples();
+
+                samples
+                    .windows(2)
+                    .position(|pair| pair[0] > 0.0 && pair[1] < 0.0)
+                    .expect("Note should oscillate")
+            })
+            .collect();
+
+        assert!(periods.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn march_starts_unmuted_on_the_first_note() {
+        let march = MarchResource::default();
+
+        assert_eq!(march.next_note, 0);
+        assert!(march.mute_timer.is_finished());
+    }
+
+    #[test]
+    fn march_notes_are_music_and_everything_else_is_effects() {
+        assert_eq!(SoundEffect::MarchNote(2).channel(), MixerChannel::Music);
+        assert_eq!(SoundEffect::UfoLoop.channel(), MixerChannel::Effects);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn settings_round_trip_through_a_file() {
+        let path = temporary_directory("settings").join(SETTINGS_FILE);
+        let mut mixer = AudioMixer::new();
+        mixer.set_volume(MixerChannel::Music, 0.25);
+        mixer.set_muted(MixerChannel::Master, true);
+
+        save_settings(&path, &mixer).expect("Settings should be saved");
+
+        assert_eq!(load_settings(&path), Ok(mixer));
+    }
+
+    #[test]
+    fn missing_settings_use_the_default_mixer() {
+        let path = temporary_directory("missing-settings").join(SETTINGS_FILE);
+
+        assert_eq!(load_settings(&path), Ok(AudioMixer::default()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn settings_from_other_versions_are_rejected() {
+        let source = "(version: 9, audio: (master: (volume: 1.0, muted: false), music: (volume: 1.0, muted: false), effects: (volume: 1.0, muted: false)))";
+
+        assert_eq!(
+            parse_settings(source),
+            Err(SettingsError::UnsupportedVersion(9))
+        );
+    }
+
+    #[test]
+    fn decoder_plays_every_sample_once() {
+        let sound = SynthSound::new(vec![0.1, 0.2, 0.3]);
+        let decoder = sound.decoder();
+
+        assert_eq!(decoder.channels(), 1);
+        assert_eq!(decoder.sample_rate(), SAMPLE_RATE);
+        assert_eq!(decoder.collect::<Vec<f32>>(), vec![0.1, 0.2, 0.3]);
+    }
+}
@@ -0,0 +1,578 @@
+use crate::domain::mixer::MixerChannel;
+use crate::infrastructure::bevy::audio::components::{
+    PlaySoundMessage, SoundEffectComponent, SoundLifetimeComponent, StopSoundMessage,
+};
+use crate::infrastructure::bevy::audio::resources::{
+    AudioMixerResource, MARCH_NOTE_FREQUENCIES, MUTE_KEY, MarchResource, SettingsStorageResource,
+    SoundEffect, SoundEffectsResource, SynthSound, load_settings, save_settings, synthesize,
+};
+use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+use crate::infrastructure::bevy::enemy_formation::components::FormationAdvancedMessage;
+use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::player::components::{PlayerFiredMessage, PlayerReloadedMessage};
+use crate::infrastructure::bevy::ufo::components::{UfoComponent, UfoShotMessage};
+use bevy::asset::Assets;
+use bevy::audio::{AudioPlayer, AudioSink, AudioSinkPlayback, PlaybackSettings, Volume};
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Added, Commands, DetectChanges, DetectChangesMut, Entity, KeyCode, MessageReader,
+    MessageWriter, Query, RemovedComponents, Res, ResMut, State, Time, Timer, TimerMode, With,
+};
+use tracing::{info, warn};
+
+pub fn synthesize_sound_effects_system(
+    mut commands: Commands,
+    mut sounds: ResMut<Assets<SynthSound>>,
+) {
+    let handles = SoundEffect::ALL
+        .into_iter()
+        .map(|effect| (effect, sounds.add(synthesize(effect))))
+        .collect();
+
+    commands.insert_resource(SoundEffectsResource(handles));
+}
+
+pub fn load_audio_settings_system(
+    storage: Res<SettingsStorageResource>,
+    mut mixer_res: ResMut<AudioMixerResource>,
+) {
+    let Some(path) = &storage.path else {
+        return;
+    };
+
+    match load_settings(path) {
+        Ok(mixer) => mixer_res.bypass_change_detection().0 = mixer,
+        Err(error) => warn!("Could not load settings from {}: {}", path.display(), error),
+    }
+}
+
+pub fn save_audio_settings_system(
+    storage: Res<SettingsStorageResource>,
+    mixer_res: Res<AudioMixerResource>,
+) {
+    if !mixer_res.is_changed() || mixer_res.is_added() {
+        return;
+    }
+
+    if let Some(path) = &storage.path
+        && let Err(error) = save_settings(path, &mixer_res.0)
+    {
+        warn!("Could not save settings to {}: {}", path.display(), error);
+    }
+}
+
+pub fn mute_hotkey_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mixer_res: ResMut<AudioMixerResource>,
+) {
+    if keyboard.just_pressed(MUTE_KEY) {
+        mixer_res.0.toggle_mute(MixerChannel::Master);
+
+        if mixer_res.0.get_level(MixerChannel::Master).is_muted() {
+            info!("Sound muted");
+        } else {
+            info!("Sound unmuted");
+        }
+    }
+}
+
+pub fn apply_mixer_volume_system(
+    mixer_res: Res<AudioMixerResource>,
+    mut sink_query: Query<(&SoundEffectComponent, &mut AudioSink)>,
+) {
+    if !mixer_res.is_changed() {
+        return;
+    }
+
+    for (playing, mut sink) in sink_query.iter_mut() {
+        let volume = mixer_res.0.effective_volume(playing.0.channel());
+        sink.set_volume(Volume::Linear(volume));
+    }
+}
+
+pub fn sound_triggers_system(
+    mut player_fired_message: MessageReader<PlayerFiredMessage>,
+    mut player_reloaded_message: MessageReader<PlayerReloadedMessage>,
+    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
+    mut player_killed_message: MessageReader<PlayerKilledMessage>,
+    mut play_sound_writer: MessageWriter<PlaySoundMessage>,
+) {
+    for _ in player_fired_message.read() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::PlayerFire));
+    }
+
+    for _ in player_reloaded_message.read() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::PlayerReload));
+    }
+
+    for _ in enemy_killed_message.read() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::InvaderDeath));
+    }
+
+    for _ in player_killed_message.read() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::PlayerDeath));
+    }
+}
+
+pub fn ufo_sound_system(
+    arrived_ufo_query: Query<(), Added<UfoComponent>>,
+    ufo_query: Query<(), With<UfoComponent>>,
+    mut departed_ufos: RemovedComponents<UfoComponent>,
+    mut ufo_shot_message: MessageReader<UfoShotMessage>,
+    mut play_sound_writer: MessageWriter<PlaySoundMessage>,
+    mut stop_sound_writer: MessageWriter<StopSoundMessage>,
+) {
+    for _ in ufo_shot_message.read() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::UfoHit));
+    }
+
+    if !arrived_ufo_query.is_empty() {
+        play_sound_writer.write(PlaySoundMessage(SoundEffect::UfoLoop));
+    }
+
+    if departed_ufos.read().count() > 0 && ufo_query.is_empty() {
+        stop_sound_writer.write(StopSoundMessage(SoundEffect::UfoLoop));
+    }
+}
+
+pub fn march_system(
+    time: Res<Time>,
+    game_state: Option<Res<State<GameState>>>,
+    mut march_res: ResMut<MarchResource>,
+    mut formation_advanced_message: MessageReader<FormationAdvancedMessage>,
+    mut player_killed_message: MessageReader<PlayerKilledMessage>,
+    mut play_sound_writer: MessageWriter<PlaySoundMessage>,
+) {
+    march_res.mute_timer.tick(time.delta());
+
+    if player_killed_message.read().count() > 0 {
+        march_res.mute_timer.reset();
+    }
+
+    let playing = game_state.is_none_or(|state| *state.get() == GameState::Playing);
+    let muted = !playing || !march_res.mute_timer.is_finished();
+
+    for _ in formation_advanced_message.read() {
+        let note = march_res.next_note;
+        march_res.next_note = (note + 1) % MARCH_NOTE_FREQUENCIES.len();
+
+        if !muted {
+            play_sound_writer.write(PlaySoundMessage(SoundEffect::MarchNote(note)));
+        }
+    }
+}
+
+pub fn reset_march_system(
+    mut march_res: ResMut<MarchResource>,
+    mut new_game_message: MessageReader<NewGameMessage>,
+) {
+    if new_game_message.read().count() > 0 {
+        *march_res = MarchResource::default();
+    }
+}
+
+pub fn play_sound_system(
+    mut commands: Commands,
+    sound_effects: Res<SoundEffectsResource>,
+    sounds: Res<Assets<SynthSound>>,
+    mixer_res: Res<AudioMixerResource>,
+    playing_query: Query<&SoundEffectComponent>,
+    mut play_sound_message: MessageReader<PlaySoundMessage>,
+) {
+    let mut looping = playing_query.iter().any(|playing| playing.0.is_looping());
+
+    for PlaySoundMessage(effect) in play_sound_message.read() {
+        let Some(handle) = sound_effects.0.get(effect) else {
+            continue;
+        };
+        let volume = Volume::Linear(mixer_res.0.effective_volume(effect.channel()));
+
+        if effect.is_looping() {
+            if looping {
+                continue;
+            }
+
+            looping = true;
+            commands.spawn((
+                SoundEffectComponent(*effect),
+                AudioPlayer(handle.clone()),
+                PlaybackSettings::LOOP.with_volume(volume),
+            ));
+        } else if let Some(sound) = sounds.get(handle) {
+            commands.spawn((
+                SoundEffectComponent(*effect),
+                SoundLifetimeComponent(Timer::new(sound.get_duration(), TimerMode::Once)),
+                AudioPlayer(handle.clone()),
+                PlaybackSettings::ONCE.with_volume(volume),
+            ));
+        }
+    }
+}
+
+pub fn stop_sound_system(
+    mut commands: Commands,
+    playing_query: Query<(Entity, &SoundEffectComponent)>,
+    mut stop_sound_message: MessageReader<StopSoundMessage>,
+) {
+    for StopSoundMessage(effect) in stop_sound_message.read() {
+        for (entity, playing) in playing_query.iter() {
+            if playing.0 == *effect {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+pub fn sound_lifetime_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut lifetime_query: Query<(Entity, &mut SoundLifetimeComponent)>,
+) {
+    for (entity, mut lifetime) in lifetime_query.iter_mut() {
+        if lifetime.0.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyId;
+    use crate::domain::enemy_formation::MovingDirection;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::mixer::AudioMixer;
+    use crate::infrastructure::bevy::audio::resources::MARCH_MUTE_AFTER_DEATH;
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::bevy::audio::resources::SETTINGS_FILE;
+    use crate::infrastructure::bevy::ufo::resources::UFO_POINTS;
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::storage::temporary_directory;
+    use bevy::app::{App, Startup, Update};
+    use bevy::asset::AssetApp;
+    use bevy::ecs::message::Messages;
+    use bevy::prelude::IntoScheduleConfigs;
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, count_components, did_message_fire,
+        get_resource_mut_or_fail, send_message, spawn_dummy_entity,
+    };
+    use std::path::PathBuf;
+
+    fn setup_triggers() -> App {
+        TestAppBuilder::new()
+            .with_message::<PlayerFiredMessage>()
+            .with_message::<PlayerReloadedMessage>()
+            .with_message::<EnemyKilledMessage>()
+            .with_message::<PlayerKilledMessage>()
+            .with_message::<PlaySoundMessage>()
+            .with_setup(|app| {
+                app.add_systems(Update, sound_triggers_system);
+            })
+            .build()
+    }
+
+    fn setup_playback() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_assets()
+            .with_time()
+            .with_message::<PlaySoundMessage>()
+            .with_message::<StopSoundMessage>()
+            .with_setup(|app| {
+                app.init_resource::<AudioMixerResource>()
+                    .init_asset::<SynthSound>()
+                    .add_systems(Startup, synthesize_sound_effects_system)
+                    .add_systems(
+                        Update,
+                        (play_sound_system, stop_sound_system, sound_lifetime_system),
+                    );
+                app.update();
+            })
+            .build()
+    }
+
+    fn setup_march() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_message::<FormationAdvancedMessage>()
+            .with_message::<PlayerKilledMessage>()
+            .with_message::<NewGameMessage>()
+            .with_message::<PlaySoundMessage>()
+            .with_setup(|app| {
+                app.init_resource::<MarchResource>()
+                    .add_systems(Update, (march_system, reset_march_system));
+            })
+            .build()
+    }
+
+    fn played_sounds(app: &App) -> Vec<PlaySoundMessage> {
+        app.world()
+            .resource::<Messages<PlaySoundMessage>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect()
+    }
+
+    fn setup_settings(path: Option<PathBuf>) -> App {
+        TestAppBuilder::new()
+            .with_input()
+            .with_setup(|app| {
+                app.insert_resource(SettingsStorageResource { path })
+                    .init_resource::<AudioMixerResource>()
+                    .add_systems(Startup, load_audio_settings_system)
+                    .add_systems(
+                        Update,
+                        (mute_hotkey_system, save_audio_settings_system).chain(),
+                    );
+                app.update();
+            })
+            .build()
+    }
+
+    fn count_sounds(app: &mut App, effect: SoundEffect) -> usize {
+        app.world_mut()
+            .query::<&SoundEffectComponent>()
+            .iter(app.world())
+            .filter(|playing| playing.0 == effect)
+            .count()
+    }
+
+    #[test]
+    fn player_fire_plays_a_sound() {
+        let mut app = setup_triggers();
+
+        send_message(&mut app, PlayerFiredMessage);
+        app.update();
+
+        assert!(did_message_fire::<PlaySoundMessage>(&mut app));
+    }
+
+    #[test]
+    fn player_reload_plays_a_click() {
+        let mut app = setup_triggers();
+
+        send_message(&mut app, PlayerReloadedMessage::new(PlayerSlot::One));
+        app.update();
+
+        assert_eq!(
+            played_sounds(&app),
+            vec![PlaySoundMessage(SoundEffect::PlayerReload)]
+        );
+    }
+
+    #[test]
+    fn enemy_killed_plays_invader_death() {
+        let mut app = setup_triggers();
+        let entity = spawn_dummy_entity(&mut app);
+
+        send_message(
+            &mut app,
+            EnemyKilledMessage::new(entity, EnemyId::new(0), entity, PlayerSlot::One),
+        );
+        app.update();
+
+        assert_eq!(
+            played_sounds(&app),
+            vec![PlaySoundMessage(SoundEffect::InvaderDeath)]
+        );
+    }
+
+    #[test]
+    fn ufos_loop_while_flying_and_chime_when_shot() {
+        let mut app = TestAppBuilder::new()
+            .with_message::<UfoShotMessage>()
+            .with_message::<PlaySoundMessage>()
+            .with_message::<StopSoundMessage>()
+            .with_setup(|app| {
+                app.add_systems(Update, ufo_sound_system);
+            })
+            .build();
+
+        let ufo = app
+            .world_mut()
+            .spawn(UfoComponent {
+                direction: MovingDirection::ToRight,
+            })
+            .id();
+        app.update();
+
+        assert_eq!(
+            played_sounds(&app),
+            vec![PlaySoundMessage(SoundEffect::UfoLoop)]
+        );
+
+        app.world_mut().despawn(ufo);
+        send_message(
+            &mut app,
+            UfoShotMessage::new(ufo, PlayerSlot::One, UFO_POINTS[0]),
+        );
+        app.update();
+
+        assert!(played_sounds(&app).contains(&PlaySoundMessage(SoundEffect::UfoHit)));
+        assert!(did_message_fire::<StopSoundMessage>(&mut app));
+    }
+
+    #[test]
+    fn march_cycles_through_four_notes() {
+        let mut app = setup_march();
+        let mut notes = Vec::new();
+
+        for _ in 0..5 {
+            send_message(&mut app, FormationAdvancedMessage);
+            app.update();
+            notes.extend(played_sounds(&app));
+        }
+
+        assert_eq!(
+            notes,
+            [0, 1, 2, 3, 0]
+                .map(|note| PlaySoundMessage(SoundEffect::MarchNote(note)))
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn march_is_muted_during_the_player_death_sequence() {
+        let mut app = setup_march();
+        let entity = spawn_dummy_entity(&mut app);
+
+        send_message(&mut app, PlayerKilledMessage::new(entity, PlayerSlot::One));
+        send_message(&mut app, FormationAdvancedMessage);
+        app.update();
+
+        assert!(played_sounds(&app).is_empty());
+
+        advance_time_by_seconds(&mut app, MARCH_MUTE_AFTER_DEATH + 0.1);
+        send_message(&mut app, FormationAdvancedMessage);
+        app.update();
+
+        assert_eq!(
+            played_sounds(&app),
+            vec![PlaySoundMessage(SoundEffect::MarchNote(1))]
+        );
+    }
+
+    #[test]
+    fn march_is_muted_outside_of_gameplay() {
+        let mut app = setup_march();
+        app.add_plugins(StatesPlugin)
+            .insert_state(GameState::GameOver);
+        app.update();
+
+        send_message(&mut app, FormationAdvancedMessage);
+        app.update();
+
+        assert!(played_sounds(&app).is_empty());
+    }
+
+    #[test]
+    fn new_game_restarts_the_march() {
+        let mut app = setup_march();
+
+        send_message(&mut app, FormationAdvancedMessage);
+        app.update();
+        send_message(&mut app, NewGameMessage);
+        app.update();
+
+        assert_eq!(app.world().resource::<MarchResource>().next_note, 0);
+    }
+
+    #[test]
+    fn synthesizes_every_effect_at_startup() {
+        let app = setup_playback();
+
+        let sound_effects = app.world().resource::<SoundEffectsResource>();
+
+        assert_eq!(sound_effects.0.len(), SoundEffect::ALL.len());
+    }
+
+    #[test]
+    fn sounds_play_at_the_volume_of_their_channel() {
+        let mut app = setup_playback();
+        app.world_mut()
+            .resource_mut::<AudioMixerResource>()
+            .0
+            .set_muted(MixerChannel::Music, true);
+
+        send_message(&mut app, PlaySoundMessage(SoundEffect::MarchNote(0)));
+        send_message(&mut app, PlaySoundMessage(SoundEffect::PlayerFire));
+        app.update();
+
+        let mut volumes: Vec<(SoundEffect, Volume)> = app
+            .world_mut()
+            .query::<(&SoundEffectComponent, &PlaybackSettings)>()
+            .iter(app.world())
+            .map(|(playing, settings)| (playing.0, settings.volume))
+            .collect();
+        volumes.sort_by_key(|(effect, _)| *effect == SoundEffect::PlayerFire);
+
+        assert_eq!(volumes[0], (SoundEffect::MarchNote(0), Volume::Linear(0.0)));
+        assert_eq!(
+            volumes[1].1,
+            Volume::Linear(AudioMixer::new().effective_volume(MixerChannel::Effects))
+        );
+    }
+
+    #[test]
+    fn mute_hotkey_toggles_the_master_channel() {
+        let mut app = setup_settings(None);
+
+        get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(MUTE_KEY);
+        app.update();
+
+        let mixer = &app.world().resource::<AudioMixerResource>().0;
+
+        assert!(mixer.get_level(MixerChannel::Master).is_muted());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn mixer_changes_are_saved_and_loaded_on_the_next_start() {
+        let path = temporary_directory("mixer").join(SETTINGS_FILE);
+        let _ = std::fs::remove_file(&path);
+        let mut app = setup_settings(Some(path.clone()));
+
+        get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(MUTE_KEY);
+        app.update();
+
+        let app = setup_settings(Some(path));
+        let mixer = &app.world().resource::<AudioMixerResource>().0;
+
+        assert!(mixer.get_level(MixerChannel::Master).is_muted());
+    }
+
+    #[test]
+    fn one_shot_sounds_are_removed_once_finished() {
+        let mut app = setup_playback();
+
+        send_message(&mut app, PlaySoundMessage(SoundEffect::PlayerFire));
+        app.update();
+
+        assert_eq!(count_components::<SoundLifetimeComponent>(&mut app), 1);
+
+        advance_time_by_seconds(&mut app, 1.0);
+        app.update();
+
+        assert_eq!(count_components::<SoundLifetimeComponent>(&mut app), 0);
+    }
+
+    #[test]
+    fn ufo_loop_plays_once_until_stopped() {
+        let mut app = setup_playback();
+
+        send_message(&mut app, PlaySoundMessage(SoundEffect::UfoLoop));
+        send_message(&mut app, PlaySoundMessage(SoundEffect::UfoLoop));
+        app.update();
+        send_message(&mut app, PlaySoundMessage(SoundEffect::UfoLoop));
+        app.update();
+
+        assert_eq!(count_sounds(&mut app, SoundEffect::UfoLoop), 1);
+
+        send_message(&mut app, StopSoundMessage(SoundEffect::UfoLoop));
+        app.update();
+
+        assert_eq!(count_sounds(&mut app, SoundEffect::UfoLoop), 0);
+    }
+}
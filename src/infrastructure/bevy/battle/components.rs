@@ -0,0 +1,37 @@
+use crate::domain::game_mode::PlayerSlot;
+use bevy::math::Vec2;
+use bevy::prelude::{Bundle, Component, Transform, Visibility};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct ArenaComponent(pub PlayerSlot);
+
+#[derive(Bundle)]
+pub struct ArenaBundle {
+    pub arena: ArenaComponent,
+    pub transform: Transform,
+    pub visibility: Visibility,
+}
+
+impl ArenaBundle {
+    pub fn new(slot: PlayerSlot, origin: Vec2) -> Self {
+        Self {
+            arena: ArenaComponent(slot),
+            transform: Transform::from_xyz(origin.x, origin.y, 0.0),
+            visibility: Visibility::Inherited,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_place_the_arena_at_its_origin() {
+        let bundle = ArenaBundle::new(PlayerSlot::Two, Vec2::new(600.0, 0.0));
+
+        assert_eq!(bundle.arena, ArenaComponent(PlayerSlot::Two));
+        assert_eq!(bundle.transform.translation.x, 600.0);
+        assert_eq!(bundle.visibility, Visibility::Inherited);
+    }
+}
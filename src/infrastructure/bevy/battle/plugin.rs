@@ -0,0 +1,268 @@
+use crate::infrastructure::bevy::battle::resources::BattleStateResource;
+use crate::infrastructure::bevy::battle::systems::{
+    is_battle_stepping, settle_arenas_system, setup_battle_system, spawn_arenas_system,
+    spawn_battle_result_system, step_remote_arena_system,
+};
+use crate::infrastructure::bevy::game_state::resources::{GameState, HudSystems, SoundSystems};
+use crate::infrastructure::bevy::high_scores::systems::spawn_game_over_screen_system;
+use bevy::app::{App, Plugin, PostStartup, PostUpdate, PreStartup, PreUpdate, Update};
+use bevy::input::InputSystems;
+use bevy::prelude::{IntoScheduleConfigs, OnEnter, not};
+use bevy::transform::TransformSystems;
+
+pub struct BattlePlugin;
+
+impl Plugin for BattlePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BattleStateResource>()
+            .configure_sets(Update, HudSystems.run_if(not(is_battle_stepping)))
+            .configure_sets(Update, SoundSystems.run_if(not(is_battle_stepping)))
+            .add_systems(PreStartup, spawn_arenas_system)
+            .add_systems(PostStartup, setup_battle_system)
+            .add_systems(PreUpdate, step_remote_arena_system.after(InputSystems))
+            .add_systems(
+                PostUpdate,
+                settle_arenas_system.before(TransformSystems::Propagate),
+            )
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                spawn_battle_result_system.after(spawn_game_over_screen_system),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::battle::BattleOutcome;
+    use crate::domain::enemy::EnemyId;
+    use crate::domain::game_mode::GameMode;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::lives::Lives;
+    use crate::infrastructure::bevy::audio::resources::SettingsStorageResource;
+    use crate::infrastructure::bevy::battle::components::ArenaComponent;
+    use crate::infrastructure::bevy::battle::resources::{
+        BATTLE_LEFT_CONTROLS, BATTLE_RIGHT_CONTROLS, BattleResource,
+    };
+    use crate::infrastructure::bevy::battle::systems::leaked_resources;
+    use crate::infrastructure::bevy::bevy_renderer::plugin::SpaceInvadersPlugin;
+    use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+    use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationResource;
+    use crate::infrastructure::bevy::game_area::resources::ArenaResource;
+    use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+    use crate::infrastructure::bevy::game_options::resources::GameOptions;
+    use crate::infrastructure::bevy::headless_renderer::plugin::HeadlessPlugin;
+    use crate::infrastructure::bevy::high_scores::resources::HighScoreStorageResource;
+    use crate::infrastructure::bevy::player::components::PlayerComponent;
+    use crate::infrastructure::bevy::player::resources::player_color;
+    use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+    use crate::infrastructure::bevy::score::resources::ScoreResource;
+    use bevy::MinimalPlugins;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::input::ButtonInput;
+    use bevy::prelude::{ChildOf, Entity, KeyCode, Sprite, State, Text, Transform, With};
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test::count_components;
+    use std::time::Duration;
+
+    fn battle_game() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(HighScoreStorageResource { path: None })
+            .insert_resource(SettingsStorageResource { path: None })
+            .add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: GameOptions {
+                    seed: Some(7),
+                    mode: GameMode::Battle,
+                    ..GameOptions::default()
+                },
+            })
+            .add_plugins(SpaceInvadersPlugin);
+        app.update();
+        app
+    }
+
+    fn count_in_arena<T: bevy::prelude::Component>(app: &mut App, arena: Entity) -> usize {
+        app.world_mut()
+            .query_filtered::<&ChildOf, With<T>>()
+            .iter(app.world())
+            .filter(|child_of| child_of.parent() == arena)
+            .count()
+    }
+
+    #[test]
+    fn plugin_builds_an_arena_for_each_player() {
+        let mut app = battle_game();
+        let [one, two] = app.world().resource::<BattleResource>().roots;
+
+        assert_eq!(count_components::<ArenaComponent>(&mut app), 2);
+        assert_eq!(count_in_arena::<PlayerComponent>(&mut app, one), 1);
+        assert_eq!(count_in_arena::<PlayerComponent>(&mut app, two), 1);
+        assert_eq!(count_in_arena::<EnemyComponent>(&mut app, one), 55);
+        assert_eq!(count_in_arena::<EnemyComponent>(&mut app, two), 55);
+    }
+
+    #[test]
+    fn each_arena_has_the_color_of_its_player() {
+        let mut app = battle_game();
+        let [one, two] = app.world().resource::<BattleResource>().roots;
+        let mut colors = app
+            .world_mut()
+            .query_filtered::<(&Sprite, &ChildOf), With<PlayerComponent>>();
+        let mut color_in = |arena: Entity| {
+            colors
+                .iter(app.world())
+                .find(|(_, child_of)| child_of.parent() == arena)
+                .map(|(sprite, _)| sprite.color)
+        };
+
+        assert_eq!(color_in(one), Some(player_color(PlayerSlot::One)));
+        assert_eq!(color_in(two), Some(player_color(PlayerSlot::Two)));
+    }
+
+    #[test]
+    fn shots_are_fired_into_the_arena_of_the_shooter() {
+        let mut app = battle_game();
+        let [one, two] = app.world().resource::<BattleResource>().roots;
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(BATTLE_RIGHT_CONTROLS.fire[0]);
+        app.update();
+
+        assert_eq!(
+            count_in_arena::<PlayerProjectileComponent>(&mut app, one),
+            0
+        );
+        assert_eq!(
+            count_in_arena::<PlayerProjectileComponent>(&mut app, two),
+            1
+        );
+        assert_eq!(
+            app.world().resource::<ArenaResource>().root,
+            one,
+            "the local arena should be active outside the remote step"
+        );
+    }
+
+    fn player_x_in_arena(app: &mut App, arena: Entity) -> f32 {
+        app.world_mut()
+            .query_filtered::<(&Transform, &ChildOf), With<PlayerComponent>>()
+            .iter(app.world())
+            .find(|(_, child_of)| child_of.parent() == arena)
+            .map(|(transform, _)| transform.translation.x)
+            .expect("Arena should have a player")
+    }
+
+    #[test]
+    fn the_arrow_keys_move_the_player_in_the_right_arena() {
+        let mut app = battle_game();
+        let [one, two] = app.world().resource::<BattleResource>().roots;
+        let one_x = player_x_in_arena(&mut app, one);
+        let two_x = player_x_in_arena(&mut app, two);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::ArrowLeft);
+        for _ in 0..5 {
+            app.update();
+        }
+
+        assert_eq!(player_x_in_arena(&mut app, one), one_x);
+        assert!(player_x_in_arena(&mut app, two) < two_x);
+    }
+
+    #[test]
+    fn clearing_a_row_sends_extra_invaders_to_the_opponent() {
+        let mut app = battle_game();
+        for id in 45..=55 {
+            app.world_mut()
+                .resource_mut::<BattleResource>()
+                .remote
+                .formation
+                .kill(EnemyId::new(id));
+        }
+        for id in 45..=55 {
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .kill(EnemyId::new(id));
+        }
+
+        app.update();
+        app.update();
+
+        let battle = app.world().resource::<BattleResource>();
+        assert_eq!(battle.battle.get_attacks_sent(PlayerSlot::One), 1);
+        assert_eq!(battle.remote.formation.cleared_rows(), 0);
+        let [_, two] = battle.roots;
+        assert_eq!(count_in_arena::<EnemyComponent>(&mut app, two), 55);
+    }
+
+    #[test]
+    fn the_last_player_standing_wins() {
+        let mut app = battle_game();
+        app.world_mut()
+            .resource_mut::<BattleResource>()
+            .remote
+            .lives = Lives::with_count(0);
+
+        app.update();
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<BattleResource>().outcome,
+            Some(BattleOutcome::Winner(PlayerSlot::One))
+        );
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::GameOver
+        );
+        assert!(
+            app.world_mut()
+                .query::<&Text>()
+                .iter(app.world())
+                .any(|text| text.0 == "PLAYER 1 WINS")
+        );
+    }
+
+    #[test]
+    fn the_remote_step_only_changes_the_resources_it_swaps() {
+        let mut app = battle_game();
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(BATTLE_RIGHT_CONTROLS.fire[0]);
+        keyboard.press(BATTLE_RIGHT_CONTROLS.left[0]);
+        let world = app.world_mut();
+        let before = world.change_tick();
+
+        world
+            .run_system_once(step_remote_arena_system)
+            .expect("The remote arena should step");
+
+        let leaked = leaked_resources(world, before);
+        assert!(
+            leaked.is_empty(),
+            "resources changed by the remote arena are not swapped: {:?}",
+            leaked
+        );
+    }
+
+    #[test]
+    fn a_long_battle_keeps_the_arenas_apart() {
+        let mut app = battle_game();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            20,
+        )));
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.press(BATTLE_RIGHT_CONTROLS.fire[0]);
+        keyboard.press(BATTLE_LEFT_CONTROLS.fire[0]);
+
+        for _ in 0..600 {
+            app.update();
+        }
+        let battle = app.world().resource::<BattleResource>();
+        assert!(battle.remote.score.get_current() > 0);
+        assert!(app.world().resource::<ScoreResource>().0.get_current() > 0);
+    }
+}
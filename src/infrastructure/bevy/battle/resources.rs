@@ -0,0 +1,253 @@
+use crate::domain::battle::{Battle, BattleOutcome};
+use crate::domain::enemy::EnemyId;
+use crate::domain::enemy_formation::EnemyFormation;
+use crate::domain::lives::Lives;
+use crate::domain::player::Player;
+use crate::domain::score::Score;
+use crate::domain::shield_formation::ShieldFormation;
+use crate::domain::wave::Wave;
+use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+use crate::infrastructure::bevy::enemy::resources::EnemyProjectileMovementTimer;
+use crate::infrastructure::bevy::enemy_formation::components::FormationAdvancedMessage;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationMovementTimer, EnemyFormationResource, EnemyHitFlashesResource, GameRngResource,
+    WaveResource,
+};
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileExpiredMessage, PlayerKilledMessage,
+};
+use crate::infrastructure::bevy::enemy_projectile::resources::{
+    ENEMY_PROJECTILE_SPEED, EnemyProjectileSpeed,
+};
+use crate::infrastructure::bevy::game_area::resources::ArenaResource;
+use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::player::components::{PlayerFiredMessage, PlayerReloadedMessage};
+use crate::infrastructure::bevy::player::resources::{
+    PLAYER_ONE_CONTROLS, PLAYER_TWO_CONTROLS, PlayerControls, PlayerResource,
+};
+use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileExpiredMessage;
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::shield_formation::resources::ShieldFormationResource;
+use bevy::app::AppExit;
+use bevy::asset::Assets;
+use bevy::color::Color;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::message::Messages;
+use bevy::ecs::schedule::Schedules;
+use bevy::image::Image;
+use bevy::input::ButtonInput;
+use bevy::prelude::{DetectChangesMut, Entity, KeyCode, Resource, Timer, World};
+use std::mem::swap;
+
+pub const BATTLE_SHOT_SPEEDUP: f32 = 1.25;
+pub const BATTLE_MAX_SHOT_SPEED: f32 = ENEMY_PROJECTILE_SPEED * 2.0;
+pub const BATTLE_RESULT_COLOR: Color = Color::srgb_u8(64, 200, 255);
+pub const BATTLE_LEFT_CONTROLS: PlayerControls = PLAYER_ONE_CONTROLS;
+pub const BATTLE_RIGHT_CONTROLS: PlayerControls = PLAYER_TWO_CONTROLS;
+
+/// The resources of the arena that is not being simulated right now.
+///
+/// Both arenas run the same gameplay systems against the same global
+/// resources, so the remote arena swaps its copy in for its step and back out
+/// afterwards. Every resource a gameplay system changes during that step must
+/// either be swapped here or be listed by [`shared_resource_ids`]; anything
+/// else would leak from one arena into the other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArenaState {
+    pub lives: Lives,
+    pub score: Score,
+    pub wave: Wave,
+    pub formation: EnemyFormation,
+    pub formation_timer: Timer,
+    pub enemy_fire_timer: Timer,
+    pub players: [Player; 2],
+    pub enemy_projectile_speed: f32,
+    pub shields: ShieldFormation,
+    pub hit_flashes: Vec<(EnemyId, Timer)>,
+}
+
+impl ArenaState {
+    pub fn resource_ids(world: &World) -> Vec<ComponentId> {
+        [
+            world.resource_id::<LivesResource>(),
+            world.resource_id::<ScoreResource>(),
+            world.resource_id::<WaveResource>(),
+            world.resource_id::<EnemyFormationResource>(),
+            world.resource_id::<EnemyFormationMovementTimer>(),
+            world.resource_id::<EnemyProjectileMovementTimer>(),
+            world.resource_id::<PlayerResource>(),
+            world.resource_id::<EnemyProjectileSpeed>(),
+            world.resource_id::<ShieldFormationResource>(),
+            world.resource_id::<EnemyHitFlashesResource>(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+
+    pub fn capture(world: &World) -> Self {
+        Self {
+            lives: world.resource::<LivesResource>().0.clone(),
+            score: world.resource::<ScoreResource>().0.clone(),
+            wave: world.resource::<WaveResource>().0,
+            formation: world.resource::<EnemyFormationResource>().0.clone(),
+            formation_timer: world.resource::<EnemyFormationMovementTimer>().0.clone(),
+            enemy_fire_timer: world.resource::<EnemyProjectileMovementTimer>().0.clone(),
+            players: world.resource::<PlayerResource>().0.clone(),
+            enemy_projectile_speed: world.resource::<EnemyProjectileSpeed>().0,
+            shields: world.resource::<ShieldFormationResource>().0.clone(),
+            hit_flashes: world.resource::<EnemyHitFlashesResource>().0.clone(),
+        }
+    }
+
+    pub fn swap_with(&mut self, world: &mut World) {
+        swap(
+            &mut world
+                .resource_mut::<LivesResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.lives,
+        );
+        swap(
+            &mut world
+                .resource_mut::<ScoreResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.score,
+        );
+        swap(
+            &mut world
+                .resource_mut::<WaveResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.wave,
+        );
+        swap(
+            &mut world
+                .resource_mut::<EnemyFormationResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.formation,
+        );
+        swap(
+            &mut world
+                .resource_mut::<EnemyFormationMovementTimer>()
+                .bypass_change_detection()
+                .0,
+            &mut self.formation_timer,
+        );
+        swap(
+            &mut world
+                .resource_mut::<EnemyProjectileMovementTimer>()
+                .bypass_change_detection()
+                .0,
+            &mut self.enemy_fire_timer,
+        );
+        swap(
+            &mut world
+                .resource_mut::<PlayerResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.players,
+        );
+        swap(
+            &mut world
+                .resource_mut::<EnemyProjectileSpeed>()
+                .bypass_change_detection()
+                .0,
+            &mut self.enemy_projectile_speed,
+        );
+        swap(
+            &mut world
+                .resource_mut::<ShieldFormationResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.shields,
+        );
+        swap(
+            &mut world
+                .resource_mut::<EnemyHitFlashesResource>()
+                .bypass_change_detection()
+                .0,
+            &mut self.hit_flashes,
+        );
+    }
+}
+
+#[derive(Resource)]
+pub struct BattleResource {
+    pub battle: Battle,
+    pub remote: ArenaState,
+    pub fresh: ArenaState,
+    pub roots: [Entity; 2],
+    pub waves: [Wave; 2],
+    pub rows: [usize; 2],
+    pub dirty: [bool; 2],
+    pub outcome: Option<BattleOutcome>,
+}
+
+#[derive(Resource, Default)]
+pub struct BattleStateResource {
+    pub stepping: bool,
+}
+
+pub fn shared_resource_ids(world: &World) -> Vec<ComponentId> {
+    [
+        world.resource_id::<Schedules>(),
+        world.resource_id::<ButtonInput<KeyCode>>(),
+        world.resource_id::<BattleStateResource>(),
+        world.resource_id::<ArenaResource>(),
+        world.resource_id::<Assets<Image>>(),
+        world.resource_id::<GameRngResource>(),
+        world.resource_id::<Messages<AppExit>>(),
+        world.resource_id::<Messages<PlayerFiredMessage>>(),
+        world.resource_id::<Messages<PlayerReloadedMessage>>(),
+        world.resource_id::<Messages<PlayerProjectileExpiredMessage>>(),
+        world.resource_id::<Messages<PlayerKilledMessage>>(),
+        world.resource_id::<Messages<EnemyKilledMessage>>(),
+        world.resource_id::<Messages<EnemyDamagedMessage>>(),
+        world.resource_id::<Messages<EnemyProjectileExpiredMessage>>(),
+        world.resource_id::<Messages<FormationAdvancedMessage>>(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+pub fn faster_shot_speed(speed: f32) -> f32 {
+    (speed * BATTLE_SHOT_SPEEDUP).min(BATTLE_MAX_SHOT_SPEED)
+}
+
+pub fn battle_result_text(outcome: BattleOutcome) -> String {
+    match outcome {
+        BattleOutcome::Winner(slot) => format!("PLAYER {} WINS", slot.number()),
+        BattleOutcome::Draw => "DRAW".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::game_mode::PlayerSlot;
+
+    #[test]
+    fn faster_shots_are_capped() {
+        assert_eq!(
+            faster_shot_speed(ENEMY_PROJECTILE_SPEED),
+            ENEMY_PROJECTILE_SPEED * BATTLE_SHOT_SPEEDUP
+        );
+        assert_eq!(
+            faster_shot_speed(BATTLE_MAX_SHOT_SPEED),
+            BATTLE_MAX_SHOT_SPEED
+        );
+    }
+
+    #[test]
+    fn the_result_names_the_winner() {
+        assert_eq!(
+            battle_result_text(BattleOutcome::Winner(PlayerSlot::Two)),
+            "PLAYER 2 WINS"
+        );
+        assert_eq!(battle_result_text(BattleOutcome::Draw), "DRAW");
+    }
+}
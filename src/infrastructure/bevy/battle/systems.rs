@@ -0,0 +1,365 @@
+use crate::domain::battle::{Battle, BattleAttack, battle_outcome};
+use crate::domain::game_mode::PlayerSlot;
+use crate::infrastructure::bevy::battle::components::{ArenaBundle, ArenaComponent};
+use crate::infrastructure::bevy::battle::resources::{
+    ArenaState, BATTLE_LEFT_CONTROLS, BATTLE_RESULT_COLOR, BATTLE_RIGHT_CONTROLS, BattleResource,
+    BattleStateResource, battle_result_text, faster_shot_speed, shared_resource_ids,
+};
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, WaveResource,
+};
+use crate::infrastructure::bevy::enemy_formation::systems::spawn_enemy_formation_system;
+use crate::infrastructure::bevy::enemy_projectile::resources::EnemyProjectileSpeed;
+use crate::infrastructure::bevy::footer::systems::spawn_footer_system;
+use crate::infrastructure::bevy::game_area::resources::{ArenaLayoutResource, ArenaResource};
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::game_state::systems::is_game_over;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::high_scores::components::{
+    GameOverScreenComponent, ScreenTextBundle,
+};
+use crate::infrastructure::bevy::high_scores::resources::SCREEN_TITLE_FONT_SIZE;
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
+use crate::infrastructure::bevy::player::systems::spawn_player_system;
+use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
+use crate::infrastructure::bevy::shield_formation::systems::spawn_shields_system;
+use bevy::asset::AssetServer;
+use bevy::ecs::change_detection::Tick;
+use bevy::ecs::component::ComponentId;
+use bevy::ecs::entity_disabling::Disabled;
+use bevy::ecs::message::{MessageCursor, Messages};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Children, Commands, DetectChanges, DetectChangesMut, Entity, KeyCode, Local, Mut, NextState,
+    Query, Res, State, Update, With, World,
+};
+use tracing::{error, info};
+
+pub fn spawn_arenas_system(mut commands: Commands, layout: Option<Res<ArenaLayoutResource>>) {
+    let layout = layout.map(|layout| layout.0.clone()).unwrap_or_default();
+    let roots = PlayerSlot::ALL.map(|slot| {
+        commands
+            .spawn(ArenaBundle::new(slot, layout.get_origin(slot.index())))
+            .id()
+    });
+
+    commands.insert_resource(ArenaResource {
+        root: roots[PlayerSlot::One.index()],
+        slot: PlayerSlot::One,
+    });
+}
+
+pub fn setup_battle_system(world: &mut World) {
+    let mut roots = [Entity::PLACEHOLDER; 2];
+    for (entity, arena) in world.query::<(Entity, &ArenaComponent)>().iter(world) {
+        roots[arena.0.index()] = entity;
+    }
+
+    let fresh = ArenaState::capture(world);
+    let mut remote = fresh.clone();
+    build_arena(world, &mut remote, roots);
+
+    world.insert_resource(BattleResource {
+        battle: Battle::new(),
+        waves: [fresh.wave; 2],
+        rows: [fresh.formation.populated_rows(); 2],
+        remote,
+        fresh,
+        roots,
+        dirty: [false; 2],
+        outcome: None,
+    });
+}
+
+pub fn step_remote_arena_system(
+    world: &mut World,
+    mut new_game_cursor: Local<MessageCursor<NewGameMessage>>,
+) {
+    let new_game = world
+        .get_resource::<Messages<NewGameMessage>>()
+        .is_some_and(|messages| new_game_cursor.read(messages).count() > 0);
+    let playing = is_playing(world);
+
+    world.try_resource_scope(|world, mut battle: Mut<BattleResource>| {
+        if new_game {
+            restart_battle(world, &mut battle);
+        }
+
+        if playing {
+            step_remote_arena(world, &mut battle);
+        }
+
+        world
+            .entity_mut(battle.roots[PlayerSlot::Two.index()])
+            .insert_recursive::<Children>(Disabled);
+    });
+}
+
+pub fn settle_arenas_system(world: &mut World) {
+    let playing = is_playing(world);
+
+    world.try_resource_scope(|world, mut battle: Mut<BattleResource>| {
+        world
+            .entity_mut(battle.roots[PlayerSlot::Two.index()])
+            .remove_recursive::<Children, Disabled>();
+
+        if !playing || battle.outcome.is_some() {
+            return;
+        }
+
+        let local_wave = world.resource::<WaveResource>().0;
+        let local_formation = &world.resource::<EnemyFormationResource>().0;
+        let local_rows = (
+            local_formation.cleared_rows(),
+            local_formation.populated_rows(),
+        );
+        let remote_wave = battle.remote.wave;
+        let remote_rows = (
+            battle.remote.formation.cleared_rows(),
+            battle.remote.formation.populated_rows(),
+        );
+
+        for (side, wave, (cleared, rows)) in [
+            (PlayerSlot::One, local_wave, local_rows),
+            (PlayerSlot::Two, remote_wave, remote_rows),
+        ] {
+            let mut attacks = Vec::new();
+
+            if battle.waves[side.index()] != wave {
+                battle.waves[side.index()] = wave;
+                let previous_rows = battle.rows[side.index()];
+                attacks.extend(battle.battle.track_wave_cleared(side, previous_rows));
+            }
+            battle.rows[side.index()] = rows;
+            attacks.extend(battle.battle.track_cleared_rows(side, cleared));
+
+            for attack in attacks {
+                launch_attack(world, &mut battle, side.other(), attack);
+            }
+        }
+
+        let one_lost = is_game_over(
+            &world.resource::<LivesResource>().0,
+            &world.resource::<EnemyFormationResource>().0,
+        );
+        let two_lost = is_game_over(&battle.remote.lives, &battle.remote.formation);
+
+        if let Some(outcome) = battle_outcome(one_lost, two_lost) {
+            info!("Battle over: {}", battle_result_text(outcome));
+            battle.outcome = Some(outcome);
+            world
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::GameOver);
+        }
+    });
+}
+
+pub fn spawn_battle_result_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    battle: Option<Res<BattleResource>>,
+    screen_query: Query<Entity, With<GameOverScreenComponent>>,
+) {
+    let Some(outcome) = battle.and_then(|battle| battle.outcome) else {
+        return;
+    };
+
+    if let Ok(screen) = screen_query.single() {
+        let result = commands
+            .spawn(ScreenTextBundle::new(
+                battle_result_text(outcome),
+                asset_server.load(FONT),
+                SCREEN_TITLE_FONT_SIZE,
+                BATTLE_RESULT_COLOR,
+            ))
+            .id();
+
+        commands.entity(screen).insert_children(1, &[result]);
+    }
+}
+
+pub fn is_battle_stepping(state: Res<BattleStateResource>) -> bool {
+    state.stepping
+}
+
+pub fn remap_remote_controls(keyboard: &ButtonInput<KeyCode>) -> ButtonInput<KeyCode> {
+    let mut remapped = ButtonInput::default();
+
+    for (from, to) in [
+        (BATTLE_RIGHT_CONTROLS.left, BATTLE_LEFT_CONTROLS.left),
+        (BATTLE_RIGHT_CONTROLS.right, BATTLE_LEFT_CONTROLS.right),
+        (BATTLE_RIGHT_CONTROLS.fire, BATTLE_LEFT_CONTROLS.fire),
+    ] {
+        if keyboard.any_pressed(from.iter().copied()) {
+            remapped.press(to[0]);
+        }
+    }
+
+    remapped
+}
+
+fn is_playing(world: &World) -> bool {
+    world
+        .get_resource::<State<GameState>>()
+        .is_some_and(|state| *state.get() == GameState::Playing)
+}
+
+fn step_remote_arena(world: &mut World, battle: &mut BattleResource) {
+    let one = battle.roots[PlayerSlot::One.index()];
+    world.entity_mut(one).insert_recursive::<Children>(Disabled);
+
+    battle.remote.swap_with(world);
+    enter_arena(world, battle.roots, PlayerSlot::Two);
+    if battle.dirty[PlayerSlot::Two.index()] {
+        world.resource_mut::<EnemyFormationResource>().set_changed();
+    }
+
+    let keyboard = world.resource::<ButtonInput<KeyCode>>().clone();
+    *world.resource_mut::<ButtonInput<KeyCode>>() = remap_remote_controls(&keyboard);
+    world.resource_mut::<BattleStateResource>().stepping = true;
+
+    let step_start = world.change_tick();
+    world.run_schedule(Update);
+
+    world.resource_mut::<BattleStateResource>().stepping = false;
+    *world.resource_mut::<ButtonInput<KeyCode>>() = keyboard;
+
+    if cfg!(debug_assertions) {
+        let leaked = leaked_resources(world, step_start);
+        debug_assert!(
+            leaked.is_empty(),
+            "resources changed by the remote arena are not swapped: {:?}",
+            leaked
+        );
+    }
+
+    enter_arena(world, battle.roots, PlayerSlot::One);
+    let lives = world.resource::<LivesResource>().0.clone();
+    let score = world.resource::<ScoreResource>().0.clone();
+    battle.remote.swap_with(world);
+
+    if world.resource::<SecondPlayerLivesResource>().0.as_ref() != Some(&lives) {
+        world.resource_mut::<SecondPlayerLivesResource>().0 = Some(lives);
+    }
+    if world.resource::<SecondPlayerScoreResource>().0.as_ref() != Some(&score) {
+        world.resource_mut::<SecondPlayerScoreResource>().0 = Some(score);
+    }
+    if battle.dirty[PlayerSlot::One.index()] {
+        world.resource_mut::<EnemyFormationResource>().set_changed();
+    }
+    battle.dirty = [false; 2];
+
+    world
+        .entity_mut(one)
+        .remove_recursive::<Children, Disabled>();
+}
+
+pub fn leaked_resources(world: &mut World, since: Tick) -> Vec<String> {
+    let expected = [ArenaState::resource_ids(world), shared_resource_ids(world)].concat();
+    let now = world.change_tick();
+    let changed: Vec<ComponentId> = world
+        .iter_resources()
+        .map(|(info, _)| info.id())
+        .filter(|id| !expected.contains(id))
+        .filter(|id| {
+            world
+                .get_resource_change_ticks_by_id(*id)
+                .is_some_and(|ticks| ticks.is_changed(since, now))
+        })
+        .collect();
+
+    let mut leaked = Vec::new();
+    for id in changed {
+        if let Some(resource) = world.get_resource_mut_by_id(id) {
+            leaked.push(resource.changed_by().to_string());
+        }
+    }
+
+    leaked
+}
+
+fn restart_battle(world: &mut World, battle: &mut BattleResource) {
+    world
+        .entity_mut(battle.roots[PlayerSlot::Two.index()])
+        .despawn_related::<Children>();
+
+    battle.remote = battle.fresh.clone();
+    build_arena(world, &mut battle.remote, battle.roots);
+
+    world.resource_mut::<EnemyProjectileSpeed>().0 = battle.fresh.enemy_projectile_speed;
+    battle.battle.reset();
+    battle.waves = [battle.fresh.wave; 2];
+    battle.rows = [battle.fresh.formation.populated_rows(); 2];
+    battle.dirty = [true, false];
+    battle.outcome = None;
+}
+
+fn build_arena(world: &mut World, state: &mut ArenaState, roots: [Entity; 2]) {
+    state.swap_with(world);
+    enter_arena(world, roots, PlayerSlot::Two);
+
+    let spawned = [
+        world.run_system_once(spawn_player_system),
+        world.run_system_once(spawn_enemy_formation_system),
+        world.run_system_once(spawn_shields_system),
+        world.run_system_once(spawn_footer_system),
+    ];
+    if spawned.iter().any(Result::is_err) {
+        error!("Cannot build the second arena");
+    }
+
+    enter_arena(world, roots, PlayerSlot::One);
+    state.swap_with(world);
+}
+
+fn enter_arena(world: &mut World, roots: [Entity; 2], slot: PlayerSlot) {
+    world.insert_resource(ArenaResource {
+        root: roots[slot.index()],
+        slot,
+    });
+}
+
+fn launch_attack(
+    world: &mut World,
+    battle: &mut BattleResource,
+    target: PlayerSlot,
+    attack: BattleAttack,
+) {
+    let reinforced = attack == BattleAttack::ExtraInvaders
+        && match target {
+            PlayerSlot::One => world
+                .resource_mut::<EnemyFormationResource>()
+                .bypass_change_detection()
+                .0
+                .reinforce(),
+            PlayerSlot::Two => battle.remote.formation.reinforce(),
+        };
+
+    if reinforced {
+        battle.dirty[target.index()] = true;
+        info!(
+            "Player {} sends extra invaders to player {}",
+            target.other().number(),
+            target.number()
+        );
+        return;
+    }
+
+    match target {
+        PlayerSlot::One => {
+            let mut speed = world.resource_mut::<EnemyProjectileSpeed>();
+            speed.0 = faster_shot_speed(speed.0);
+        }
+        PlayerSlot::Two => {
+            battle.remote.enemy_projectile_speed =
+                faster_shot_speed(battle.remote.enemy_projectile_speed);
+        }
+    }
+    info!(
+        "Player {} speeds up the shots of player {}",
+        target.other().number(),
+        target.number()
+    );
+}
@@ -16,12 +16,16 @@ pub struct CameraBundle {
 
 impl CameraBundle {
     pub fn new() -> Self {
+        Self::with_width(WINDOW_WIDTH)
+    }
+
+    pub fn with_width(min_width: f32) -> Self {
         Self {
             camera: CameraComponent,
             camera_2d: Camera2d,
             projection: Projection::from(OrthographicProjection {
                 scaling_mode: ScalingMode::AutoMin {
-                    min_width: WINDOW_WIDTH,
+                    min_width,
                     min_height: WINDOW_HEIGHT,
                 },
                 ..OrthographicProjection::default_2d()
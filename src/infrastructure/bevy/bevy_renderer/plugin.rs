@@ -1,26 +1,38 @@
-use crate::infrastructure::bevy::bevy_renderer::resources::{
-    WINDOW_HEIGHT, WINDOW_NAME, WINDOW_WIDTH,
-};
+use crate::infrastructure::bevy::audio::plugin::SoundPlugin;
+use crate::infrastructure::bevy::bevy_renderer::resources::WINDOW_NAME;
 use crate::infrastructure::bevy::bevy_renderer::systems::{camera_system, window_scale_system};
+use crate::infrastructure::bevy::difficulty::plugin::DifficultyPlugin;
 use crate::infrastructure::bevy::enemy_formation::plugin::EnemyFormationPlugin;
 use crate::infrastructure::bevy::enemy_projectile::plugin::EnemyProjectilePlugin;
 use crate::infrastructure::bevy::footer::plugin::FooterPlugin;
 use crate::infrastructure::bevy::game_area::plugin::GameAreaPlugin;
+use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+use crate::infrastructure::bevy::game_options::resources::GameOptions;
+use crate::infrastructure::bevy::game_state::plugin::GameStatePlugin;
 use crate::infrastructure::bevy::header::plugin::HeaderPlugin;
+use crate::infrastructure::bevy::high_scores::plugin::HighScoresPlugin;
 use crate::infrastructure::bevy::lives::plugin::LivesPlugin;
 use crate::infrastructure::bevy::player::plugin::PlayerPlugin;
 use crate::infrastructure::bevy::player_projectile::plugin::PlayerProjectilePlugin;
+use crate::infrastructure::bevy::save_game::plugin::SaveGamePlugin;
 use crate::infrastructure::bevy::score::plugin::ScorePlugin;
+use crate::infrastructure::bevy::shield::plugin::ShieldPlugin;
 use crate::infrastructure::bevy::shield_formation::plugin::ShieldFormationPlugin;
+use crate::infrastructure::bevy::tunables::plugin::TunablesPlugin;
+use crate::infrastructure::bevy::turns::plugin::TurnsPlugin;
 use crate::infrastructure::renderer::Renderer;
 use bevy::DefaultPlugins;
 use bevy::app::{App, Plugin, PluginGroup, PostUpdate, Startup};
 use bevy::log::LogPlugin;
 use bevy::prelude::*;
 use bevy::utils::default;
-use bevy::window::{PresentMode, Window, WindowPlugin, WindowResolution};
+use bevy::window::{
+    MonitorSelection, PresentMode, Window, WindowMode, WindowPlugin, WindowResolution,
+};
 
-pub struct BevyRenderer;
+pub struct BevyRenderer {
+    options: GameOptions,
+}
 
 pub struct SpaceInvadersPlugin;
 
@@ -39,6 +51,16 @@ impl Plugin for SpaceInvadersPlugin {
                 FooterPlugin,
                 EnemyProjectilePlugin,
                 PlayerProjectilePlugin,
+                TunablesPlugin,
+                GameStatePlugin,
+                (
+                    HighScoresPlugin,
+                    SaveGamePlugin,
+                    SoundPlugin,
+                    TurnsPlugin,
+                    DifficultyPlugin,
+                    ShieldPlugin,
+                ),
             ));
     }
 }
@@ -46,24 +68,35 @@ impl Plugin for SpaceInvadersPlugin {
 impl Renderer for BevyRenderer {
     fn render(&self) {
         App::new()
-            .add_plugins(Self::window_plugin_config())
+            .add_plugins(self.window_plugin_config())
+            .add_plugins(GameOptionsPlugin {
+                options: self.options.clone(),
+            })
             .add_plugins(SpaceInvadersPlugin)
             .run();
     }
 }
 
 impl BevyRenderer {
-    pub fn new() -> Self {
-        Self
+    pub fn new(options: GameOptions) -> Self {
+        Self { options }
     }
 
-    fn window_plugin_config() -> impl PluginGroup {
+    fn window_plugin_config(&self) -> impl PluginGroup {
+        let (width, height) = self.options.window_size;
+        let mode = if self.options.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        };
+
         DefaultPlugins
             .set(WindowPlugin {
                 primary_window: Some(Window {
-                    resolution: WindowResolution::new(WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32),
+                    resolution: WindowResolution::new(width, height),
                     title: WINDOW_NAME.to_string(),
                     present_mode: PresentMode::Fifo,
+                    mode,
                     ..default()
                 }),
                 ..default()
@@ -74,7 +107,7 @@ impl BevyRenderer {
 
 impl Default for BevyRenderer {
     fn default() -> Self {
-        Self::new()
+        Self::new(GameOptions::default())
     }
 }
 
@@ -1,9 +1,12 @@
 use crate::infrastructure::bevy::bevy_renderer::components::CameraBundle;
 use crate::infrastructure::bevy::bevy_renderer::resources::{WINDOW_HEIGHT, WINDOW_WIDTH};
-use bevy::prelude::{Changed, Commands, Query, ResMut, UiScale, Window};
+use crate::infrastructure::bevy::game_area::resources::ArenaLayoutResource;
+use bevy::prelude::{Changed, Commands, Query, Res, ResMut, UiScale, Window};
 
-pub fn camera_system(mut commands: Commands) {
-    commands.spawn(CameraBundle::new());
+pub fn camera_system(mut commands: Commands, layout: Option<Res<ArenaLayoutResource>>) {
+    let width = layout.map_or(WINDOW_WIDTH, |layout| layout.0.width());
+
+    commands.spawn(CameraBundle::with_width(width));
 }
 
 pub fn window_scale_system(
@@ -26,6 +29,9 @@ mod tests {
     mod camera_system {
         use super::*;
         use crate::infrastructure::bevy::bevy_renderer::components::CameraComponent;
+        use crate::infrastructure::bevy::game_area::resources::ArenaLayout;
+        use bevy::camera::ScalingMode;
+        use bevy::prelude::Projection;
         use bevy_test::{TestAppBuilder, contains_single_component};
 
         #[test]
@@ -40,6 +46,29 @@ mod tests {
 
             assert!(contains_single_component::<CameraComponent>(&mut app));
         }
+
+        #[test]
+        fn should_fit_every_arena_in_view() {
+            let mut app = TestAppBuilder::new()
+                .without_auto_update()
+                .with_setup(|app| {
+                    app.insert_resource(ArenaLayoutResource(ArenaLayout::side_by_side(2)))
+                        .add_systems(Startup, camera_system);
+                    app.update();
+                })
+                .build();
+
+            let mut query = app.world_mut().query::<&Projection>();
+            let projection = query.single(app.world()).expect("Camera not found");
+
+            let Projection::Orthographic(orthographic_projection) = projection else {
+                panic!("Expected an orthographic projection");
+            };
+            assert!(matches!(
+                orthographic_projection.scaling_mode,
+                ScalingMode::AutoMin { min_width, .. } if min_width == WINDOW_WIDTH * 2.0
+            ));
+        }
     }
 
     #[cfg(test)]
@@ -0,0 +1,174 @@
+use crate::domain::boss::BossPart;
+use crate::domain::enemy::EnemyId;
+use crate::domain::game_mode::PlayerSlot;
+use crate::infrastructure::bevy::boss::resources::{
+    BOSS_HEALTH_BAR_BACKGROUND, BOSS_HEALTH_BAR_COLOR, BOSS_HEALTH_BAR_HEIGHT,
+    BOSS_HEALTH_BAR_WIDTH, BOSS_LASER_DURATION, BOSS_LASER_WARNING, BOSS_LASER_WARNING_COLOR,
+    BOSS_LASER_WARNING_WIDTH, BOSS_PART_HEIGHT, BOSS_PART_WIDTH, MINION_COLOR, MINION_HEIGHT,
+    MINION_WIDTH, boss_health_bar_y, boss_part_color,
+};
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use bevy::math::Vec2;
+use bevy::prelude::{Bundle, Component, Message, Sprite, Timer, TimerMode, Transform, default};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct BossPartComponent {
+    pub id: EnemyId,
+}
+
+#[derive(Bundle)]
+pub struct BossPartBundle {
+    pub part: BossPartComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl BossPartBundle {
+    pub fn new(part: &BossPart, position: Vec2) -> Self {
+        Self {
+            part: BossPartComponent { id: part.get_id() },
+            sprite: Sprite {
+                color: boss_part_color(part),
+                custom_size: Some(Vec2::new(BOSS_PART_WIDTH, BOSS_PART_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(position.x, position.y, 0.0),
+        }
+    }
+}
+
+#[derive(Component, PartialEq, Debug)]
+pub struct BossHealthBarComponent;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct BossHealthFillComponent;
+
+#[derive(Bundle)]
+pub struct BossHealthBarBundle {
+    pub bar: BossHealthBarComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+#[derive(Bundle)]
+pub struct BossHealthFillBundle {
+    pub bar: BossHealthBarComponent,
+    pub fill: BossHealthFillComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl BossHealthBarBundle {
+    pub fn new() -> Self {
+        Self {
+            bar: BossHealthBarComponent,
+            sprite: Sprite {
+                color: BOSS_HEALTH_BAR_BACKGROUND,
+                custom_size: Some(Vec2::new(BOSS_HEALTH_BAR_WIDTH, BOSS_HEALTH_BAR_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, boss_health_bar_y(), 0.0),
+        }
+    }
+}
+
+impl BossHealthFillBundle {
+    pub fn new() -> Self {
+        Self {
+            bar: BossHealthBarComponent,
+            fill: BossHealthFillComponent,
+            sprite: Sprite {
+                color: BOSS_HEALTH_BAR_COLOR,
+                custom_size: Some(Vec2::new(BOSS_HEALTH_BAR_WIDTH, BOSS_HEALTH_BAR_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(0.0, boss_health_bar_y(), 1.0),
+        }
+    }
+}
+
+impl Default for BossHealthBarBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for BossHealthFillBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Component, PartialEq, Debug)]
+pub struct BossShotDriftComponent(pub f32);
+
+#[derive(Component, Debug)]
+pub struct BossLaserComponent {
+    pub part: EnemyId,
+    pub warning: Timer,
+    pub beam: Timer,
+}
+
+#[derive(Bundle)]
+pub struct BossLaserBundle {
+    pub laser: BossLaserComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl BossLaserBundle {
+    pub fn new(part: EnemyId, x: f32, top: f32) -> Self {
+        let bottom = -GAME_AREA_HEIGHT / 2.0;
+
+        Self {
+            laser: BossLaserComponent {
+                part,
+                warning: Timer::from_seconds(BOSS_LASER_WARNING, TimerMode::Once),
+                beam: Timer::from_seconds(BOSS_LASER_DURATION, TimerMode::Once),
+            },
+            sprite: Sprite {
+                color: BOSS_LASER_WARNING_COLOR,
+                custom_size: Some(Vec2::new(BOSS_LASER_WARNING_WIDTH, top - bottom)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, (top + bottom) / 2.0, 0.0),
+        }
+    }
+}
+
+#[derive(Component, PartialEq, Debug)]
+pub struct BossMinionComponent {
+    pub id: EnemyId,
+}
+
+#[derive(Bundle)]
+pub struct BossMinionBundle {
+    pub minion: BossMinionComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl BossMinionBundle {
+    pub fn new(id: EnemyId, x: f32, y: f32) -> Self {
+        Self {
+            minion: BossMinionComponent { id },
+            sprite: Sprite {
+                color: MINION_COLOR,
+                custom_size: Some(Vec2::new(MINION_WIDTH, MINION_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, y, 0.0),
+        }
+    }
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct BossDefeatedMessage {
+    pub player: PlayerSlot,
+}
+
+impl BossDefeatedMessage {
+    pub fn new(player: PlayerSlot) -> Self {
+        Self { player }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::infrastructure::bevy::boss::components::BossDefeatedMessage;
+use crate::infrastructure::bevy::boss::resources::{BossResource, BossScheduleResource};
+use crate::infrastructure::bevy::boss::systems::{
+    boss_attack_system, boss_collisions_system, boss_laser_system, boss_minion_system,
+    boss_shot_drift_system, despawn_shot_minions_system, reset_boss_system, start_boss_wave_system,
+    sync_boss_parts_system, update_boss_health_bar_system,
+};
+use crate::infrastructure::bevy::enemy_formation::systems::{
+    enemy_formation_movement_system, next_wave_system, reset_enemy_formation_system,
+};
+use crate::infrastructure::bevy::enemy_projectile::systems::on_enemy_projectile_hitting_player_system;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::power_up::systems::drop_power_ups_system;
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::IntoScheduleConfigs;
+
+pub struct BossPlugin {
+    pub interval: u32,
+}
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossResource>()
+            .insert_resource(BossScheduleResource(self.interval))
+            .add_message::<BossDefeatedMessage>()
+            .add_systems(
+                Update,
+                (
+                    start_boss_wave_system
+                        .after(next_wave_system)
+                        .after(reset_enemy_formation_system)
+                        .before(enemy_formation_movement_system),
+                    boss_attack_system.after(start_boss_wave_system),
+                    boss_collisions_system.after(start_boss_wave_system),
+                    sync_boss_parts_system
+                        .after(boss_attack_system)
+                        .after(boss_collisions_system)
+                        .after(drop_power_ups_system),
+                    despawn_shot_minions_system
+                        .after(boss_collisions_system)
+                        .after(drop_power_ups_system),
+                    update_boss_health_bar_system.after(sync_boss_parts_system),
+                    boss_shot_drift_system,
+                    boss_laser_system.before(on_enemy_projectile_hitting_player_system),
+                    boss_minion_system.before(on_enemy_projectile_hitting_player_system),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_boss_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_formation::plugin::EnemyFormationPlugin;
+    use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(EnemyFormationPlugin)
+            .with_plugin(BossPlugin { interval: 5 })
+            .with_message::<NewGameMessage>()
+            .with_message::<PlayerKilledMessage>()
+            .build();
+    }
+}
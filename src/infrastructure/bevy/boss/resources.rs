@@ -0,0 +1,66 @@
+use crate::domain::boss::{Boss, BossPart, BossPartKind};
+use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::header::resources::HEADER_HEIGHT;
+use bevy::color::Color;
+use bevy::math::Vec2;
+use bevy::prelude::Resource;
+
+pub const BOSS_PART_WIDTH: f32 = 56.0;
+pub const BOSS_PART_HEIGHT: f32 = 32.0;
+pub const BOSS_PART_GAP: f32 = 4.0;
+pub const BOSS_TOP_MARGIN: f32 = 70.0;
+pub const BOSS_HEALTH_BAR_WIDTH: f32 = 300.0;
+pub const BOSS_HEALTH_BAR_HEIGHT: f32 = 8.0;
+pub const BOSS_HEALTH_BAR_MARGIN: f32 = 16.0;
+pub const BOSS_HEALTH_BAR_BACKGROUND: Color = Color::srgb(0.25, 0.25, 0.25);
+pub const BOSS_HEALTH_BAR_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+pub const SPREAD_SHOTS: usize = 5;
+pub const SPREAD_DRIFT: f32 = 150.0;
+pub const BOSS_LASER_WIDTH: f32 = 12.0;
+pub const BOSS_LASER_WARNING_WIDTH: f32 = 2.0;
+pub const BOSS_LASER_WARNING: f32 = 0.7;
+pub const BOSS_LASER_DURATION: f32 = 0.8;
+pub const BOSS_LASER_WARNING_COLOR: Color = Color::srgba(1.0, 0.3, 0.3, 0.5);
+pub const BOSS_LASER_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+pub const MINION_WIDTH: f32 = 24.0;
+pub const MINION_HEIGHT: f32 = 16.0;
+pub const MINION_SPEED: f32 = 120.0;
+pub const MINION_HOMING_SPEED: f32 = 60.0;
+pub const MINION_COLOR: Color = Color::srgb(0.9, 0.5, 1.0);
+
+#[derive(Resource, Default)]
+pub struct BossResource(pub Option<Boss>);
+
+#[derive(Resource)]
+pub struct BossScheduleResource(pub u32);
+
+pub fn boss_part_color(part: &BossPart) -> Color {
+    match (part.get_kind(), part.is_damaged()) {
+        (BossPartKind::Core, _) => Color::srgb(1.0, 0.85, 0.1),
+        (BossPartKind::Turret, false) => Color::srgb(0.3, 0.8, 1.0),
+        (BossPartKind::LaserCannon, false) => Color::srgb(1.0, 0.4, 0.4),
+        (BossPartKind::Hangar, false) => Color::srgb(0.7, 0.4, 1.0),
+        (_, true) => Color::srgb(1.0, 0.6, 0.6),
+    }
+}
+
+pub fn boss_part_translation(boss: &Boss, part: &BossPart) -> Vec2 {
+    let columns = boss
+        .get_parts()
+        .iter()
+        .map(|part| part.get_column().abs())
+        .max()
+        .unwrap_or(0) as f32;
+    let half_width = (columns + 0.5) * (BOSS_PART_WIDTH + BOSS_PART_GAP);
+    let sway_range = (GAME_AREA_WIDTH / 2.0 - half_width).max(0.0);
+    let top = GAME_AREA_HEIGHT / 2.0 - HEADER_HEIGHT - BOSS_TOP_MARGIN;
+
+    Vec2::new(
+        boss.get_sway() * sway_range + part.get_column() as f32 * (BOSS_PART_WIDTH + BOSS_PART_GAP),
+        top - part.get_row() as f32 * (BOSS_PART_HEIGHT + BOSS_PART_GAP),
+    )
+}
+
+pub fn boss_health_bar_y() -> f32 {
+    GAME_AREA_HEIGHT / 2.0 - HEADER_HEIGHT - BOSS_HEALTH_BAR_MARGIN
+}
@@ -0,0 +1,789 @@
+use crate::domain::boss::{Boss, BossAttackKind, BossHit, boss_encounter};
+use crate::domain::collision::check_aabb_collision;
+use crate::domain::enemy_formation::EnemyFormation;
+use crate::infrastructure::bevy::boss::components::{
+    BossDefeatedMessage, BossHealthBarBundle, BossHealthBarComponent, BossHealthFillBundle,
+    BossHealthFillComponent, BossLaserBundle, BossLaserComponent, BossMinionBundle,
+    BossMinionComponent, BossPartBundle, BossPartComponent, BossShotDriftComponent,
+};
+use crate::infrastructure::bevy::boss::resources::{
+    BOSS_HEALTH_BAR_WIDTH, BOSS_LASER_COLOR, BOSS_LASER_WIDTH, BOSS_PART_HEIGHT, BossResource,
+    BossScheduleResource, MINION_HEIGHT, MINION_HOMING_SPEED, MINION_SPEED, SPREAD_DRIFT,
+    SPREAD_SHOTS, boss_part_color, boss_part_translation,
+};
+use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, WaveResource,
+};
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileBundle, PlayerKilledMessage,
+};
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+use bevy::prelude::*;
+use tracing::info;
+
+type BossRemnantFilter = Or<(With<BossLaserComponent>, With<BossMinionComponent>)>;
+
+pub fn start_boss_wave_system(
+    wave_res: Res<WaveResource>,
+    schedule: Res<BossScheduleResource>,
+    mut boss_res: ResMut<BossResource>,
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+) {
+    if !wave_res.is_changed() {
+        return;
+    }
+
+    match boss_encounter(wave_res.0, schedule.0) {
+        Some(encounter) => {
+            boss_res.0 = Some(Boss::new(encounter));
+            enemy_formation_res.0 = EnemyFormation::cleared();
+
+            info!("Boss incoming on wave {}", wave_res.0.get_number());
+        }
+        None if boss_res.0.is_some() => boss_res.0 = None,
+        None => {}
+    }
+}
+
+pub fn boss_attack_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut boss_res: ResMut<BossResource>,
+) {
+    let Some(boss) = &mut boss_res.0 else {
+        return;
+    };
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+
+    for attack in boss.tick(time.delta_secs() * time_scale) {
+        let Some(part) = boss.get_part(attack.part) else {
+            continue;
+        };
+        let position = boss_part_translation(boss, part);
+        let muzzle_y = position.y - BOSS_PART_HEIGHT / 2.0;
+
+        match attack.kind {
+            BossAttackKind::Spread => {
+                for shot in 0..SPREAD_SHOTS {
+                    let spread = shot as f32 / (SPREAD_SHOTS - 1) as f32 * 2.0 - 1.0;
+
+                    commands.spawn((
+                        EnemyProjectileBundle::new(position.x, muzzle_y),
+                        BossShotDriftComponent(spread * SPREAD_DRIFT),
+                    ));
+                }
+            }
+            BossAttackKind::Laser => {
+                commands.spawn(BossLaserBundle::new(attack.part, position.x, muzzle_y));
+            }
+            BossAttackKind::Minions => {
+                let id = boss.launch_minion();
+                commands.spawn(BossMinionBundle::new(id, position.x, muzzle_y));
+            }
+        }
+    }
+}
+
+pub fn sync_boss_parts_system(
+    mut commands: Commands,
+    boss_res: Res<BossResource>,
+    mut part_query: Query<(Entity, &BossPartComponent, &mut Sprite, &mut Transform)>,
+    bar_query: Query<Entity, With<BossHealthBarComponent>>,
+    remnant_query: Query<Entity, BossRemnantFilter>,
+) {
+    if !boss_res.is_changed() {
+        return;
+    }
+
+    let Some(boss) = &boss_res.0 else {
+        for (entity, ..) in part_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        for entity in bar_query.iter().chain(remnant_query.iter()) {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let mut spawned = Vec::new();
+
+    for (entity, part_component, mut sprite, mut transform) in part_query.iter_mut() {
+        match boss
+            .get_part(part_component.id)
+            .filter(|part| !part.is_destroyed())
+        {
+            Some(part) => {
+                let position = boss_part_translation(boss, part);
+                transform.translation.x = position.x;
+                transform.translation.y = position.y;
+                sprite.color = boss_part_color(part);
+                spawned.push(part.get_id());
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    for part in boss
+        .intact_parts()
+        .filter(|part| !spawned.contains(&part.get_id()))
+    {
+        commands.spawn(BossPartBundle::new(part, boss_part_translation(boss, part)));
+    }
+
+    if bar_query.is_empty() {
+        commands.spawn(BossHealthBarBundle::new());
+        commands.spawn(BossHealthFillBundle::new());
+    }
+}
+
+pub fn update_boss_health_bar_system(
+    boss_res: Res<BossResource>,
+    mut fill_query: Query<(&mut Sprite, &mut Transform), With<BossHealthFillComponent>>,
+) {
+    let Some(boss) = &boss_res.0 else {
+        return;
+    };
+
+    let width = BOSS_HEALTH_BAR_WIDTH * boss.health_fraction();
+
+    for (mut sprite, mut transform) in fill_query.iter_mut() {
+        if let Some(size) = &mut sprite.custom_size {
+            size.x = width;
+        }
+        transform.translation.x = -(BOSS_HEALTH_BAR_WIDTH - width) / 2.0;
+    }
+}
+
+pub fn boss_shot_drift_system(
+    time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut query: Query<(&mut Transform, &BossShotDriftComponent)>,
+) {
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+
+    for (mut transform, drift) in query.iter_mut() {
+        transform.translation.x += drift.0 * time_scale * time.delta_secs();
+    }
+}
+
+pub fn boss_laser_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    boss_res: Res<BossResource>,
+    mut laser_query: Query<(Entity, &mut BossLaserComponent, &mut Sprite, &mut Transform)>,
+    player_query: Query<(&Transform, &Sprite, &PlayerComponent), Without<BossLaserComponent>>,
+    mut player_killed_message_writer: MessageWriter<PlayerKilledMessage>,
+) {
+    let Some(boss) = &boss_res.0 else {
+        return;
+    };
+
+    for (entity, mut laser, mut sprite, mut transform) in laser_query.iter_mut() {
+        let Some(cannon) = boss
+            .get_part(laser.part)
+            .filter(|part| !part.is_destroyed())
+        else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        transform.translation.x = boss_part_translation(boss, cannon).x;
+
+        if !laser.warning.tick(time.delta()).is_finished() {
+            continue;
+        }
+
+        sprite.color = BOSS_LASER_COLOR;
+        if let Some(size) = &mut sprite.custom_size {
+            size.x = BOSS_LASER_WIDTH;
+        }
+
+        let hit = player_query
+            .iter()
+            .find(|(player_transform, player_sprite, _)| {
+                let player_width = player_sprite.custom_size.unwrap_or(Vec2::ONE).x;
+
+                (player_transform.translation.x - transform.translation.x).abs()
+                    < (player_width + BOSS_LASER_WIDTH) / 2.0
+            });
+
+        if let Some((_, _, player)) = hit {
+            player_killed_message_writer.write(PlayerKilledMessage::new(entity, player.slot));
+        } else if laser.beam.tick(time.delta()).is_finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn boss_minion_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut minion_query: Query<(Entity, &mut Transform, &Sprite), With<BossMinionComponent>>,
+    player_query: Query<(&Transform, &Sprite, &PlayerComponent), Without<BossMinionComponent>>,
+    mut player_killed_message_writer: MessageWriter<PlayerKilledMessage>,
+) {
+    let delta = time.delta_secs() * power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+
+    for (entity, mut transform, sprite) in minion_query.iter_mut() {
+        let nearest = player_query.iter().min_by(|(a, ..), (b, ..)| {
+            let distance_a = (a.translation.x - transform.translation.x).abs();
+            let distance_b = (b.translation.x - transform.translation.x).abs();
+            distance_a.total_cmp(&distance_b)
+        });
+
+        transform.translation.y -= MINION_SPEED * delta;
+
+        if let Some((player_transform, player_sprite, player)) = nearest {
+            let offset = player_transform.translation.x - transform.translation.x;
+            transform.translation.x += offset.clamp(-1.0, 1.0) * MINION_HOMING_SPEED * delta;
+
+            let minion_size = sprite.custom_size.unwrap_or(Vec2::ONE);
+            let player_size = player_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+            let collision = check_aabb_collision(
+                (transform.translation.x, transform.translation.y),
+                (minion_size.x, minion_size.y),
+                (
+                    player_transform.translation.x,
+                    player_transform.translation.y,
+                ),
+                (player_size.x, player_size.y),
+            );
+
+            if collision {
+                player_killed_message_writer.write(PlayerKilledMessage::new(entity, player.slot));
+                continue;
+            }
+        }
+
+        if transform.translation.y < -GAME_AREA_HEIGHT / 2.0 - MINION_HEIGHT {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn boss_collisions_system(
+    mut boss_res: ResMut<BossResource>,
+    player_projectile_query: Query<(Entity, &Transform, &Sprite, &PlayerProjectileComponent)>,
+    part_query: Query<(Entity, &Transform, &Sprite, &BossPartComponent)>,
+    minion_query: Query<(Entity, &Transform, &Sprite, &BossMinionComponent)>,
+    mut enemy_killed_message_writer: MessageWriter<EnemyKilledMessage>,
+    mut enemy_damaged_message_writer: MessageWriter<EnemyDamagedMessage>,
+    mut boss_defeated_message_writer: MessageWriter<BossDefeatedMessage>,
+) {
+    let mut shot_minions = Vec::new();
+
+    'projectiles: for (projectile_entity, projectile_transform, projectile_sprite, projectile) in
+        player_projectile_query.iter()
+    {
+        let projectile_size = projectile_sprite.custom_size.unwrap_or(Vec2::ONE);
+        let hits = |transform: &Transform, sprite: &Sprite| {
+            let size = sprite.custom_size.unwrap_or(Vec2::ONE);
+
+            check_aabb_collision(
+                (
+                    projectile_transform.translation.x,
+                    projectile_transform.translation.y,
+                ),
+                (projectile_size.x, projectile_size.y),
+                (transform.translation.x, transform.translation.y),
+                (size.x, size.y),
+            )
+        };
+
+        for (minion_entity, minion_transform, minion_sprite, minion) in minion_query.iter() {
+            if shot_minions.contains(&minion_entity) || !hits(minion_transform, minion_sprite) {
+                continue;
+            }
+
+            shot_minions.push(minion_entity);
+            enemy_killed_message_writer.write(EnemyKilledMessage::new(
+                minion_entity,
+                minion.id,
+                projectile_entity,
+                projectile.shooter,
+            ));
+
+            if !projectile.piercing {
+                continue 'projectiles;
+            }
+        }
+
+        let Some(boss) = &mut boss_res.0 else {
+            continue;
+        };
+
+        for (part_entity, part_transform, part_sprite, part) in part_query.iter() {
+            if !hits(part_transform, part_sprite) {
+                continue;
+            }
+
+            match boss.hit(part.id) {
+                BossHit::Missed => continue,
+                BossHit::Armor | BossHit::WeakPoint => {
+                    enemy_damaged_message_writer.write(EnemyDamagedMessage::new(
+                        part.id,
+                        projectile_entity,
+                        projectile.shooter,
+                    ));
+                }
+                BossHit::PartDestroyed => {
+                    enemy_killed_message_writer.write(EnemyKilledMessage::new(
+                        part_entity,
+                        part.id,
+                        projectile_entity,
+                        projectile.shooter,
+                    ));
+                }
+                BossHit::Defeated => {
+                    enemy_killed_message_writer.write(EnemyKilledMessage::new(
+                        part_entity,
+                        part.id,
+                        projectile_entity,
+                        projectile.shooter,
+                    ));
+                    boss_defeated_message_writer
+                        .write(BossDefeatedMessage::new(projectile.shooter));
+
+                    info!("Boss defeated");
+                }
+            }
+            break;
+        }
+    }
+
+    if boss_res.0.as_ref().is_some_and(Boss::is_defeated) {
+        boss_res.0 = None;
+    }
+}
+
+pub fn despawn_shot_minions_system(
+    mut commands: Commands,
+    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
+    minion_query: Query<Entity, With<BossMinionComponent>>,
+) {
+    for message in enemy_killed_message.read() {
+        if minion_query.contains(message.enemy_entity) {
+            commands.entity(message.enemy_entity).despawn();
+        }
+    }
+}
+
+pub fn reset_boss_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    query: Query<Entity, BossRemnantFilter>,
+) {
+    if new_game_message.read().count() > 0 {
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::boss::{BOSS_ATTACK_INTERVAL, BossPart, BossPartKind, WEAK_POINT_DAMAGE};
+    use crate::domain::enemy::EnemyId;
+    use crate::domain::enemy_formation::FormationStatus;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::bevy::boss::resources::BOSS_LASER_WARNING;
+    use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileComponent;
+    use crate::infrastructure::bevy::player::resources::{PLAYER_HEIGHT, PLAYER_WIDTH};
+    use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileBundle;
+    use bevy::app::{App, Update};
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, count_components, did_message_fire,
+        get_resource_mut_or_fail, get_resource_or_fail, send_message,
+    };
+
+    fn setup() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_setup(|app| {
+                app.insert_resource(BossResource(Some(Boss::new(1))))
+                    .insert_resource(BossScheduleResource(5))
+                    .insert_resource(WaveResource(Wave::new(5)))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::cleared()))
+                    .add_message::<EnemyKilledMessage>()
+                    .add_message::<EnemyDamagedMessage>()
+                    .add_message::<BossDefeatedMessage>()
+                    .add_message::<PlayerKilledMessage>()
+                    .add_message::<NewGameMessage>();
+            })
+            .build()
+    }
+
+    fn part_of(app: &mut App, kind: BossPartKind) -> BossPart {
+        get_resource_or_fail::<BossResource>(app)
+            .0
+            .as_ref()
+            .and_then(|boss| {
+                boss.get_parts()
+                    .iter()
+                    .find(|part| part.get_kind() == kind)
+                    .copied()
+            })
+            .expect("Boss should have the part")
+    }
+
+    fn position_of(app: &mut App, part: &BossPart) -> Vec2 {
+        let boss = get_resource_or_fail::<BossResource>(app)
+            .0
+            .as_ref()
+            .expect("Boss should be alive");
+        boss_part_translation(boss, part)
+    }
+
+    fn spawn_player(app: &mut App, x: f32) {
+        app.world_mut().spawn((
+            PlayerComponent::default(),
+            Sprite {
+                custom_size: Some(Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT)),
+                ..Default::default()
+            },
+            Transform::from_xyz(x, -GAME_AREA_HEIGHT / 2.0 + PLAYER_HEIGHT, 0.0),
+        ));
+    }
+
+    #[cfg(test)]
+    mod start_boss_wave_system {
+        use super::*;
+
+        #[test]
+        fn should_replace_the_formation_on_boss_waves() {
+            let mut app = setup();
+            app.insert_resource(BossResource(None))
+                .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                .add_systems(Update, start_boss_wave_system);
+
+            app.update();
+
+            assert!(get_resource_or_fail::<BossResource>(&mut app).0.is_some());
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.get_status(), FormationStatus::Annihilated);
+            assert_eq!(formation.count_alive(), 0);
+        }
+
+        #[test]
+        fn should_leave_regular_waves_to_the_formation() {
+            let mut app = setup();
+            app.insert_resource(WaveResource(Wave::new(4)))
+                .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                .add_systems(Update, start_boss_wave_system);
+
+            app.update();
+
+            assert!(get_resource_or_fail::<BossResource>(&mut app).0.is_none());
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .count_alive(),
+                55
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod sync_boss_parts_system {
+        use super::*;
+
+        #[test]
+        fn should_spawn_the_parts_and_the_health_bar() {
+            let mut app = setup();
+            app.add_systems(Update, sync_boss_parts_system);
+
+            app.update();
+
+            assert_eq!(count_components::<BossPartComponent>(&mut app), 6);
+            assert_eq!(count_components::<BossHealthBarComponent>(&mut app), 2);
+        }
+
+        #[test]
+        fn should_remove_destroyed_parts() {
+            let mut app = setup();
+            app.add_systems(Update, sync_boss_parts_system);
+            app.update();
+
+            let hangar = part_of(&mut app, BossPartKind::Hangar).get_id();
+            if let Some(boss) = &mut get_resource_mut_or_fail::<BossResource>(&mut app).0 {
+                while boss.hit(hangar) != BossHit::PartDestroyed {}
+            }
+            app.update();
+
+            assert_eq!(count_components::<BossPartComponent>(&mut app), 5);
+        }
+
+        #[test]
+        fn should_clear_everything_once_the_boss_is_gone() {
+            let mut app = setup();
+            app.add_systems(Update, sync_boss_parts_system);
+            app.update();
+            app.world_mut()
+                .spawn(BossMinionBundle::new(EnemyId::new(1), 0.0, 0.0));
+
+            get_resource_mut_or_fail::<BossResource>(&mut app).0 = None;
+            app.update();
+
+            assert_eq!(count_components::<BossPartComponent>(&mut app), 0);
+            assert_eq!(count_components::<BossHealthBarComponent>(&mut app), 0);
+            assert_eq!(count_components::<BossMinionComponent>(&mut app), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod update_boss_health_bar_system {
+        use super::*;
+
+        #[test]
+        fn should_shrink_with_the_boss_health() {
+            let mut app = setup();
+            app.add_systems(
+                Update,
+                (sync_boss_parts_system, update_boss_health_bar_system).chain(),
+            );
+            app.update();
+
+            let core = part_of(&mut app, BossPartKind::Core).get_id();
+            if let Some(boss) = &mut get_resource_mut_or_fail::<BossResource>(&mut app).0 {
+                while !boss.is_enraged() {
+                    boss.hit(core);
+                }
+            }
+            app.update();
+
+            let width = app
+                .world_mut()
+                .query_filtered::<&Sprite, With<BossHealthFillComponent>>()
+                .single(app.world())
+                .expect("Health bar not found")
+                .custom_size
+                .map_or(0.0, |size| size.x);
+            assert!(width <= BOSS_HEALTH_BAR_WIDTH / 2.0);
+        }
+    }
+
+    #[cfg(test)]
+    mod boss_attack_system {
+        use super::*;
+
+        #[test]
+        fn should_open_with_spread_shots_from_both_turrets() {
+            let mut app = setup();
+            app.add_systems(Update, boss_attack_system);
+
+            advance_time_by_seconds(&mut app, BOSS_ATTACK_INTERVAL);
+            app.update();
+
+            assert_eq!(
+                count_components::<EnemyProjectileComponent>(&mut app),
+                SPREAD_SHOTS * 2
+            );
+            assert_eq!(
+                count_components::<BossShotDriftComponent>(&mut app),
+                SPREAD_SHOTS * 2
+            );
+        }
+
+        #[test]
+        fn should_follow_up_with_a_laser() {
+            let mut app = setup();
+            app.add_systems(Update, boss_attack_system);
+
+            for _ in 0..2 {
+                advance_time_by_seconds(&mut app, BOSS_ATTACK_INTERVAL);
+                app.update();
+            }
+
+            assert_eq!(count_components::<BossLaserComponent>(&mut app), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod boss_collisions_system {
+        use super::*;
+
+        fn shoot_at(app: &mut App, kind: BossPartKind) {
+            let part = part_of(app, kind);
+            let position = position_of(app, &part);
+            app.world_mut().spawn(BossPartBundle::new(&part, position));
+            app.world_mut().spawn(PlayerProjectileBundle::new(
+                position.x,
+                position.y,
+                PlayerSlot::One,
+            ));
+        }
+
+        #[test]
+        fn should_wear_down_an_armored_part() {
+            let mut app = setup();
+            app.add_systems(Update, boss_collisions_system);
+            shoot_at(&mut app, BossPartKind::LaserCannon);
+
+            app.update();
+
+            assert!(did_message_fire::<EnemyDamagedMessage>(&mut app));
+            assert!(!did_message_fire::<EnemyKilledMessage>(&mut app));
+            let boss = get_resource_or_fail::<BossResource>(&mut app)
+                .0
+                .as_ref()
+                .expect("Boss should be alive");
+            assert_eq!(boss.get_health(), boss.get_max_health() - 1);
+        }
+
+        #[test]
+        fn should_defeat_the_boss_through_its_weak_point() {
+            let mut app = setup();
+            app.add_systems(Update, boss_collisions_system);
+            let core = part_of(&mut app, BossPartKind::Core).get_id();
+            if let Some(boss) = &mut get_resource_mut_or_fail::<BossResource>(&mut app).0 {
+                while boss.get_health() > WEAK_POINT_DAMAGE {
+                    boss.hit(core);
+                }
+            }
+            shoot_at(&mut app, BossPartKind::Core);
+
+            app.update();
+
+            assert!(did_message_fire::<BossDefeatedMessage>(&mut app));
+            assert!(did_message_fire::<EnemyKilledMessage>(&mut app));
+            assert!(get_resource_or_fail::<BossResource>(&mut app).0.is_none());
+        }
+
+        #[test]
+        fn should_shoot_down_minions() {
+            let mut app = setup();
+            app.add_systems(Update, boss_collisions_system);
+            app.world_mut()
+                .spawn(BossMinionBundle::new(EnemyId::new(1), 0.0, 0.0));
+            app.world_mut()
+                .spawn(PlayerProjectileBundle::new(0.0, 0.0, PlayerSlot::One));
+
+            app.update();
+
+            assert!(did_message_fire::<EnemyKilledMessage>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod boss_laser_system {
+        use super::*;
+
+        fn fire_laser(app: &mut App) {
+            let cannon = part_of(app, BossPartKind::LaserCannon);
+            let position = position_of(app, &cannon);
+            app.world_mut().spawn(BossLaserBundle::new(
+                cannon.get_id(),
+                position.x,
+                position.y,
+            ));
+        }
+
+        #[test]
+        fn should_warn_before_firing() {
+            let mut app = setup();
+            app.add_systems(Update, boss_laser_system);
+            spawn_player(&mut app, 0.0);
+            fire_laser(&mut app);
+
+            app.update();
+
+            assert!(!did_message_fire::<PlayerKilledMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_hit_a_player_under_the_beam() {
+            let mut app = setup();
+            app.add_systems(Update, boss_laser_system);
+            spawn_player(&mut app, 0.0);
+            fire_laser(&mut app);
+
+            advance_time_by_seconds(&mut app, BOSS_LASER_WARNING);
+            app.update();
+
+            assert!(did_message_fire::<PlayerKilledMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_miss_a_player_out_of_the_beam() {
+            let mut app = setup();
+            app.add_systems(Update, boss_laser_system);
+            spawn_player(&mut app, 200.0);
+            fire_laser(&mut app);
+
+            advance_time_by_seconds(&mut app, BOSS_LASER_WARNING);
+            app.update();
+
+            assert!(!did_message_fire::<PlayerKilledMessage>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod boss_minion_system {
+        use super::*;
+
+        #[test]
+        fn should_home_in_on_the_player() {
+            let mut app = setup();
+            app.add_systems(Update, boss_minion_system);
+            spawn_player(&mut app, 200.0);
+            let minion = app
+                .world_mut()
+                .spawn(BossMinionBundle::new(EnemyId::new(1), 0.0, 100.0))
+                .id();
+
+            advance_time_by_seconds(&mut app, 0.5);
+            app.update();
+
+            let transform = app
+                .world()
+                .get::<Transform>(minion)
+                .expect("Minion should still fly");
+            assert!(transform.translation.x > 0.0);
+            assert!(transform.translation.y < 100.0);
+        }
+
+        #[test]
+        fn should_hit_the_player_it_reaches() {
+            let mut app = setup();
+            app.add_systems(Update, boss_minion_system);
+            spawn_player(&mut app, 0.0);
+            app.world_mut().spawn(BossMinionBundle::new(
+                EnemyId::new(1),
+                0.0,
+                -GAME_AREA_HEIGHT / 2.0 + PLAYER_HEIGHT,
+            ));
+
+            app.update();
+
+            assert!(did_message_fire::<PlayerKilledMessage>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_boss_system {
+        use super::*;
+
+        #[test]
+        fn should_clear_minions_and_lasers_on_new_game() {
+            let mut app = setup();
+            app.add_systems(Update, reset_boss_system);
+            app.world_mut()
+                .spawn(BossMinionBundle::new(EnemyId::new(1), 0.0, 0.0));
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<BossMinionComponent>(&mut app), 0);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use crate::infrastructure::bevy::campaign::resources::{
+    CampaignAsset, CampaignHandleResource, CampaignLoader, CampaignPathResource, CampaignResource,
+    WaveEventsResource, WaveScriptAsset, WaveScriptLoader, WaveUfoResource,
+};
+use crate::infrastructure::bevy::campaign::systems::{
+    apply_campaign_system, load_campaign_system, wave_events_system, wave_script_system,
+    wave_tempo_system, wave_ufo_system,
+};
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::ufo::plugin::UfoPlugin;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::asset::AssetApp;
+use bevy::prelude::{IntoScheduleConfigs, not, resource_exists};
+
+pub struct CampaignPlugin {
+    pub path: String,
+}
+
+pub struct WaveScriptPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<WaveScriptPlugin>() {
+            app.add_plugins(WaveScriptPlugin);
+        }
+
+        app.init_asset::<CampaignAsset>()
+            .init_asset::<WaveScriptAsset>()
+            .init_asset_loader::<CampaignLoader>()
+            .init_asset_loader::<WaveScriptLoader>()
+            .insert_resource(CampaignPathResource(self.path.clone()))
+            .add_systems(Startup, load_campaign_system)
+            .add_systems(
+                Update,
+                apply_campaign_system
+                    .run_if(resource_exists::<CampaignHandleResource>)
+                    .run_if(not(resource_exists::<CampaignResource>)),
+            );
+    }
+}
+
+impl Plugin for WaveScriptPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<UfoPlugin>() {
+            app.add_plugins(UfoPlugin);
+        }
+
+        app.init_resource::<WaveEventsResource>()
+            .init_resource::<WaveUfoResource>()
+            .add_systems(
+                Update,
+                (wave_script_system, wave_tempo_system).run_if(resource_exists::<CampaignResource>),
+            )
+            .add_systems(
+                Update,
+                (wave_events_system, wave_ufo_system)
+                    .after(wave_script_system)
+                    .in_set(GameplaySystems)
+                    .run_if(resource_exists::<CampaignResource>),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_formation::plugin::EnemyFormationPlugin;
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::bevy::enemy_formation::resources::FormationLayoutsResource;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::shield_formation::plugin::ShieldFormationPlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(EnemyFormationPlugin)
+            .with_plugin(ShieldFormationPlugin)
+            .with_plugin(CampaignPlugin {
+                path: "main.campaign.ron".to_string(),
+            })
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bundled_campaign_loads_through_the_asset_server() {
+        let mut app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(EnemyFormationPlugin)
+            .with_plugin(ShieldFormationPlugin)
+            .with_plugin(CampaignPlugin {
+                path: "main.campaign.ron".to_string(),
+            })
+            .with_message::<NewGameMessage>()
+            .build();
+
+        for _ in 0..1000 {
+            if app.world().contains_resource::<CampaignResource>()
+                || !app.world().contains_resource::<CampaignHandleResource>()
+            {
+                break;
+            }
+            app.update();
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+
+        let campaign = &app.world().resource::<CampaignResource>().0;
+        assert_eq!(
+            app.world().resource::<FormationLayoutsResource>().0.count(),
+            campaign.get_waves().len()
+        );
+    }
+}
@@ -0,0 +1,699 @@
+use crate::domain::formation_layout::FormationLayout;
+use crate::domain::movement_pattern::FormationMovement;
+#[cfg(feature = "serde")]
+use crate::domain::shield_layout::{DEFAULT_SHIELD_COUNT, DEFAULT_SHIELD_SPREAD};
+use crate::domain::shield_layout::{ShieldLayout, ShieldShape};
+use crate::domain::wave::Wave;
+use crate::domain::wave_script::{
+    Campaign, ShieldRebuild, ShotType, SpeedCurve, UfoSchedule, WAVE_SCRIPT_VERSION, WaveAction,
+    WaveEvent, WaveScript, WaveScriptError,
+};
+use crate::infrastructure::storage::{from_ron, to_pretty_ron};
+use bevy::asset::io::Reader;
+use bevy::asset::{Asset, AssetLoader, Handle, LoadContext};
+use bevy::prelude::{Resource, Timer};
+use bevy::reflect::TypePath;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+pub const CAMPAIGN_EXTENSION: &str = "campaign.ron";
+pub const WAVE_SCRIPT_EXTENSION: &str = "wave.ron";
+
+#[derive(Asset, TypePath, Debug)]
+pub struct CampaignAsset(pub Campaign);
+
+#[derive(Asset, TypePath, Debug)]
+pub struct WaveScriptAsset(pub WaveScript);
+
+#[derive(Resource)]
+pub struct CampaignPathResource(pub String);
+
+#[derive(Resource)]
+pub struct CampaignHandleResource(pub Handle<CampaignAsset>);
+
+#[derive(Resource)]
+pub struct CampaignResource(pub Campaign);
+
+#[derive(Resource, Default)]
+pub struct WaveEventsResource {
+    pub wave: Wave,
+    pub fired: Vec<bool>,
+}
+
+#[derive(Resource, Default)]
+pub struct WaveUfoResource {
+    pub schedule: Option<UfoSchedule>,
+    pub timer: Timer,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct CampaignFile {
+    version: u32,
+    name: String,
+    waves: Vec<String>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct WaveScriptFile {
+    version: u32,
+    name: String,
+    formation: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    movement: MovementEntry,
+    speed: SpeedEntry,
+    fire_probability: f64,
+    #[cfg_attr(feature = "serde", serde(default = "default_shot_types"))]
+    shot_types: Vec<ShotTypeEntry>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    ufo: Option<UfoEntry>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    shields: Option<ShieldsEntry>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    shield_rebuild: ShieldRebuildEntry,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    events: Vec<EventEntry>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct SpeedEntry {
+    start: f32,
+    end: f32,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ShotTypeEntry {
+    Rolling,
+    Plunger,
+    Squiggly,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct UfoEntry {
+    first: f32,
+    interval: f32,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct ShieldsEntry {
+    #[cfg_attr(feature = "serde", serde(default = "default_shield_count"))]
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(default = "default_shield_spread"))]
+    spread: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    shape: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum MovementEntry {
+    #[default]
+    ClassicMarch,
+    SineWave,
+    ZigZag,
+    Circling,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ShieldRebuildEntry {
+    #[default]
+    Keep,
+    Rebuild,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct EventEntry {
+    enemies_left: usize,
+    action: ActionEntry,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ActionEntry {
+    Announce(String),
+    Reinforce,
+    FireProbability(f64),
+}
+
+#[cfg(feature = "serde")]
+fn default_shot_types() -> Vec<ShotTypeEntry> {
+    vec![ShotTypeEntry::Rolling]
+}
+
+#[cfg(feature = "serde")]
+fn default_shield_count() -> usize {
+    DEFAULT_SHIELD_COUNT
+}
+
+#[cfg(feature = "serde")]
+fn default_shield_spread() -> f32 {
+    DEFAULT_SHIELD_SPREAD
+}
+
+impl From<ShotTypeEntry> for ShotType {
+    fn from(entry: ShotTypeEntry) -> Self {
+        match entry {
+            ShotTypeEntry::Rolling => ShotType::Rolling,
+            ShotTypeEntry::Plunger => ShotType::Plunger,
+            ShotTypeEntry::Squiggly => ShotType::Squiggly,
+        }
+    }
+}
+
+impl From<ShotType> for ShotTypeEntry {
+    fn from(shot_type: ShotType) -> Self {
+        match shot_type {
+            ShotType::Rolling => ShotTypeEntry::Rolling,
+            ShotType::Plunger => ShotTypeEntry::Plunger,
+            ShotType::Squiggly => ShotTypeEntry::Squiggly,
+        }
+    }
+}
+
+impl From<MovementEntry> for FormationMovement {
+    fn from(entry: MovementEntry) -> Self {
+        match entry {
+            MovementEntry::ClassicMarch => FormationMovement::ClassicMarch,
+            MovementEntry::SineWave => FormationMovement::SineWave,
+            MovementEntry::ZigZag => FormationMovement::ZigZag,
+            MovementEntry::Circling => FormationMovement::Circling,
+        }
+    }
+}
+
+impl From<FormationMovement> for MovementEntry {
+    fn from(movement: FormationMovement) -> Self {
+        match movement {
+            FormationMovement::ClassicMarch => MovementEntry::ClassicMarch,
+            FormationMovement::SineWave => MovementEntry::SineWave,
+            FormationMovement::ZigZag => MovementEntry::ZigZag,
+            FormationMovement::Circling => MovementEntry::Circling,
+        }
+    }
+}
+
+impl From<ShieldRebuildEntry> for ShieldRebuild {
+    fn from(entry: ShieldRebuildEntry) -> Self {
+        match entry {
+            ShieldRebuildEntry::Keep => ShieldRebuild::Keep,
+            ShieldRebuildEntry::Rebuild => ShieldRebuild::Rebuild,
+        }
+    }
+}
+
+impl From<ShieldRebuild> for ShieldRebuildEntry {
+    fn from(rebuild: ShieldRebuild) -> Self {
+        match rebuild {
+            ShieldRebuild::Keep => ShieldRebuildEntry::Keep,
+            ShieldRebuild::Rebuild => ShieldRebuildEntry::Rebuild,
+        }
+    }
+}
+
+impl From<ActionEntry> for WaveAction {
+    fn from(entry: ActionEntry) -> Self {
+        match entry {
+            ActionEntry::Announce(text) => WaveAction::Announce(text),
+            ActionEntry::Reinforce => WaveAction::Reinforce,
+            ActionEntry::FireProbability(probability) => WaveAction::FireProbability(probability),
+        }
+    }
+}
+
+impl From<&WaveAction> for ActionEntry {
+    fn from(action: &WaveAction) -> Self {
+        match action {
+            WaveAction::Announce(text) => ActionEntry::Announce(text.clone()),
+            WaveAction::Reinforce => ActionEntry::Reinforce,
+            WaveAction::FireProbability(probability) => ActionEntry::FireProbability(*probability),
+        }
+    }
+}
+
+impl From<&WaveScript> for WaveScriptFile {
+    fn from(script: &WaveScript) -> Self {
+        Self {
+            version: WAVE_SCRIPT_VERSION,
+            name: script.name.clone(),
+            formation: script.formation.to_rows(),
+            movement: script.movement.into(),
+            speed: SpeedEntry {
+                start: script.speed.start,
+                end: script.speed.end,
+            },
+            fire_probability: script.fire_probability,
+            shot_types: script
+                .shot_types
+                .iter()
+                .map(|shot| (*shot).into())
+                .collect(),
+            ufo: script.ufo.map(|ufo| UfoEntry {
+                first: ufo.first,
+                interval: ufo.interval,
+            }),
+            shields: Some(ShieldsEntry {
+                count: script.shields.get_count(),
+                spread: script.shields.get_spread(),
+                shape: Some(script.shields.get_shape().to_rows()),
+            }),
+            shield_rebuild: script.shield_rebuild.into(),
+            events: script
+                .events
+                .iter()
+                .map(|event| EventEntry {
+                    enemies_left: event.enemies_left,
+                    action: (&event.action).into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CampaignError {
+    Read {
+        path: String,
+        reason: String,
+    },
+    Parse {
+        path: String,
+        reason: String,
+    },
+    UnsupportedVersion {
+        path: String,
+        version: u32,
+    },
+    Invalid {
+        path: String,
+        error: WaveScriptError,
+    },
+}
+
+impl Display for CampaignError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CampaignError::Read { path, reason } => write!(f, "cannot read {}: {}", path, reason),
+            CampaignError::Parse { path, reason } => {
+                write!(f, "cannot parse {}: {}", path, reason)
+            }
+            CampaignError::UnsupportedVersion { path, version } => write!(
+                f,
+                "{} uses version {}, only version {} is supported",
+                path, version, WAVE_SCRIPT_VERSION
+            ),
+            CampaignError::Invalid { path, error } => write!(f, "{} is invalid: {}", path, error),
+        }
+    }
+}
+
+impl Error for CampaignError {}
+
+fn check_version(path: &str, version: u32) -> Result<(), CampaignError> {
+    if version != WAVE_SCRIPT_VERSION {
+        return Err(CampaignError::UnsupportedVersion {
+            path: path.to_string(),
+            version,
+        });
+    }
+
+    Ok(())
+}
+
+pub fn parse_campaign_manifest(
+    path: &str,
+    source: &str,
+) -> Result<(String, Vec<String>), CampaignError> {
+    let file: CampaignFile = from_ron(source).map_err(|reason| CampaignError::Parse {
+        path: path.to_string(),
+        reason,
+    })?;
+
+    check_version(path, file.version)?;
+
+    if file.waves.is_empty() {
+        return Err(CampaignError::Invalid {
+            path: path.to_string(),
+            error: WaveScriptError::NoWaves,
+        });
+    }
+
+    Ok((file.name, file.waves))
+}
+
+pub fn parse_wave_script(path: &str, source: &str) -> Result<WaveScript, CampaignError> {
+    let file: WaveScriptFile = from_ron(source).map_err(|reason| CampaignError::Parse {
+        path: path.to_string(),
+        reason,
+    })?;
+
+    check_version(path, file.version)?;
+
+    let invalid = |error| CampaignError::Invalid {
+        path: path.to_string(),
+        error,
+    };
+
+    let formation = FormationLayout::parse(&file.formation)
+        .map_err(|error| invalid(WaveScriptError::Formation(error)))?;
+
+    let shields = match file.shields {
+        Some(entry) => {
+            let shape = match entry.shape {
+                Some(rows) => ShieldShape::parse(&rows),
+                None => Ok(ShieldShape::classic()),
+            };
+
+            shape
+                .and_then(|shape| ShieldLayout::new(shape, entry.count, entry.spread))
+                .map_err(|error| invalid(WaveScriptError::Shields(error)))?
+        }
+        None => ShieldLayout::classic(),
+    };
+
+    let script = WaveScript {
+        name: file.name,
+        formation,
+        movement: file.movement.into(),
+        speed: SpeedCurve {
+            start: file.speed.start,
+            end: file.speed.end,
+        },
+        fire_probability: file.fire_probability,
+        shot_types: file.shot_types.into_iter().map(ShotType::from).collect(),
+        ufo: file.ufo.map(|ufo| UfoSchedule {
+            first: ufo.first,
+            interval: ufo.interval,
+        }),
+        shields,
+        shield_rebuild: file.shield_rebuild.into(),
+        events: file
+            .events
+            .into_iter()
+            .map(|event| WaveEvent {
+                enemies_left: event.enemies_left,
+                action: event.action.into(),
+            })
+            .collect(),
+    };
+
+    script.validate().map_err(invalid)?;
+
+    Ok(script)
+}
+
+pub fn format_wave_script(script: &WaveScript) -> Result<String, String> {
+    to_pretty_ron(&WaveScriptFile::from(script))
+}
+
+async fn read_source(reader: &mut dyn Reader, path: &str) -> Result<String, CampaignError> {
+    let mut bytes = Vec::new();
+    reader
+        .read_to_end(&mut bytes)
+        .await
+        .map_err(|error| CampaignError::Read {
+            path: path.to_string(),
+            reason: error.to_string(),
+        })?;
+
+    String::from_utf8(bytes).map_err(|error| CampaignError::Read {
+        path: path.to_string(),
+        reason: error.to_string(),
+    })
+}
+
+#[derive(Default, TypePath)]
+pub struct WaveScriptLoader;
+
+impl AssetLoader for WaveScriptLoader {
+    type Asset = WaveScriptAsset;
+    type Settings = ();
+    type Error = CampaignError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<WaveScriptAsset, CampaignError> {
+        let path = load_context.path().to_string();
+        let source = read_source(reader, &path).await?;
+
+        parse_wave_script(&path, &source).map(WaveScriptAsset)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[WAVE_SCRIPT_EXTENSION]
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct CampaignLoader;
+
+impl AssetLoader for CampaignLoader {
+    type Asset = CampaignAsset;
+    type Settings = ();
+    type Error = CampaignError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<CampaignAsset, CampaignError> {
+        let path = load_context.path().clone();
+        let source = read_source(reader, &path.to_string()).await?;
+        let (name, wave_paths) = parse_campaign_manifest(&path.to_string(), &source)?;
+
+        let mut waves = Vec::with_capacity(wave_paths.len());
+        for wave_path in wave_paths {
+            let wave_path =
+                path.resolve_embed(&wave_path)
+                    .map_err(|error| CampaignError::Read {
+                        path: wave_path.clone(),
+                        reason: error.to_string(),
+                    })?;
+            let bytes = load_context
+                .read_asset_bytes(&wave_path)
+                .await
+                .map_err(|error| CampaignError::Read {
+                    path: wave_path.to_string(),
+                    reason: error.to_string(),
+                })?;
+            let source = String::from_utf8(bytes).map_err(|error| CampaignError::Read {
+                path: wave_path.to_string(),
+                reason: error.to_string(),
+            })?;
+
+            waves.push(parse_wave_script(&wave_path.to_string(), &source)?);
+        }
+
+        Campaign::new(name, waves)
+            .map(CampaignAsset)
+            .map_err(|error| CampaignError::Invalid {
+                path: path.to_string(),
+                error,
+            })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &[CAMPAIGN_EXTENSION]
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::domain::formation_layout::FormationLayoutError;
+    use crate::domain::shield_layout::ShieldLayoutError;
+
+    const WAVE: &str = r#"(
+        version: 1,
+        name: "opening",
+        formation: ["SSS", "CCC"],
+        movement: ZigZag,
+        speed: (start: 0.8, end: 0.1),
+        fire_probability: 0.3,
+        shot_types: [Rolling, Squiggly],
+        ufo: Some((first: 10.0, interval: 25.0)),
+        shields: Some((count: 2, spread: 0.5)),
+        shield_rebuild: Rebuild,
+        events: [
+            (enemies_left: 3, action: Announce("Half way")),
+            (enemies_left: 1, action: FireProbability(0.9)),
+        ],
+    )"#;
+
+    #[test]
+    fn wave_scripts_are_parsed() {
+        let script = parse_wave_script("opening.wave.ron", WAVE).expect("Wave should parse");
+
+        assert_eq!(script.name, "opening");
+        assert_eq!(script.formation.count_enemies(), 6);
+        assert_eq!(script.movement, FormationMovement::ZigZag);
+        assert_eq!(
+            script.speed,
+            SpeedCurve {
+                start: 0.8,
+                end: 0.1
+            }
+        );
+        assert_eq!(script.fire_probability, 0.3);
+        assert_eq!(
+            script.shot_types,
+            vec![ShotType::Rolling, ShotType::Squiggly]
+        );
+        assert_eq!(
+            script.ufo,
+            Some(UfoSchedule {
+                first: 10.0,
+                interval: 25.0
+            })
+        );
+        assert_eq!(script.shields.get_count(), 2);
+        assert_eq!(script.shields.get_shape(), &ShieldShape::classic());
+        assert_eq!(script.shield_rebuild, ShieldRebuild::Rebuild);
+        assert_eq!(
+            script.events[1],
+            WaveEvent {
+                enemies_left: 1,
+                action: WaveAction::FireProbability(0.9)
+            }
+        );
+    }
+
+    #[test]
+    fn optional_wave_fields_have_classic_defaults() {
+        let script = parse_wave_script(
+            "plain.wave.ron",
+            r#"(version: 1, name: "plain", formation: ["C"], speed: (start: 0.5, end: 0.5), fire_probability: 0.1)"#,
+        )
+        .expect("Wave should parse");
+
+        assert_eq!(script.movement, FormationMovement::ClassicMarch);
+        assert_eq!(script.shot_types, vec![ShotType::Rolling]);
+        assert_eq!(script.ufo, None);
+        assert_eq!(script.shields, ShieldLayout::classic());
+        assert_eq!(script.shield_rebuild, ShieldRebuild::Keep);
+        assert!(script.events.is_empty());
+    }
+
+    #[test]
+    fn wave_errors_name_the_file_and_the_problem() {
+        let error = parse_wave_script(
+            "bad.wave.ron",
+            r#"(version: 1, name: "bad", formation: ["CXC"], speed: (start: 0.5, end: 0.5), fire_probability: 0.1)"#,
+        )
+        .expect_err("Wave should be rejected");
+
+        assert_eq!(
+            error,
+            CampaignError::Invalid {
+                path: "bad.wave.ron".to_string(),
+                error: WaveScriptError::Formation(FormationLayoutError::UnknownCell {
+                    row: 0,
+                    column: 1,
+                    symbol: 'X'
+                })
+            }
+        );
+        assert!(
+            error
+                .to_string()
+                .starts_with("bad.wave.ron is invalid: formation:")
+        );
+
+        let error = parse_wave_script(
+            "bad.wave.ron",
+            r#"(version: 1, name: "bad", formation: ["C"], speed: (start: 0.5, end: 0.5), fire_probability: 0.1, shields: Some((count: 9)))"#,
+        )
+        .expect_err("Wave should be rejected");
+
+        assert_eq!(
+            error,
+            CampaignError::Invalid {
+                path: "bad.wave.ron".to_string(),
+                error: WaveScriptError::Shields(ShieldLayoutError::TooManyShields(9))
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_versions_and_fields_are_rejected() {
+        assert_eq!(
+            parse_wave_script(
+                "future.wave.ron",
+                r#"(version: 2, name: "future", formation: ["C"], speed: (start: 0.5, end: 0.5), fire_probability: 0.1)"#,
+            ),
+            Err(CampaignError::UnsupportedVersion {
+                path: "future.wave.ron".to_string(),
+                version: 2
+            })
+        );
+        assert_eq!(
+            CampaignError::UnsupportedVersion {
+                path: "future.wave.ron".to_string(),
+                version: 2
+            }
+            .to_string(),
+            "future.wave.ron uses version 2, only version 1 is supported"
+        );
+        assert!(matches!(
+            parse_wave_script(
+                "typo.wave.ron",
+                r#"(version: 1, name: "typo", formation: ["C"], speed: (start: 0.5, end: 0.5), fire_probabilty: 0.1)"#,
+            ),
+            Err(CampaignError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn formatted_wave_scripts_parse_back_unchanged() {
+        let script = parse_wave_script("opening.wave.ron", WAVE).expect("Wave should parse");
+
+        let source = format_wave_script(&script).expect("Wave should format");
+
+        assert_eq!(parse_wave_script("copy.wave.ron", &source), Ok(script));
+        assert!(source.contains("version: 1"));
+    }
+
+    #[test]
+    fn campaign_manifests_list_waves_in_order() {
+        let (name, waves) = parse_campaign_manifest(
+            "main.campaign.ron",
+            r#"(version: 1, name: "Main", waves: ["waves/01.wave.ron", "waves/02.wave.ron"])"#,
+        )
+        .expect("Manifest should parse");
+
+        assert_eq!(name, "Main");
+        assert_eq!(waves, vec!["waves/01.wave.ron", "waves/02.wave.ron"]);
+        assert_eq!(
+            parse_campaign_manifest(
+                "empty.campaign.ron",
+                r#"(version: 1, name: "Empty", waves: [])"#
+            ),
+            Err(CampaignError::Invalid {
+                path: "empty.campaign.ron".to_string(),
+                error: WaveScriptError::NoWaves
+            })
+        );
+    }
+}
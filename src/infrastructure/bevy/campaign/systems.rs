@@ -0,0 +1,455 @@
+use crate::domain::enemy_formation::MovingDirection;
+use crate::domain::wave_script::{ShieldRebuild, WaveAction};
+use crate::infrastructure::bevy::campaign::resources::{
+    CampaignAsset, CampaignHandleResource, CampaignPathResource, CampaignResource,
+    WaveEventsResource, WaveUfoResource,
+};
+use crate::infrastructure::bevy::enemy::resources::{EnemyFireProbability, EnemyShotTypesResource};
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationMovementTimer, EnemyFormationResource, FormationLayoutsResource,
+    FormationMovementsResource, GameRngResource, WaveResource,
+};
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::shield_formation::components::RebuildShieldsMessage;
+use crate::infrastructure::bevy::shield_formation::resources::ShieldLayoutsResource;
+use crate::infrastructure::bevy::ufo::components::{UfoBundle, UfoComponent};
+use bevy::asset::{AssetServer, Assets, LoadState};
+use bevy::prelude::{
+    Commands, DetectChanges, MessageWriter, Query, Res, ResMut, Time, Timer, TimerMode, With,
+};
+use rand::Rng;
+use std::time::Duration;
+use tracing::{error, info};
+
+pub fn load_campaign_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    path: Res<CampaignPathResource>,
+) {
+    info!("Loading campaign {}", path.0);
+    commands.insert_resource(CampaignHandleResource(asset_server.load(path.0.clone())));
+}
+
+pub fn apply_campaign_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    campaigns: Res<Assets<CampaignAsset>>,
+    handle: Res<CampaignHandleResource>,
+    mut new_game_writer: MessageWriter<NewGameMessage>,
+) {
+    if let LoadState::Failed(reason) = asset_server.load_state(&handle.0) {
+        error!("Keeping the classic waves, {}", reason);
+        commands.remove_resource::<CampaignHandleResource>();
+        return;
+    }
+
+    let Some(CampaignAsset(campaign)) = campaigns.get(&handle.0) else {
+        return;
+    };
+
+    info!(
+        "Campaign '{}' loaded with {} waves",
+        campaign.get_name(),
+        campaign.get_waves().len()
+    );
+
+    commands.insert_resource(FormationLayoutsResource(campaign.formation_layouts()));
+    commands.insert_resource(FormationMovementsResource(campaign.formation_movements()));
+    commands.insert_resource(ShieldLayoutsResource(campaign.shield_layouts()));
+    commands.insert_resource(CampaignResource(campaign.clone()));
+    new_game_writer.write(NewGameMessage);
+}
+
+pub fn wave_script_system(
+    campaign: Res<CampaignResource>,
+    wave: Res<WaveResource>,
+    mut fire_probability: ResMut<EnemyFireProbability>,
+    mut shot_types: ResMut<EnemyShotTypesResource>,
+    mut events: ResMut<WaveEventsResource>,
+    mut ufo: ResMut<WaveUfoResource>,
+    mut rebuild_writer: MessageWriter<RebuildShieldsMessage>,
+) {
+    if !wave.is_changed() && !campaign.is_changed() {
+        return;
+    }
+
+    let script = campaign.0.for_wave(wave.0);
+
+    fire_probability.0 = script.fire_probability;
+    shot_types.0 = script.shot_types.clone();
+
+    if events.wave != wave.0 && script.shield_rebuild == ShieldRebuild::Rebuild {
+        rebuild_writer.write(RebuildShieldsMessage);
+    }
+
+    events.wave = wave.0;
+    events.fired = vec![false; script.events.len()];
+
+    ufo.schedule = script.ufo;
+    ufo.timer = Timer::from_seconds(
+        script.ufo.map_or(0.0, |schedule| schedule.first),
+        TimerMode::Once,
+    );
+
+    info!("Wave {} plays '{}'", wave.0.get_number(), script.name);
+}
+
+pub fn wave_tempo_system(
+    campaign: Res<CampaignResource>,
+    wave: Res<WaveResource>,
+    formation: Res<EnemyFormationResource>,
+    mut timer: ResMut<EnemyFormationMovementTimer>,
+) {
+    let script = campaign.0.for_wave(wave.0);
+    let duration = Duration::from_secs_f32(script.speed.step_duration(
+        formation.0.count_alive(),
+        formation.0.get_layout().count_enemies(),
+    ));
+
+    if timer.0.duration() != duration {
+        timer.0.set_duration(duration);
+    }
+}
+
+pub fn wave_events_system(
+    campaign: Res<CampaignResource>,
+    wave: Res<WaveResource>,
+    mut formation: ResMut<EnemyFormationResource>,
+    mut fire_probability: ResMut<EnemyFireProbability>,
+    mut events: ResMut<WaveEventsResource>,
+) {
+    let script = campaign.0.for_wave(wave.0);
+    let enemies_alive = formation.0.count_alive();
+
+    for (event, fired) in script.events.iter().zip(events.fired.iter_mut()) {
+        if *fired || enemies_alive > event.enemies_left {
+            continue;
+        }
+
+        *fired = true;
+
+        match &event.action {
+            WaveAction::Announce(text) => info!("Wave {}: {}", wave.0.get_number(), text),
+            WaveAction::Reinforce => {
+                formation.0.reinforce();
+            }
+            WaveAction::FireProbability(probability) => fire_probability.0 = *probability,
+        }
+    }
+}
+
+pub fn wave_ufo_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ufo: ResMut<WaveUfoResource>,
+    mut rng: ResMut<GameRngResource>,
+    ufo_query: Query<(), With<UfoComponent>>,
+) {
+    let Some(schedule) = ufo.schedule else {
+        return;
+    };
+
+    if !ufo.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    ufo.timer = Timer::from_seconds(schedule.interval, TimerMode::Once);
+
+    if !ufo_query.is_empty() {
+        return;
+    }
+
+    let direction = if rng.0.random_bool(0.5) {
+        MovingDirection::ToRight
+    } else {
+        MovingDirection::ToLeft
+    };
+
+    commands.spawn(UfoBundle::new(direction));
+    info!("The wave sent the UFO");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyId;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::formation_layout::FormationLayout;
+    use crate::domain::wave::Wave;
+    use crate::domain::wave_script::{
+        Campaign, ShotType, SpeedCurve, UfoSchedule, WaveEvent, WaveScript,
+    };
+    use bevy::app::{App, Update};
+    use bevy::prelude::{IntoScheduleConfigs, Messages, Timer, TimerMode};
+    use bevy_test::{TestAppBuilder, get_resource_or_fail};
+
+    fn campaign() -> Campaign {
+        let mut opening = WaveScript::classic(1.0, 0.1);
+        opening.formation = FormationLayout::parse(&["CC", "CC"]).expect("Layout should parse");
+        opening.speed = SpeedCurve {
+            start: 1.0,
+            end: 0.2,
+        };
+        opening.events = vec![
+            WaveEvent {
+                enemies_left: 2,
+                action: WaveAction::FireProbability(0.9),
+            },
+            WaveEvent {
+                enemies_left: 1,
+                action: WaveAction::Reinforce,
+            },
+        ];
+        opening.ufo = Some(UfoSchedule {
+            first: 2.0,
+            interval: 5.0,
+        });
+
+        let mut second = WaveScript::classic(0.5, 0.4);
+        second.shot_types = vec![ShotType::Plunger, ShotType::Squiggly];
+        second.shield_rebuild = ShieldRebuild::Rebuild;
+
+        Campaign::new("test".to_string(), vec![opening, second]).expect("Campaign is valid")
+    }
+
+    fn setup() -> App {
+        let campaign = campaign();
+        let layout = campaign.for_wave(Wave::new(1)).formation.clone();
+
+        TestAppBuilder::new()
+            .with_setup(move |app| {
+                app.insert_resource(CampaignResource(campaign.clone()))
+                    .insert_resource(WaveResource(Wave::new(1)))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::from_layout(&layout)))
+                    .insert_resource(EnemyFireProbability(0.0))
+                    .insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                        0.6,
+                        TimerMode::Repeating,
+                    )))
+                    .init_resource::<EnemyShotTypesResource>()
+                    .init_resource::<WaveEventsResource>()
+                    .init_resource::<WaveUfoResource>()
+                    .add_message::<RebuildShieldsMessage>()
+                    .add_systems(
+                        Update,
+                        (
+                            wave_script_system,
+                            wave_tempo_system,
+                            wave_events_system.after(wave_script_system),
+                        ),
+                    );
+            })
+            .build()
+    }
+
+    fn rebuild_requests(app: &mut App) -> usize {
+        app.world_mut()
+            .resource_mut::<Messages<RebuildShieldsMessage>>()
+            .drain()
+            .count()
+    }
+
+    #[cfg(test)]
+    mod wave_script_system {
+        use super::*;
+
+        #[test]
+        fn should_apply_the_script_of_the_current_wave() {
+            let mut app = setup();
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.1
+            );
+            assert_eq!(rebuild_requests(&mut app), 0);
+
+            app.insert_resource(WaveResource(Wave::new(2)));
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.4
+            );
+            assert_eq!(
+                get_resource_or_fail::<EnemyShotTypesResource>(&mut app).0,
+                vec![ShotType::Plunger, ShotType::Squiggly]
+            );
+            assert_eq!(rebuild_requests(&mut app), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod wave_tempo_system {
+        use super::*;
+
+        #[test]
+        fn should_speed_up_as_enemies_die() {
+            let mut app = setup();
+            app.update();
+
+            let full = get_resource_or_fail::<EnemyFormationMovementTimer>(&mut app)
+                .0
+                .duration();
+            assert_eq!(full, Duration::from_secs_f32(1.0));
+
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .kill(EnemyId::new(1));
+            app.update();
+
+            let fewer = get_resource_or_fail::<EnemyFormationMovementTimer>(&mut app)
+                .0
+                .duration();
+            assert!(fewer < full);
+        }
+    }
+
+    #[cfg(test)]
+    mod wave_events_system {
+        use super::*;
+
+        #[test]
+        fn should_trigger_each_event_once_when_enough_enemies_died() {
+            let mut app = setup();
+            app.update();
+
+            for id in 1..=2 {
+                app.world_mut()
+                    .resource_mut::<EnemyFormationResource>()
+                    .0
+                    .kill(EnemyId::new(id));
+            }
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.9
+            );
+
+            app.world_mut().resource_mut::<EnemyFireProbability>().0 = 0.5;
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.5
+            );
+        }
+
+        #[test]
+        fn should_reinforce_the_formation() {
+            let mut app = setup();
+            app.update();
+
+            for id in 1..=3 {
+                app.world_mut()
+                    .resource_mut::<EnemyFormationResource>()
+                    .0
+                    .kill(EnemyId::new(id));
+            }
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .count_alive(),
+                3
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod wave_ufo_system {
+        use super::*;
+        use bevy::prelude::Entity;
+        use bevy_test::{advance_time_by_seconds, count_components};
+
+        fn setup() -> App {
+            TestAppBuilder::with_time_disabled()
+                .with_time()
+                .with_setup(|app| {
+                    app.insert_resource(CampaignResource(campaign()))
+                        .insert_resource(WaveResource(Wave::new(1)))
+                        .insert_resource(EnemyFireProbability(0.0))
+                        .insert_resource(GameRngResource::seeded(7))
+                        .init_resource::<EnemyShotTypesResource>()
+                        .init_resource::<WaveEventsResource>()
+                        .init_resource::<WaveUfoResource>()
+                        .add_message::<RebuildShieldsMessage>()
+                        .add_systems(
+                            Update,
+                            (
+                                wave_script_system,
+                                wave_ufo_system.after(wave_script_system),
+                            ),
+                        );
+                })
+                .build()
+        }
+
+        #[test]
+        fn should_send_the_first_ufo_after_the_scheduled_delay() {
+            let mut app = setup();
+            app.update();
+
+            advance_time_by_seconds(&mut app, 1.5);
+            app.update();
+            assert_eq!(count_components::<UfoComponent>(&mut app), 0);
+
+            advance_time_by_seconds(&mut app, 0.6);
+            app.update();
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn should_send_the_next_ufo_after_the_interval() {
+            let mut app = setup();
+            app.update();
+
+            advance_time_by_seconds(&mut app, 2.1);
+            app.update();
+            let world = app.world_mut();
+            let ufo = world
+                .query_filtered::<Entity, With<UfoComponent>>()
+                .single(world)
+                .expect("One UFO should fly");
+            world.despawn(ufo);
+
+            advance_time_by_seconds(&mut app, 4.5);
+            app.update();
+            assert_eq!(count_components::<UfoComponent>(&mut app), 0);
+
+            advance_time_by_seconds(&mut app, 0.6);
+            app.update();
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn should_not_send_a_second_ufo_while_one_is_flying() {
+            let mut app = setup();
+            app.update();
+
+            advance_time_by_seconds(&mut app, 2.1);
+            app.update();
+            advance_time_by_seconds(&mut app, 5.1);
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn should_not_send_a_ufo_in_waves_without_a_schedule() {
+            let mut app = setup();
+            app.insert_resource(WaveResource(Wave::new(2)));
+            app.update();
+
+            for _ in 0..10 {
+                advance_time_by_seconds(&mut app, 2.0);
+                app.update();
+            }
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 0);
+        }
+    }
+}
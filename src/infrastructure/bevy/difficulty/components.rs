@@ -0,0 +1,49 @@
+use crate::infrastructure::bevy::difficulty::resources::{
+    DIFFICULTY_FONT_COLOR, DIFFICULTY_FONT_SIZE,
+};
+use bevy::asset::Handle;
+use bevy::prelude::{Bundle, Component, default};
+use bevy::text::{Font, TextColor, TextFont};
+use bevy::ui::widget::Text;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct DifficultyLabelComponent;
+
+#[derive(Bundle)]
+pub struct DifficultyLabelBundle {
+    pub difficulty_label: DifficultyLabelComponent,
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl DifficultyLabelBundle {
+    pub fn new(font: Handle<Font>, label: String) -> Self {
+        Self {
+            difficulty_label: DifficultyLabelComponent,
+            text: Text::new(label),
+            text_font: TextFont {
+                font,
+                font_size: DIFFICULTY_FONT_SIZE,
+                ..default()
+            },
+            text_color: TextColor(DIFFICULTY_FONT_COLOR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_test::{TestAppBuilder, dummy_font};
+
+    #[test]
+    fn should_create_difficulty_label_bundle() {
+        let app = TestAppBuilder::new().with_assets().build();
+
+        let bundle = DifficultyLabelBundle::new(dummy_font(&app), "HARD".to_string());
+
+        assert_eq!(bundle.text.0, "HARD");
+        assert_eq!(bundle.text_color.0, DIFFICULTY_FONT_COLOR);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::infrastructure::bevy::difficulty::resources::DynamicDifficultyResource;
+use crate::infrastructure::bevy::difficulty::systems::{
+    reset_dynamic_difficulty_system, spawn_difficulty_label_system, track_player_performance_system,
+};
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::lives::systems::spawn_lives_system;
+use crate::infrastructure::bevy::score::systems::spawn_score_system;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, resource_exists};
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DifficultyResource>()
+            .add_systems(
+                Startup,
+                spawn_difficulty_label_system
+                    .after(spawn_score_system)
+                    .before(spawn_lives_system),
+            )
+            .add_systems(
+                Update,
+                (
+                    track_player_performance_system.in_set(GameplaySystems),
+                    reset_dynamic_difficulty_system,
+                )
+                    .run_if(resource_exists::<DynamicDifficultyResource>),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+    use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::header::plugin::HeaderPlugin;
+    use crate::infrastructure::bevy::player::components::PlayerFiredMessage;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(HeaderPlugin)
+            .with_plugin(DifficultyPlugin)
+            .with_message::<PlayerFiredMessage>()
+            .with_message::<EnemyKilledMessage>()
+            .with_message::<EnemyDamagedMessage>()
+            .with_message::<PlayerKilledMessage>()
+            .with_message::<NewGameMessage>()
+            .with_setup(|app| {
+                app.init_resource::<DynamicDifficultyResource>();
+            })
+            .build();
+    }
+}
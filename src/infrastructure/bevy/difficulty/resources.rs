@@ -0,0 +1,30 @@
+use crate::domain::difficulty::{Difficulty, DynamicDifficulty};
+use bevy::color::Color;
+use bevy::prelude::Resource;
+
+pub const DIFFICULTY_FONT_SIZE: f32 = 14.0;
+pub const DIFFICULTY_FONT_COLOR: Color = Color::srgb_u8(255, 215, 0);
+
+#[derive(Resource, Default)]
+pub struct DynamicDifficultyResource(pub DynamicDifficulty);
+
+pub fn difficulty_label(difficulty: Difficulty, dynamic: bool) -> String {
+    let name = difficulty.as_str().to_uppercase();
+
+    if dynamic {
+        format!("{} (DYNAMIC)", name)
+    } else {
+        name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_name_the_preset_and_the_dynamic_mode() {
+        assert_eq!(difficulty_label(Difficulty::Hard, false), "HARD");
+        assert_eq!(difficulty_label(Difficulty::Easy, true), "EASY (DYNAMIC)");
+    }
+}
@@ -0,0 +1,214 @@
+use crate::domain::difficulty::DynamicDifficulty;
+use crate::infrastructure::bevy::difficulty::components::DifficultyLabelBundle;
+use crate::infrastructure::bevy::difficulty::resources::{
+    DynamicDifficultyResource, difficulty_label,
+};
+use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::header::components::HeaderComponent;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::player::components::PlayerFiredMessage;
+use bevy::asset::AssetServer;
+use bevy::prelude::{Commands, DetectChanges, Entity, MessageReader, Query, Res, ResMut, With};
+use tracing::debug;
+
+pub fn spawn_difficulty_label_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    difficulty: Res<DifficultyResource>,
+    dynamic: Option<Res<DynamicDifficultyResource>>,
+    header_query: Query<Entity, With<HeaderComponent>>,
+) {
+    if let Ok(header) = header_query.single() {
+        let label = difficulty_label(difficulty.0, dynamic.is_some());
+
+        commands.entity(header).with_children(|parent| {
+            parent.spawn(DifficultyLabelBundle::new(asset_server.load(FONT), label));
+        });
+    }
+}
+
+pub fn track_player_performance_system(
+    mut dynamic: ResMut<DynamicDifficultyResource>,
+    mut player_fired_message: MessageReader<PlayerFiredMessage>,
+    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
+    mut enemy_damaged_message: MessageReader<EnemyDamagedMessage>,
+    mut player_killed_message: MessageReader<PlayerKilledMessage>,
+) {
+    for _ in player_fired_message.read() {
+        dynamic.0.record_shot();
+    }
+
+    for _ in enemy_killed_message.read() {
+        dynamic.0.record_hit();
+    }
+
+    for _ in enemy_damaged_message.read() {
+        dynamic.0.record_hit();
+    }
+
+    for _ in player_killed_message.read() {
+        dynamic.0.record_death();
+    }
+
+    if dynamic.is_changed() {
+        debug!("Dynamic fire scale {:.2}", dynamic.0.fire_scale());
+    }
+}
+
+pub fn reset_dynamic_difficulty_system(
+    mut dynamic: ResMut<DynamicDifficultyResource>,
+    mut new_game_message: MessageReader<NewGameMessage>,
+) {
+    if new_game_message.read().count() > 0 {
+        dynamic.0 = DynamicDifficulty::new();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::difficulty::{DYNAMIC_MIN_SHOTS, Difficulty};
+    use crate::domain::enemy::{Enemy, EnemyId, EnemyKind};
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::infrastructure::bevy::difficulty::components::DifficultyLabelComponent;
+    use bevy::app::{App, Startup, Update};
+    use bevy::image::Image;
+    use bevy::prelude::AssetApp;
+    use bevy::text::Font;
+    use bevy_test::{
+        TestAppBuilder, get_resource_or_fail, query_single_text, send_message, spawn_dummy_entity,
+    };
+
+    fn setup() -> App {
+        TestAppBuilder::new()
+            .with_assets()
+            .with_setup(|app| {
+                app.init_asset::<Image>()
+                    .init_asset::<Font>()
+                    .insert_resource(DifficultyResource(Difficulty::Hard))
+                    .add_message::<PlayerFiredMessage>()
+                    .add_message::<EnemyKilledMessage>()
+                    .add_message::<EnemyDamagedMessage>()
+                    .add_message::<PlayerKilledMessage>()
+                    .add_message::<NewGameMessage>();
+
+                app.world_mut().spawn(HeaderComponent);
+            })
+            .build()
+    }
+
+    #[cfg(test)]
+    mod spawn_difficulty_label_system {
+        use super::*;
+
+        #[test]
+        fn should_show_the_active_preset() {
+            let mut app = setup();
+            app.add_systems(Startup, spawn_difficulty_label_system);
+            app.update();
+
+            assert_eq!(
+                query_single_text::<DifficultyLabelComponent>(&mut app),
+                "HARD"
+            );
+        }
+
+        #[test]
+        fn should_mention_the_dynamic_mode() {
+            let mut app = setup();
+            app.init_resource::<DynamicDifficultyResource>()
+                .add_systems(Startup, spawn_difficulty_label_system);
+            app.update();
+
+            assert_eq!(
+                query_single_text::<DifficultyLabelComponent>(&mut app),
+                "HARD (DYNAMIC)"
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod track_player_performance_system {
+        use super::*;
+
+        #[test]
+        fn should_track_accuracy_and_deaths() {
+            let mut app = setup();
+            app.init_resource::<DynamicDifficultyResource>()
+                .add_systems(Update, track_player_performance_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            for id in 0..DYNAMIC_MIN_SHOTS {
+                send_message(&mut app, PlayerFiredMessage);
+                if id % 2 == 0 {
+                    send_message(
+                        &mut app,
+                        EnemyKilledMessage::new(dummy, EnemyId::new(id), dummy, PlayerSlot::One),
+                    );
+                }
+            }
+            send_message(&mut app, PlayerKilledMessage::new(dummy, PlayerSlot::One));
+            app.update();
+
+            let dynamic = &get_resource_or_fail::<DynamicDifficultyResource>(&mut app).0;
+            assert_eq!(dynamic.accuracy(), Some(0.6));
+            assert_eq!(dynamic.recent_deaths(), 1);
+        }
+
+        #[test]
+        fn should_count_hits_on_armored_enemies_that_survive() {
+            let mut app = setup();
+            app.init_resource::<DynamicDifficultyResource>()
+                .add_systems(Update, track_player_performance_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            let mut armored = Enemy::with_kind(1, EnemyKind::Armored);
+            for _ in 0..DYNAMIC_MIN_SHOTS {
+                send_message(&mut app, PlayerFiredMessage);
+                if armored.damage() {
+                    armored = Enemy::with_kind(1, EnemyKind::Armored);
+                    send_message(
+                        &mut app,
+                        EnemyKilledMessage::new(dummy, armored.get_id(), dummy, PlayerSlot::One),
+                    );
+                } else {
+                    send_message(
+                        &mut app,
+                        EnemyDamagedMessage::new(armored.get_id(), dummy, PlayerSlot::One),
+                    );
+                }
+            }
+            app.update();
+
+            let dynamic = &get_resource_or_fail::<DynamicDifficultyResource>(&mut app).0;
+            assert_eq!(dynamic.accuracy(), Some(1.0));
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_dynamic_difficulty_system {
+        use super::*;
+
+        #[test]
+        fn should_forget_the_last_game() {
+            let mut app = setup();
+            let mut dynamic = DynamicDifficulty::new();
+            dynamic.record_death();
+            app.insert_resource(DynamicDifficultyResource(dynamic))
+                .add_systems(Update, reset_dynamic_difficulty_system);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<DynamicDifficultyResource>(&mut app)
+                    .0
+                    .recent_deaths(),
+                0
+            );
+        }
+    }
+}
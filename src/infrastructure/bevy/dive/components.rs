@@ -0,0 +1,13 @@
+use crate::domain::dive::Dive;
+use bevy::prelude::Component;
+
+#[derive(Component, Debug)]
+pub struct DiverComponent {
+    pub dive: Dive,
+}
+
+impl DiverComponent {
+    pub fn new(dive: Dive) -> Self {
+        Self { dive }
+    }
+}
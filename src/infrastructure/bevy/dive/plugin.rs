@@ -0,0 +1,47 @@
+use crate::infrastructure::bevy::dive::resources::DiveTimerResource;
+use crate::infrastructure::bevy::dive::systems::{dive_movement_system, launch_dives_system};
+use crate::infrastructure::bevy::enemy_formation::resources::GameRngResource;
+use crate::infrastructure::bevy::enemy_formation::systems::{
+    collisions_system, enemy_formation_movement_system, next_wave_system,
+};
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::IntoScheduleConfigs;
+
+pub struct DivePlugin;
+
+impl Plugin for DivePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiveTimerResource>()
+            .init_resource::<GameRngResource>()
+            .add_systems(
+                Update,
+                (
+                    launch_dives_system
+                        .after(collisions_system)
+                        .after(next_wave_system),
+                    dive_movement_system.after(launch_dives_system),
+                )
+                    .before(enemy_formation_movement_system)
+                    .in_set(GameplaySystems),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_formation::plugin::EnemyFormationPlugin;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(EnemyFormationPlugin)
+            .with_plugin(DivePlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::{Resource, Timer, TimerMode};
+
+pub const DIVE_INTERVAL: f32 = 2.5;
+pub const MAX_DIVERS: usize = 2;
+
+#[derive(Resource)]
+pub struct DiveTimerResource(pub Timer);
+
+impl Default for DiveTimerResource {
+    fn default() -> Self {
+        Self(Timer::from_seconds(DIVE_INTERVAL, TimerMode::Repeating))
+    }
+}
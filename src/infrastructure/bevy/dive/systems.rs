@@ -0,0 +1,277 @@
+use crate::domain::dive::{DIVE_EXIT_CHANCE, DIVE_SWING, Dive, DiveEnding};
+use crate::infrastructure::bevy::dive::components::DiverComponent;
+use crate::infrastructure::bevy::dive::resources::{DiveTimerResource, MAX_DIVERS};
+use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+use crate::infrastructure::bevy::enemy::resources::{ENEMY_HEIGHT, ENEMY_WIDTH};
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, GameRngResource,
+};
+use crate::infrastructure::bevy::enemy_formation::systems::slot_translation;
+use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+use bevy::prelude::*;
+use rand::Rng;
+use rand::prelude::IteratorRandom;
+
+pub fn launch_dives_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut timer: ResMut<DiveTimerResource>,
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+    mut rng: ResMut<GameRngResource>,
+    enemy_query: Query<(Entity, &EnemyComponent, &Transform), Without<DiverComponent>>,
+    player_query: Query<&Transform, With<PlayerComponent>>,
+) {
+    if !timer.0.tick(time.delta()).just_finished()
+        || enemy_formation_res.0.count_away() >= MAX_DIVERS
+    {
+        return;
+    }
+
+    let rng = &mut rng.0;
+
+    let Some((entity, enemy, transform)) = enemy_query.iter().choose(rng) else {
+        return;
+    };
+
+    if !enemy_formation_res.0.dive(enemy.id) {
+        return;
+    }
+
+    let start = (transform.translation.x, transform.translation.y);
+    let target = player_query
+        .iter()
+        .choose(rng)
+        .map_or((start.0, -(GAME_AREA_HEIGHT / 2.0)), |player| {
+            (player.translation.x, player.translation.y)
+        });
+    let swing = if rng.random_bool(0.5) {
+        DIVE_SWING
+    } else {
+        -DIVE_SWING
+    };
+    let ending = if rng.random_bool(DIVE_EXIT_CHANCE) {
+        DiveEnding::Exit
+    } else {
+        DiveEnding::Return
+    };
+    let exit_y = -(GAME_AREA_HEIGHT / 2.0) - ENEMY_HEIGHT;
+
+    commands
+        .entity(entity)
+        .insert(DiverComponent::new(Dive::new(
+            start, target, swing, exit_y, ending,
+        )));
+}
+
+pub fn dive_movement_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+    mut diver_query: Query<(Entity, &EnemyComponent, &mut DiverComponent, &mut Transform)>,
+) {
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+    let half_width = (GAME_AREA_WIDTH - ENEMY_WIDTH) / 2.0;
+
+    for (entity, enemy, mut diver, mut transform) in diver_query.iter_mut() {
+        let slot = enemy_formation_res.0.get_slot(enemy.id);
+
+        let Some((row, column)) = slot.filter(|_| enemy_formation_res.0.is_away(enemy.id)) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        diver.dive.tick(time.delta_secs() * time_scale);
+
+        let slot = slot_translation(&enemy_formation_res.0, row, column);
+        let (x, y) = diver.dive.position((slot.x, slot.y));
+        transform.translation.x = x.clamp(-half_width, half_width);
+        transform.translation.y = y;
+
+        if diver.dive.is_finished() {
+            match diver.dive.get_ending() {
+                DiveEnding::Return => enemy_formation_res.0.return_to_slot(enemy.id),
+                DiveEnding::Exit => enemy_formation_res.0.leave(enemy.id),
+            };
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dive::DIVE_DURATION;
+    use crate::domain::enemy::{EnemyId, EnemyKind};
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::formation_layout::FormationLayout;
+    use crate::infrastructure::bevy::dive::resources::DIVE_INTERVAL;
+    use crate::infrastructure::bevy::enemy::components::EnemyBundle;
+    use bevy::app::{App, Update};
+    use bevy::image::Image;
+    use bevy::prelude::AssetApp;
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, count_components, get_resource_mut_or_fail,
+        get_resource_or_fail,
+    };
+
+    fn setup(layout: &[&str]) -> App {
+        let layout = FormationLayout::parse(layout).expect("Layout should parse");
+        let mut app = TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_assets()
+            .with_setup(move |app| {
+                app.init_asset::<Image>()
+                    .init_resource::<DiveTimerResource>()
+                    .insert_resource(GameRngResource::seeded(7))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::from_layout(&layout)));
+            })
+            .build();
+
+        app.world_mut().spawn((
+            PlayerComponent::default(),
+            Transform::from_xyz(0.0, -300.0, 0.0),
+        ));
+        app
+    }
+
+    fn spawn_enemy(app: &mut App, id: usize) -> Entity {
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        app.world_mut()
+            .spawn(EnemyBundle::new(
+                EnemyId::new(id),
+                EnemyKind::Squid,
+                0.0,
+                200.0,
+                &asset_server,
+            ))
+            .id()
+    }
+
+    fn send_diving(app: &mut App, id: usize, ending: DiveEnding) -> Entity {
+        let entity = spawn_enemy(app, id);
+        get_resource_mut_or_fail::<EnemyFormationResource>(app)
+            .0
+            .dive(EnemyId::new(id));
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(DiverComponent::new(Dive::new(
+                (0.0, 200.0),
+                (0.0, -300.0),
+                DIVE_SWING,
+                -400.0,
+                ending,
+            )));
+        entity
+    }
+
+    #[cfg(test)]
+    mod launch_dives_system {
+        use super::*;
+
+        #[test]
+        fn should_send_an_invader_diving_on_each_interval() {
+            let mut app = setup(&["SS"]);
+            app.add_systems(Update, launch_dives_system);
+            spawn_enemy(&mut app, 1);
+            spawn_enemy(&mut app, 2);
+
+            app.update();
+            assert_eq!(count_components::<DiverComponent>(&mut app), 0);
+
+            advance_time_by_seconds(&mut app, DIVE_INTERVAL);
+            app.update();
+
+            assert_eq!(count_components::<DiverComponent>(&mut app), 1);
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .count_away(),
+                1
+            );
+        }
+
+        #[test]
+        fn should_not_exceed_the_number_of_divers() {
+            let mut app = setup(&["SSSS"]);
+            app.add_systems(Update, launch_dives_system);
+            for id in 1..=4 {
+                spawn_enemy(&mut app, id);
+            }
+
+            for _ in 0..4 {
+                advance_time_by_seconds(&mut app, DIVE_INTERVAL);
+                app.update();
+            }
+
+            assert_eq!(count_components::<DiverComponent>(&mut app), MAX_DIVERS);
+        }
+    }
+
+    #[cfg(test)]
+    mod dive_movement_system {
+        use super::*;
+
+        #[test]
+        fn should_swoop_toward_the_player() {
+            let mut app = setup(&["S"]);
+            app.add_systems(Update, dive_movement_system);
+            let diver = send_diving(&mut app, 1, DiveEnding::Return);
+
+            advance_time_by_seconds(&mut app, DIVE_DURATION / 2.0);
+            app.update();
+
+            let transform = app
+                .world()
+                .get::<Transform>(diver)
+                .expect("Diver should still fly");
+            assert!(transform.translation.y < 0.0);
+        }
+
+        #[test]
+        fn should_hand_returning_divers_back_to_the_formation() {
+            let mut app = setup(&["S"]);
+            app.add_systems(Update, dive_movement_system);
+            send_diving(&mut app, 1, DiveEnding::Return);
+
+            advance_time_by_seconds(&mut app, DIVE_DURATION);
+            app.update();
+
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.count_away(), 0);
+            assert_eq!(formation.count_alive(), 1);
+            assert_eq!(count_components::<DiverComponent>(&mut app), 0);
+        }
+
+        #[test]
+        fn should_lose_divers_that_leave_the_screen() {
+            let mut app = setup(&["SS"]);
+            app.add_systems(Update, dive_movement_system);
+            send_diving(&mut app, 1, DiveEnding::Exit);
+
+            advance_time_by_seconds(&mut app, DIVE_DURATION);
+            app.update();
+
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.count_away(), 0);
+            assert_eq!(formation.count_alive(), 1);
+            assert_eq!(count_components::<DiverComponent>(&mut app), 0);
+        }
+
+        #[test]
+        fn should_remove_divers_that_were_shot_down() {
+            let mut app = setup(&["S"]);
+            app.add_systems(Update, dive_movement_system);
+            send_diving(&mut app, 1, DiveEnding::Return);
+            get_resource_mut_or_fail::<EnemyFormationResource>(&mut app)
+                .0
+                .kill(EnemyId::new(1));
+
+            app.update();
+
+            assert_eq!(count_components::<DiverComponent>(&mut app), 0);
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::domain::level_editor::EditorParameter;
+use crate::infrastructure::bevy::editor::resources::EDITOR_GAP;
+use bevy::color::Color;
+use bevy::prelude::{Bundle, Component, default};
+use bevy::ui::widget::Button;
+use bevy::ui::{BackgroundColor, Node, UiRect, Val};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct EditorScreenComponent;
+
+#[derive(Component, PartialEq, Debug, Clone, Copy)]
+pub struct EditorCellComponent {
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Component, PartialEq, Debug, Clone, Copy)]
+pub struct EditorPixelComponent {
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Component, PartialEq, Debug)]
+pub struct EditorParameterComponent(pub EditorParameter);
+
+#[derive(Component, PartialEq, Debug)]
+pub struct EditorBrushComponent;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct EditorStatusComponent;
+
+#[derive(Bundle)]
+pub struct EditorSquareBundle {
+    pub button: Button,
+    pub node: Node,
+    pub background_color: BackgroundColor,
+}
+
+impl EditorSquareBundle {
+    pub fn new(size: f32, color: Color) -> Self {
+        Self {
+            button: Button,
+            node: Node {
+                width: Val::Px(size),
+                height: Val::Px(size),
+                margin: UiRect::all(Val::Px(EDITOR_GAP / 2.0)),
+                ..default()
+            },
+            background_color: BackgroundColor(color),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::editor::resources::EDITOR_EMPTY_COLOR;
+
+    #[test]
+    fn should_create_a_square_button() {
+        let bundle = EditorSquareBundle::new(10.0, EDITOR_EMPTY_COLOR);
+
+        assert_eq!(bundle.node.width, Val::Px(10.0));
+        assert_eq!(bundle.node.height, Val::Px(10.0));
+        assert_eq!(bundle.background_color.0, EDITOR_EMPTY_COLOR);
+    }
+}
@@ -0,0 +1,70 @@
+use crate::infrastructure::bevy::campaign::plugin::WaveScriptPlugin;
+use crate::infrastructure::bevy::editor::resources::{
+    EditorFileResource, EditorResource, EditorStatusResource,
+};
+use crate::infrastructure::bevy::editor::systems::{
+    editor_click_system, editor_input_system, load_editor_system, return_to_editor_system,
+    save_wave_system, spawn_editor_screen_system, test_play_system, update_editor_brush_system,
+    update_editor_grid_system, update_editor_parameters_system, update_editor_status_system,
+};
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::save_game::resources::SaveGameStorageResource;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, OnEnter, in_state, not};
+use std::path::PathBuf;
+
+pub struct EditorPlugin {
+    pub path: PathBuf,
+}
+
+impl Plugin for EditorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<WaveScriptPlugin>() {
+            app.add_plugins(WaveScriptPlugin);
+        }
+
+        app.init_resource::<EditorResource>()
+            .init_resource::<EditorStatusResource>()
+            .insert_resource(EditorFileResource(self.path.clone()))
+            .insert_resource(SaveGameStorageResource { path: None })
+            .add_systems(Startup, load_editor_system)
+            .add_systems(OnEnter(GameState::Editor), spawn_editor_screen_system)
+            .add_systems(
+                Update,
+                (
+                    editor_click_system,
+                    editor_input_system,
+                    save_wave_system,
+                    test_play_system,
+                    update_editor_grid_system,
+                    update_editor_parameters_system,
+                    update_editor_brush_system,
+                    update_editor_status_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Editor)),
+            )
+            .add_systems(
+                Update,
+                return_to_editor_system.run_if(not(in_state(GameState::Editor))),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::game_state::plugin::GameStatePlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(GameStatePlugin)
+            .with_plugin(EditorPlugin {
+                path: PathBuf::from("custom.wave.ron"),
+            })
+            .build();
+    }
+}
@@ -0,0 +1,103 @@
+use crate::domain::enemy::EnemyKind;
+use crate::domain::level_editor::LevelEditor;
+use crate::domain::wave_script::WaveScript;
+use crate::infrastructure::bevy::campaign::resources::{format_wave_script, parse_wave_script};
+use crate::infrastructure::storage::write_atomically;
+use bevy::color::Color;
+use bevy::prelude::Resource;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const EDITOR_CELL_SIZE: f32 = 22.0;
+pub const EDITOR_PIXEL_SIZE: f32 = 6.0;
+pub const EDITOR_GAP: f32 = 2.0;
+pub const EDITOR_FONT_SIZE: f32 = 12.0;
+pub const EDITOR_EMPTY_COLOR: Color = Color::srgb_u8(40, 40, 40);
+pub const EDITOR_SHIELD_COLOR: Color = Color::srgb_u8(51, 255, 3);
+pub const EDITOR_HELP_TEXT: &str = "CLICK: PAINT  1/2/3: BRUSH  UP/DOWN: PARAMETER  LEFT/RIGHT: ADJUST  S: SAVE  ENTER: PLAY  ESC: BACK";
+
+#[derive(Resource, Default)]
+pub struct EditorResource(pub LevelEditor);
+
+#[derive(Resource)]
+pub struct EditorFileResource(pub PathBuf);
+
+#[derive(Resource, Default)]
+pub struct EditorStatusResource(pub String);
+
+pub fn brush_color(kind: EnemyKind) -> Color {
+    match kind {
+        EnemyKind::Squid => Color::srgb_u8(0, 255, 255),
+        EnemyKind::Crab => Color::srgb_u8(255, 0, 255),
+        EnemyKind::Octopus => Color::srgb_u8(255, 215, 0),
+        EnemyKind::Armored => Color::srgb_u8(160, 170, 190),
+    }
+}
+
+pub fn load_wave_file(path: &Path) -> Result<LevelEditor, String> {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_wave_script(&path.display().to_string(), &source)
+            .map(LevelEditor::from_script)
+            .map_err(|error| error.to_string()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+            let mut script = WaveScript::classic(0.6, 0.2);
+            script.name = wave_name(path);
+
+            Ok(LevelEditor::from_script(script))
+        }
+        Err(error) => Err(error.to_string()),
+    }
+}
+
+pub fn save_wave_file(path: &Path, script: &WaveScript) -> Result<(), String> {
+    let source = format_wave_script(script)?;
+
+    write_atomically(path, source.as_bytes()).map_err(|error| error.to_string())
+}
+
+fn wave_name(path: &Path) -> String {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.split('.').next())
+        .filter(|name| !name.is_empty())
+        .unwrap_or("custom")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::temporary_directory;
+
+    #[test]
+    fn missing_files_start_from_the_classic_wave() {
+        let editor = load_wave_file(&temporary_directory("editor-missing").join("custom.wave.ron"))
+            .expect("Missing file should start a new wave");
+
+        assert_eq!(editor.get_name(), "custom");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saved_waves_load_back_into_the_editor() {
+        let path = temporary_directory("editor-round-trip").join("custom.wave.ron");
+        let mut editor = LevelEditor::new();
+        editor.toggle_cell(0, 0);
+        editor.increase();
+        let script = editor.to_script().expect("Wave should be valid");
+
+        save_wave_file(&path, &script).expect("Wave should be saved");
+        let loaded = load_wave_file(&path).expect("Wave should load");
+
+        assert_eq!(loaded.to_script(), Ok(script));
+        assert!(!path.with_extension("ron.tmp").exists());
+    }
+
+    #[test]
+    fn should_report_invalid_files() {
+        let path = temporary_directory("editor-invalid").join("custom.wave.ron");
+        write_atomically(&path, b"not a wave").expect("File should be written");
+
+        assert!(load_wave_file(&path).is_err());
+    }
+}
@@ -0,0 +1,599 @@
+use crate::domain::enemy::EnemyKind;
+use crate::domain::level_editor::{EditorParameter, LevelEditor};
+use crate::domain::wave_script::Campaign;
+use crate::infrastructure::bevy::campaign::resources::CampaignResource;
+use crate::infrastructure::bevy::editor::components::{
+    EditorBrushComponent, EditorCellComponent, EditorParameterComponent, EditorPixelComponent,
+    EditorScreenComponent, EditorSquareBundle, EditorStatusComponent,
+};
+use crate::infrastructure::bevy::editor::resources::{
+    EDITOR_CELL_SIZE, EDITOR_EMPTY_COLOR, EDITOR_FONT_SIZE, EDITOR_HELP_TEXT, EDITOR_PIXEL_SIZE,
+    EDITOR_SHIELD_COLOR, EditorFileResource, EditorResource, EditorStatusResource, brush_color,
+    load_wave_file, save_wave_file,
+};
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    FormationLayoutsResource, FormationMovementsResource,
+};
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::high_scores::components::{ScreenBundle, ScreenTextBundle};
+use crate::infrastructure::bevy::high_scores::resources::{
+    SCREEN_TEXT_COLOR, SCREEN_TEXT_FONT_SIZE, SCREEN_TITLE_COLOR, SELECTED_LETTER_COLOR,
+};
+use crate::infrastructure::bevy::shield_formation::resources::ShieldLayoutsResource;
+use bevy::asset::AssetServer;
+use bevy::color::Color;
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Changed, Commands, DespawnOnExit, DetectChanges, KeyCode, MessageWriter, NextState, Query, Res,
+    ResMut, Text, TextColor, With, Without, default,
+};
+use bevy::ui::{BackgroundColor, FlexDirection, Interaction, Node, Val};
+use tracing::{error, info};
+
+pub fn load_editor_system(
+    file: Res<EditorFileResource>,
+    mut editor: ResMut<EditorResource>,
+    mut status: ResMut<EditorStatusResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    match load_wave_file(&file.0) {
+        Ok(loaded) => {
+            info!("Editing {}", file.0.display());
+            editor.0 = loaded;
+        }
+        Err(reason) => {
+            error!("Cannot load {}: {}", file.0.display(), reason);
+            status.0 = format!("CANNOT LOAD, STARTING OVER: {}", reason);
+        }
+    }
+
+    next_state.set(GameState::Editor);
+}
+
+pub fn spawn_editor_screen_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    editor: Res<EditorResource>,
+    status: Res<EditorStatusResource>,
+) {
+    let font = asset_server.load(FONT);
+    let text = |value: String, color: Color| {
+        ScreenTextBundle::new(value, font.clone(), EDITOR_FONT_SIZE, color)
+    };
+
+    commands
+        .spawn((
+            ScreenBundle::new(),
+            EditorScreenComponent,
+            DespawnOnExit(GameState::Editor),
+        ))
+        .with_children(|screen| {
+            screen.spawn(ScreenTextBundle::new(
+                format!("EDITING {}", editor.0.get_name().to_uppercase()),
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+            screen.spawn((
+                text(brush_text(editor.0.get_brush()), SCREEN_TEXT_COLOR),
+                EditorBrushComponent,
+            ));
+
+            screen
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(24.0),
+                    ..default()
+                })
+                .with_children(|panels| {
+                    panels.spawn(column_node()).with_children(|grid| {
+                        for (row, cells) in editor.0.get_cells().iter().enumerate() {
+                            grid.spawn(Node::default()).with_children(|line| {
+                                for column in 0..cells.len() {
+                                    line.spawn((
+                                        EditorSquareBundle::new(
+                                            EDITOR_CELL_SIZE,
+                                            cell_color(&editor.0, row, column),
+                                        ),
+                                        EditorCellComponent { row, column },
+                                    ));
+                                }
+                            });
+                        }
+                    });
+
+                    panels.spawn(column_node()).with_children(|grid| {
+                        for (row, pixels) in editor.0.get_pixels().iter().enumerate() {
+                            grid.spawn(Node::default()).with_children(|line| {
+                                for column in 0..pixels.len() {
+                                    line.spawn((
+                                        EditorSquareBundle::new(
+                                            EDITOR_PIXEL_SIZE,
+                                            pixel_color(&editor.0, row, column),
+                                        ),
+                                        EditorPixelComponent { row, column },
+                                    ));
+                                }
+                            });
+                        }
+                    });
+
+                    panels.spawn(column_node()).with_children(|list| {
+                        for parameter in EditorParameter::ALL {
+                            list.spawn((
+                                text(
+                                    parameter_text(&editor.0, parameter),
+                                    parameter_color(&editor.0, parameter),
+                                ),
+                                EditorParameterComponent(parameter),
+                            ));
+                        }
+                    });
+                });
+
+            screen.spawn((
+                text(status.0.clone(), SELECTED_LETTER_COLOR),
+                EditorStatusComponent,
+            ));
+            screen.spawn(text(EDITOR_HELP_TEXT.to_string(), SCREEN_TEXT_COLOR));
+        });
+}
+
+pub fn editor_click_system(
+    mut editor: ResMut<EditorResource>,
+    cell_query: Query<(&Interaction, &EditorCellComponent), Changed<Interaction>>,
+    pixel_query: Query<(&Interaction, &EditorPixelComponent), Changed<Interaction>>,
+) {
+    for (interaction, cell) in &cell_query {
+        if *interaction == Interaction::Pressed {
+            editor.0.toggle_cell(cell.row, cell.column);
+        }
+    }
+
+    for (interaction, pixel) in &pixel_query {
+        if *interaction == Interaction::Pressed {
+            editor.0.toggle_pixel(pixel.row, pixel.column);
+        }
+    }
+}
+
+pub fn editor_input_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut editor: ResMut<EditorResource>,
+) {
+    let brushes = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+    ];
+    for (key, kind) in brushes.into_iter().zip(EnemyKind::ALL) {
+        if keyboard.just_pressed(key) {
+            editor.0.set_brush(kind);
+        }
+    }
+
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        editor.0.previous_parameter();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        editor.0.next_parameter();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowLeft) {
+        editor.0.decrease();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowRight) {
+        editor.0.increase();
+    }
+}
+
+pub fn save_wave_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    editor: Res<EditorResource>,
+    file: Res<EditorFileResource>,
+    mut status: ResMut<EditorStatusResource>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyS) {
+        return;
+    }
+
+    let saved = editor
+        .0
+        .to_script()
+        .map_err(|error| error.to_string())
+        .and_then(|script| save_wave_file(&file.0, &script));
+
+    status.0 = match saved {
+        Ok(()) => {
+            info!("Wave saved to {}", file.0.display());
+            format!("SAVED {}", file.0.display())
+        }
+        Err(reason) => {
+            error!("Cannot save {}: {}", file.0.display(), reason);
+            format!("CANNOT SAVE: {}", reason)
+        }
+    };
+}
+
+pub fn test_play_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    editor: Res<EditorResource>,
+    mut status: ResMut<EditorStatusResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut new_game_writer: MessageWriter<NewGameMessage>,
+) {
+    if !keyboard.just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    let campaign = editor
+        .0
+        .to_script()
+        .and_then(|script| Campaign::new(editor.0.get_name().to_string(), vec![script]));
+
+    match campaign {
+        Ok(campaign) => {
+            info!("Test playing '{}'", campaign.get_name());
+            commands.insert_resource(FormationLayoutsResource(campaign.formation_layouts()));
+            commands.insert_resource(FormationMovementsResource(campaign.formation_movements()));
+            commands.insert_resource(ShieldLayoutsResource(campaign.shield_layouts()));
+            commands.insert_resource(CampaignResource(campaign));
+            status.0.clear();
+            new_game_writer.write(NewGameMessage);
+            next_state.set(GameState::Playing);
+        }
+        Err(reason) => status.0 = format!("CANNOT PLAY: {}", reason),
+    }
+}
+
+pub fn return_to_editor_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        next_state.set(GameState::Editor);
+    }
+}
+
+pub fn update_editor_grid_system(
+    editor: Res<EditorResource>,
+    mut cell_query: Query<
+        (&EditorCellComponent, &mut BackgroundColor),
+        Without<EditorPixelComponent>,
+    >,
+    mut pixel_query: Query<(&EditorPixelComponent, &mut BackgroundColor)>,
+) {
+    if !editor.is_changed() {
+        return;
+    }
+
+    for (cell, mut color) in &mut cell_query {
+        color.0 = cell_color(&editor.0, cell.row, cell.column);
+    }
+
+    for (pixel, mut color) in &mut pixel_query {
+        color.0 = pixel_color(&editor.0, pixel.row, pixel.column);
+    }
+}
+
+pub fn update_editor_parameters_system(
+    editor: Res<EditorResource>,
+    mut parameter_query: Query<(&EditorParameterComponent, &mut Text, &mut TextColor)>,
+) {
+    if !editor.is_changed() {
+        return;
+    }
+
+    for (parameter, mut text, mut color) in &mut parameter_query {
+        text.0 = parameter_text(&editor.0, parameter.0);
+        color.0 = parameter_color(&editor.0, parameter.0);
+    }
+}
+
+pub fn update_editor_brush_system(
+    editor: Res<EditorResource>,
+    mut brush_query: Query<&mut Text, With<EditorBrushComponent>>,
+) {
+    if !editor.is_changed() {
+        return;
+    }
+
+    for mut text in &mut brush_query {
+        text.0 = brush_text(editor.0.get_brush());
+    }
+}
+
+pub fn update_editor_status_system(
+    status: Res<EditorStatusResource>,
+    mut status_query: Query<&mut Text, With<EditorStatusComponent>>,
+) {
+    if !status.is_changed() {
+        return;
+    }
+
+    for mut text in &mut status_query {
+        text.0 = status.0.clone();
+    }
+}
+
+fn column_node() -> Node {
+    Node {
+        flex_direction: FlexDirection::Column,
+        ..default()
+    }
+}
+
+fn cell_color(editor: &LevelEditor, row: usize, column: usize) -> Color {
+    editor
+        .get_cells()
+        .get(row)
+        .and_then(|cells| cells.get(column).copied().flatten())
+        .map_or(EDITOR_EMPTY_COLOR, brush_color)
+}
+
+fn pixel_color(editor: &LevelEditor, row: usize, column: usize) -> Color {
+    let solid = editor
+        .get_pixels()
+        .get(row)
+        .and_then(|pixels| pixels.get(column))
+        .is_some_and(|pixel| *pixel);
+
+    if solid {
+        EDITOR_SHIELD_COLOR
+    } else {
+        EDITOR_EMPTY_COLOR
+    }
+}
+
+fn parameter_text(editor: &LevelEditor, parameter: EditorParameter) -> String {
+    format!(
+        "{}: {}",
+        parameter.label(),
+        editor.parameter_value(parameter)
+    )
+}
+
+fn parameter_color(editor: &LevelEditor, parameter: EditorParameter) -> Color {
+    if editor.get_parameter() == parameter {
+        SELECTED_LETTER_COLOR
+    } else {
+        SCREEN_TEXT_COLOR
+    }
+}
+
+fn brush_text(kind: EnemyKind) -> String {
+    format!("BRUSH: {:?}", kind).to_uppercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::storage::temporary_directory;
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Entity, IntoScheduleConfigs, OnEnter, State, in_state, not};
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{
+        TestAppBuilder, count_components, did_message_fire, get_resource_or_fail, query_single_text,
+    };
+    use std::path::PathBuf;
+
+    fn setup(path: PathBuf) -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_assets()
+            .with_input()
+            .with_plugin(StatesPlugin)
+            .with_setup(move |app| {
+                app.init_state::<GameState>()
+                    .add_message::<NewGameMessage>()
+                    .init_resource::<EditorResource>()
+                    .init_resource::<EditorStatusResource>()
+                    .insert_resource(EditorFileResource(path.clone()))
+                    .add_systems(Startup, load_editor_system)
+                    .add_systems(OnEnter(GameState::Editor), spawn_editor_screen_system)
+                    .add_systems(
+                        Update,
+                        (
+                            editor_click_system,
+                            editor_input_system,
+                            save_wave_system,
+                            test_play_system,
+                            update_editor_grid_system,
+                            update_editor_parameters_system,
+                            update_editor_brush_system,
+                            update_editor_status_system,
+                        )
+                            .chain()
+                            .run_if(in_state(GameState::Editor)),
+                    )
+                    .add_systems(
+                        Update,
+                        return_to_editor_system.run_if(not(in_state(GameState::Editor))),
+                    );
+            })
+            .build()
+    }
+
+    fn current_state(app: &mut App) -> GameState {
+        *get_resource_or_fail::<State<GameState>>(app).get()
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(key);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+    }
+
+    fn cell_entity(app: &mut App, row: usize, column: usize) -> Entity {
+        let mut query = app.world_mut().query::<(Entity, &EditorCellComponent)>();
+        query
+            .iter(app.world())
+            .find(|(_, cell)| cell.row == row && cell.column == column)
+            .map(|(entity, _)| entity)
+            .expect("Cell should exist")
+    }
+
+    #[cfg(test)]
+    mod spawn_editor_screen_system {
+        use super::*;
+        use crate::domain::level_editor::{
+            EDITOR_COLUMNS, EDITOR_ROWS, EDITOR_SHIELD_HEIGHT, EDITOR_SHIELD_WIDTH,
+        };
+
+        #[test]
+        fn should_open_the_editor_with_a_grid_for_enemies_and_shields() {
+            let mut app = setup(temporary_directory("editor-screen").join("custom.wave.ron"));
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Editor);
+            assert_eq!(count_components::<EditorScreenComponent>(&mut app), 1);
+            assert_eq!(
+                count_components::<EditorCellComponent>(&mut app),
+                EDITOR_ROWS * EDITOR_COLUMNS
+            );
+            assert_eq!(
+                count_components::<EditorPixelComponent>(&mut app),
+                EDITOR_SHIELD_HEIGHT * EDITOR_SHIELD_WIDTH
+            );
+            assert_eq!(
+                count_components::<EditorParameterComponent>(&mut app),
+                EditorParameter::ALL.len()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod editor_click_system {
+        use super::*;
+
+        #[test]
+        fn should_paint_the_clicked_cell_with_the_brush() {
+            let mut app = setup(temporary_directory("editor-click").join("custom.wave.ron"));
+            app.update();
+
+            press(&mut app, KeyCode::Digit3);
+            let cell = cell_entity(&mut app, 7, 15);
+            app.world_mut()
+                .entity_mut(cell)
+                .insert(Interaction::Pressed);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EditorResource>(&mut app)
+                    .0
+                    .get_cells()[7][15],
+                Some(EnemyKind::Octopus)
+            );
+            assert_eq!(
+                app.world()
+                    .get::<BackgroundColor>(cell)
+                    .map(|color| color.0),
+                Some(brush_color(EnemyKind::Octopus))
+            );
+            assert_eq!(
+                query_single_text::<EditorBrushComponent>(&mut app),
+                "BRUSH: OCTOPUS"
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod editor_input_system {
+        use super::*;
+
+        #[test]
+        fn should_select_and_adjust_parameters() {
+            let mut app = setup(temporary_directory("editor-input").join("custom.wave.ron"));
+            app.update();
+
+            press(&mut app, KeyCode::ArrowDown);
+            press(&mut app, KeyCode::ArrowDown);
+            press(&mut app, KeyCode::ArrowRight);
+
+            let editor = &get_resource_or_fail::<EditorResource>(&mut app).0;
+            assert_eq!(editor.get_parameter(), EditorParameter::FireProbability);
+            assert_eq!(
+                editor.parameter_value(EditorParameter::FireProbability),
+                "0.25"
+            );
+        }
+    }
+
+    #[cfg(all(test, feature = "serde"))]
+    mod save_wave_system {
+        use super::*;
+        use crate::infrastructure::bevy::editor::resources::load_wave_file;
+
+        #[test]
+        fn should_save_the_wave_to_the_edited_file() {
+            let path = temporary_directory("editor-save").join("custom.wave.ron");
+            let mut app = setup(path.clone());
+            app.update();
+
+            press(&mut app, KeyCode::ArrowRight);
+            press(&mut app, KeyCode::KeyS);
+
+            let expected = get_resource_or_fail::<EditorResource>(&mut app)
+                .0
+                .to_script();
+            let saved = load_wave_file(&path).expect("Wave should load");
+
+            assert_eq!(saved.to_script(), expected);
+            assert!(query_single_text::<EditorStatusComponent>(&mut app).starts_with("SAVED"));
+        }
+    }
+
+    #[cfg(test)]
+    mod test_play_system {
+        use super::*;
+
+        #[test]
+        fn should_play_the_edited_wave_and_come_back() {
+            let mut app = setup(temporary_directory("editor-play").join("custom.wave.ron"));
+            app.update();
+
+            press(&mut app, KeyCode::Enter);
+            app.update();
+
+            assert!(did_message_fire::<NewGameMessage>(&mut app));
+            assert_eq!(current_state(&mut app), GameState::Playing);
+            assert_eq!(count_components::<EditorScreenComponent>(&mut app), 0);
+            let campaign = &get_resource_or_fail::<CampaignResource>(&mut app).0;
+            assert_eq!(campaign.get_waves().len(), 1);
+            assert_eq!(campaign.for_wave(Wave::new(1)).name, "custom");
+
+            press(&mut app, KeyCode::Escape);
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Editor);
+            assert_eq!(count_components::<EditorScreenComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn should_refuse_to_play_an_invalid_wave() {
+            let mut app = setup(temporary_directory("editor-invalid-play").join("custom.wave.ron"));
+            app.update();
+
+            for _ in 0..3 {
+                press(&mut app, KeyCode::ArrowDown);
+            }
+            press(&mut app, KeyCode::ArrowLeft);
+            press(&mut app, KeyCode::Enter);
+
+            assert_eq!(current_state(&mut app), GameState::Editor);
+            assert!(
+                query_single_text::<EditorStatusComponent>(&mut app).starts_with("CANNOT PLAY")
+            );
+        }
+    }
+}
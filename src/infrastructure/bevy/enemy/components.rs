@@ -1,8 +1,10 @@
-use crate::domain::enemy::EnemyId;
+use crate::domain::enemy::{EnemyId, EnemyKind};
+use crate::domain::game_mode::PlayerSlot;
 use crate::infrastructure::bevy::enemy::resources::{
-    ENEMY_COLOR, ENEMY_HEIGHT, ENEMY_IMAGE, ENEMY_WIDTH,
+    ENEMY_COLOR, ENEMY_HEIGHT, ENEMY_WIDTH, enemy_image,
 };
 use bevy::asset::AssetServer;
+use bevy::color::Color;
 use bevy::math::Vec2;
 use bevy::prelude::{Bundle, Component, Entity, Message, Sprite, Transform, default};
 
@@ -11,14 +13,38 @@ pub struct EnemyKilledMessage {
     pub enemy_entity: Entity,
     pub enemy_id: EnemyId,
     pub projectile_entity: Entity,
+    pub player: PlayerSlot,
 }
 
 impl EnemyKilledMessage {
-    pub fn new(enemy_entity: Entity, enemy_id: EnemyId, projectile_entity: Entity) -> Self {
+    pub fn new(
+        enemy_entity: Entity,
+        enemy_id: EnemyId,
+        projectile_entity: Entity,
+        player: PlayerSlot,
+    ) -> Self {
         EnemyKilledMessage {
             enemy_entity,
             enemy_id,
             projectile_entity,
+            player,
+        }
+    }
+}
+
+#[derive(Message)]
+pub struct EnemyDamagedMessage {
+    pub enemy_id: EnemyId,
+    pub projectile_entity: Entity,
+    pub player: PlayerSlot,
+}
+
+impl EnemyDamagedMessage {
+    pub fn new(enemy_id: EnemyId, projectile_entity: Entity, player: PlayerSlot) -> Self {
+        EnemyDamagedMessage {
+            enemy_id,
+            projectile_entity,
+            player,
         }
     }
 }
@@ -42,11 +68,11 @@ impl EnemyComponent {
 }
 
 impl EnemyBundle {
-    pub fn new(id: EnemyId, x: f32, y: f32, asset_server: &AssetServer) -> Self {
+    pub fn new(id: EnemyId, kind: EnemyKind, x: f32, y: f32, asset_server: &AssetServer) -> Self {
         Self {
             enemy: EnemyComponent::new(id),
             sprite: Sprite {
-                image: asset_server.load(ENEMY_IMAGE),
+                image: asset_server.load(enemy_image(kind)),
                 custom_size: Some(Vec2::new(ENEMY_WIDTH, ENEMY_HEIGHT)),
                 color: ENEMY_COLOR,
                 ..default()
@@ -54,6 +80,11 @@ impl EnemyBundle {
             transform: Transform::from_xyz(x, y, 0.0),
         }
     }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.sprite.color = color;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +105,7 @@ mod tests {
 
         app.world_mut().spawn(EnemyBundle::new(
             expected_id,
+            EnemyKind::Squid,
             expected_x,
             expected_y,
             &asset_server,
@@ -1,13 +1,47 @@
+use crate::domain::enemy::{Enemy, EnemyKind};
+use crate::domain::wave_script::ShotType;
 use bevy::color::Color;
 use bevy::prelude::{Resource, Timer};
 
 pub const ENEMY_WIDTH: f32 = 60.0;
 pub const ENEMY_HEIGHT: f32 = 40.0;
-pub const ENEMY_IMAGE: &str = "red.png";
+pub const SQUID_IMAGE: &str = "yellow.png";
+pub const CRAB_IMAGE: &str = "red.png";
+pub const OCTOPUS_IMAGE: &str = "green.png";
+pub const ARMORED_IMAGE: &str = "extra.png";
 pub const ENEMY_COLOR: Color = Color::srgb(255.0, 255.0, 255.0);
+pub const DAMAGED_ENEMY_COLOR: Color = Color::srgb(1.0, 0.6, 0.6);
+pub const ENEMY_HIT_FLASH_COLOR: Color = Color::srgb(1.0, 0.1, 0.1);
+pub const ENEMY_HIT_FLASH_DURATION: f32 = 0.12;
 
 #[derive(Resource)]
 pub struct EnemyProjectileMovementTimer(pub Timer);
 
 #[derive(Resource)]
 pub struct EnemyFireProbability(pub f64);
+
+#[derive(Resource)]
+pub struct EnemyShotTypesResource(pub Vec<ShotType>);
+
+impl Default for EnemyShotTypesResource {
+    fn default() -> Self {
+        Self(vec![ShotType::Rolling])
+    }
+}
+
+pub fn enemy_color(enemy: &Enemy) -> Color {
+    if enemy.is_damaged() {
+        DAMAGED_ENEMY_COLOR
+    } else {
+        ENEMY_COLOR
+    }
+}
+
+pub fn enemy_image(kind: EnemyKind) -> &'static str {
+    match kind {
+        EnemyKind::Squid => SQUID_IMAGE,
+        EnemyKind::Crab => CRAB_IMAGE,
+        EnemyKind::Octopus => OCTOPUS_IMAGE,
+        EnemyKind::Armored => ARMORED_IMAGE,
+    }
+}
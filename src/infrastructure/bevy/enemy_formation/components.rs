@@ -1,4 +1,7 @@
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Message};
 
 #[derive(Component)]
 pub struct EnemyFormationComponent;
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct FormationAdvancedMessage;
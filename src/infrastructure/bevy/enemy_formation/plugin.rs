@@ -1,25 +1,62 @@
 use crate::domain::enemy_formation::EnemyFormation;
-use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
 use crate::infrastructure::bevy::enemy::resources::{
-    EnemyFireProbability, EnemyProjectileMovementTimer,
+    EnemyFireProbability, EnemyProjectileMovementTimer, EnemyShotTypesResource,
 };
+use crate::infrastructure::bevy::enemy_formation::components::FormationAdvancedMessage;
 use crate::infrastructure::bevy::enemy_formation::resources::{
-    ENEMY_FIRE_PROBABILITY, ENEMY_FORMATION_STEP_DURATION, EnemyFormationMovementTimer,
-    EnemyFormationResource,
+    DifficultyResource, ENEMY_FIRE_PROBABILITY, ENEMY_FORMATION_STEP_DURATION, EnemyFireController,
+    EnemyFireControllerResource, EnemyFormationMovementTimer, EnemyFormationResource,
+    EnemyHitFlashesResource, FORMATIONS_FILE, FormationLayoutsResource, FormationMovementsResource,
+    GameRngResource, StartingWaveResource, WaveResource, load_formations,
 };
 use crate::infrastructure::bevy::enemy_formation::systems::{
     collisions_system, enemy_formation_lifecycle_system, enemy_formation_movement_system,
-    spawn_enemy_formation_system, spawn_random_projectiles_system,
+    flash_damaged_enemies_system, next_wave_system, reload_enemy_weapons_system,
+    reset_enemy_formation_system, spawn_enemy_formation_system, spawn_random_projectiles_system,
 };
 use crate::infrastructure::bevy::enemy_projectile::resources::ENEMY_PROJECTILE_DURATION;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::storage::asset_file;
 use bevy::app::{App, Plugin, Startup, Update};
-use bevy::prelude::{Timer, TimerMode};
+use bevy::prelude::{IntoScheduleConfigs, Timer, TimerMode, resource_equals};
 
 pub struct EnemyFormationPlugin;
 
 impl Plugin for EnemyFormationPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(EnemyFormationResource(EnemyFormation::new()))
+        app.init_resource::<StartingWaveResource>()
+            .init_resource::<DifficultyResource>()
+            .init_resource::<GameRngResource>()
+            .init_resource::<EnemyFireControllerResource>()
+            .init_resource::<EnemyShotTypesResource>()
+            .init_resource::<EnemyHitFlashesResource>()
+            .init_resource::<FormationMovementsResource>();
+
+        if !app.world().contains_resource::<FormationLayoutsResource>() {
+            app.insert_resource(FormationLayoutsResource(load_formations(&asset_file(
+                FORMATIONS_FILE,
+            ))));
+        }
+
+        let starting_wave = app.world().resource::<StartingWaveResource>().0;
+        let layout = app
+            .world()
+            .resource::<FormationLayoutsResource>()
+            .0
+            .for_wave(starting_wave)
+            .clone();
+        let movement = app
+            .world()
+            .resource::<FormationMovementsResource>()
+            .0
+            .for_wave(starting_wave);
+
+        app.insert_resource(WaveResource(starting_wave))
+            .insert_resource(EnemyFormationResource(
+                EnemyFormation::from_layout_at_row(&layout, starting_wave.starting_row())
+                    .with_movement(movement),
+            ))
             .insert_resource(EnemyFireProbability(ENEMY_FIRE_PROBABILITY))
             .insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
                 ENEMY_FORMATION_STEP_DURATION,
@@ -30,25 +67,82 @@ impl Plugin for EnemyFormationPlugin {
                 TimerMode::Repeating,
             )))
             .add_message::<EnemyKilledMessage>()
+            .add_message::<EnemyDamagedMessage>()
+            .add_message::<FormationAdvancedMessage>()
             .add_systems(Startup, spawn_enemy_formation_system)
             .add_systems(
                 Update,
                 (
                     enemy_formation_lifecycle_system,
+                    next_wave_system,
                     collisions_system,
-                    enemy_formation_movement_system,
-                    spawn_random_projectiles_system,
-                ),
-            );
+                    enemy_formation_movement_system
+                        .after(enemy_formation_lifecycle_system)
+                        .after(next_wave_system)
+                        .after(collisions_system),
+                    flash_damaged_enemies_system.after(enemy_formation_movement_system),
+                    reload_enemy_weapons_system,
+                    spawn_random_projectiles_system
+                        .after(reload_enemy_weapons_system)
+                        .run_if(resource_equals(EnemyFireControllerResource(
+                            EnemyFireController::Random,
+                        ))),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_enemy_formation_system);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::formation_layout::{FormationLayout, FormationLayouts};
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 
     #[test]
     fn plugin_loads_successfully() {
-        let _app = bevy_test::smoke_test_plugin_with_assets(EnemyFormationPlugin);
+        let _app = bevy_test::TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(EnemyFormationPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+
+    #[test]
+    fn formation_starts_at_the_row_of_the_configured_wave() {
+        let app = bevy_test::TestAppBuilder::new()
+            .with_assets()
+            .with_setup(|app| {
+                app.insert_resource(StartingWaveResource(Wave::new(3)));
+            })
+            .with_plugin(EnemyFormationPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+
+        let formation = &app.world().resource::<EnemyFormationResource>().0;
+
+        assert_eq!(formation.get_position(), (0, 2));
+    }
+
+    #[test]
+    fn formation_uses_the_layout_of_the_starting_wave() {
+        let diamond = FormationLayout::parse(&[".C.", "CCC", ".C."]).expect("Layout should parse");
+        let layouts = FormationLayouts::new(vec![FormationLayout::classic(), diamond.clone()]);
+        let app = bevy_test::TestAppBuilder::new()
+            .with_assets()
+            .with_setup(move |app| {
+                app.insert_resource(StartingWaveResource(Wave::new(2)))
+                    .insert_resource(FormationLayoutsResource(layouts.clone()));
+            })
+            .with_plugin(EnemyFormationPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+
+        let formation = &app.world().resource::<EnemyFormationResource>().0;
+
+        assert_eq!(formation.get_layout(), &diamond);
+        assert_eq!(formation.get_rows(), 3);
     }
 }
@@ -1,14 +1,188 @@
+use crate::domain::difficulty::Difficulty;
+use crate::domain::enemy::EnemyId;
 use crate::domain::enemy_formation::EnemyFormation;
+use crate::domain::formation_layout::{FormationLayout, FormationLayoutError, FormationLayouts};
+use crate::domain::movement_pattern::FormationMovements;
+use crate::domain::wave::Wave;
+use crate::infrastructure::storage::from_ron;
 use bevy::prelude::{Resource, Timer};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use tracing::warn;
 
 pub const ENEMY_FORMATION_STEP_DURATION: f32 = 0.6;
 pub const SPACE_BETWEEN_ENEMIES_X: f32 = 15.0;
 pub const SPACE_BETWEEN_ENEMIES_Y: f32 = 15.0;
 pub const VERTICAL_DROP: f32 = 15.0;
 pub const ENEMY_FIRE_PROBABILITY: f64 = 0.2;
+pub const FORMATIONS_FILE: &str = "formations.ron";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnemyFireController {
+    #[default]
+    Random,
+    Gunner,
+}
 
 #[derive(Resource)]
 pub struct EnemyFormationResource(pub EnemyFormation);
 
+#[derive(Resource, Default)]
+pub struct FormationLayoutsResource(pub FormationLayouts);
+
+#[derive(Resource, Default)]
+pub struct FormationMovementsResource(pub FormationMovements);
+
 #[derive(Resource)]
 pub struct EnemyFormationMovementTimer(pub Timer);
+
+#[derive(Resource, Default)]
+pub struct WaveResource(pub Wave);
+
+#[derive(Resource, Default)]
+pub struct StartingWaveResource(pub Wave);
+
+#[derive(Resource, Default)]
+pub struct DifficultyResource(pub Difficulty);
+
+#[derive(Resource, Default, PartialEq)]
+pub struct EnemyFireControllerResource(pub EnemyFireController);
+
+#[derive(Resource)]
+pub struct GameRngResource(pub StdRng);
+
+#[derive(Resource, Default)]
+pub struct EnemyHitFlashesResource(pub Vec<(EnemyId, Timer)>);
+
+impl GameRngResource {
+    pub fn seeded(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl Default for GameRngResource {
+    fn default() -> Self {
+        Self(StdRng::from_os_rng())
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct FormationsFile {
+    layouts: Vec<FormationEntry>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct FormationEntry {
+    name: String,
+    rows: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum FormationsError {
+    Parse(String),
+    Invalid {
+        name: String,
+        error: FormationLayoutError,
+    },
+}
+
+impl Display for FormationsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FormationsError::Parse(reason) => write!(f, "cannot parse formations: {}", reason),
+            FormationsError::Invalid { name, error } => {
+                write!(f, "formation '{}' is invalid: {}", name, error)
+            }
+        }
+    }
+}
+
+pub fn parse_formations(source: &str) -> Result<FormationLayouts, FormationsError> {
+    let file: FormationsFile = from_ron(source).map_err(FormationsError::Parse)?;
+
+    let layouts = file
+        .layouts
+        .into_iter()
+        .map(|entry| {
+            FormationLayout::parse(&entry.rows).map_err(|error| FormationsError::Invalid {
+                name: entry.name,
+                error,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(FormationLayouts::new(layouts))
+}
+
+pub fn load_formations(path: &Path) -> FormationLayouts {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_formations(&source).unwrap_or_else(|error| {
+            warn!("Using the classic formation: {}", error);
+            FormationLayouts::default()
+        }),
+        Err(error) => {
+            warn!(
+                "Using the classic formation, cannot read {}: {}",
+                path.display(),
+                error
+            );
+            FormationLayouts::default()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyKind;
+    use crate::infrastructure::storage::asset_file;
+
+    #[test]
+    fn formations_are_parsed_in_wave_order() {
+        let layouts = parse_formations(
+            r#"(layouts: [
+                (name: "classic", rows: ["SSS", "CCC"]),
+                (name: "arrow", rows: [".O.", "O.O"]),
+            ])"#,
+        )
+        .expect("Formations should parse");
+
+        assert_eq!(layouts.count(), 2);
+        assert_eq!(
+            layouts.for_wave(Wave::new(2)).get_cell(0, 1),
+            Some(EnemyKind::Octopus)
+        );
+    }
+
+    #[test]
+    fn invalid_formations_name_the_culprit() {
+        let result = parse_formations(r#"(layouts: [(name: "broken", rows: ["CC", "C"])])"#);
+
+        assert_eq!(
+            result.map_err(|error| error.to_string()),
+            Err("formation 'broken' is invalid: row 2 has 1 cells but the first row has 2".into())
+        );
+        assert!(matches!(
+            parse_formations("(layouts: 3)"),
+            Err(FormationsError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn bundled_formations_start_with_the_classic_layout() {
+        let layouts = load_formations(&asset_file(FORMATIONS_FILE));
+
+        assert!(layouts.count() > 1);
+        assert_eq!(
+            layouts.for_wave(Wave::default()),
+            &FormationLayout::classic()
+        );
+    }
+}
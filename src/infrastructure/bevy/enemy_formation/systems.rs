@@ -1,84 +1,172 @@
 use crate::domain::collision::check_aabb_collision;
-use crate::domain::enemy_formation::{COLUMNS, FormationStatus, NUMBER_OF_STEPS_ON_X_AXE};
+use crate::domain::enemy::EnemyId;
+use crate::domain::enemy_formation::{
+    DamageOutcome, EnemyFormation, FormationStatus, NUMBER_OF_STEPS_ON_X_AXE,
+};
+use crate::domain::weapons::Armed;
+use crate::infrastructure::bevy::boss::resources::BossResource;
+use crate::infrastructure::bevy::difficulty::resources::DynamicDifficultyResource;
+use crate::infrastructure::bevy::dive::components::DiverComponent;
 use crate::infrastructure::bevy::enemy::components::{
-    EnemyBundle, EnemyComponent, EnemyKilledMessage,
+    EnemyBundle, EnemyComponent, EnemyDamagedMessage, EnemyKilledMessage,
+};
+use crate::infrastructure::bevy::enemy::resources::{
+    ENEMY_COLOR, ENEMY_HEIGHT, ENEMY_HIT_FLASH_COLOR, ENEMY_HIT_FLASH_DURATION, ENEMY_WIDTH,
+    enemy_color,
 };
-use crate::infrastructure::bevy::enemy::resources::{ENEMY_HEIGHT, ENEMY_WIDTH};
 use crate::infrastructure::bevy::enemy::resources::{
-    EnemyFireProbability, EnemyProjectileMovementTimer,
+    EnemyFireProbability, EnemyProjectileMovementTimer, EnemyShotTypesResource,
 };
+use crate::infrastructure::bevy::enemy_formation::components::FormationAdvancedMessage;
 use crate::infrastructure::bevy::enemy_formation::resources::{
-    EnemyFormationMovementTimer, EnemyFormationResource, SPACE_BETWEEN_ENEMIES_X,
-    SPACE_BETWEEN_ENEMIES_Y, VERTICAL_DROP,
+    DifficultyResource, EnemyFormationMovementTimer, EnemyFormationResource,
+    EnemyHitFlashesResource, FormationLayoutsResource, FormationMovementsResource, GameRngResource,
+    SPACE_BETWEEN_ENEMIES_X, SPACE_BETWEEN_ENEMIES_Y, StartingWaveResource, VERTICAL_DROP,
+    WaveResource,
+};
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileBundle, EnemyShooterComponent,
+};
+use crate::infrastructure::bevy::game_area::resources::{
+    ArenaResource, GAME_AREA_HEIGHT, GAME_AREA_WIDTH,
 };
-use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileBundle;
-use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::game_area::systems::spawn_in_arena;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 use crate::infrastructure::bevy::header::resources::HEADER_HEIGHT;
 use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::Rng;
-use rand::prelude::IteratorRandom;
+use rand::prelude::{IndexedRandom, IteratorRandom};
+use tracing::info;
 
 pub fn spawn_enemy_formation_system(
     commands: Commands,
     asset_server: Res<AssetServer>,
     enemy_formation_res: Res<EnemyFormationResource>,
+    arena: Option<Res<ArenaResource>>,
 ) {
-    spawn_enemies(commands, &asset_server, &enemy_formation_res);
+    spawn_enemies(
+        commands,
+        &asset_server,
+        &enemy_formation_res,
+        arena.as_deref(),
+    );
 }
 
 pub fn enemy_formation_movement_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     enemy_formation_res: Res<EnemyFormationResource>,
-    enemy_query: Query<Entity, With<EnemyComponent>>,
+    arena: Option<Res<ArenaResource>>,
+    enemy_query: Query<Entity, (With<EnemyComponent>, Without<DiverComponent>)>,
 ) {
     if enemy_formation_res.is_changed() {
         for entity in enemy_query.iter() {
             commands.entity(entity).despawn();
         }
-        spawn_enemies(commands, &asset_server, &enemy_formation_res);
+        spawn_enemies(
+            commands,
+            &asset_server,
+            &enemy_formation_res,
+            arena.as_deref(),
+        );
     }
 }
 
 pub fn enemy_formation_lifecycle_system(
     time: Res<Time>,
+    difficulty: Res<DifficultyResource>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
     mut enemy_formation_res: ResMut<EnemyFormationResource>,
     mut timer: ResMut<EnemyFormationMovementTimer>,
+    mut formation_advanced_writer: MessageWriter<FormationAdvancedMessage>,
 ) {
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+
     if enemy_formation_res.0.get_status() == FormationStatus::Breached
         || enemy_formation_res.0.get_status() == FormationStatus::Annihilated
     {
         timer.0.finish();
-    } else if timer.0.tick(time.delta()).just_finished() {
+    } else if timer
+        .0
+        .tick(
+            time.delta()
+                .mul_f32(difficulty.0.tempo_scale() * time_scale),
+        )
+        .just_finished()
+    {
         enemy_formation_res.0.advance();
+        formation_advanced_writer.write(FormationAdvancedMessage);
     }
 }
 
-fn calculate_step_x(enemy_width: f32, gap_x: f32) -> f32 {
-    let n_enemies = COLUMNS as f32;
-    let n_gaps = (COLUMNS - 1) as f32;
-    let n_steps = (NUMBER_OF_STEPS_ON_X_AXE - COLUMNS) as f32;
-    let block_width = (n_enemies * enemy_width) + (n_gaps * gap_x);
-    let remaining_screen = GAME_AREA_WIDTH - block_width;
-    let step = remaining_screen / n_steps;
+pub fn next_wave_system(
+    layouts: Res<FormationLayoutsResource>,
+    movements: Res<FormationMovementsResource>,
+    boss: Option<Res<BossResource>>,
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+    mut wave_res: ResMut<WaveResource>,
+    mut timer: ResMut<EnemyFormationMovementTimer>,
+) {
+    if enemy_formation_res.0.get_status() != FormationStatus::Annihilated
+        || boss.is_some_and(|boss| boss.0.is_some())
+    {
+        return;
+    }
 
-    step.max(1.0)
+    wave_res.0 = wave_res.0.next();
+    enemy_formation_res.0 = EnemyFormation::from_layout_at_row(
+        layouts.0.for_wave(wave_res.0),
+        wave_res.0.starting_row(),
+    )
+    .with_movement(movements.0.for_wave(wave_res.0));
+    timer.0.reset();
+
+    info!("Wave {} incoming", wave_res.0.get_number());
 }
 
-fn spawn_enemies(
-    mut commands: Commands,
-    asset_server: &AssetServer,
-    enemy_formation: &EnemyFormationResource,
+pub fn reset_enemy_formation_system(
+    mut new_game_message: MessageReader<NewGameMessage>,
+    starting_wave_res: Res<StartingWaveResource>,
+    layouts: Res<FormationLayoutsResource>,
+    movements: Res<FormationMovementsResource>,
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+    mut wave_res: ResMut<WaveResource>,
+    mut timer: ResMut<EnemyFormationMovementTimer>,
 ) {
-    let enemies = enemy_formation.0.get_enemies();
-    let (enemy_formation_x, enemy_formation_y) = enemy_formation.0.get_position();
-
-    if enemies.is_empty() {
+    if new_game_message.read().count() == 0 {
         return;
     }
 
-    let step_size_x = calculate_step_x(ENEMY_WIDTH, SPACE_BETWEEN_ENEMIES_X);
+    wave_res.0 = starting_wave_res.0;
+    enemy_formation_res.0 = EnemyFormation::from_layout_at_row(
+        layouts.0.for_wave(wave_res.0),
+        wave_res.0.starting_row(),
+    )
+    .with_movement(movements.0.for_wave(wave_res.0));
+    timer.0.reset();
+}
+
+fn calculate_step_x(columns: usize, enemy_width: f32, gap_x: f32) -> f32 {
+    let n_enemies = columns as f32;
+    let n_gaps = columns.saturating_sub(1) as f32;
+    let n_steps = NUMBER_OF_STEPS_ON_X_AXE.saturating_sub(columns).max(1) as f32;
+    let block_width = (n_enemies * enemy_width) + (n_gaps * gap_x);
+    let remaining_screen = GAME_AREA_WIDTH - block_width;
+    let step = remaining_screen / n_steps;
+
+    step.max(1.0)
+}
+
+pub fn slot_translation(enemy_formation: &EnemyFormation, row: usize, column: usize) -> Vec2 {
+    let (enemy_formation_x, enemy_formation_y) = enemy_formation.get_position();
+    let step_size_x = calculate_step_x(
+        enemy_formation.get_columns(),
+        ENEMY_WIDTH,
+        SPACE_BETWEEN_ENEMIES_X,
+    );
 
     let enemy_formation_start_x = -(GAME_AREA_WIDTH / 2.0);
     let enemy_formation_start_y = (GAME_AREA_HEIGHT / 2.0) - HEADER_HEIGHT;
@@ -87,18 +175,42 @@ fn spawn_enemies(
     let enemy_formation_height =
         enemy_formation_start_y - (enemy_formation_y as f32 * VERTICAL_DROP);
 
-    for (row_index, row) in enemies.iter().enumerate() {
-        for (column_index, enemy_slot) in row.iter().enumerate() {
-            if let Some(enemy) = enemy_slot {
-                let new_x = enemy_formation_width
-                    + (column_index as f32 * (ENEMY_WIDTH + SPACE_BETWEEN_ENEMIES_X))
-                    + (ENEMY_WIDTH / 2.0);
+    let x = enemy_formation_width
+        + (column as f32 * (ENEMY_WIDTH + SPACE_BETWEEN_ENEMIES_X))
+        + (ENEMY_WIDTH / 2.0);
+
+    let y = enemy_formation_height
+        - (row as f32 * (ENEMY_HEIGHT + SPACE_BETWEEN_ENEMIES_Y))
+        - (ENEMY_HEIGHT / 2.0);
 
-                let new_y = enemy_formation_height
-                    - (row_index as f32 * (ENEMY_HEIGHT + SPACE_BETWEEN_ENEMIES_Y))
-                    - (ENEMY_HEIGHT / 2.0);
+    Vec2::new(x, y)
+}
 
-                commands.spawn(EnemyBundle::new(enemy.get_id(), new_x, new_y, asset_server));
+pub fn spawn_enemies(
+    mut commands: Commands,
+    asset_server: &AssetServer,
+    enemy_formation: &EnemyFormationResource,
+    arena: Option<&ArenaResource>,
+) {
+    for (row_index, row) in enemy_formation.0.get_enemies().iter().enumerate() {
+        for (column_index, enemy_slot) in row.iter().enumerate() {
+            if let Some(enemy) = enemy_slot
+                && !enemy_formation.0.is_away(enemy.get_id())
+            {
+                let position = slot_translation(&enemy_formation.0, row_index, column_index);
+
+                spawn_in_arena(
+                    &mut commands,
+                    arena,
+                    EnemyBundle::new(
+                        enemy.get_id(),
+                        enemy.get_kind(),
+                        position.x,
+                        position.y,
+                        asset_server,
+                    )
+                    .with_color(enemy_color(enemy)),
+                );
             }
         }
     }
@@ -106,12 +218,17 @@ fn spawn_enemies(
 
 pub fn collisions_system(
     mut enemy_formation_resource: ResMut<EnemyFormationResource>,
-    player_projectile_query: Query<(Entity, &Transform, &Sprite), With<PlayerProjectileComponent>>,
+    player_projectile_query: Query<(Entity, &Transform, &Sprite, &PlayerProjectileComponent)>,
     enemy_query: Query<(Entity, &Transform, &Sprite, &EnemyComponent), With<EnemyComponent>>,
     mut despawn_enemy_message_writer: MessageWriter<EnemyKilledMessage>,
+    mut enemy_damaged_message_writer: MessageWriter<EnemyDamagedMessage>,
 ) {
-    for (player_projectile_entity, player_projectile_transform, player_projectile_sprite) in
-        player_projectile_query.iter()
+    for (
+        player_projectile_entity,
+        player_projectile_transform,
+        player_projectile_sprite,
+        player_projectile,
+    ) in player_projectile_query.iter()
     {
         let player_projectile_size = player_projectile_sprite.custom_size.unwrap_or(Vec2::ONE);
 
@@ -128,44 +245,178 @@ pub fn collisions_system(
                 (enemy_size.x, enemy_size.y),
             );
 
-            if collision {
-                enemy_formation_resource.0.kill(enemy_component.id);
-                despawn_enemy_message_writer.write(EnemyKilledMessage::new(
-                    enemy_entity,
-                    enemy_component.id,
-                    player_projectile_entity,
-                ));
-                break;
+            if !collision {
+                continue;
+            }
+
+            match enemy_formation_resource.0.damage(enemy_component.id) {
+                DamageOutcome::Killed => {
+                    despawn_enemy_message_writer.write(EnemyKilledMessage::new(
+                        enemy_entity,
+                        enemy_component.id,
+                        player_projectile_entity,
+                        player_projectile.shooter,
+                    ));
+
+                    if !player_projectile.piercing {
+                        break;
+                    }
+                }
+                DamageOutcome::Wounded => {
+                    enemy_damaged_message_writer.write(EnemyDamagedMessage::new(
+                        enemy_component.id,
+                        player_projectile_entity,
+                        player_projectile.shooter,
+                    ));
+                    break;
+                }
+                DamageOutcome::Missed => {}
             }
         }
     }
 }
 
+pub fn flash_damaged_enemies_system(
+    time: Res<Time>,
+    enemy_formation_res: Res<EnemyFormationResource>,
+    mut enemy_damaged_message: MessageReader<EnemyDamagedMessage>,
+    mut flashes: ResMut<EnemyHitFlashesResource>,
+    mut enemy_query: Query<(&EnemyComponent, &mut Sprite)>,
+) {
+    for message in enemy_damaged_message.read() {
+        flashes.0.retain(|(id, _)| *id != message.enemy_id);
+        flashes.0.push((
+            message.enemy_id,
+            Timer::from_seconds(ENEMY_HIT_FLASH_DURATION, TimerMode::Once),
+        ));
+    }
+
+    if flashes.0.is_empty() {
+        return;
+    }
+
+    for (_, timer) in flashes.0.iter_mut() {
+        timer.tick(time.delta());
+    }
+
+    for (enemy, mut sprite) in enemy_query.iter_mut() {
+        let Some((_, timer)) = flashes.0.iter().find(|(id, _)| *id == enemy.id) else {
+            continue;
+        };
+
+        sprite.color = if timer.is_finished() {
+            enemy_formation_res
+                .0
+                .get_enemy(enemy.id)
+                .map_or(ENEMY_COLOR, enemy_color)
+        } else {
+            ENEMY_HIT_FLASH_COLOR
+        };
+    }
+
+    flashes.0.retain(|(_, timer)| !timer.is_finished());
+}
+
+#[derive(SystemParam)]
+pub struct EnemyFireSettings<'w> {
+    probability: Res<'w, EnemyFireProbability>,
+    shot_types: Res<'w, EnemyShotTypesResource>,
+    difficulty: Res<'w, DifficultyResource>,
+    dynamic: Option<Res<'w, DynamicDifficultyResource>>,
+}
+
+#[derive(SystemParam)]
+pub struct EnemyShooters<'w, 's> {
+    formation: ResMut<'w, EnemyFormationResource>,
+    enemies: Query<'w, 's, (&'static EnemyComponent, &'static Transform)>,
+}
+
+impl EnemyFireSettings<'_> {
+    fn fire_probability(&self) -> f64 {
+        let probability = self.difficulty.0.scale_fire_probability(self.probability.0);
+
+        match &self.dynamic {
+            Some(dynamic) => (probability * dynamic.0.fire_scale()).clamp(0.0, 1.0),
+            None => probability,
+        }
+    }
+}
+
+pub fn reload_enemy_weapons_system(
+    mut enemy_formation_res: ResMut<EnemyFormationResource>,
+    shooter_query: Query<&EnemyShooterComponent>,
+) {
+    let ended: Vec<EnemyId> = enemy_formation_res
+        .0
+        .get_enemies()
+        .iter()
+        .flatten()
+        .flatten()
+        .flat_map(|enemy| {
+            let id = enemy.get_id();
+            let in_flight = shooter_query
+                .iter()
+                .filter(|shooter| shooter.0 == id)
+                .count();
+            let shots_in_flight = enemy.weapon().shots_in_flight() as usize;
+
+            std::iter::repeat_n(id, shots_in_flight.saturating_sub(in_flight))
+        })
+        .collect();
+
+    // Reloading is bookkeeping only; marking the formation as changed would respawn every enemy.
+    for id in ended {
+        enemy_formation_res.bypass_change_detection().0.end_shot(id);
+    }
+}
+
 pub fn spawn_random_projectiles_system(
     mut commands: Commands,
     time: Res<Time>,
     mut timer: ResMut<EnemyProjectileMovementTimer>,
-    enemy_query: Query<&Transform, With<EnemyComponent>>,
-    enemy_fire_probability: ResMut<EnemyFireProbability>,
+    mut shooters: EnemyShooters,
+    fire_settings: EnemyFireSettings,
+    mut rng: ResMut<GameRngResource>,
+    arena: Option<Res<ArenaResource>>,
 ) {
     if !timer.0.tick(time.delta()).just_finished() {
         return;
     }
 
-    let mut rng = rand::rng();
+    let rng = &mut rng.0;
+    let fire_probability = fire_settings.fire_probability();
+    let formation = shooters.formation.bypass_change_detection();
 
-    enemy_query
+    shooters
+        .enemies
         .iter()
-        .choose_multiple(&mut rng, 5)
+        .filter(|(enemy, _)| {
+            formation
+                .0
+                .get_enemy(enemy.id)
+                .is_some_and(|enemy| enemy.weapon().can_fire())
+        })
+        .choose_multiple(rng, 5)
         .iter()
-        .for_each(|chosen| {
-            let should_shoot = rng.random_bool(enemy_fire_probability.0);
+        .for_each(|(enemy, chosen)| {
+            let should_shoot = rng.random_bool(fire_probability);
 
-            if should_shoot {
+            if should_shoot && formation.0.fire(enemy.id) {
                 let start_x = chosen.translation.x;
                 let start_y = chosen.translation.y;
-
-                commands.spawn(EnemyProjectileBundle::new(start_x, start_y));
+                let shot_type = match fire_settings.shot_types.0.as_slice() {
+                    [only] => *only,
+                    many => many.choose(rng).copied().unwrap_or_default(),
+                };
+
+                spawn_in_arena(
+                    &mut commands,
+                    arena.as_deref(),
+                    (
+                        EnemyProjectileBundle::new(start_x, start_y).with_shot_type(shot_type),
+                        EnemyShooterComponent(enemy.id),
+                    ),
+                );
             }
         })
 }
@@ -173,30 +424,49 @@ pub fn spawn_random_projectiles_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::enemy::{ARMORED_HIT_POINTS, Enemy, EnemyId};
     use crate::domain::enemy_formation::EnemyFormation;
-    use crate::domain::player::Player;
+    use crate::domain::formation_layout::FormationLayout;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::wave::Wave;
     use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::enemy::resources::DAMAGED_ENEMY_COLOR;
     use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationResource;
     use crate::infrastructure::bevy::player::resources::PlayerResource;
     use bevy::app::{App, Startup};
     use bevy::image::Image;
     use bevy::prelude::{AssetApp, Transform, With};
     use bevy::text::Font;
-    use bevy_test::TestAppBuilder;
+    use bevy_test::{TestAppBuilder, get_resource_or_fail};
 
     fn setup() -> App {
         TestAppBuilder::with_time_disabled()
             .with_assets()
             .with_setup(|app| {
                 app.insert_resource(EnemyFormationResource(EnemyFormation::new()))
-                    .insert_resource(PlayerResource(Player::new()))
+                    .init_resource::<PlayerResource>()
+                    .insert_resource(WaveResource(Wave::default()))
+                    .init_resource::<FormationLayoutsResource>()
+                    .init_resource::<FormationMovementsResource>()
+                    .insert_resource(DifficultyResource(Difficulty::Normal))
+                    .insert_resource(GameRngResource::seeded(7))
+                    .init_resource::<EnemyShotTypesResource>()
                     .init_asset::<Image>()
                     .init_asset::<Font>()
-                    .add_message::<EnemyKilledMessage>();
+                    .init_resource::<EnemyHitFlashesResource>()
+                    .add_message::<EnemyKilledMessage>()
+                    .add_message::<EnemyDamagedMessage>()
+                    .add_message::<FormationAdvancedMessage>();
             })
             .build()
     }
 
+    fn armored_formation() -> EnemyFormationResource {
+        let layout = FormationLayout::parse(&["A"]).expect("Layout should parse");
+        EnemyFormationResource(EnemyFormation::from_layout(&layout))
+    }
+
     fn get_first_enemy_coordinates(app: &mut App) -> (f32, f32) {
         let translation = app
             .world_mut()
@@ -224,11 +494,48 @@ mod tests {
 
             assert_eq!(enemy_count, 55);
         }
+
+        #[test]
+        fn should_leave_the_slots_of_divers_empty() {
+            let mut app = setup();
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .dive(EnemyId::new(1));
+            app.add_systems(Startup, spawn_enemy_formation_system);
+            app.update();
+
+            let enemy_count = count_components::<EnemyComponent>(&mut app);
+
+            assert_eq!(enemy_count, 54);
+        }
+
+        #[test]
+        fn should_spawn_the_enemies_in_the_arena() {
+            let mut app = setup();
+            let root = app.world_mut().spawn_empty().id();
+            app.insert_resource(ArenaResource {
+                root,
+                slot: PlayerSlot::Two,
+            })
+            .add_systems(Startup, spawn_enemy_formation_system);
+            app.update();
+
+            let in_arena = app
+                .world_mut()
+                .query_filtered::<&ChildOf, With<EnemyComponent>>()
+                .iter(app.world())
+                .filter(|child_of| child_of.parent() == root)
+                .count();
+
+            assert_eq!(in_arena, 55);
+        }
     }
 
     #[cfg(test)]
     mod enemy_formation_movement_system {
         use super::*;
+        use crate::domain::dive::{DIVE_SWING, Dive, DiveEnding};
         use bevy::app::Update;
 
         #[test]
@@ -251,6 +558,37 @@ mod tests {
             assert!(first_enemy_x_t1 > first_enemy_x_t0);
         }
 
+        #[test]
+        fn should_keep_divers_in_flight_when_the_grid_moves() {
+            let mut app = setup();
+            app.add_systems(Startup, spawn_enemy_formation_system);
+            app.add_systems(Update, enemy_formation_movement_system);
+            app.update();
+
+            let diver = app
+                .world_mut()
+                .spawn((
+                    EnemyComponent {
+                        id: EnemyId::new(1),
+                    },
+                    DiverComponent::new(Dive::new(
+                        (0.0, 0.0),
+                        (0.0, -300.0),
+                        DIVE_SWING,
+                        -400.0,
+                        DiveEnding::Return,
+                    )),
+                ))
+                .id();
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .advance();
+            app.update();
+
+            assert!(app.world().get_entity(diver).is_ok());
+        }
+
         #[test]
         fn should_move_to_the_left_when_there_is_enough_space() {
             let mut app = setup();
@@ -338,7 +676,7 @@ mod tests {
         use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationMovementTimer;
         use bevy::app::Update;
         use bevy::prelude::{Time, Timer, TimerMode};
-        use bevy_test::advance_time_by_seconds;
+        use bevy_test::{advance_time_by_seconds, did_message_fire};
 
         #[test]
         fn should_advance_on_tick() {
@@ -368,6 +706,173 @@ mod tests {
             let first_enemy_x_t1 = get_first_enemy_coordinates(&mut app).0;
 
             assert!(first_enemy_x_t1 > first_enemy_x_t0);
+            assert!(did_message_fire::<FormationAdvancedMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_not_announce_an_advance_before_the_tick() {
+            let mut app = setup();
+            app.init_resource::<Time>();
+            app.insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                1.0,
+                TimerMode::Once,
+            )));
+            app.add_systems(Update, enemy_formation_lifecycle_system);
+
+            advance_time_by_seconds(&mut app, 0.5);
+            app.update();
+
+            assert!(!did_message_fire::<FormationAdvancedMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_advance_sooner_on_harder_difficulties() {
+            let mut app = setup();
+            app.init_resource::<Time>();
+            app.insert_resource(DifficultyResource(Difficulty::Arcade));
+            app.insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                1.0,
+                TimerMode::Once,
+            )));
+            app.add_systems(Update, enemy_formation_lifecycle_system);
+
+            advance_time_by_seconds(&mut app, 0.8);
+            app.update();
+
+            assert_eq!(
+                app.world()
+                    .resource::<EnemyFormationResource>()
+                    .0
+                    .get_position(),
+                (1, 0)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod next_wave_system {
+        use super::*;
+        use crate::domain::boss::Boss;
+        use crate::domain::enemy::EnemyId;
+        use crate::domain::enemy_formation::{COLUMNS, ROWS};
+        use crate::domain::formation_layout::{FormationLayout, FormationLayouts};
+        use crate::domain::movement_pattern::{FormationMovement, FormationMovements};
+        use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationMovementTimer;
+        use bevy::app::Update;
+        use bevy::prelude::{Timer, TimerMode};
+        use bevy_test::get_resource_or_fail;
+
+        fn setup_with_timer() -> App {
+            let mut app = setup();
+            app.insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                1.0,
+                TimerMode::Repeating,
+            )));
+            app.add_systems(Update, next_wave_system);
+            app
+        }
+
+        #[test]
+        fn should_start_the_next_wave_when_the_formation_is_annihilated() {
+            let mut app = setup_with_timer();
+
+            {
+                let mut formation = app.world_mut().resource_mut::<EnemyFormationResource>();
+                (1..=COLUMNS * ROWS).for_each(|id| {
+                    formation.0.kill(EnemyId::new(id));
+                });
+            }
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app)
+                    .0
+                    .get_number(),
+                2
+            );
+
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.get_status(), FormationStatus::Assembled);
+            assert_eq!(formation.get_position(), (0, 1));
+        }
+
+        #[test]
+        fn should_hold_the_wave_while_a_boss_is_alive() {
+            let mut app = setup_with_timer();
+            app.insert_resource(BossResource(Some(Boss::new(1))))
+                .insert_resource(EnemyFormationResource(EnemyFormation::cleared()));
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app)
+                    .0
+                    .get_number(),
+                1
+            );
+        }
+
+        #[test]
+        fn should_deploy_the_layout_of_the_next_wave() {
+            let mut app = setup_with_timer();
+            let diamond =
+                FormationLayout::parse(&[".C.", "CCC", ".C."]).expect("Layout should parse");
+            app.insert_resource(FormationLayoutsResource(FormationLayouts::new(vec![
+                FormationLayout::classic(),
+                diamond.clone(),
+            ])));
+
+            {
+                let mut formation = app.world_mut().resource_mut::<EnemyFormationResource>();
+                (1..=COLUMNS * ROWS).for_each(|id| {
+                    formation.0.kill(EnemyId::new(id));
+                });
+            }
+
+            app.update();
+
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.get_layout(), &diamond);
+            assert_eq!(formation.get_columns(), 3);
+        }
+
+        #[test]
+        fn should_move_the_next_wave_with_its_pattern() {
+            let mut app = setup_with_timer();
+            app.insert_resource(FormationMovementsResource(FormationMovements::new(vec![
+                FormationMovement::ClassicMarch,
+                FormationMovement::Circling,
+            ])))
+            .insert_resource(EnemyFormationResource(EnemyFormation::cleared()));
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .get_movement(),
+                FormationMovement::Circling
+            );
+        }
+
+        #[test]
+        fn should_keep_the_wave_while_enemies_are_alive() {
+            let mut app = setup_with_timer();
+
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .kill(EnemyId::new(1));
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app)
+                    .0
+                    .get_number(),
+                1
+            );
         }
     }
 
@@ -401,8 +906,11 @@ mod tests {
             let enemy_x = enemy_info.0.x;
             let enemy_y = enemy_info.0.y;
 
-            app.world_mut()
-                .spawn(PlayerProjectileBundle::new(enemy_x, enemy_y));
+            app.world_mut().spawn(PlayerProjectileBundle::new(
+                enemy_x,
+                enemy_y,
+                PlayerSlot::One,
+            ));
 
             app.update();
 
@@ -417,23 +925,123 @@ mod tests {
                 post_update_enemy_formation_resource
                     .0
                     .get_enemies()
-                    .get(0)
+                    .first()
                     .unwrap()
-                    .get(0)
+                    .first()
                     .unwrap()
                     .is_none()
             );
         }
+
+        #[test]
+        fn should_kill_an_enemy_only_once_when_several_shots_hit_it() {
+            let mut app = setup();
+            app.add_systems(Startup, spawn_enemy_formation_system);
+            app.add_systems(Update, collisions_system);
+
+            app.update();
+
+            let (enemy_x, enemy_y) = get_first_enemy_coordinates(&mut app);
+            for shooter in [PlayerSlot::One, PlayerSlot::Two] {
+                app.world_mut()
+                    .spawn(PlayerProjectileBundle::new(enemy_x, enemy_y, shooter));
+            }
+
+            app.update();
+
+            let kills = app
+                .world_mut()
+                .resource_mut::<Messages<EnemyKilledMessage>>()
+                .drain()
+                .count();
+            assert_eq!(kills, 1);
+        }
+
+        #[test]
+        fn should_only_damage_an_armored_enemy() {
+            let mut app = setup();
+            app.insert_resource(armored_formation())
+                .add_systems(Startup, spawn_enemy_formation_system)
+                .add_systems(Update, collisions_system);
+
+            app.update();
+
+            let (enemy_x, enemy_y) = get_first_enemy_coordinates(&mut app);
+            app.world_mut().spawn(PlayerProjectileBundle::new(
+                enemy_x,
+                enemy_y,
+                PlayerSlot::One,
+            ));
+
+            app.update();
+
+            assert!(did_message_fire::<EnemyDamagedMessage>(&mut app));
+            assert!(!did_message_fire::<EnemyKilledMessage>(&mut app));
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .get_enemy(EnemyId::new(1))
+                    .map(Enemy::get_hit_points),
+                Some(ARMORED_HIT_POINTS - 1)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod flash_damaged_enemies_system {
+        use super::*;
+        use bevy::app::Update;
+        use bevy_test::{advance_time_by_seconds, send_message, spawn_dummy_entity};
+
+        fn enemy_color_of(app: &mut App) -> Color {
+            app.world_mut()
+                .query_filtered::<&Sprite, With<EnemyComponent>>()
+                .single(app.world())
+                .expect("Enemy sprite not found")
+                .color
+        }
+
+        #[test]
+        fn damaged_enemies_should_flash_then_keep_a_tint() {
+            let mut app = setup();
+            let mut formation = armored_formation();
+            formation.0.damage(EnemyId::new(1));
+            app.insert_resource(formation)
+                .init_resource::<Time>()
+                .add_systems(Startup, spawn_enemy_formation_system)
+                .add_systems(Update, flash_damaged_enemies_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            send_message(
+                &mut app,
+                EnemyDamagedMessage::new(EnemyId::new(1), dummy, PlayerSlot::One),
+            );
+            app.update();
+
+            assert_eq!(enemy_color_of(&mut app), ENEMY_HIT_FLASH_COLOR);
+
+            advance_time_by_seconds(&mut app, ENEMY_HIT_FLASH_DURATION);
+            app.update();
+
+            assert_eq!(enemy_color_of(&mut app), DAMAGED_ENEMY_COLOR);
+            assert!(
+                get_resource_or_fail::<EnemyHitFlashesResource>(&mut app)
+                    .0
+                    .is_empty()
+            );
+        }
     }
 
     #[cfg(test)]
     mod spawn_random_projectiles_system {
         use super::*;
+        use crate::domain::difficulty::DynamicDifficulty;
         use crate::infrastructure::bevy::enemy::resources::{
             EnemyFireProbability, EnemyProjectileMovementTimer,
         };
         use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileComponent;
         use bevy::app::Update;
+        use bevy::ecs::system::RunSystemOnce;
         use bevy::prelude::{Timer, TimerMode};
         use bevy_test::{advance_time_by_seconds, count_components};
 
@@ -459,5 +1067,133 @@ mod tests {
 
             assert!(projectiles > 0);
         }
+
+        #[test]
+        fn recent_deaths_should_ease_the_dynamic_fire() {
+            let mut app = setup();
+            let mut dynamic = DynamicDifficulty::new();
+            dynamic.record_death();
+            dynamic.record_death();
+            app.insert_resource(EnemyFireProbability(0.4))
+                .insert_resource(DynamicDifficultyResource(dynamic));
+
+            let probability = app
+                .world_mut()
+                .run_system_once(|settings: EnemyFireSettings| settings.fire_probability())
+                .expect("Failed to read the fire probability");
+
+            assert!(probability < 0.4);
+        }
+
+        #[test]
+        fn enemies_should_not_fire_again_while_their_shot_is_in_flight() {
+            let mut app = setup();
+            let layout = FormationLayout::parse(&["C"]).expect("Layout should parse");
+            app.init_resource::<Time>()
+                .insert_resource(EnemyFormationResource(EnemyFormation::from_layout(&layout)))
+                .insert_resource(EnemyProjectileMovementTimer(Timer::from_seconds(
+                    1.0,
+                    TimerMode::Repeating,
+                )))
+                .insert_resource(EnemyFireProbability(1.0))
+                .add_systems(Update, spawn_random_projectiles_system);
+            app.world_mut()
+                .spawn((EnemyComponent::new(EnemyId::new(1)), Transform::default()));
+
+            for _ in 0..3 {
+                advance_time_by_seconds(&mut app, 1.1);
+                app.update();
+            }
+
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 1);
+            assert!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .get_enemy(EnemyId::new(1))
+                    .is_some_and(|enemy| enemy.weapon().is_firing())
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod reload_enemy_weapons_system {
+        use super::*;
+        use crate::infrastructure::bevy::enemy_projectile::components::EnemyShooterComponent;
+        use bevy::app::Update;
+
+        fn firing_formation() -> EnemyFormationResource {
+            let layout = FormationLayout::parse(&["C"]).expect("Layout should parse");
+            let mut formation = EnemyFormation::from_layout(&layout);
+            formation.fire(EnemyId::new(1));
+
+            EnemyFormationResource(formation)
+        }
+
+        fn can_fire(app: &mut App) -> bool {
+            get_resource_or_fail::<EnemyFormationResource>(app)
+                .0
+                .get_enemy(EnemyId::new(1))
+                .is_some_and(|enemy| enemy.weapon().can_fire())
+        }
+
+        #[test]
+        fn should_reload_once_the_shot_is_gone() {
+            let mut app = setup();
+            app.insert_resource(firing_formation())
+                .add_systems(Update, reload_enemy_weapons_system);
+
+            app.update();
+
+            assert!(can_fire(&mut app));
+        }
+
+        #[test]
+        fn should_keep_firing_while_the_shot_is_in_flight() {
+            let mut app = setup();
+            app.insert_resource(firing_formation())
+                .add_systems(Update, reload_enemy_weapons_system);
+            app.world_mut()
+                .spawn(EnemyShooterComponent(EnemyId::new(1)));
+
+            app.update();
+
+            assert!(!can_fire(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_enemy_formation_system {
+        use super::*;
+        use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationMovementTimer;
+        use bevy::app::Update;
+        use bevy::prelude::{Timer, TimerMode};
+        use bevy_test::{get_resource_or_fail, send_message};
+
+        #[test]
+        fn should_restart_from_the_starting_wave_on_new_game() {
+            let mut app = setup();
+            app.insert_resource(StartingWaveResource(Wave::new(2)))
+                .insert_resource(WaveResource(Wave::new(5)))
+                .insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                    1.0,
+                    TimerMode::Repeating,
+                )))
+                .add_message::<NewGameMessage>()
+                .add_systems(Update, reset_enemy_formation_system);
+            app.world_mut()
+                .resource_mut::<EnemyFormationResource>()
+                .0
+                .advance();
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app).0,
+                Wave::new(2)
+            );
+            let formation = &get_resource_or_fail::<EnemyFormationResource>(&mut app).0;
+            assert_eq!(formation.get_position(), (0, 1));
+        }
     }
 }
@@ -1,3 +1,6 @@
+use crate::domain::enemy::EnemyId;
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::wave_script::ShotType;
 use crate::infrastructure::bevy::enemy_projectile::resources::{
     ENEMY_PROJECTILE_COLOR, ENEMY_PROJECTILE_DURATION, ENEMY_PROJECTILE_HEIGHT,
     ENEMY_PROJECTILE_WIDTH,
@@ -12,6 +15,7 @@ pub struct EnemyProjectileBundle {
     pub sprite: Sprite,
     pub transform: Transform,
     pub timer: EnemyProjectileTimer,
+    pub shot: EnemyShotComponent,
 }
 
 #[derive(Component)]
@@ -29,17 +33,27 @@ pub struct EnemyProjectileExpiredMessage;
 #[derive(Message)]
 pub struct PlayerKilledMessage {
     pub projectile_entity: Entity,
+    pub player: PlayerSlot,
 }
 
 impl PlayerKilledMessage {
-    pub fn new(projectile_entity: Entity) -> Self {
-        PlayerKilledMessage { projectile_entity }
+    pub fn new(projectile_entity: Entity, player: PlayerSlot) -> Self {
+        PlayerKilledMessage {
+            projectile_entity,
+            player,
+        }
     }
 }
 
 #[derive(Component, PartialEq, Debug)]
 pub struct EnemyProjectileComponent;
 
+#[derive(Component, PartialEq, Debug, Default)]
+pub struct EnemyShotComponent(pub ShotType);
+
+#[derive(Component, PartialEq, Debug)]
+pub struct EnemyShooterComponent(pub EnemyId);
+
 impl EnemyProjectileBundle {
     pub fn new(x: f32, y: f32) -> Self {
         Self {
@@ -54,8 +68,14 @@ impl EnemyProjectileBundle {
                 ENEMY_PROJECTILE_DURATION,
                 TimerMode::Repeating,
             )),
+            shot: EnemyShotComponent::default(),
         }
     }
+
+    pub fn with_shot_type(mut self, shot_type: ShotType) -> Self {
+        self.shot = EnemyShotComponent(shot_type);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -93,4 +113,18 @@ mod tests {
         assert_eq!(sprite.color, ENEMY_PROJECTILE_COLOR);
         assert_eq!(timer.0.mode(), TimerMode::Repeating);
     }
+
+    #[test]
+    fn enemy_projectiles_default_to_rolling_shots() {
+        assert_eq!(
+            EnemyProjectileBundle::new(0.0, 0.0).shot,
+            EnemyShotComponent(ShotType::Rolling)
+        );
+        assert_eq!(
+            EnemyProjectileBundle::new(0.0, 0.0)
+                .with_shot_type(ShotType::Plunger)
+                .shot,
+            EnemyShotComponent(ShotType::Plunger)
+        );
+    }
 }
@@ -1,36 +1,51 @@
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
 use crate::infrastructure::bevy::enemy_projectile::components::{
     EnemyProjectileExpiredMessage, PlayerKilledMessage,
 };
+use crate::infrastructure::bevy::enemy_projectile::resources::{
+    ENEMY_PROJECTILE_SPEED, EnemyProjectileSpeed,
+};
 use crate::infrastructure::bevy::enemy_projectile::systems::{
     collision_system, enemy_projectile_lifecycle_system, enemy_projectile_movement_system,
-    on_enemy_projectile_hitting_player_system,
+    on_enemy_projectile_hitting_player_system, reset_enemy_projectiles_system,
 };
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
 use bevy::app::{App, Plugin, Update};
+use bevy::prelude::IntoScheduleConfigs;
 
 pub struct EnemyProjectilePlugin;
 
 impl Plugin for EnemyProjectilePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
-            Update,
-            (
-                collision_system,
-                enemy_projectile_movement_system,
-                enemy_projectile_lifecycle_system,
-                on_enemy_projectile_hitting_player_system,
-            ),
-        )
-        .add_message::<EnemyProjectileExpiredMessage>()
-        .add_message::<PlayerKilledMessage>();
+        app.insert_resource(EnemyProjectileSpeed(ENEMY_PROJECTILE_SPEED))
+            .init_resource::<DifficultyResource>()
+            .add_systems(
+                Update,
+                (
+                    collision_system,
+                    enemy_projectile_movement_system,
+                    enemy_projectile_lifecycle_system,
+                    on_enemy_projectile_hitting_player_system.after(collision_system),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_enemy_projectiles_system)
+            .add_message::<EnemyProjectileExpiredMessage>()
+            .add_message::<PlayerKilledMessage>();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use bevy_test::TestAppBuilder;
 
     #[test]
     fn plugin_loads_successfully() {
-        let _app = bevy_test::smoke_test_plugin(EnemyProjectilePlugin);
+        let _app = TestAppBuilder::new()
+            .with_plugin(EnemyProjectilePlugin)
+            .with_message::<NewGameMessage>()
+            .build();
     }
 }
@@ -1,34 +1,58 @@
 use crate::domain::collision::check_aabb_collision;
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
 use crate::infrastructure::bevy::enemy_projectile::components::{
     EnemyProjectileComponent, EnemyProjectileExpiredMessage, EnemyProjectileTimer,
-    PlayerKilledMessage,
+    EnemyShotComponent, PlayerKilledMessage,
 };
-use crate::infrastructure::bevy::enemy_projectile::resources::ENEMY_PROJECTILE_SPEED;
+use crate::infrastructure::bevy::enemy_projectile::resources::EnemyProjectileSpeed;
 use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
 use bevy::math::Vec2;
 use bevy::prelude::{
     Commands, Entity, MessageReader, MessageWriter, Query, Res, Sprite, Time, Transform, With,
 };
 
+pub fn reset_enemy_projectiles_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    query: Query<Entity, With<EnemyProjectileComponent>>,
+) {
+    if new_game_message.read().count() > 0 {
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
 pub fn enemy_projectile_movement_system(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<EnemyProjectileComponent>>,
+    projectile_speed: Res<EnemyProjectileSpeed>,
+    difficulty: Res<DifficultyResource>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut query: Query<(&mut Transform, &EnemyShotComponent), With<EnemyProjectileComponent>>,
 ) {
-    for mut transform in query.iter_mut() {
-        transform.translation.y -= ENEMY_PROJECTILE_SPEED * time.delta_secs();
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+    let speed = projectile_speed.0 * difficulty.0.enemy_projectile_speed_scale() * time_scale;
+
+    for (mut transform, shot) in query.iter_mut() {
+        transform.translation.y -= speed * shot.0.speed_scale() * time.delta_secs();
     }
 }
 
 pub fn enemy_projectile_lifecycle_system(
     mut commands: Commands,
     time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
     mut query: Query<
         (Entity, &Transform, &mut EnemyProjectileTimer),
         With<EnemyProjectileComponent>,
     >,
     mut message_writer: MessageWriter<EnemyProjectileExpiredMessage>,
 ) {
+    let time_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.time_scale());
+
     for (entity, transform, mut timer_component) in query.iter_mut() {
         let mut reset_needed = false;
         let out_of_bound_y = -GAME_AREA_HEIGHT / 2.0;
@@ -37,7 +61,7 @@ pub fn enemy_projectile_lifecycle_system(
             reset_needed = true;
         }
 
-        timer_component.0.tick(time.delta());
+        timer_component.0.tick(time.delta().mul_f32(time_scale));
 
         if timer_component.0.is_finished() {
             reset_needed = true;
@@ -65,7 +89,7 @@ pub fn collision_system(
     player_query: Query<(&Transform, &Sprite, &PlayerComponent), With<PlayerComponent>>,
     mut player_killed_message_writer: MessageWriter<PlayerKilledMessage>,
 ) {
-    for (player_transform, player_sprite, _) in player_query.iter() {
+    for (player_transform, player_sprite, player) in player_query.iter() {
         for (projectile_entity, projectile_transform, projectile_sprite) in
             projectile_query.iter_mut()
         {
@@ -86,7 +110,8 @@ pub fn collision_system(
             );
 
             if collision {
-                player_killed_message_writer.write(PlayerKilledMessage::new(projectile_entity));
+                player_killed_message_writer
+                    .write(PlayerKilledMessage::new(projectile_entity, player.slot));
                 break;
             }
         }
@@ -96,10 +121,15 @@ pub fn collision_system(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::power_up::{ActivePowerUps, PowerUpKind, TIME_SLOW_SCALE};
+    use crate::domain::wave_script::ShotType;
     use crate::infrastructure::bevy::enemy_projectile::components::{
         EnemyProjectileComponent, EnemyProjectileExpiredMessage,
     };
-    use crate::infrastructure::bevy::enemy_projectile::resources::ENEMY_PROJECTILE_SPEED;
+    use crate::infrastructure::bevy::enemy_projectile::resources::{
+        ENEMY_PROJECTILE_SPEED, EnemyProjectileSpeed,
+    };
     use bevy::app::{App, Update};
     use bevy::prelude::Transform;
     use bevy_test::{TestAppBuilder, advance_time_by_seconds, get_component_or_fail};
@@ -108,7 +138,9 @@ mod tests {
         TestAppBuilder::with_time_disabled()
             .with_time()
             .with_setup(|app| {
-                app.add_message::<EnemyProjectileExpiredMessage>();
+                app.add_message::<EnemyProjectileExpiredMessage>()
+                    .insert_resource(EnemyProjectileSpeed(ENEMY_PROJECTILE_SPEED))
+                    .init_resource::<DifficultyResource>();
             })
             .build()
     }
@@ -124,7 +156,11 @@ mod tests {
 
             let projectile = app
                 .world_mut()
-                .spawn((EnemyProjectileComponent, Transform::from_xyz(0.0, 0.0, 0.0)))
+                .spawn((
+                    EnemyProjectileComponent,
+                    EnemyShotComponent::default(),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
                 .id();
 
             let delta_time = 0.1;
@@ -138,6 +174,87 @@ mod tests {
             assert!((transform.translation.y - expected_y).abs() < 0.001);
         }
 
+        #[test]
+        fn should_move_each_shot_type_at_its_own_speed() {
+            let mut app = setup();
+            app.add_systems(Update, enemy_projectile_movement_system);
+
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    EnemyProjectileComponent,
+                    EnemyShotComponent(ShotType::Plunger),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            let delta_time = 0.1;
+            advance_time_by_seconds(&mut app, delta_time);
+
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_y = -ENEMY_PROJECTILE_SPEED * ShotType::Plunger.speed_scale() * delta_time;
+
+            assert!((transform.translation.y - expected_y).abs() < 0.001);
+        }
+
+        #[test]
+        fn should_move_faster_on_harder_difficulties() {
+            let mut app = setup();
+            app.insert_resource(DifficultyResource(Difficulty::Arcade))
+                .add_systems(Update, enemy_projectile_movement_system);
+
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    EnemyProjectileComponent,
+                    EnemyShotComponent::default(),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            let delta_time = 0.1;
+            advance_time_by_seconds(&mut app, delta_time);
+
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_y = -ENEMY_PROJECTILE_SPEED
+                * Difficulty::Arcade.enemy_projectile_speed_scale()
+                * delta_time;
+
+            assert!((transform.translation.y - expected_y).abs() < 0.001);
+        }
+
+        #[test]
+        fn should_move_slower_while_time_is_slowed() {
+            let mut app = setup();
+            let mut power_ups = ActivePowerUps::new();
+            power_ups.activate(PowerUpKind::TimeSlow);
+            app.insert_resource(ActivePowerUpsResource(power_ups))
+                .add_systems(Update, enemy_projectile_movement_system);
+
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    EnemyProjectileComponent,
+                    EnemyShotComponent::default(),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            let delta_time = 0.1;
+            advance_time_by_seconds(&mut app, delta_time);
+
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_y = -ENEMY_PROJECTILE_SPEED * TIME_SLOW_SCALE * delta_time;
+
+            assert!((transform.translation.y - expected_y).abs() < 0.001);
+        }
+
         #[test]
         fn should_not_move_when_time_delta_is_zero() {
             let mut app = setup();
@@ -147,6 +264,7 @@ mod tests {
                 .world_mut()
                 .spawn((
                     EnemyProjectileComponent,
+                    EnemyShotComponent::default(),
                     Transform::from_xyz(0.0, 100.0, 0.0),
                 ))
                 .id();
@@ -204,19 +322,20 @@ mod tests {
 
     #[cfg(test)]
     mod collision_system {
-        use crate::domain::player::Player;
+        use crate::domain::game_mode::PlayerSlot;
         use crate::infrastructure::bevy::enemy_projectile::components::{
             EnemyProjectileBundle, PlayerKilledMessage,
         };
         use crate::infrastructure::bevy::enemy_projectile::systems::collision_system;
         use crate::infrastructure::bevy::enemy_projectile::systems::tests::setup;
         use crate::infrastructure::bevy::player::components::PlayerComponent;
-        use crate::infrastructure::bevy::player::resources::PlayerResource;
+        use crate::infrastructure::bevy::player::resources::{PLAYER_TWO_CONTROLS, PlayerResource};
         use crate::infrastructure::bevy::player::systems::spawn_player_system;
         use bevy::app::{Startup, Update};
         use bevy::asset::{AssetApp, AssetPlugin};
         use bevy::image::Image;
-        use bevy::prelude::Transform;
+        use bevy::math::Vec2;
+        use bevy::prelude::{Messages, Sprite, Transform};
         use bevy_test::did_message_fire;
 
         #[test]
@@ -228,7 +347,7 @@ mod tests {
             app.add_message::<PlayerKilledMessage>();
             app.init_asset::<Image>();
 
-            app.insert_resource(PlayerResource(Player::new()));
+            app.init_resource::<PlayerResource>();
 
             app.update();
 
@@ -247,10 +366,35 @@ mod tests {
 
             assert!(did_message_fire::<PlayerKilledMessage>(&mut app));
         }
+
+        #[test]
+        fn should_name_the_player_that_was_hit() {
+            let mut app = setup();
+            app.add_systems(Update, collision_system)
+                .add_message::<PlayerKilledMessage>();
+            app.world_mut().spawn((
+                PlayerComponent::new(PlayerSlot::Two, PLAYER_TWO_CONTROLS),
+                Sprite::from_color(bevy::color::Color::WHITE, Vec2::new(10.0, 10.0)),
+                Transform::from_xyz(50.0, 0.0, 0.0),
+            ));
+            app.world_mut().spawn(EnemyProjectileBundle::new(50.0, 0.0));
+
+            app.update();
+
+            let players = app
+                .world()
+                .resource::<Messages<PlayerKilledMessage>>()
+                .iter_current_update_messages()
+                .map(|message| message.player)
+                .collect::<Vec<_>>();
+
+            assert_eq!(players, vec![PlayerSlot::Two]);
+        }
     }
 
     #[cfg(test)]
     mod on_enemy_projectile_hitting_player_system {
+        use crate::domain::game_mode::PlayerSlot;
         use crate::infrastructure::bevy::enemy_projectile::components::{
             EnemyProjectileBundle, EnemyProjectileComponent, PlayerKilledMessage,
         };
@@ -284,7 +428,10 @@ mod tests {
 
             assert!(enemy_projectile_info.is_some());
 
-            send_message(&mut app, PlayerKilledMessage::new(projectile));
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::One),
+            );
 
             app.update();
 
@@ -297,4 +444,23 @@ mod tests {
             assert!(enemy_projectile_info.is_none());
         }
     }
+
+    #[cfg(test)]
+    mod reset_enemy_projectiles_system {
+        use super::*;
+        use bevy_test::{count_components, send_message};
+
+        #[test]
+        fn should_despawn_projectiles_on_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>()
+                .add_systems(Update, reset_enemy_projectiles_system);
+            app.world_mut().spawn(EnemyProjectileComponent);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 0);
+        }
+    }
 }
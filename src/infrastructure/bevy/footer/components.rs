@@ -30,6 +30,12 @@ impl FooterBundle {
     }
 }
 
+impl Default for FooterBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
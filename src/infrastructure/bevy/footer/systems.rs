@@ -1,8 +1,10 @@
 use crate::infrastructure::bevy::footer::components::FooterBundle;
-use bevy::prelude::Commands;
+use crate::infrastructure::bevy::game_area::resources::ArenaResource;
+use crate::infrastructure::bevy::game_area::systems::spawn_in_arena;
+use bevy::prelude::{Commands, Res};
 
-pub fn spawn_footer_system(mut commands: Commands) {
-    commands.spawn(FooterBundle::new());
+pub fn spawn_footer_system(mut commands: Commands, arena: Option<Res<ArenaResource>>) {
+    spawn_in_arena(&mut commands, arena.as_deref(), FooterBundle::new());
 }
 
 #[cfg(test)]
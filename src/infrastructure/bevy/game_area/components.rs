@@ -15,6 +15,10 @@ pub struct GameAreaBundle {
 
 impl GameAreaBundle {
     pub fn new(asset_server: &AssetServer, width: f32, height: f32) -> Self {
+        Self::at(asset_server, Vec2::ZERO, width, height)
+    }
+
+    pub fn at(asset_server: &AssetServer, origin: Vec2, width: f32, height: f32) -> Self {
         Self {
             game_area: GameAreaComponent,
             sprite: Sprite {
@@ -22,7 +26,7 @@ impl GameAreaBundle {
                 custom_size: Some(Vec2::new(width, height)),
                 ..default()
             },
-            transform: Transform::from_xyz(0.0, 0.0, -1.0),
+            transform: Transform::from_xyz(origin.x, origin.y, -1.0),
         }
     }
 }
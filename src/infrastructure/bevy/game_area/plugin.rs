@@ -1,3 +1,4 @@
+use crate::infrastructure::bevy::game_area::resources::ArenaLayoutResource;
 use crate::infrastructure::bevy::game_area::systems::{
     resize_game_area_system, spawn_game_area_system,
 };
@@ -7,7 +8,8 @@ pub struct GameAreaPlugin;
 
 impl Plugin for GameAreaPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, spawn_game_area_system)
+        app.init_resource::<ArenaLayoutResource>()
+            .add_systems(Startup, spawn_game_area_system)
             .add_systems(PostUpdate, resize_game_area_system);
     }
 }
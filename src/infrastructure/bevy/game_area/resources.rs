@@ -1,6 +1,92 @@
+use crate::domain::game_mode::PlayerSlot;
 use crate::infrastructure::bevy::bevy_renderer::resources::{WINDOW_HEIGHT, WINDOW_WIDTH};
 use crate::infrastructure::bevy::header::resources::HEADER_HEIGHT;
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, Resource};
 
 pub const GAME_AREA_WIDTH: f32 = WINDOW_WIDTH * 0.93;
 pub const GAME_AREA_HEIGHT: f32 = (WINDOW_HEIGHT - HEADER_HEIGHT) * 0.98;
 pub const BACKGROUND_IMAGE: &str = "tv.png";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArenaLayout {
+    origins: Vec<Vec2>,
+}
+
+impl ArenaLayout {
+    pub fn single() -> Self {
+        Self {
+            origins: vec![Vec2::ZERO],
+        }
+    }
+
+    pub fn side_by_side(count: usize) -> Self {
+        let count = count.max(1);
+        let first_x = -WINDOW_WIDTH * (count - 1) as f32 / 2.0;
+
+        Self {
+            origins: (0..count)
+                .map(|index| Vec2::new(first_x + WINDOW_WIDTH * index as f32, 0.0))
+                .collect(),
+        }
+    }
+
+    pub fn get_origins(&self) -> &[Vec2] {
+        &self.origins
+    }
+
+    pub fn get_origin(&self, index: usize) -> Vec2 {
+        self.origins.get(index).copied().unwrap_or(Vec2::ZERO)
+    }
+
+    pub fn count(&self) -> usize {
+        self.origins.len()
+    }
+
+    pub fn width(&self) -> f32 {
+        WINDOW_WIDTH * self.count() as f32
+    }
+}
+
+impl Default for ArenaLayout {
+    fn default() -> Self {
+        Self::single()
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct ArenaLayoutResource(pub ArenaLayout);
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct ArenaResource {
+    pub root: Entity,
+    pub slot: PlayerSlot,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_single_arena_sits_in_the_middle() {
+        let layout = ArenaLayout::single();
+
+        assert_eq!(layout.get_origins(), &[Vec2::ZERO]);
+        assert_eq!(layout.width(), WINDOW_WIDTH);
+    }
+
+    #[test]
+    fn side_by_side_arenas_are_centred_one_window_apart() {
+        let layout = ArenaLayout::side_by_side(2);
+
+        assert_eq!(
+            layout.get_origins(),
+            &[
+                Vec2::new(-WINDOW_WIDTH / 2.0, 0.0),
+                Vec2::new(WINDOW_WIDTH / 2.0, 0.0)
+            ]
+        );
+        assert_eq!(layout.width(), WINDOW_WIDTH * 2.0);
+        assert_eq!(layout.get_origin(5), Vec2::ZERO);
+    }
+}
@@ -1,39 +1,62 @@
 use crate::infrastructure::bevy::game_area::components::{GameAreaBundle, GameAreaComponent};
+use crate::infrastructure::bevy::game_area::resources::{ArenaLayoutResource, ArenaResource};
 use bevy::asset::AssetServer;
 use bevy::camera::{Camera2d, Projection};
+use bevy::ecs::system::EntityCommands;
 use bevy::math::Vec2;
-use bevy::prelude::{Changed, Commands, Query, Res, Sprite, Window, With};
+use bevy::prelude::{Bundle, Changed, ChildOf, Commands, Query, Res, Sprite, Window, With};
 
 pub fn spawn_game_area_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    layout: Res<ArenaLayoutResource>,
     window_query: Query<&Window>,
 ) {
     if let Ok(window) = window_query.single() {
-        commands.spawn(GameAreaBundle::new(
-            &asset_server,
-            window.width(),
-            window.height(),
-        ));
+        let width = window.width() / layout.0.count() as f32;
+
+        for origin in layout.0.get_origins() {
+            commands.spawn(GameAreaBundle::at(
+                &asset_server,
+                *origin,
+                width,
+                window.height(),
+            ));
+        }
     }
 }
 
 pub fn resize_game_area_system(
+    layout: Res<ArenaLayoutResource>,
     camera_query: Query<&Projection, (With<Camera2d>, Changed<Projection>)>,
     mut background_query: Query<&mut Sprite, With<GameAreaComponent>>,
 ) {
-    if let Ok(projection) = camera_query.single() {
-        if let Projection::Orthographic(orthographic_projection) = projection {
-            let width = orthographic_projection.area.width();
-            let height = orthographic_projection.area.height();
-
-            for mut sprite in background_query.iter_mut() {
-                sprite.custom_size = Some(Vec2::new(width, height));
-            }
+    if let Ok(projection) = camera_query.single()
+        && let Projection::Orthographic(orthographic_projection) = projection
+    {
+        let width = orthographic_projection.area.width() / layout.0.count() as f32;
+        let height = orthographic_projection.area.height();
+
+        for mut sprite in background_query.iter_mut() {
+            sprite.custom_size = Some(Vec2::new(width, height));
         }
     }
 }
 
+pub fn spawn_in_arena<'a>(
+    commands: &'a mut Commands,
+    arena: Option<&ArenaResource>,
+    bundle: impl Bundle,
+) -> EntityCommands<'a> {
+    let mut entity = commands.spawn(bundle);
+
+    if let Some(arena) = arena {
+        entity.insert(ChildOf(arena.root));
+    }
+
+    entity
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,10 +71,13 @@ mod tests {
         TestAppBuilder::new()
             .with_assets()
             .with_setup(|app| {
-                app.init_asset::<Image>().world_mut().spawn(Window {
-                    resolution: WindowResolution::new(800, 600),
-                    ..default()
-                });
+                app.init_asset::<Image>()
+                    .init_resource::<ArenaLayoutResource>()
+                    .world_mut()
+                    .spawn(Window {
+                        resolution: WindowResolution::new(800, 600),
+                        ..default()
+                    });
             })
             .build()
     }
@@ -59,7 +85,10 @@ mod tests {
     #[cfg(test)]
     mod spawn_game_area_system {
         use super::*;
+        use crate::infrastructure::bevy::bevy_renderer::resources::WINDOW_WIDTH;
+        use crate::infrastructure::bevy::game_area::resources::ArenaLayout;
         use bevy::app::Startup;
+        use bevy::prelude::Transform;
         use bevy_test::contains_single_component;
 
         #[test]
@@ -70,6 +99,31 @@ mod tests {
 
             assert!(contains_single_component::<GameAreaComponent>(&mut app));
         }
+
+        #[test]
+        fn should_spawn_one_game_area_per_arena() {
+            let mut app = setup();
+            app.insert_resource(ArenaLayoutResource(ArenaLayout::side_by_side(2)));
+            app.add_systems(Startup, spawn_game_area_system);
+            app.update();
+
+            let mut query = app
+                .world_mut()
+                .query_filtered::<(&Transform, &Sprite), With<GameAreaComponent>>();
+            let mut areas = query
+                .iter(app.world())
+                .map(|(transform, sprite)| (transform.translation.x, sprite.custom_size))
+                .collect::<Vec<_>>();
+            areas.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            assert_eq!(
+                areas,
+                vec![
+                    (-WINDOW_WIDTH / 2.0, Some(Vec2::new(400.0, 600.0))),
+                    (WINDOW_WIDTH / 2.0, Some(Vec2::new(400.0, 600.0)))
+                ]
+            );
+        }
     }
 
     #[cfg(test)]
@@ -109,4 +163,43 @@ mod tests {
             assert_eq!(sprite.custom_size, Some(Vec2::new(new_width, new_height)));
         }
     }
+
+    #[cfg(test)]
+    mod spawn_in_arena {
+        use super::*;
+        use crate::domain::game_mode::PlayerSlot;
+        use bevy::ecs::system::RunSystemOnce;
+        use bevy::prelude::{Entity, Transform};
+
+        fn spawn(app: &mut App, arena: Option<ArenaResource>) -> Entity {
+            app.world_mut()
+                .run_system_once(move |mut commands: Commands| {
+                    spawn_in_arena(&mut commands, arena.as_ref(), Transform::default()).id()
+                })
+                .expect("Entity should spawn")
+        }
+
+        #[test]
+        fn should_parent_the_entity_to_the_arena() {
+            let mut app = setup();
+            let root = app.world_mut().spawn_empty().id();
+            let arena = ArenaResource {
+                root,
+                slot: PlayerSlot::Two,
+            };
+
+            let entity = spawn(&mut app, Some(arena));
+
+            assert_eq!(app.world().get::<ChildOf>(entity), Some(&ChildOf(root)));
+        }
+
+        #[test]
+        fn should_leave_the_entity_unparented_without_an_arena() {
+            let mut app = setup();
+
+            let entity = spawn(&mut app, None);
+
+            assert!(app.world().get::<ChildOf>(entity).is_none());
+        }
+    }
 }
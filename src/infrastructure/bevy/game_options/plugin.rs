@@ -0,0 +1,365 @@
+use crate::domain::game_mode::{GameMode, Turns};
+use crate::domain::lives::Lives;
+use crate::domain::score::Score;
+use crate::infrastructure::bevy::battle::plugin::BattlePlugin;
+use crate::infrastructure::bevy::boss::plugin::BossPlugin;
+use crate::infrastructure::bevy::campaign::plugin::CampaignPlugin;
+use crate::infrastructure::bevy::difficulty::resources::DynamicDifficultyResource;
+use crate::infrastructure::bevy::dive::plugin::DivePlugin;
+use crate::infrastructure::bevy::editor::plugin::EditorPlugin;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    DifficultyResource, GameRngResource, StartingWaveResource,
+};
+use crate::infrastructure::bevy::game_area::resources::{ArenaLayout, ArenaLayoutResource};
+use crate::infrastructure::bevy::game_options::resources::{GameOptions, ReplayMode};
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
+use crate::infrastructure::bevy::netplay::plugin::NetplayPlugin;
+use crate::infrastructure::bevy::netplay::resources::netplay_session_id;
+use crate::infrastructure::bevy::player::resources::RulesetResource;
+use crate::infrastructure::bevy::power_up::plugin::PowerUpPlugin;
+use crate::infrastructure::bevy::replay::plugin::{ReplayPlaybackPlugin, ReplayRecordPlugin};
+use crate::infrastructure::bevy::replay::resources::Replay;
+use crate::infrastructure::bevy::save_game::resources::SaveGameStorageResource;
+use crate::infrastructure::bevy::score::resources::SecondPlayerScoreResource;
+use crate::infrastructure::bevy::turns::resources::TurnsResource;
+use crate::infrastructure::bevy::ufo::plugin::UfoPlugin;
+use crate::infrastructure::bevy::versus::plugin::VersusPlugin;
+use bevy::app::{App, Plugin};
+use tracing::info;
+
+pub struct GameOptionsPlugin {
+    pub options: GameOptions,
+}
+
+impl Plugin for GameOptionsPlugin {
+    fn build(&self, app: &mut App) {
+        let (seed, difficulty, starting_wave) = self.options.resolved();
+
+        info!(
+            "Starting {} game with seed {}, difficulty {}, wave {}",
+            self.options.mode,
+            seed,
+            difficulty,
+            starting_wave.get_number()
+        );
+
+        app.insert_resource(GameRngResource::seeded(seed))
+            .insert_resource(DifficultyResource(difficulty))
+            .insert_resource(StartingWaveResource(starting_wave))
+            .insert_resource(LivesResource(Lives::with_count(
+                difficulty.starting_lives(),
+            )))
+            .insert_resource(RulesetResource(self.options.ruleset))
+            .insert_resource(TurnsResource(Turns::new(self.options.mode)));
+
+        if matches!(self.options.mode, GameMode::Coop | GameMode::Battle) {
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(
+                difficulty.starting_lives(),
+            ))))
+            .insert_resource(SecondPlayerScoreResource(Some(Score::new())));
+        }
+
+        if self.options.dynamic_difficulty {
+            app.insert_resource(DynamicDifficultyResource::default());
+        }
+
+        if self.options.ruleset.drops_power_ups() {
+            app.add_plugins(PowerUpPlugin);
+        }
+
+        if self.options.diving {
+            app.add_plugins(DivePlugin);
+        }
+
+        if let Some(interval) = self.options.boss_waves {
+            app.add_plugins(BossPlugin { interval });
+        }
+
+        if let Some(path) = &self.options.campaign {
+            app.add_plugins(CampaignPlugin { path: path.clone() });
+        }
+
+        if let Some(path) = &self.options.editor {
+            app.add_plugins(EditorPlugin { path: path.clone() });
+        }
+
+        if self.options.mode == GameMode::Versus {
+            app.add_plugins(VersusPlugin);
+
+            if !app.is_plugin_added::<UfoPlugin>() {
+                app.add_plugins(UfoPlugin);
+            }
+        }
+
+        if self.options.mode == GameMode::Battle {
+            app.insert_resource(ArenaLayoutResource(ArenaLayout::side_by_side(2)))
+                .add_plugins(BattlePlugin);
+        }
+
+        match &self.options.replay {
+            ReplayMode::Off => {}
+            ReplayMode::Record(path) => {
+                app.insert_resource(SaveGameStorageResource { path: None })
+                    .add_plugins(ReplayRecordPlugin {
+                        path: path.clone(),
+                        replay: Replay::new(seed, difficulty, starting_wave),
+                    });
+            }
+            ReplayMode::Playback(replay) => {
+                app.insert_resource(SaveGameStorageResource { path: None })
+                    .add_plugins(ReplayPlaybackPlugin {
+                        replay: replay.clone(),
+                    });
+            }
+        }
+
+        if let Some(netplay) = self.options.netplay {
+            app.insert_resource(SaveGameStorageResource { path: None })
+                .add_plugins(NetplayPlugin {
+                    options: netplay,
+                    session_id: netplay_session_id(seed, difficulty, starting_wave),
+                });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::ruleset::Ruleset;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::bevy::battle::resources::BattleStateResource;
+    use crate::infrastructure::bevy::boss::resources::BossResource;
+    use crate::infrastructure::bevy::dive::resources::DiveTimerResource;
+    use crate::infrastructure::bevy::enemy_formation::resources::{
+        EnemyFireController, EnemyFireControllerResource,
+    };
+    use crate::infrastructure::bevy::netplay::resources::{NetplayOptions, NetplayResource};
+    use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+    use crate::infrastructure::bevy::replay::resources::ReplayRecorderResource;
+    use bevy_test::{TestAppBuilder, get_resource_or_fail};
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = bevy_test::smoke_test_plugin(GameOptionsPlugin {
+            options: GameOptions::default(),
+        });
+    }
+
+    #[test]
+    fn should_insert_the_configured_resources() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    seed: Some(3),
+                    difficulty: Difficulty::Hard,
+                    starting_wave: Wave::new(6),
+                    replay: ReplayMode::Record("unused.ron".into()),
+                    ..GameOptions::default()
+                },
+            })
+            .build();
+
+        assert_eq!(
+            get_resource_or_fail::<DifficultyResource>(&mut app).0,
+            Difficulty::Hard
+        );
+        assert_eq!(
+            get_resource_or_fail::<StartingWaveResource>(&mut app).0,
+            Wave::new(6)
+        );
+        assert_eq!(
+            get_resource_or_fail::<LivesResource>(&mut app).0,
+            Lives::with_count(Difficulty::Hard.starting_lives())
+        );
+        assert!(!app.world().contains_resource::<DynamicDifficultyResource>());
+        assert_eq!(
+            get_resource_or_fail::<RulesetResource>(&mut app).0,
+            Ruleset::Classic
+        );
+        assert!(!app.world().contains_resource::<ActivePowerUpsResource>());
+        assert!(
+            get_resource_or_fail::<SaveGameStorageResource>(&mut app)
+                .path
+                .is_none()
+        );
+        assert_eq!(
+            get_resource_or_fail::<ReplayRecorderResource>(&mut app)
+                .replay
+                .seed,
+            3
+        );
+    }
+
+    #[test]
+    fn should_enable_the_dynamic_difficulty_when_requested() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    difficulty: Difficulty::Easy,
+                    dynamic_difficulty: true,
+                    ..GameOptions::default()
+                },
+            })
+            .build();
+
+        assert!(app.world().contains_resource::<DynamicDifficultyResource>());
+        assert_eq!(
+            get_resource_or_fail::<LivesResource>(&mut app).0,
+            Lives::with_count(Difficulty::Easy.starting_lives())
+        );
+    }
+
+    #[test]
+    fn should_drop_power_ups_in_the_modern_ruleset() {
+        let app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    ruleset: Ruleset::Modern,
+                    ..GameOptions::default()
+                },
+            })
+            .without_auto_update()
+            .build();
+
+        assert!(app.world().contains_resource::<ActivePowerUpsResource>());
+    }
+
+    #[test]
+    fn should_send_invaders_diving_when_requested() {
+        let app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    diving: true,
+                    ..GameOptions::default()
+                },
+            })
+            .without_auto_update()
+            .build();
+
+        assert!(app.world().contains_resource::<DiveTimerResource>());
+    }
+
+    #[test]
+    fn should_schedule_boss_waves_when_requested() {
+        let app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    boss_waves: Some(4),
+                    ..GameOptions::default()
+                },
+            })
+            .without_auto_update()
+            .build();
+
+        assert!(app.world().contains_resource::<BossResource>());
+    }
+
+    #[test]
+    fn should_track_the_second_player_in_coop() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    mode: GameMode::Coop,
+                    ..GameOptions::default()
+                },
+            })
+            .build();
+
+        assert!(
+            get_resource_or_fail::<SecondPlayerLivesResource>(&mut app)
+                .0
+                .is_some()
+        );
+        assert!(
+            get_resource_or_fail::<SecondPlayerScoreResource>(&mut app)
+                .0
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn should_hand_the_invaders_to_player_two_in_versus() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    mode: GameMode::Versus,
+                    ..GameOptions::default()
+                },
+            })
+            .without_auto_update()
+            .build();
+
+        assert!(
+            get_resource_or_fail::<EnemyFireControllerResource>(&mut app).0
+                == EnemyFireController::Gunner
+        );
+        assert!(!app.world().contains_resource::<SecondPlayerLivesResource>());
+        assert!(app.is_plugin_added::<UfoPlugin>());
+    }
+
+    #[test]
+    fn should_split_the_screen_in_battle() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    mode: GameMode::Battle,
+                    ..GameOptions::default()
+                },
+            })
+            .without_auto_update()
+            .build();
+
+        assert_eq!(
+            get_resource_or_fail::<ArenaLayoutResource>(&mut app).0,
+            ArenaLayout::side_by_side(2)
+        );
+        assert!(app.world().contains_resource::<BattleStateResource>());
+        assert!(
+            get_resource_or_fail::<SecondPlayerScoreResource>(&mut app)
+                .0
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn should_start_an_online_game_with_a_peer() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(GameOptionsPlugin {
+                options: GameOptions {
+                    seed: Some(7),
+                    mode: GameMode::Coop,
+                    netplay: Some(NetplayOptions {
+                        bind: "127.0.0.1:0".parse().expect("Address should parse"),
+                        peer: "127.0.0.1:9".parse().expect("Address should parse"),
+                        local_player: PlayerSlot::Two,
+                    }),
+                    ..GameOptions::default()
+                },
+            })
+            .build();
+
+        assert_eq!(
+            get_resource_or_fail::<NetplayResource>(&mut app)
+                .session
+                .get_local(),
+            PlayerSlot::Two
+        );
+        assert!(
+            get_resource_or_fail::<SaveGameStorageResource>(&mut app)
+                .path
+                .is_none()
+        );
+    }
+}
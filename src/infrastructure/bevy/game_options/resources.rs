@@ -0,0 +1,98 @@
+use crate::domain::difficulty::Difficulty;
+use crate::domain::game_mode::GameMode;
+use crate::domain::ruleset::Ruleset;
+use crate::domain::wave::Wave;
+use crate::infrastructure::bevy::bevy_renderer::resources::{WINDOW_HEIGHT, WINDOW_WIDTH};
+use crate::infrastructure::bevy::netplay::resources::NetplayOptions;
+use crate::infrastructure::bevy::replay::resources::Replay;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplayMode {
+    Off,
+    Record(PathBuf),
+    Playback(Replay),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameOptions {
+    pub seed: Option<u64>,
+    pub difficulty: Difficulty,
+    pub dynamic_difficulty: bool,
+    pub ruleset: Ruleset,
+    pub diving: bool,
+    pub boss_waves: Option<u32>,
+    pub starting_wave: Wave,
+    pub mode: GameMode,
+    pub window_size: (u32, u32),
+    pub fullscreen: bool,
+    pub campaign: Option<String>,
+    pub editor: Option<PathBuf>,
+    pub replay: ReplayMode,
+    pub netplay: Option<NetplayOptions>,
+}
+
+impl Default for GameOptions {
+    fn default() -> Self {
+        Self {
+            seed: None,
+            difficulty: Difficulty::default(),
+            dynamic_difficulty: false,
+            ruleset: Ruleset::default(),
+            diving: false,
+            boss_waves: None,
+            starting_wave: Wave::default(),
+            mode: GameMode::default(),
+            window_size: (WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32),
+            fullscreen: false,
+            campaign: None,
+            editor: None,
+            replay: ReplayMode::Off,
+            netplay: None,
+        }
+    }
+}
+
+impl GameOptions {
+    pub fn resolved(&self) -> (u64, Difficulty, Wave) {
+        match &self.replay {
+            ReplayMode::Playback(replay) => (
+                replay.seed,
+                replay.get_difficulty(),
+                replay.get_starting_wave(),
+            ),
+            _ => (
+                self.seed.unwrap_or_else(rand::random),
+                self.difficulty,
+                self.starting_wave,
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolved_options_keep_an_explicit_seed() {
+        let options = GameOptions {
+            seed: Some(99),
+            difficulty: Difficulty::Easy,
+            ..GameOptions::default()
+        };
+
+        assert_eq!(options.resolved(), (99, Difficulty::Easy, Wave::default()));
+    }
+
+    #[test]
+    fn playback_options_come_from_the_replay() {
+        let options = GameOptions {
+            seed: Some(99),
+            replay: ReplayMode::Playback(Replay::new(5, Difficulty::Arcade, Wave::new(4))),
+            ..GameOptions::default()
+        };
+
+        assert_eq!(options.resolved(), (5, Difficulty::Arcade, Wave::new(4)));
+    }
+}
@@ -0,0 +1,4 @@
+use bevy::prelude::Message;
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct NewGameMessage;
@@ -0,0 +1,49 @@
+use crate::infrastructure::bevy::battle::resources::BattleStateResource;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::{GameState, GameplaySystems};
+use crate::infrastructure::bevy::game_state::systems::{
+    detect_game_over_system, restart_game_system,
+};
+use crate::infrastructure::bevy::netplay::resources::NetplayResource;
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::{IntoScheduleConfigs, in_state, not, resource_exists};
+use bevy::state::app::{AppExtStates, StatesPlugin};
+
+pub struct GameStatePlugin;
+
+impl Plugin for GameStatePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        app.init_state::<GameState>()
+            .add_message::<NewGameMessage>()
+            .configure_sets(Update, GameplaySystems.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                (
+                    detect_game_over_system
+                        .in_set(GameplaySystems)
+                        .run_if(not(resource_exists::<BattleStateResource>)),
+                    restart_game_system
+                        .run_if(in_state(GameState::GameOver))
+                        .run_if(not(resource_exists::<NetplayResource>)),
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(GameStatePlugin)
+            .build();
+    }
+}
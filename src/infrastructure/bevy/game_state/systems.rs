@@ -0,0 +1,276 @@
+use crate::domain::enemy_formation::{EnemyFormation, FormationStatus};
+use crate::domain::lives::Lives;
+use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationResource;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::high_scores::resources::HighScoresResource;
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::turns::resources::WaitingPlayerResource;
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, MessageWriter, NextState, Res, ResMut};
+use tracing::info;
+
+pub fn is_game_over(lives: &Lives, enemy_formation: &EnemyFormation) -> bool {
+    lives.get_current() == 0 || enemy_formation.get_status() == FormationStatus::Breached
+}
+
+pub fn is_waiting_player_alive(waiting_player: Option<&WaitingPlayerResource>) -> bool {
+    waiting_player
+        .and_then(|waiting| waiting.0.as_ref())
+        .is_some_and(|snapshot| snapshot.lives > 0)
+}
+
+pub fn is_second_player_alive(
+    second_player_lives: Option<&SecondPlayerLivesResource>,
+    enemy_formation: &EnemyFormation,
+) -> bool {
+    enemy_formation.get_status() != FormationStatus::Breached
+        && second_player_lives
+            .and_then(|second| second.0.as_ref())
+            .is_some_and(|lives| lives.get_current() > 0)
+}
+
+pub fn detect_game_over_system(
+    lives: Res<LivesResource>,
+    enemy_formation: Res<EnemyFormationResource>,
+    score: Res<ScoreResource>,
+    high_scores: Res<HighScoresResource>,
+    waiting_player: Option<Res<WaitingPlayerResource>>,
+    second_player_lives: Option<Res<SecondPlayerLivesResource>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !is_game_over(&lives.0, &enemy_formation.0) {
+        return;
+    }
+
+    if is_waiting_player_alive(waiting_player.as_deref())
+        || is_second_player_alive(second_player_lives.as_deref(), &enemy_formation.0)
+    {
+        return;
+    }
+
+    let score = score.0.get_current();
+    info!("Game over with {} points", score);
+
+    if high_scores.0.qualifies(score) {
+        next_state.set(GameState::InitialsEntry);
+    } else {
+        next_state.set(GameState::GameOver);
+    }
+}
+
+pub fn restart_game_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut new_game_writer: MessageWriter<NewGameMessage>,
+) {
+    if keyboard.just_pressed(KeyCode::Enter) {
+        new_game_writer.write(NewGameMessage);
+        next_state.set(GameState::Playing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::formation_layout::FormationLayout;
+    use crate::domain::high_scores::HighScoreTable;
+    use crate::domain::movement_pattern::FormationMovement;
+    use crate::domain::score::Score;
+    use crate::domain::shield_layout::ShieldLayout;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::bevy::turns::resources::fresh_player_snapshot;
+    use bevy::app::{App, Update};
+    use bevy::prelude::State;
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{TestAppBuilder, did_message_fire, get_resource_or_fail};
+
+    fn lose_all_lives(lives: &mut Lives) {
+        while lives.get_current() > 0 {
+            lives.decrement();
+        }
+    }
+
+    fn setup() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_input()
+            .with_plugin(StatesPlugin)
+            .with_setup(|app| {
+                app.init_state::<GameState>()
+                    .add_message::<NewGameMessage>()
+                    .insert_resource(LivesResource(Lives::new()))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                    .insert_resource(ScoreResource(Score::new()))
+                    .insert_resource(HighScoresResource(HighScoreTable::default()));
+            })
+            .build()
+    }
+
+    fn current_state(app: &mut App) -> GameState {
+        *get_resource_or_fail::<State<GameState>>(app).get()
+    }
+
+    #[test]
+    fn game_is_not_over_at_the_start() {
+        assert!(!is_game_over(&Lives::new(), &EnemyFormation::new()));
+    }
+
+    #[test]
+    fn game_is_over_without_lives() {
+        let mut lives = Lives::new();
+        lose_all_lives(&mut lives);
+
+        assert!(is_game_over(&lives, &EnemyFormation::new()));
+    }
+
+    #[test]
+    fn game_is_over_when_the_formation_breaches() {
+        let mut enemy_formation = EnemyFormation::new();
+        while enemy_formation.get_status() != FormationStatus::Breached {
+            enemy_formation.advance();
+        }
+
+        assert!(is_game_over(&Lives::new(), &enemy_formation));
+    }
+
+    #[cfg(test)]
+    mod detect_game_over_system {
+        use super::*;
+
+        #[test]
+        fn should_keep_playing_while_the_player_has_lives() {
+            let mut app = setup();
+            app.add_systems(Update, detect_game_over_system);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn should_ask_for_initials_when_the_score_makes_the_table() {
+            let mut app = setup();
+            app.add_systems(Update, detect_game_over_system);
+            lose_all_lives(&mut app.world_mut().resource_mut::<LivesResource>().0);
+            app.world_mut()
+                .resource_mut::<ScoreResource>()
+                .0
+                .increment(10);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::InitialsEntry);
+        }
+
+        #[test]
+        fn should_end_the_game_when_the_score_does_not_make_the_table() {
+            let mut app = setup();
+            app.add_systems(Update, detect_game_over_system);
+            lose_all_lives(&mut app.world_mut().resource_mut::<LivesResource>().0);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::GameOver);
+        }
+
+        #[test]
+        fn should_keep_playing_while_the_other_player_has_lives() {
+            let mut app = setup();
+            app.insert_resource(WaitingPlayerResource(Some(fresh_player_snapshot(
+                Wave::default(),
+                &FormationLayout::classic(),
+                FormationMovement::ClassicMarch,
+                &ShieldLayout::classic(),
+            ))))
+            .add_systems(Update, detect_game_over_system);
+            lose_all_lives(&mut app.world_mut().resource_mut::<LivesResource>().0);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn should_keep_playing_while_the_coop_partner_has_lives() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(1))))
+                .add_systems(Update, detect_game_over_system);
+            lose_all_lives(&mut app.world_mut().resource_mut::<LivesResource>().0);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn should_end_the_coop_game_when_both_players_are_out() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(0))))
+                .add_systems(Update, detect_game_over_system);
+            lose_all_lives(&mut app.world_mut().resource_mut::<LivesResource>().0);
+
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::GameOver);
+        }
+    }
+
+    #[test]
+    fn a_breach_ends_the_game_for_the_coop_partner_too() {
+        let mut enemy_formation = EnemyFormation::new();
+        while enemy_formation.get_status() != FormationStatus::Breached {
+            enemy_formation.advance();
+        }
+        let second_player_lives = SecondPlayerLivesResource(Some(Lives::new()));
+
+        assert!(is_second_player_alive(
+            Some(&second_player_lives),
+            &EnemyFormation::new()
+        ));
+        assert!(!is_second_player_alive(
+            Some(&second_player_lives),
+            &enemy_formation
+        ));
+    }
+
+    #[cfg(test)]
+    mod restart_game_system {
+        use super::*;
+
+        #[test]
+        fn should_start_a_new_game_on_enter() {
+            let mut app = setup();
+            app.add_systems(Update, restart_game_system);
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::GameOver);
+            app.update();
+
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::Enter);
+            app.update();
+
+            assert!(did_message_fire::<NewGameMessage>(&mut app));
+            app.update();
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn should_wait_for_enter() {
+            let mut app = setup();
+            app.add_systems(Update, restart_game_system);
+
+            app.update();
+
+            assert!(!did_message_fire::<NewGameMessage>(&mut app));
+        }
+    }
+}
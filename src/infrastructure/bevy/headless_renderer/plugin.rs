@@ -0,0 +1,89 @@
+use crate::infrastructure::bevy::bevy_renderer::plugin::SpaceInvadersPlugin;
+use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+use crate::infrastructure::bevy::game_options::resources::GameOptions;
+use crate::infrastructure::bevy::headless_renderer::resources::HEADLESS_FRAME_DURATION;
+use crate::infrastructure::bevy::headless_renderer::systems::{
+    exit_on_game_over_system, print_summary_system,
+};
+use crate::infrastructure::bevy::save_game::resources::SaveGameStorageResource;
+use crate::infrastructure::renderer::Renderer;
+use bevy::MinimalPlugins;
+use bevy::app::{App, Last, Plugin, PluginGroup, ScheduleRunnerPlugin, Update};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::image::{CompressedImageFormats, ImageLoader, ImagePlugin};
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, UiScale};
+use bevy::text::{Font, FontLoader};
+use std::time::Duration;
+
+pub struct HeadlessRenderer {
+    options: GameOptions,
+}
+
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((AssetPlugin::default(), ImagePlugin::default()))
+            .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+            .init_asset::<Font>()
+            .init_asset_loader::<FontLoader>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<UiScale>()
+            .insert_resource(SaveGameStorageResource { path: None })
+            .add_systems(Update, exit_on_game_over_system);
+    }
+}
+
+impl Renderer for HeadlessRenderer {
+    fn render(&self) {
+        App::new()
+            .add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(
+                Duration::from_secs_f64(HEADLESS_FRAME_DURATION),
+            )))
+            .add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: self.options.clone(),
+            })
+            .add_plugins(SpaceInvadersPlugin)
+            .add_systems(Last, print_summary_system)
+            .run();
+    }
+}
+
+impl HeadlessRenderer {
+    pub fn new(options: GameOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+    use crate::infrastructure::bevy::player::components::PlayerComponent;
+    use bevy_test::{TestAppBuilder, contains_single_component, count_components, minimal_app};
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(HeadlessPlugin)
+            .build();
+    }
+
+    #[test]
+    fn should_run_the_game_without_a_window() {
+        let mut app = minimal_app(false);
+        app.add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: GameOptions::default(),
+            })
+            .add_plugins(SpaceInvadersPlugin);
+
+        app.update();
+
+        assert!(contains_single_component::<PlayerComponent>(&mut app));
+        assert_eq!(count_components::<EnemyComponent>(&mut app), 55);
+    }
+}
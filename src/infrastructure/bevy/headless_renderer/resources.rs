@@ -0,0 +1 @@
+pub const HEADLESS_FRAME_DURATION: f64 = 1.0 / 60.0;
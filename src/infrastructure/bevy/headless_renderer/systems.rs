@@ -0,0 +1,119 @@
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, WaveResource,
+};
+use crate::infrastructure::bevy::game_state::systems::{
+    is_game_over, is_second_player_alive, is_waiting_player_alive,
+};
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
+use crate::infrastructure::bevy::netplay::resources::NetplayStateResource;
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::turns::resources::WaitingPlayerResource;
+use bevy::app::AppExit;
+use bevy::prelude::{MessageReader, MessageWriter, Res};
+use tracing::info;
+
+pub fn exit_on_game_over_system(
+    lives: Res<LivesResource>,
+    enemy_formation: Res<EnemyFormationResource>,
+    waiting_player: Option<Res<WaitingPlayerResource>>,
+    second_player_lives: Option<Res<SecondPlayerLivesResource>>,
+    netplay_state: Option<Res<NetplayStateResource>>,
+    mut app_exit_writer: MessageWriter<AppExit>,
+) {
+    if netplay_state.is_some_and(|state| !state.finished) {
+        return;
+    }
+
+    if is_game_over(&lives.0, &enemy_formation.0)
+        && !is_waiting_player_alive(waiting_player.as_deref())
+        && !is_second_player_alive(second_player_lives.as_deref(), &enemy_formation.0)
+    {
+        info!("Game over");
+        app_exit_writer.write(AppExit::Success);
+    }
+}
+
+pub fn print_summary_system(
+    mut app_exit_reader: MessageReader<AppExit>,
+    score: Res<ScoreResource>,
+    wave: Res<WaveResource>,
+    lives: Res<LivesResource>,
+) {
+    if app_exit_reader.read().next().is_some() {
+        println!(
+            "Final score: {} (wave {}, {} lives left)",
+            score.0.get_current(),
+            wave.0.get_number(),
+            lives.0.get_current()
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::lives::Lives;
+    use bevy::app::{App, Update};
+    use bevy_test::{TestAppBuilder, did_message_fire};
+
+    fn lose_all_lives(lives: &mut Lives) {
+        while lives.get_current() > 0 {
+            lives.decrement();
+        }
+    }
+
+    #[cfg(test)]
+    mod exit_on_game_over_system {
+        use super::*;
+
+        fn setup(lives: Lives) -> App {
+            TestAppBuilder::with_time_disabled()
+                .with_setup(|app| {
+                    app.insert_resource(LivesResource(lives))
+                        .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                        .add_systems(Update, exit_on_game_over_system);
+                })
+                .build()
+        }
+
+        #[test]
+        fn should_exit_when_the_game_is_over() {
+            let mut lives = Lives::new();
+            lose_all_lives(&mut lives);
+            let mut app = setup(lives);
+
+            app.update();
+
+            assert!(did_message_fire::<AppExit>(&mut app));
+        }
+
+        #[test]
+        fn should_keep_running_while_the_player_has_lives() {
+            let mut app = setup(Lives::new());
+
+            app.update();
+
+            assert!(!did_message_fire::<AppExit>(&mut app));
+        }
+
+        #[test]
+        fn should_wait_for_an_online_game_to_finish() {
+            let mut lives = Lives::new();
+            lose_all_lives(&mut lives);
+            let mut app = setup(lives);
+            app.init_resource::<NetplayStateResource>();
+
+            app.update();
+
+            assert!(!did_message_fire::<AppExit>(&mut app));
+
+            app.world_mut()
+                .resource_mut::<NetplayStateResource>()
+                .finished = true;
+            app.update();
+
+            assert!(did_message_fire::<AppExit>(&mut app));
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::infrastructure::bevy::high_scores::resources::{
+    HIGH_SCORE_FONT_SIZE, HIGH_SCORE_LABEL_COLOR, HIGH_SCORE_LABEL_TEXT, HIGH_SCORE_MARGIN_LEFT,
+    HIGH_SCORE_VALUE_COLOR, SCREEN_BACKGROUND,
+};
+use bevy::asset::Handle;
+use bevy::color::Color;
+use bevy::prelude::{Bundle, Component, default};
+use bevy::text::{Font, TextColor, TextFont};
+use bevy::ui::widget::Text;
+use bevy::ui::{
+    AlignItems, BackgroundColor, FlexDirection, JustifyContent, Node, PositionType, UiRect, Val,
+};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct HighScoreValueComponent;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct InitialsEntryScreenComponent;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct InitialsLetterComponent(pub usize);
+
+#[derive(Component, PartialEq, Debug)]
+pub struct GameOverScreenComponent;
+
+#[derive(Bundle)]
+pub struct HighScoreLabelBundle {
+    pub node: Node,
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl HighScoreLabelBundle {
+    pub fn new(font: Handle<Font>) -> Self {
+        Self {
+            node: Node {
+                margin: UiRect::left(Val::Px(HIGH_SCORE_MARGIN_LEFT)),
+                ..default()
+            },
+            text: Text::new(HIGH_SCORE_LABEL_TEXT),
+            text_font: TextFont {
+                font,
+                font_size: HIGH_SCORE_FONT_SIZE,
+                ..default()
+            },
+            text_color: TextColor(HIGH_SCORE_LABEL_COLOR),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct HighScoreValueBundle {
+    pub high_score_value: HighScoreValueComponent,
+    pub node: Node,
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl HighScoreValueBundle {
+    pub fn new(font: Handle<Font>, high_score: u32) -> Self {
+        Self {
+            high_score_value: HighScoreValueComponent,
+            node: Node::default(),
+            text: Text::new(high_score.to_string()),
+            text_font: TextFont {
+                font,
+                font_size: HIGH_SCORE_FONT_SIZE,
+                ..default()
+            },
+            text_color: TextColor(HIGH_SCORE_VALUE_COLOR),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ScreenBundle {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+}
+
+impl ScreenBundle {
+    pub fn new() -> Self {
+        Self {
+            node: Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            background_color: BackgroundColor(SCREEN_BACKGROUND),
+        }
+    }
+}
+
+impl Default for ScreenBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Bundle)]
+pub struct ScreenTextBundle {
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl ScreenTextBundle {
+    pub fn new(text: impl Into<String>, font: Handle<Font>, font_size: f32, color: Color) -> Self {
+        Self {
+            text: Text::new(text),
+            text_font: TextFont {
+                font,
+                font_size,
+                ..default()
+            },
+            text_color: TextColor(color),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_test::{TestAppBuilder, dummy_font};
+
+    #[test]
+    fn should_create_high_score_value_bundle() {
+        let app = TestAppBuilder::new().with_assets().build();
+        let font = dummy_font(&app);
+
+        let bundle = HighScoreValueBundle::new(font.clone(), 1200);
+
+        assert_eq!(bundle.high_score_value, HighScoreValueComponent);
+        assert_eq!(bundle.text.0, "1200");
+        assert_eq!(bundle.text_font.font, font);
+        assert_eq!(bundle.text_color.0, HIGH_SCORE_VALUE_COLOR);
+    }
+
+    #[test]
+    fn should_create_high_score_label_bundle() {
+        let app = TestAppBuilder::new().with_assets().build();
+
+        let bundle = HighScoreLabelBundle::new(dummy_font(&app));
+
+        assert_eq!(bundle.text.0, HIGH_SCORE_LABEL_TEXT);
+        assert_eq!(
+            bundle.node.margin,
+            UiRect::left(Val::Px(HIGH_SCORE_MARGIN_LEFT))
+        );
+    }
+
+    #[test]
+    fn screen_covers_the_whole_window() {
+        let bundle = ScreenBundle::new();
+
+        assert_eq!(bundle.node.position_type, PositionType::Absolute);
+        assert_eq!(bundle.node.width, Val::Percent(100.0));
+        assert_eq!(bundle.node.height, Val::Percent(100.0));
+        assert_eq!(bundle.background_color.0, SCREEN_BACKGROUND);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::infrastructure::bevy::game_state::resources::{GameState, HudSystems};
+use crate::infrastructure::bevy::high_scores::resources::{
+    HighScoreStorageResource, HighScoresResource, InitialsEntryResource,
+};
+use crate::infrastructure::bevy::high_scores::systems::{
+    initials_entry_input_system, load_high_scores_system, spawn_game_over_screen_system,
+    spawn_high_score_system, spawn_initials_entry_screen_system, update_high_score_text_system,
+    update_initials_letters_system,
+};
+use crate::infrastructure::bevy::score::systems::spawn_score_system;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, OnEnter, in_state};
+
+pub struct HighScoresPlugin;
+
+impl Plugin for HighScoresPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HighScoresResource>()
+            .init_resource::<InitialsEntryResource>()
+            .init_resource::<HighScoreStorageResource>()
+            .add_systems(
+                Startup,
+                (
+                    load_high_scores_system,
+                    spawn_high_score_system.after(spawn_score_system),
+                )
+                    .chain(),
+            )
+            .add_systems(Update, update_high_score_text_system.in_set(HudSystems))
+            .add_systems(
+                OnEnter(GameState::InitialsEntry),
+                spawn_initials_entry_screen_system,
+            )
+            .add_systems(
+                Update,
+                (initials_entry_input_system, update_initials_letters_system)
+                    .chain()
+                    .run_if(in_state(GameState::InitialsEntry)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over_screen_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::game_state::plugin::GameStatePlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(GameStatePlugin)
+            .with_plugin(HighScoresPlugin)
+            .build();
+    }
+}
@@ -0,0 +1,237 @@
+use crate::domain::high_scores::{HighScoreEntry, HighScoreTable, InitialsEntry};
+use crate::infrastructure::storage::{data_file, from_ron, to_pretty_ron, write_atomically};
+use bevy::color::Color;
+use bevy::prelude::Resource;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const HIGH_SCORES_FILE: &str = "high_scores.ron";
+pub const HIGH_SCORES_VERSION: u32 = 1;
+
+pub const HIGH_SCORE_LABEL_TEXT: &str = "HI-SCORE: ";
+pub const HIGH_SCORE_FONT_SIZE: f32 = 14.0;
+pub const HIGH_SCORE_LABEL_COLOR: Color = Color::WHITE;
+pub const HIGH_SCORE_VALUE_COLOR: Color = Color::srgb_u8(255, 215, 0);
+pub const HIGH_SCORE_MARGIN_LEFT: f32 = 40.0;
+
+pub const SCREEN_BACKGROUND: Color = Color::srgba(0.0, 0.0, 0.0, 0.85);
+pub const SCREEN_TITLE_FONT_SIZE: f32 = 32.0;
+pub const SCREEN_TEXT_FONT_SIZE: f32 = 16.0;
+pub const SCREEN_TITLE_COLOR: Color = Color::srgb_u8(51, 255, 3);
+pub const SCREEN_TEXT_COLOR: Color = Color::WHITE;
+pub const SELECTED_LETTER_COLOR: Color = Color::srgb_u8(255, 215, 0);
+pub const INITIALS_FONT_SIZE: f32 = 48.0;
+
+#[derive(Resource, Default)]
+pub struct HighScoresResource(pub HighScoreTable);
+
+#[derive(Resource, Default)]
+pub struct InitialsEntryResource(pub InitialsEntry);
+
+#[derive(Resource)]
+pub struct HighScoreStorageResource {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for HighScoreStorageResource {
+    fn default() -> Self {
+        Self {
+            path: data_file(HIGH_SCORES_FILE),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum HighScoresError {
+    Io(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+}
+
+impl Display for HighScoresError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HighScoresError::Io(reason) => write!(f, "{}", reason),
+            HighScoresError::Parse(reason) => write!(f, "invalid high-score file: {}", reason),
+            HighScoresError::UnsupportedVersion(version) => write!(
+                f,
+                "high-score file version {} is not supported (expected {})",
+                version, HIGH_SCORES_VERSION
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HighScoresFile {
+    version: u32,
+    entries: Vec<HighScoreRecord>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HighScoreRecord {
+    initials: String,
+    score: u32,
+    wave: u32,
+    date: String,
+}
+
+pub fn parse_high_scores(source: &str) -> Result<HighScoreTable, HighScoresError> {
+    let file: HighScoresFile = from_ron(source).map_err(HighScoresError::Parse)?;
+
+    if file.version != HIGH_SCORES_VERSION {
+        return Err(HighScoresError::UnsupportedVersion(file.version));
+    }
+
+    let entries = file
+        .entries
+        .into_iter()
+        .map(|record| {
+            Ok(HighScoreEntry {
+                initials: record.initials.parse().map_err(HighScoresError::Parse)?,
+                score: record.score,
+                wave: record.wave,
+                date: record.date,
+            })
+        })
+        .collect::<Result<Vec<_>, HighScoresError>>()?;
+
+    Ok(HighScoreTable::new(entries))
+}
+
+pub fn load_high_scores(path: &Path) -> Result<HighScoreTable, HighScoresError> {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_high_scores(&source),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(HighScoreTable::default()),
+        Err(error) => Err(HighScoresError::Io(error.to_string())),
+    }
+}
+
+pub fn save_high_scores(path: &Path, table: &HighScoreTable) -> Result<(), HighScoresError> {
+    let file = HighScoresFile {
+        version: HIGH_SCORES_VERSION,
+        entries: table
+            .get_entries()
+            .iter()
+            .map(|entry| HighScoreRecord {
+                initials: entry.initials.to_string(),
+                score: entry.score,
+                wave: entry.wave,
+                date: entry.date.clone(),
+            })
+            .collect(),
+    };
+
+    let source = to_pretty_ron(&file).map_err(HighScoresError::Parse)?;
+
+    write_atomically(path, source.as_bytes())
+        .map_err(|error| HighScoresError::Io(error.to_string()))
+}
+
+pub fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    format_date(seconds)
+}
+
+pub fn format_date(unix_seconds: u64) -> String {
+    let days = (unix_seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::storage::{DATA_DIRECTORY, temporary_directory};
+
+    #[cfg(feature = "serde")]
+    fn create_table() -> HighScoreTable {
+        HighScoreTable::new(vec![
+            HighScoreEntry {
+                initials: "ACE".parse().expect("Initials should parse"),
+                score: 1500,
+                wave: 3,
+                date: "2024-02-29".to_string(),
+            },
+            HighScoreEntry {
+                initials: "BOB".parse().expect("Initials should parse"),
+                score: 700,
+                wave: 2,
+                date: "2024-03-01".to_string(),
+            },
+        ])
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_round_trip_through_a_file() {
+        let path = temporary_directory("high-scores").join(HIGH_SCORES_FILE);
+
+        save_high_scores(&path, &create_table()).expect("High scores should be saved");
+
+        assert_eq!(load_high_scores(&path), Ok(create_table()));
+        assert!(!path.with_extension("ron.tmp").exists());
+    }
+
+    #[test]
+    fn missing_file_is_an_empty_table() {
+        let path = temporary_directory("missing-high-scores").join(HIGH_SCORES_FILE);
+
+        assert_eq!(load_high_scores(&path), Ok(HighScoreTable::default()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_other_versions() {
+        assert_eq!(
+            parse_high_scores("(version: 2, entries: [])"),
+            Err(HighScoresError::UnsupportedVersion(2))
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_initials() {
+        let result = parse_high_scores(
+            "(version: 1, entries: [(initials: \"toolong\", score: 1, wave: 1, date: \"\")])",
+        );
+
+        assert!(matches!(result, Err(HighScoresError::Parse(_))));
+    }
+
+    #[test]
+    fn should_format_dates_from_unix_time() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_709_251_199), "2024-02-29");
+    }
+
+    #[test]
+    fn default_storage_lives_in_the_data_directory() {
+        let storage = HighScoreStorageResource::default();
+
+        if let Some(path) = storage.path {
+            assert!(path.ends_with(format!("{}/{}", DATA_DIRECTORY, HIGH_SCORES_FILE)));
+        }
+    }
+}
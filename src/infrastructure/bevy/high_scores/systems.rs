@@ -0,0 +1,499 @@
+use crate::domain::high_scores::HighScoreEntry;
+use crate::infrastructure::bevy::enemy_formation::resources::WaveResource;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::high_scores::components::{
+    GameOverScreenComponent, HighScoreLabelBundle, HighScoreValueBundle, HighScoreValueComponent,
+    InitialsEntryScreenComponent, InitialsLetterComponent, ScreenBundle, ScreenTextBundle,
+};
+use crate::infrastructure::bevy::high_scores::resources::{
+    HighScoreStorageResource, HighScoresResource, INITIALS_FONT_SIZE, InitialsEntryResource,
+    SCREEN_TEXT_COLOR, SCREEN_TEXT_FONT_SIZE, SCREEN_TITLE_COLOR, SCREEN_TITLE_FONT_SIZE,
+    SELECTED_LETTER_COLOR, load_high_scores, save_high_scores, today,
+};
+use crate::infrastructure::bevy::score::components::ScoreContainerComponent;
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use bevy::asset::AssetServer;
+use bevy::color::Color;
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Commands, DespawnOnExit, DetectChanges, Entity, KeyCode, NextState, Query, Res, ResMut, Text,
+    TextColor, With,
+};
+use bevy::ui::{FlexDirection, Node, Val};
+use tracing::{error, info, warn};
+
+pub fn load_high_scores_system(
+    storage: Res<HighScoreStorageResource>,
+    mut high_scores: ResMut<HighScoresResource>,
+) {
+    let Some(path) = &storage.path else {
+        return;
+    };
+
+    match load_high_scores(path) {
+        Ok(table) => high_scores.0 = table,
+        Err(reason) => warn!(
+            "Cannot load high scores from {}: {}",
+            path.display(),
+            reason
+        ),
+    }
+}
+
+pub fn spawn_high_score_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    high_scores: Res<HighScoresResource>,
+    score_container_query: Query<Entity, With<ScoreContainerComponent>>,
+) {
+    if let Ok(score_container) = score_container_query.single() {
+        let font = asset_server.load(FONT);
+        let high_score = high_scores.0.best();
+
+        commands.entity(score_container).with_children(|parent| {
+            parent.spawn(HighScoreLabelBundle::new(font.clone()));
+            parent.spawn(HighScoreValueBundle::new(font, high_score));
+        });
+    }
+}
+
+pub fn update_high_score_text_system(
+    high_scores: Res<HighScoresResource>,
+    score: Res<ScoreResource>,
+    mut high_score_query: Query<&mut Text, With<HighScoreValueComponent>>,
+) {
+    if high_scores.is_changed() || score.is_changed() {
+        let high_score = high_scores.0.best().max(score.0.get_current());
+
+        for mut text in &mut high_score_query {
+            text.0 = high_score.to_string();
+        }
+    }
+}
+
+pub fn spawn_initials_entry_screen_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    score: Res<ScoreResource>,
+    mut initials_entry: ResMut<InitialsEntryResource>,
+) {
+    let font = asset_server.load(FONT);
+    initials_entry.0 = Default::default();
+
+    commands
+        .spawn((
+            InitialsEntryScreenComponent,
+            ScreenBundle::new(),
+            DespawnOnExit(GameState::InitialsEntry),
+        ))
+        .with_children(|screen| {
+            screen.spawn(ScreenTextBundle::new(
+                "NEW HIGH SCORE",
+                font.clone(),
+                SCREEN_TITLE_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                score.0.get_current().to_string(),
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                "ENTER YOUR INITIALS",
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+            screen
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(24.0),
+                    ..Default::default()
+                })
+                .with_children(|letters| {
+                    for (index, letter) in initials_entry.0.get_letters().iter().enumerate() {
+                        letters.spawn((
+                            InitialsLetterComponent(index),
+                            ScreenTextBundle::new(
+                                letter.to_string(),
+                                font.clone(),
+                                INITIALS_FONT_SIZE,
+                                letter_color(index, initials_entry.0.get_cursor()),
+                            ),
+                        ));
+                    }
+                });
+            screen.spawn(ScreenTextBundle::new(
+                "UP/DOWN CHANGE - ENTER CONFIRM - BACKSPACE BACK",
+                font,
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+        });
+}
+
+fn letter_color(index: usize, cursor: usize) -> Color {
+    if index == cursor {
+        SELECTED_LETTER_COLOR
+    } else {
+        SCREEN_TEXT_COLOR
+    }
+}
+
+pub fn initials_entry_input_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut initials_entry: ResMut<InitialsEntryResource>,
+    mut high_scores: ResMut<HighScoresResource>,
+    storage: Res<HighScoreStorageResource>,
+    score: Res<ScoreResource>,
+    wave: Res<WaveResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if keyboard.just_pressed(KeyCode::ArrowUp) {
+        initials_entry.0.next_letter();
+    }
+    if keyboard.just_pressed(KeyCode::ArrowDown) {
+        initials_entry.0.previous_letter();
+    }
+    if keyboard.any_just_pressed([KeyCode::Backspace, KeyCode::ArrowLeft]) {
+        initials_entry.0.back();
+    }
+    if keyboard.any_just_pressed([KeyCode::Enter, KeyCode::ArrowRight]) {
+        initials_entry.0.confirm();
+    }
+
+    if !initials_entry.0.is_complete() {
+        return;
+    }
+
+    let entry = HighScoreEntry {
+        initials: initials_entry.0.to_initials(),
+        score: score.0.get_current(),
+        wave: wave.0.get_number(),
+        date: today(),
+    };
+
+    if let Some(rank) = high_scores.0.insert(entry) {
+        info!("New high score at rank {}", rank + 1);
+    }
+
+    if let Some(path) = &storage.path
+        && let Err(reason) = save_high_scores(path, &high_scores.0)
+    {
+        error!("Cannot save high scores to {}: {}", path.display(), reason);
+    }
+
+    next_state.set(GameState::GameOver);
+}
+
+pub fn update_initials_letters_system(
+    initials_entry: Res<InitialsEntryResource>,
+    mut letters_query: Query<(&InitialsLetterComponent, &mut Text, &mut TextColor)>,
+) {
+    if !initials_entry.is_changed() {
+        return;
+    }
+
+    let letters = initials_entry.0.get_letters();
+    let cursor = initials_entry.0.get_cursor();
+
+    for (letter, mut text, mut color) in &mut letters_query {
+        if let Some(value) = letters.get(letter.0) {
+            text.0 = value.to_string();
+        }
+        color.0 = letter_color(letter.0, cursor);
+    }
+}
+
+pub fn spawn_game_over_screen_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    high_scores: Res<HighScoresResource>,
+    score: Res<ScoreResource>,
+) {
+    let font = asset_server.load(FONT);
+
+    commands
+        .spawn((
+            GameOverScreenComponent,
+            ScreenBundle::new(),
+            DespawnOnExit(GameState::GameOver),
+        ))
+        .with_children(|screen| {
+            screen.spawn(ScreenTextBundle::new(
+                "GAME OVER",
+                font.clone(),
+                SCREEN_TITLE_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                format!("SCORE {}", score.0.get_current()),
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                "HIGH SCORES",
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+
+            for (rank, entry) in high_scores.0.get_entries().iter().enumerate() {
+                screen.spawn(ScreenTextBundle::new(
+                    format!(
+                        "{:>2}. {}  {:>6}  WAVE {:>2}  {}",
+                        rank + 1,
+                        entry.initials,
+                        entry.score,
+                        entry.wave,
+                        entry.date
+                    ),
+                    font.clone(),
+                    SCREEN_TEXT_FONT_SIZE,
+                    SCREEN_TEXT_COLOR,
+                ));
+            }
+
+            screen.spawn(ScreenTextBundle::new(
+                "PRESS ENTER TO PLAY AGAIN",
+                font,
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::high_scores::{HighScoreTable, InitialsEntry};
+    use crate::domain::score::Score;
+    use crate::domain::wave::Wave;
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::bevy::high_scores::resources::HIGH_SCORES_FILE;
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::storage::temporary_directory;
+    use bevy::app::{App, Startup, Update};
+    #[cfg(feature = "serde")]
+    use bevy::prelude::State;
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{
+        TestAppBuilder, contains_single_component, count_components, get_resource_mut_or_fail,
+        get_resource_or_fail,
+    };
+    use std::path::PathBuf;
+
+    fn create_entry(initials: &str, score: u32) -> HighScoreEntry {
+        HighScoreEntry {
+            initials: initials.parse().expect("Initials should parse"),
+            score,
+            wave: 1,
+            date: "2024-01-01".to_string(),
+        }
+    }
+
+    fn setup(path: Option<PathBuf>) -> App {
+        TestAppBuilder::new()
+            .with_assets()
+            .with_input()
+            .with_setup(|app| {
+                app.add_plugins(StatesPlugin)
+                    .init_state::<GameState>()
+                    .insert_resource(HighScoresResource(HighScoreTable::default()))
+                    .insert_resource(HighScoreStorageResource { path })
+                    .insert_resource(InitialsEntryResource(InitialsEntry::new()))
+                    .insert_resource(ScoreResource(Score::new()))
+                    .insert_resource(WaveResource(Wave::new(2)));
+            })
+            .build()
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keyboard = get_resource_mut_or_fail::<ButtonInput<KeyCode>>(app);
+        keyboard.release_all();
+        keyboard.clear();
+        keyboard.press(key);
+        app.update();
+    }
+
+    #[cfg(test)]
+    mod load_high_scores_system {
+        use super::*;
+        #[cfg(feature = "serde")]
+        use crate::infrastructure::bevy::high_scores::resources::save_high_scores;
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_load_the_saved_table() {
+            let path = temporary_directory("load-system").join(HIGH_SCORES_FILE);
+            let table = HighScoreTable::new(vec![create_entry("ZED", 900)]);
+            save_high_scores(&path, &table).expect("High scores should be saved");
+
+            let mut app = setup(Some(path));
+            app.add_systems(Startup, load_high_scores_system);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<HighScoresResource>(&mut app).0,
+                table
+            );
+        }
+
+        #[test]
+        fn should_keep_an_empty_table_without_storage() {
+            let mut app = setup(None);
+            app.add_systems(Startup, load_high_scores_system);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<HighScoresResource>(&mut app).0,
+                HighScoreTable::default()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod spawn_high_score_system {
+        use super::*;
+
+        #[test]
+        fn should_spawn_the_high_score_in_the_score_container() {
+            let mut app = setup(None);
+            app.world_mut().spawn(ScoreContainerComponent);
+            app.add_systems(Startup, spawn_high_score_system);
+
+            app.update();
+
+            assert!(contains_single_component::<HighScoreValueComponent>(
+                &mut app
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod update_high_score_text_system {
+        use super::*;
+        use bevy_test::assert_text_equals;
+
+        #[test]
+        fn should_show_the_best_of_table_and_current_score() {
+            let mut app = setup(None);
+            app.add_systems(Update, update_high_score_text_system);
+            app.world_mut()
+                .spawn((HighScoreValueComponent, Text::new("0")));
+            get_resource_mut_or_fail::<HighScoresResource>(&mut app)
+                .0
+                .insert(create_entry("AAA", 500));
+
+            app.update();
+            assert_text_equals::<HighScoreValueComponent>(&mut app, "500");
+
+            get_resource_mut_or_fail::<ScoreResource>(&mut app)
+                .0
+                .increment(600);
+            app.update();
+            assert_text_equals::<HighScoreValueComponent>(&mut app, "600");
+        }
+    }
+
+    #[cfg(test)]
+    mod initials_entry {
+        use super::*;
+
+        fn setup_initials_entry(path: Option<PathBuf>) -> App {
+            let mut app = setup(path);
+            app.add_systems(
+                bevy::state::state::OnEnter(GameState::InitialsEntry),
+                spawn_initials_entry_screen_system,
+            )
+            .add_systems(
+                Update,
+                (initials_entry_input_system, update_initials_letters_system),
+            );
+            get_resource_mut_or_fail::<ScoreResource>(&mut app)
+                .0
+                .increment(1200);
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::InitialsEntry);
+            app.update();
+            app
+        }
+
+        #[cfg(feature = "serde")]
+        fn current_state(app: &mut App) -> GameState {
+            *get_resource_or_fail::<State<GameState>>(app).get()
+        }
+
+        #[test]
+        fn should_show_the_initials_screen() {
+            let mut app = setup_initials_entry(None);
+
+            assert!(contains_single_component::<InitialsEntryScreenComponent>(
+                &mut app
+            ));
+            assert_eq!(count_components::<InitialsLetterComponent>(&mut app), 3);
+        }
+
+        #[test]
+        fn should_edit_the_selected_letter() {
+            let mut app = setup_initials_entry(None);
+
+            press(&mut app, KeyCode::ArrowUp);
+            press(&mut app, KeyCode::ArrowUp);
+
+            assert_eq!(
+                get_resource_or_fail::<InitialsEntryResource>(&mut app)
+                    .0
+                    .get_letters(),
+                ['C', 'A', 'A']
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_save_the_entry_and_show_the_game_over_screen() {
+            let path = temporary_directory("initials-entry").join(HIGH_SCORES_FILE);
+            let mut app = setup_initials_entry(Some(path.clone()));
+
+            press(&mut app, KeyCode::ArrowDown);
+            press(&mut app, KeyCode::Enter);
+            press(&mut app, KeyCode::Enter);
+            press(&mut app, KeyCode::ArrowUp);
+            press(&mut app, KeyCode::Enter);
+            app.update();
+
+            let table = &get_resource_or_fail::<HighScoresResource>(&mut app).0;
+            let entry = table.get_entries().first().expect("Entry should be saved");
+            assert_eq!(entry.initials.to_string(), "ZAB");
+            assert_eq!(entry.score, 1200);
+            assert_eq!(entry.wave, 2);
+
+            assert_eq!(load_high_scores(&path).map(|table| table.best()), Ok(1200));
+            assert_eq!(current_state(&mut app), GameState::GameOver);
+            assert!(!contains_single_component::<InitialsEntryScreenComponent>(
+                &mut app
+            ));
+        }
+    }
+
+    #[cfg(test)]
+    mod spawn_game_over_screen_system {
+        use super::*;
+
+        #[test]
+        fn should_spawn_the_game_over_screen() {
+            let mut app = setup(None);
+            app.add_systems(Startup, spawn_game_over_screen_system);
+
+            app.update();
+
+            assert!(contains_single_component::<GameOverScreenComponent>(
+                &mut app
+            ));
+        }
+    }
+}
@@ -1,13 +1,20 @@
+use crate::domain::game_mode::PlayerSlot;
+use crate::infrastructure::bevy::player::resources::player_color;
 use bevy::asset::Handle;
 use bevy::color::Color;
 use bevy::image::Image;
-use bevy::prelude::{Bundle, Component, ImageNode, default};
+use bevy::prelude::{Bundle, Component, ImageNode, Message, default};
 use bevy::text::{Font, TextColor, TextFont};
 use bevy::ui::widget::Text;
 use bevy::ui::{AlignItems, FlexDirection, JustifyContent, Node, UiRect, Val};
 
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct BonusLifeMessage {
+    pub player: PlayerSlot,
+}
+
 #[derive(Component, PartialEq, Debug)]
-pub struct LivesViewComponent;
+pub struct LivesViewComponent(pub PlayerSlot);
 
 #[derive(Bundle)]
 pub struct LivesViewBundle {
@@ -16,7 +23,7 @@ pub struct LivesViewBundle {
 }
 
 #[derive(Component, PartialEq, Debug)]
-pub struct LivesValueComponent;
+pub struct LivesValueComponent(pub PlayerSlot);
 
 #[derive(Bundle)]
 pub struct LivesValueBundle {
@@ -26,11 +33,12 @@ pub struct LivesValueBundle {
 }
 
 impl LivesValueBundle {
-    pub fn new(handle: Handle<Image>) -> Self {
+    pub fn new(handle: Handle<Image>, slot: PlayerSlot) -> Self {
         Self {
-            component: LivesValueComponent,
+            component: LivesValueComponent(slot),
             image: ImageNode {
                 image: handle,
+                color: player_color(slot),
                 ..default()
             },
             node: Node {
@@ -43,11 +51,11 @@ impl LivesValueBundle {
 }
 
 impl LivesViewBundle {
-    pub fn new() -> Self {
+    pub fn new(slot: PlayerSlot, player_count: usize) -> Self {
         Self {
-            component: LivesViewComponent,
+            component: LivesViewComponent(slot),
             node: Node {
-                width: Val::Percent(50.0),
+                width: Val::Percent(50.0 / player_count.max(1) as f32),
                 height: Val::Px(50.0),
                 flex_direction: FlexDirection::Row,
                 justify_content: JustifyContent::Center,
@@ -58,12 +66,6 @@ impl LivesViewBundle {
     }
 }
 
-impl Default for LivesViewBundle {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 #[derive(Bundle)]
 pub struct LivesLabelBundle {
     pub node: Node,
@@ -73,14 +75,14 @@ pub struct LivesLabelBundle {
 }
 
 impl LivesLabelBundle {
-    pub fn new(font: Handle<Font>) -> Self {
+    pub fn new(font: Handle<Font>, label: String) -> Self {
         Self {
             node: Node {
                 height: Val::Percent(50.0),
                 margin: UiRect::right(Val::Px(20.0)),
                 ..default()
             },
-            text: Text::new("LIVES"),
+            text: Text::new(label),
             text_font: TextFont {
                 font,
                 font_size: 14.0,
@@ -99,9 +101,9 @@ mod tests {
 
     #[test]
     fn should_create_lives_view_bundle() {
-        let bundle = LivesViewBundle::new();
+        let bundle = LivesViewBundle::new(PlayerSlot::One, 1);
 
-        assert_eq!(bundle.component, LivesViewComponent);
+        assert_eq!(bundle.component, LivesViewComponent(PlayerSlot::One));
 
         assert_eq!(bundle.node.width, Val::Percent(50.0));
         assert_eq!(bundle.node.height, Val::Px(50.0));
@@ -117,7 +119,7 @@ mod tests {
 
         let font = dummy_font(&app);
 
-        let bundle = LivesLabelBundle::new(font.clone());
+        let bundle = LivesLabelBundle::new(font.clone(), "LIVES".to_string());
 
         assert_eq!(bundle.node.height, Val::Percent(50.0));
         assert_eq!(bundle.node.margin, UiRect::right(Val::Px(20.0)));
@@ -137,13 +139,21 @@ mod tests {
         let asset_server = app.world().resource::<AssetServer>().clone();
         let handle: Handle<Image> = asset_server.load("tmp.png");
 
-        let bundle = LivesValueBundle::new(handle.clone());
+        let bundle = LivesValueBundle::new(handle.clone(), PlayerSlot::One);
 
-        assert_eq!(bundle.component, LivesValueComponent);
+        assert_eq!(bundle.component, LivesValueComponent(PlayerSlot::One));
 
         assert_eq!(bundle.node.height, Val::Percent(35.0));
         assert_eq!(bundle.node.margin, UiRect::right(Val::Px(25.0)));
 
         assert_eq!(bundle.image.image, handle);
     }
+
+    #[test]
+    fn should_share_the_header_between_coop_players() {
+        let bundle = LivesViewBundle::new(PlayerSlot::Two, 2);
+
+        assert_eq!(bundle.component, LivesViewComponent(PlayerSlot::Two));
+        assert_eq!(bundle.node.width, Val::Percent(25.0));
+    }
 }
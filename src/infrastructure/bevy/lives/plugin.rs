@@ -1,8 +1,12 @@
-use crate::domain::lives::Lives;
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::enemy_projectile::systems::collision_system;
+use crate::infrastructure::bevy::game_state::resources::HudSystems;
 use crate::infrastructure::bevy::header::systems::spawn_header_system;
-use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::lives::components::BonusLifeMessage;
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
 use crate::infrastructure::bevy::lives::systems::{
-    handle_player_killed_system, spawn_lives_system, update_lives_system,
+    handle_bonus_life_system, handle_player_killed_system, reset_lives_system, spawn_lives_system,
+    update_lives_system,
 };
 use bevy::app::{App, Plugin, Startup, Update};
 use bevy::prelude::IntoScheduleConfigs;
@@ -11,10 +15,25 @@ pub struct LivesPlugin;
 
 impl Plugin for LivesPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(LivesResource(Lives::new()))
+        app.init_resource::<LivesResource>()
+            .init_resource::<SecondPlayerLivesResource>()
+            .init_resource::<DifficultyResource>()
+            .add_message::<BonusLifeMessage>()
             .add_systems(Startup, spawn_lives_system.after(spawn_header_system))
-            .add_systems(Update, handle_player_killed_system)
-            .add_systems(Update, update_lives_system.after(spawn_lives_system));
+            .add_systems(
+                Update,
+                (
+                    handle_player_killed_system.after(collision_system),
+                    handle_bonus_life_system,
+                    reset_lives_system,
+                ),
+            )
+            .add_systems(
+                Update,
+                update_lives_system
+                    .after(spawn_lives_system)
+                    .in_set(HudSystems),
+            );
     }
 }
 
@@ -22,6 +41,7 @@ impl Plugin for LivesPlugin {
 mod tests {
     use super::*;
     use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
     use crate::infrastructure::bevy::header::plugin::HeaderPlugin;
     use bevy_test::TestAppBuilder;
 
@@ -32,6 +52,7 @@ mod tests {
             .with_plugin(HeaderPlugin)
             .with_plugin(LivesPlugin)
             .with_message::<PlayerKilledMessage>()
+            .with_message::<NewGameMessage>()
             .build();
     }
 }
@@ -1,5 +1,70 @@
+use crate::domain::game_mode::PlayerSlot;
 use crate::domain::lives::Lives;
 use bevy::prelude::Resource;
 
-#[derive(Resource)]
+pub const LIVES_LABEL: &str = "LIVES";
+
+#[derive(Resource, Default)]
 pub struct LivesResource(pub Lives);
+
+#[derive(Resource, Default)]
+pub struct SecondPlayerLivesResource(pub Option<Lives>);
+
+pub fn lives_label(slot: PlayerSlot, coop: bool) -> String {
+    if coop {
+        format!("{}UP", slot.number())
+    } else {
+        LIVES_LABEL.to_string()
+    }
+}
+
+pub fn is_out_of_coop_lives(
+    slot: PlayerSlot,
+    lives: &LivesResource,
+    second_player_lives: &SecondPlayerLivesResource,
+) -> bool {
+    match (slot, &second_player_lives.0) {
+        (_, None) => false,
+        (PlayerSlot::One, Some(_)) => lives.0.get_current() == 0,
+        (PlayerSlot::Two, Some(second)) => second.get_current() == 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coop_labels_name_the_player() {
+        assert_eq!(lives_label(PlayerSlot::One, false), "LIVES");
+        assert_eq!(lives_label(PlayerSlot::Two, true), "2UP");
+    }
+
+    #[test]
+    fn a_lone_player_is_never_out_of_coop_lives() {
+        let lives = LivesResource(Lives::with_count(0));
+
+        assert!(!is_out_of_coop_lives(
+            PlayerSlot::One,
+            &lives,
+            &SecondPlayerLivesResource(None)
+        ));
+    }
+
+    #[test]
+    fn coop_players_run_out_of_lives_separately() {
+        let lives = LivesResource(Lives::with_count(2));
+        let second_player_lives = SecondPlayerLivesResource(Some(Lives::with_count(0)));
+
+        assert!(!is_out_of_coop_lives(
+            PlayerSlot::One,
+            &lives,
+            &second_player_lives
+        ));
+        assert!(is_out_of_coop_lives(
+            PlayerSlot::Two,
+            &lives,
+            &second_player_lives
+        ));
+    }
+}
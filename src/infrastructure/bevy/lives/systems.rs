@@ -1,10 +1,17 @@
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::lives::Lives;
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
 use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 use crate::infrastructure::bevy::header::components::HeaderComponent;
 use crate::infrastructure::bevy::header::resources::FONT;
 use crate::infrastructure::bevy::lives::components::{
-    LivesLabelBundle, LivesValueBundle, LivesValueComponent, LivesViewBundle,
+    BonusLifeMessage, LivesLabelBundle, LivesValueBundle, LivesValueComponent, LivesViewBundle,
+    LivesViewComponent,
+};
+use crate::infrastructure::bevy::lives::resources::{
+    LivesResource, SecondPlayerLivesResource, lives_label,
 };
-use crate::infrastructure::bevy::lives::resources::LivesResource;
 use crate::infrastructure::bevy::player::resources::PLAYER_IMAGE;
 use bevy::asset::AssetServer;
 use bevy::ecs::entity::Entity;
@@ -12,55 +19,129 @@ use bevy::ecs::query::With;
 use bevy::ecs::system::{Commands, Query, Res};
 use bevy::prelude::{DetectChanges, MessageReader, ResMut};
 
+fn lives_by_player(
+    lives: &LivesResource,
+    second_player_lives: &SecondPlayerLivesResource,
+) -> Vec<(PlayerSlot, u8)> {
+    let mut players = vec![(PlayerSlot::One, lives.0.get_current())];
+
+    if let Some(second) = &second_player_lives.0 {
+        players.push((PlayerSlot::Two, second.get_current()));
+    }
+
+    players
+}
+
 pub fn spawn_lives_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     lives_res: Res<LivesResource>,
+    second_player_lives: Res<SecondPlayerLivesResource>,
     header_query: Query<Entity, With<HeaderComponent>>,
 ) {
     if let Ok(header) = header_query.single() {
         let font = asset_server.load(FONT);
-        let remaining_lives = lives_res.0.get_current();
+        let players = lives_by_player(&lives_res, &second_player_lives);
+        let coop = players.len() > 1;
 
         commands.entity(header).with_children(|parent| {
-            parent
-                .spawn(LivesViewBundle::new())
-                .with_children(|lives_section| {
-                    lives_section.spawn(LivesLabelBundle::new(font));
-
-                    for _ in 0..remaining_lives {
-                        let image = asset_server.load(PLAYER_IMAGE);
-                        lives_section.spawn(LivesValueBundle::new(image));
-                    }
-                });
+            for &(slot, remaining_lives) in &players {
+                parent
+                    .spawn(LivesViewBundle::new(slot, players.len()))
+                    .with_children(|lives_section| {
+                        lives_section
+                            .spawn(LivesLabelBundle::new(font.clone(), lives_label(slot, coop)));
+
+                        for _ in 0..remaining_lives {
+                            let image = asset_server.load(PLAYER_IMAGE);
+                            lives_section.spawn(LivesValueBundle::new(image, slot));
+                        }
+                    });
+            }
         });
     }
 }
 
 pub fn handle_player_killed_system(
     mut lives_res: ResMut<LivesResource>,
+    mut second_player_lives: ResMut<SecondPlayerLivesResource>,
     mut player_killed_message: MessageReader<PlayerKilledMessage>,
 ) {
-    for _ in player_killed_message.read() {
-        lives_res.0.decrement();
+    for message in player_killed_message.read() {
+        match (message.player, &mut second_player_lives.0) {
+            (PlayerSlot::Two, Some(second)) => second.decrement(),
+            _ => lives_res.0.decrement(),
+        }
+    }
+}
+
+pub fn handle_bonus_life_system(
+    mut lives_res: ResMut<LivesResource>,
+    mut second_player_lives: ResMut<SecondPlayerLivesResource>,
+    mut bonus_life_message: MessageReader<BonusLifeMessage>,
+) {
+    for message in bonus_life_message.read() {
+        match (message.player, &mut second_player_lives.0) {
+            (PlayerSlot::Two, Some(second)) => second.increment(),
+            _ => lives_res.0.increment(),
+        }
+    }
+}
+
+pub fn reset_lives_system(
+    mut lives_res: ResMut<LivesResource>,
+    mut second_player_lives: ResMut<SecondPlayerLivesResource>,
+    difficulty: Res<DifficultyResource>,
+    mut new_game_message: MessageReader<NewGameMessage>,
+) {
+    if new_game_message.read().count() > 0 {
+        let starting_lives = difficulty.0.starting_lives();
+        lives_res.0 = Lives::with_count(starting_lives);
+
+        if let Some(second) = &mut second_player_lives.0 {
+            *second = Lives::with_count(starting_lives);
+        }
     }
 }
 
 pub fn update_lives_system(
     lives_resource: Res<LivesResource>,
+    second_player_lives: Res<SecondPlayerLivesResource>,
     mut commands: Commands,
+    asset_server: Res<AssetServer>,
     lives_icons_query: Query<(Entity, &LivesValueComponent)>,
+    lives_view_query: Query<(Entity, &LivesViewComponent)>,
 ) {
-    if lives_resource.is_changed() {
-        let target_lives_count = lives_resource.0.get_current() as usize;
-        let current_icon_count = lives_icons_query.iter().count();
+    if !lives_resource.is_changed() && !second_player_lives.is_changed() {
+        return;
+    }
+
+    for (slot, target_lives) in lives_by_player(&lives_resource, &second_player_lives) {
+        let target_lives_count = target_lives as usize;
+        let icons = lives_icons_query
+            .iter()
+            .filter(|(_, icon)| icon.0 == slot)
+            .map(|(entity, _)| entity)
+            .collect::<Vec<_>>();
+        let current_icon_count = icons.len();
 
         if current_icon_count > target_lives_count {
             let diff = current_icon_count - target_lives_count;
 
-            for (entity, _) in lives_icons_query.iter().take(diff) {
+            for entity in icons.into_iter().take(diff) {
                 commands.entity(entity).despawn();
             }
+        } else if let Some((lives_view, _)) =
+            lives_view_query.iter().find(|(_, view)| view.0 == slot)
+        {
+            let diff = target_lives_count - current_icon_count;
+
+            commands.entity(lives_view).with_children(|lives_section| {
+                for _ in 0..diff {
+                    let image = asset_server.load(PLAYER_IMAGE);
+                    lives_section.spawn(LivesValueBundle::new(image, slot));
+                }
+            });
         }
     }
 }
@@ -68,15 +149,14 @@ pub fn update_lives_system(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::lives::Lives;
+    use crate::domain::difficulty::Difficulty;
     use crate::infrastructure::bevy::header::components::HeaderComponent;
-    use crate::infrastructure::bevy::lives::components::LivesViewComponent;
-    use crate::infrastructure::bevy::lives::resources::LivesResource;
+    use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
     use bevy::app::{App, Startup};
     use bevy::image::Image;
     use bevy::prelude::{AssetApp, Children, ImageNode, Text};
     use bevy::text::Font;
-    use bevy_test::{TestAppBuilder, contains_single_component};
+    use bevy_test::{TestAppBuilder, contains_single_component, count_components};
 
     fn setup() -> App {
         TestAppBuilder::new()
@@ -84,7 +164,9 @@ mod tests {
             .with_setup(|app| {
                 app.init_asset::<Image>()
                     .init_asset::<Font>()
-                    .insert_resource(LivesResource(Lives::new()));
+                    .insert_resource(LivesResource(Lives::new()))
+                    .init_resource::<SecondPlayerLivesResource>()
+                    .init_resource::<DifficultyResource>();
 
                 app.world_mut().spawn(HeaderComponent);
             })
@@ -118,7 +200,7 @@ mod tests {
                 .filter(|child| {
                     app.world()
                         .get::<Text>(**child)
-                        .map_or(false, |text| text.0 == "LIVES")
+                        .is_some_and(|text| text.0 == "LIVES")
                 })
                 .count();
 
@@ -130,6 +212,32 @@ mod tests {
             assert_eq!(label_count, 1);
             assert_eq!(icon_count, 3);
         }
+
+        #[test]
+        fn should_show_both_players_in_coop() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(2))))
+                .add_systems(Startup, spawn_lives_system);
+            app.update();
+
+            let labels = app
+                .world_mut()
+                .query::<&Text>()
+                .iter(app.world())
+                .map(|text| text.0.clone())
+                .collect::<Vec<_>>();
+            let second_player_icons = app
+                .world_mut()
+                .query::<&LivesValueComponent>()
+                .iter(app.world())
+                .filter(|icon| icon.0 == PlayerSlot::Two)
+                .count();
+
+            assert_eq!(count_components::<LivesViewComponent>(&mut app), 2);
+            assert!(labels.contains(&"1UP".to_string()));
+            assert!(labels.contains(&"2UP".to_string()));
+            assert_eq!(second_player_icons, 2);
+        }
     }
 
     #[cfg(test)]
@@ -138,7 +246,9 @@ mod tests {
         use crate::infrastructure::bevy::lives::components::LivesValueComponent;
         use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
         use bevy::app::Update;
-        use bevy_test::{get_resource_mut_or_fail, get_resource_or_fail, send_message};
+        use bevy_test::{
+            get_resource_mut_or_fail, get_resource_or_fail, send_message, spawn_dummy_entity,
+        };
 
         #[test]
         fn should_decrease_lives_when_player_killed() {
@@ -149,9 +259,15 @@ mod tests {
             let res = get_resource_or_fail::<LivesResource>(&mut app);
             assert_eq!(res.0.get_current(), 3);
 
-            let projectile = app.world_mut().spawn(PlayerProjectileComponent).id();
+            let projectile = app
+                .world_mut()
+                .spawn(PlayerProjectileComponent::new(PlayerSlot::One))
+                .id();
 
-            send_message(&mut app, PlayerKilledMessage::new(projectile));
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::One),
+            );
 
             app.update();
 
@@ -159,6 +275,72 @@ mod tests {
             assert_eq!(res.0.get_current(), 2);
         }
 
+        #[test]
+        fn should_take_the_life_from_the_player_that_was_hit() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::new())))
+                .add_message::<PlayerKilledMessage>()
+                .add_systems(Update, handle_player_killed_system);
+            let projectile = spawn_dummy_entity(&mut app);
+
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::Two),
+            );
+            app.update();
+
+            let second = get_resource_or_fail::<SecondPlayerLivesResource>(&mut app)
+                .0
+                .clone()
+                .expect("Second player lives not found");
+
+            assert_eq!(
+                get_resource_or_fail::<LivesResource>(&mut app)
+                    .0
+                    .get_current(),
+                3
+            );
+            assert_eq!(second.get_current(), 2);
+        }
+
+        #[test]
+        fn should_only_update_the_icons_of_the_player_that_was_hit() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::new())))
+                .add_systems(Startup, spawn_lives_system)
+                .add_systems(Update, update_lives_system);
+            app.update();
+
+            get_resource_mut_or_fail::<SecondPlayerLivesResource>(&mut app)
+                .0
+                .as_mut()
+                .expect("Second player lives not found")
+                .decrement();
+            app.update();
+
+            let icons = app
+                .world_mut()
+                .query::<&LivesValueComponent>()
+                .iter(app.world())
+                .map(|icon| icon.0)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                icons
+                    .iter()
+                    .filter(|slot| **slot == PlayerSlot::One)
+                    .count(),
+                3
+            );
+            assert_eq!(
+                icons
+                    .iter()
+                    .filter(|slot| **slot == PlayerSlot::Two)
+                    .count(),
+                2
+            );
+        }
+
         #[test]
         fn should_render_the_updated_lives() {
             let mut app = setup();
@@ -186,4 +368,99 @@ mod tests {
             assert_eq!(lives_image_count, 2);
         }
     }
+
+    #[cfg(test)]
+    mod reset_lives_system {
+        use super::*;
+        use bevy::app::Update;
+        use bevy::prelude::IntoScheduleConfigs;
+        use bevy_test::{get_resource_or_fail, send_message};
+
+        #[test]
+        fn should_restore_lives_and_icons_on_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>()
+                .add_systems(Startup, spawn_lives_system)
+                .add_systems(Update, (reset_lives_system, update_lives_system).chain());
+            app.update();
+            app.world_mut()
+                .resource_mut::<LivesResource>()
+                .0
+                .decrement();
+            app.update();
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            let res = get_resource_or_fail::<LivesResource>(&mut app);
+            assert_eq!(res.0.get_current(), 3);
+
+            let mut query = app.world_mut().query::<&LivesValueComponent>();
+            assert_eq!(query.iter(app.world()).count(), 3);
+        }
+
+        #[test]
+        fn should_start_with_the_lives_of_the_difficulty() {
+            let mut app = setup();
+            app.insert_resource(DifficultyResource(Difficulty::Easy))
+                .insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(1))))
+                .add_message::<NewGameMessage>()
+                .add_systems(Update, reset_lives_system);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            let expected = Difficulty::Easy.starting_lives();
+            assert_eq!(
+                get_resource_or_fail::<LivesResource>(&mut app)
+                    .0
+                    .get_current(),
+                expected
+            );
+            assert_eq!(
+                get_resource_or_fail::<SecondPlayerLivesResource>(&mut app)
+                    .0
+                    .as_ref()
+                    .map(Lives::get_current),
+                Some(expected)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod handle_bonus_life_system {
+        use super::*;
+        use bevy::app::Update;
+        use bevy_test::{get_resource_or_fail, send_message};
+
+        #[test]
+        fn should_give_the_player_an_extra_life() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(1))))
+                .add_message::<BonusLifeMessage>()
+                .add_systems(Update, handle_bonus_life_system);
+
+            send_message(
+                &mut app,
+                BonusLifeMessage {
+                    player: PlayerSlot::Two,
+                },
+            );
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<LivesResource>(&mut app)
+                    .0
+                    .get_current(),
+                3
+            );
+            assert_eq!(
+                get_resource_or_fail::<SecondPlayerLivesResource>(&mut app)
+                    .0
+                    .as_ref()
+                    .map(Lives::get_current),
+                Some(2)
+            );
+        }
+    }
 }
@@ -1,13 +1,34 @@
+pub mod audio;
+pub mod battle;
 pub mod bevy_renderer;
+pub mod boss;
+pub mod campaign;
+pub mod difficulty;
+pub mod dive;
+pub mod editor;
 pub mod enemy;
 pub mod enemy_formation;
 pub mod enemy_projectile;
 pub mod footer;
 pub mod game_area;
+pub mod game_options;
+pub mod game_state;
 pub mod header;
+pub mod headless_renderer;
+pub mod high_scores;
 pub mod lives;
+pub mod netplay;
 pub mod player;
 pub mod player_projectile;
+pub mod power_up;
+pub mod replay;
+pub mod save_game;
 pub mod score;
 pub mod shield;
 pub mod shield_formation;
+pub mod simulation;
+pub mod terminal_renderer;
+pub mod tunables;
+pub mod turns;
+pub mod ufo;
+pub mod versus;
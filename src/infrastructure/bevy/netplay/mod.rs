@@ -0,0 +1,3 @@
+pub mod plugin;
+pub mod resources;
+pub mod systems;
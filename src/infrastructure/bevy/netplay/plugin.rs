@@ -0,0 +1,192 @@
+use crate::domain::netplay::RollbackSession;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::netplay::resources::{
+    NETPLAY_MAX_PREDICTION_FRAMES, NetplayOptions, NetplayResource, NetplaySocket,
+    NetplayStateResource,
+};
+use crate::infrastructure::bevy::netplay::systems::{is_netplay_stepping, netplay_system};
+use bevy::app::{App, AppExit, Plugin, PreUpdate, Update};
+use bevy::ecs::schedule::ExecutorKind;
+use bevy::input::InputSystems;
+use bevy::prelude::IntoScheduleConfigs;
+use std::time::Duration;
+use tracing::{error, info};
+
+pub struct NetplayPlugin {
+    pub options: NetplayOptions,
+    pub session_id: u64,
+}
+
+impl Plugin for NetplayPlugin {
+    fn build(&self, app: &mut App) {
+        let socket = match NetplaySocket::bind(self.options.bind, self.options.peer) {
+            Ok(socket) => socket,
+            Err(reason) => {
+                error!("Cannot start the online game: {}", reason);
+                app.world_mut().write_message(AppExit::error());
+                return;
+            }
+        };
+
+        info!(
+            "Waiting for player {} at {} (listening on {})",
+            self.options.local_player.other().number(),
+            self.options.peer,
+            self.options.bind
+        );
+
+        app.insert_resource(NetplayResource {
+            session: RollbackSession::new(self.options.local_player, NETPLAY_MAX_PREDICTION_FRAMES),
+            socket,
+            session_id: self.session_id,
+            accumulator: Duration::ZERO,
+            silence: Duration::ZERO,
+            heard_from_peer: false,
+            linger: None,
+        })
+        .init_resource::<NetplayStateResource>()
+        .edit_schedule(Update, |schedule| {
+            schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+        })
+        .configure_sets(Update, GameplaySystems.run_if(is_netplay_stepping))
+        .add_systems(PreUpdate, netplay_system.after(InputSystems));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::game_mode::{GameMode, PlayerSlot};
+    use crate::infrastructure::bevy::audio::resources::SettingsStorageResource;
+    use crate::infrastructure::bevy::bevy_renderer::plugin::SpaceInvadersPlugin;
+    use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+    use crate::infrastructure::bevy::game_options::resources::GameOptions;
+    use crate::infrastructure::bevy::headless_renderer::plugin::HeadlessPlugin;
+    use crate::infrastructure::bevy::high_scores::resources::HighScoreStorageResource;
+    use crate::infrastructure::bevy::netplay::resources::{
+        GameplaySnapshot, NETPLAY_FRAME_DURATION,
+    };
+    use crate::infrastructure::bevy::netplay::systems::capture_snapshot;
+    use crate::infrastructure::bevy::score::resources::ScoreResource;
+    use bevy::MinimalPlugins;
+    use bevy::input::ButtonInput;
+    use bevy::prelude::KeyCode;
+    use bevy::time::TimeUpdateStrategy;
+    use bevy_test::{TestAppBuilder, get_resource_or_fail};
+    use std::cmp::Ordering;
+    use std::net::{SocketAddr, UdpSocket};
+
+    fn free_address() -> SocketAddr {
+        UdpSocket::bind("127.0.0.1:0")
+            .and_then(|socket| socket.local_addr())
+            .expect("Should reserve a local address")
+    }
+
+    fn online_game(bind: SocketAddr, peer: SocketAddr, local_player: PlayerSlot) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                NETPLAY_FRAME_DURATION,
+            )))
+            .insert_resource(HighScoreStorageResource { path: None })
+            .insert_resource(SettingsStorageResource { path: None })
+            .add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: GameOptions {
+                    seed: Some(21),
+                    mode: GameMode::Coop,
+                    netplay: Some(NetplayOptions {
+                        bind,
+                        peer,
+                        local_player,
+                    }),
+                    ..GameOptions::default()
+                },
+            })
+            .add_plugins(SpaceInvadersPlugin);
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+    }
+
+    fn session(app: &App) -> &RollbackSession<GameplaySnapshot> {
+        &app.world().resource::<NetplayResource>().session
+    }
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(NetplayPlugin {
+                options: NetplayOptions {
+                    bind: "127.0.0.1:0".parse().expect("Address should parse"),
+                    peer: "127.0.0.1:9".parse().expect("Address should parse"),
+                    local_player: PlayerSlot::One,
+                },
+                session_id: 1,
+            })
+            .build();
+
+        assert!(!get_resource_or_fail::<NetplayStateResource>(&mut app).stepping);
+    }
+
+    #[test]
+    fn both_sides_should_play_the_same_game() {
+        let first_address = free_address();
+        let second_address = free_address();
+        let mut first = online_game(first_address, second_address, PlayerSlot::One);
+        let mut second = online_game(second_address, first_address, PlayerSlot::Two);
+
+        press(&mut first, KeyCode::Space);
+        for _ in 0..4 {
+            first.update();
+        }
+
+        press(&mut second, KeyCode::ArrowLeft);
+        press(&mut second, KeyCode::Space);
+        for _ in 0..240 {
+            first.update();
+            second.update();
+        }
+
+        for app in [&mut first, &mut second] {
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .release_all();
+        }
+        for _ in 0..20 {
+            first.update();
+            second.update();
+        }
+        for _ in 0..NETPLAY_MAX_PREDICTION_FRAMES {
+            match session(&first)
+                .current_frame()
+                .cmp(&session(&second).current_frame())
+            {
+                Ordering::Less => first.update(),
+                Ordering::Greater => second.update(),
+                Ordering::Equal => break,
+            }
+        }
+
+        assert!(session(&first).get_rollbacks() > 0 || session(&second).get_rollbacks() > 0);
+        assert!(!first.world().resource::<NetplayStateResource>().finished);
+        assert!(!second.world().resource::<NetplayStateResource>().finished);
+        assert_eq!(
+            session(&first).current_frame(),
+            session(&second).current_frame()
+        );
+        assert_eq!(
+            first.world().resource::<ScoreResource>().0.get_current(),
+            second.world().resource::<ScoreResource>().0.get_current()
+        );
+        assert_eq!(
+            capture_snapshot(first.world_mut()).checksum(),
+            capture_snapshot(second.world_mut()).checksum()
+        );
+    }
+}
@@ -0,0 +1,461 @@
+use crate::domain::difficulty::Difficulty;
+use crate::domain::enemy_formation::EnemyFormation;
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::lives::Lives;
+use crate::domain::netplay::{ActionInput, Desync, Frame, RollbackSession, StateHasher};
+use crate::domain::player::Player;
+use crate::domain::score::Score;
+use crate::domain::wave::Wave;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+use bevy::math::Vec3;
+use bevy::prelude::{Resource, Timer};
+use rand::RngCore;
+use rand::rngs::StdRng;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::io::ErrorKind;
+use std::net::{SocketAddr, UdpSocket};
+use std::time::Duration;
+use tracing::warn;
+
+pub const NETPLAY_DEFAULT_PORT: u16 = 7777;
+pub const NETPLAY_PROTOCOL_VERSION: u8 = 1;
+pub const NETPLAY_FRAME_DURATION: f64 = 1.0 / 60.0;
+pub const NETPLAY_MAX_PREDICTION_FRAMES: u32 = 8;
+pub const NETPLAY_MAX_FRAMES_PER_UPDATE: u32 = 4;
+pub const NETPLAY_MAX_FRAME_ADVANTAGE: i64 = 1;
+pub const NETPLAY_CONNECT_TIMEOUT: f32 = 60.0;
+pub const NETPLAY_DISCONNECT_TIMEOUT: f32 = 10.0;
+pub const NETPLAY_LINGER_DURATION: f32 = 1.0;
+pub const MAX_INPUTS_PER_PACKET: usize = 64;
+const PACKET_MAGIC: [u8; 3] = *b"SIN";
+const PACKET_HEADER_LENGTH: usize = 35;
+const MAX_PACKET_LENGTH: usize = PACKET_HEADER_LENGTH + MAX_INPUTS_PER_PACKET;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NetplayOptions {
+    pub bind: SocketAddr,
+    pub peer: SocketAddr,
+    pub local_player: PlayerSlot,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetplayPacket {
+    pub session: u64,
+    pub sender: PlayerSlot,
+    pub first_frame: Frame,
+    pub inputs: Vec<ActionInput>,
+    pub ack: Frame,
+    pub checksum: Option<(Frame, u64)>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum NetplayError {
+    Io(String),
+    Malformed(String),
+    SessionMismatch,
+    SamePlayer(PlayerSlot),
+    Desync(Desync),
+    Timeout(SocketAddr, f32),
+}
+
+pub struct NetplaySocket {
+    socket: UdpSocket,
+    peer: SocketAddr,
+}
+
+#[derive(Clone)]
+pub struct GameplaySnapshot {
+    pub state: GameState,
+    pub score: Score,
+    pub second_player_score: Option<Score>,
+    pub lives: Lives,
+    pub second_player_lives: Option<Lives>,
+    pub wave: Wave,
+    pub formation: EnemyFormation,
+    pub formation_timer: Timer,
+    pub enemy_fire_timer: Timer,
+    pub rng: StdRng,
+    pub weapons: [Player; 2],
+    pub players: Vec<(PlayerSlot, Vec3)>,
+    pub player_projectiles: Vec<(PlayerProjectileComponent, Vec3)>,
+    pub enemy_projectiles: Vec<(Vec3, Timer)>,
+}
+
+#[derive(Resource)]
+pub struct NetplayResource {
+    pub session: RollbackSession<GameplaySnapshot>,
+    pub socket: NetplaySocket,
+    pub session_id: u64,
+    pub accumulator: Duration,
+    pub silence: Duration,
+    pub heard_from_peer: bool,
+    pub linger: Option<Duration>,
+}
+
+#[derive(Resource, Default)]
+pub struct NetplayStateResource {
+    pub stepping: bool,
+    pub finished: bool,
+}
+
+impl Display for NetplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetplayError::Io(reason) => write!(f, "{}", reason),
+            NetplayError::Malformed(reason) => write!(f, "malformed packet: {}", reason),
+            NetplayError::SessionMismatch => write!(
+                f,
+                "the peer plays with a different seed, difficulty, starting wave or version"
+            ),
+            NetplayError::SamePlayer(slot) => {
+                write!(f, "both peers chose to be player {}", slot.number())
+            }
+            NetplayError::Desync(desync) => write!(f, "{}", desync),
+            NetplayError::Timeout(peer, seconds) => {
+                write!(f, "no packets from {} for {} seconds", peer, seconds)
+            }
+        }
+    }
+}
+
+impl NetplayPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let inputs = &self.inputs[..self.inputs.len().min(MAX_INPUTS_PER_PACKET)];
+        let (checksum_frame, checksum) = self.checksum.unwrap_or_default();
+
+        let mut bytes = Vec::with_capacity(PACKET_HEADER_LENGTH + inputs.len());
+        bytes.extend_from_slice(&PACKET_MAGIC);
+        bytes.push(NETPLAY_PROTOCOL_VERSION);
+        bytes.extend_from_slice(&self.session.to_le_bytes());
+        bytes.push(self.sender.number());
+        bytes.extend_from_slice(&self.first_frame.to_le_bytes());
+        bytes.extend_from_slice(&self.ack.to_le_bytes());
+        bytes.push(self.checksum.is_some() as u8);
+        bytes.extend_from_slice(&checksum_frame.to_le_bytes());
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+        bytes.push(inputs.len() as u8);
+        bytes.extend(inputs.iter().map(ActionInput::bits));
+
+        bytes
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, NetplayError> {
+        if bytes.len() < PACKET_HEADER_LENGTH || bytes[..3] != PACKET_MAGIC {
+            return Err(NetplayError::Malformed("not a netplay packet".to_string()));
+        }
+        if bytes[3] != NETPLAY_PROTOCOL_VERSION {
+            return Err(NetplayError::SessionMismatch);
+        }
+
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap_or_default())
+        };
+        let u64_at = |offset: usize| {
+            u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap_or_default())
+        };
+
+        let sender = match bytes[12] {
+            1 => PlayerSlot::One,
+            2 => PlayerSlot::Two,
+            other => return Err(NetplayError::Malformed(format!("unknown player {}", other))),
+        };
+        let count = bytes[34] as usize;
+
+        if bytes.len() != PACKET_HEADER_LENGTH + count {
+            return Err(NetplayError::Malformed(format!(
+                "expected {} inputs, got {} bytes",
+                count,
+                bytes.len() - PACKET_HEADER_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            session: u64_at(4),
+            sender,
+            first_frame: u32_at(13),
+            ack: u32_at(17),
+            checksum: (bytes[21] != 0).then(|| (u32_at(22), u64_at(26))),
+            inputs: bytes[PACKET_HEADER_LENGTH..]
+                .iter()
+                .map(|bits| ActionInput::from_bits(*bits))
+                .collect(),
+        })
+    }
+}
+
+impl NetplaySocket {
+    pub fn bind(local: SocketAddr, peer: SocketAddr) -> Result<Self, NetplayError> {
+        let socket = UdpSocket::bind(local)
+            .map_err(|error| NetplayError::Io(format!("cannot bind {}: {}", local, error)))?;
+
+        Self::new(socket, peer)
+    }
+
+    pub fn new(socket: UdpSocket, peer: SocketAddr) -> Result<Self, NetplayError> {
+        socket
+            .set_nonblocking(true)
+            .map_err(|error| NetplayError::Io(error.to_string()))?;
+
+        Ok(Self { socket, peer })
+    }
+
+    pub fn get_peer(&self) -> SocketAddr {
+        self.peer
+    }
+
+    pub fn send(&self, packet: &NetplayPacket) -> Result<(), NetplayError> {
+        match self.socket.send_to(&packet.encode(), self.peer) {
+            Ok(_) => Ok(()),
+            Err(error) if is_transient(&error) => Ok(()),
+            Err(error) => Err(NetplayError::Io(format!(
+                "cannot send to {}: {}",
+                self.peer, error
+            ))),
+        }
+    }
+
+    pub fn receive(&self) -> Result<Vec<NetplayPacket>, NetplayError> {
+        let mut packets = vec![];
+        let mut buffer = [0u8; MAX_PACKET_LENGTH];
+
+        loop {
+            match self.socket.recv_from(&mut buffer) {
+                Ok((_, from)) if from != self.peer => {}
+                Ok((length, _)) => match NetplayPacket::decode(&buffer[..length]) {
+                    Ok(packet) => packets.push(packet),
+                    Err(NetplayError::Malformed(reason)) => {
+                        warn!("Ignoring packet from {}: {}", self.peer, reason)
+                    }
+                    Err(error) => return Err(error),
+                },
+                Err(error) if error.kind() == ErrorKind::WouldBlock => return Ok(packets),
+                Err(error) if is_transient(&error) => {}
+                Err(error) => return Err(NetplayError::Io(error.to_string())),
+            }
+        }
+    }
+}
+
+fn is_transient(error: &std::io::Error) -> bool {
+    matches!(
+        error.kind(),
+        ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset | ErrorKind::Interrupted
+    )
+}
+
+impl GameplaySnapshot {
+    pub fn checksum(&self) -> u64 {
+        let mut hasher = StateHasher::default();
+
+        self.state.hash(&mut hasher);
+        self.score.get_current().hash(&mut hasher);
+        self.second_player_score
+            .as_ref()
+            .map(Score::get_current)
+            .hash(&mut hasher);
+        self.lives.get_current().hash(&mut hasher);
+        self.second_player_lives
+            .as_ref()
+            .map(Lives::get_current)
+            .hash(&mut hasher);
+        self.wave.get_number().hash(&mut hasher);
+
+        for row in self.formation.get_enemies() {
+            for enemy in row {
+                enemy.map(|enemy| enemy.get_id().value()).hash(&mut hasher);
+            }
+        }
+        self.formation.get_position().hash(&mut hasher);
+        format!(
+            "{:?}{:?}",
+            self.formation.get_direction(),
+            self.formation.get_status()
+        )
+        .hash(&mut hasher);
+
+        for timer in [&self.formation_timer, &self.enemy_fire_timer] {
+            timer.elapsed().hash(&mut hasher);
+        }
+        self.rng.clone().next_u64().hash(&mut hasher);
+
+        for weapon in &self.weapons {
+            format!("{:?}", weapon).hash(&mut hasher);
+        }
+        for (slot, position) in &self.players {
+            slot.hash(&mut hasher);
+            hash_position(*position, &mut hasher);
+        }
+        for (projectile, position) in &self.player_projectiles {
+            projectile.shooter.hash(&mut hasher);
+            projectile.lifetime.elapsed().hash(&mut hasher);
+            hash_position(*position, &mut hasher);
+        }
+        for (position, timer) in &self.enemy_projectiles {
+            hash_position(*position, &mut hasher);
+            timer.elapsed().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
+fn hash_position(position: Vec3, hasher: &mut StateHasher) {
+    position.x.to_bits().hash(hasher);
+    position.y.to_bits().hash(hasher);
+}
+
+pub fn netplay_session_id(seed: u64, difficulty: Difficulty, starting_wave: Wave) -> u64 {
+    let mut hasher = StateHasher::default();
+
+    seed.hash(&mut hasher);
+    difficulty.as_str().hash(&mut hasher);
+    starting_wave.get_number().hash(&mut hasher);
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_packet() -> NetplayPacket {
+        NetplayPacket {
+            session: 0xdead_beef,
+            sender: PlayerSlot::Two,
+            first_frame: 40,
+            inputs: vec![
+                ActionInput::LEFT,
+                ActionInput::FIRE.with(ActionInput::RIGHT),
+            ],
+            ack: 38,
+            checksum: Some((37, 0x0123_4567_89ab_cdef)),
+        }
+    }
+
+    fn loopback_pair() -> (NetplaySocket, NetplaySocket) {
+        let first = UdpSocket::bind("127.0.0.1:0").expect("Socket should bind");
+        let second = UdpSocket::bind("127.0.0.1:0").expect("Socket should bind");
+        let first_address = first.local_addr().expect("Socket should have an address");
+        let second_address = second.local_addr().expect("Socket should have an address");
+
+        (
+            NetplaySocket::new(first, second_address).expect("Socket should be usable"),
+            NetplaySocket::new(second, first_address).expect("Socket should be usable"),
+        )
+    }
+
+    fn receive_eventually(socket: &NetplaySocket) -> Vec<NetplayPacket> {
+        for _ in 0..100 {
+            let packets = socket.receive().expect("Receiving should succeed");
+            if !packets.is_empty() {
+                return packets;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        vec![]
+    }
+
+    #[test]
+    fn packet_survives_an_encoding_round_trip() {
+        let packet = create_packet();
+
+        assert_eq!(NetplayPacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn packet_without_checksum_survives_an_encoding_round_trip() {
+        let packet = NetplayPacket {
+            checksum: None,
+            inputs: vec![],
+            ..create_packet()
+        };
+
+        assert_eq!(NetplayPacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn encoding_caps_the_number_of_inputs() {
+        let packet = NetplayPacket {
+            inputs: vec![ActionInput::FIRE; MAX_INPUTS_PER_PACKET + 10],
+            ..create_packet()
+        };
+
+        let decoded = NetplayPacket::decode(&packet.encode()).expect("Packet should decode");
+
+        assert_eq!(decoded.inputs.len(), MAX_INPUTS_PER_PACKET);
+    }
+
+    #[test]
+    fn truncated_packets_are_malformed() {
+        let bytes = create_packet().encode();
+
+        assert!(matches!(
+            NetplayPacket::decode(&bytes[..bytes.len() - 1]),
+            Err(NetplayError::Malformed(_))
+        ));
+        assert!(matches!(
+            NetplayPacket::decode(b"hello"),
+            Err(NetplayError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn other_protocol_versions_are_rejected() {
+        let mut bytes = create_packet().encode();
+        bytes[3] = NETPLAY_PROTOCOL_VERSION + 1;
+
+        assert_eq!(
+            NetplayPacket::decode(&bytes),
+            Err(NetplayError::SessionMismatch)
+        );
+    }
+
+    #[test]
+    fn sockets_exchange_packets_over_loopback() {
+        let (first, second) = loopback_pair();
+        let packet = create_packet();
+
+        first.send(&packet).expect("Sending should succeed");
+
+        assert_eq!(receive_eventually(&second), vec![packet]);
+        assert_eq!(first.receive(), Ok(vec![]));
+    }
+
+    #[test]
+    fn sockets_ignore_strangers() {
+        let (first, _second) = loopback_pair();
+        let stranger = UdpSocket::bind("127.0.0.1:0").expect("Socket should bind");
+        let address = first
+            .socket
+            .local_addr()
+            .expect("Socket should have an address");
+
+        stranger
+            .send_to(&create_packet().encode(), address)
+            .expect("Sending should succeed");
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(first.receive(), Ok(vec![]));
+    }
+
+    #[test]
+    fn session_id_depends_on_the_game_settings() {
+        let session = netplay_session_id(1, Difficulty::Normal, Wave::default());
+
+        assert_eq!(
+            session,
+            netplay_session_id(1, Difficulty::Normal, Wave::default())
+        );
+        assert_ne!(
+            session,
+            netplay_session_id(2, Difficulty::Normal, Wave::default())
+        );
+        assert_ne!(
+            session,
+            netplay_session_id(1, Difficulty::Hard, Wave::default())
+        );
+    }
+}
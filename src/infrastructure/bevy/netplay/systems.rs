@@ -0,0 +1,351 @@
+use crate::domain::game_mode::{GameMode, PlayerSlot};
+use crate::domain::netplay::{ActionInput, RollbackGame};
+use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+use crate::infrastructure::bevy::enemy::resources::EnemyProjectileMovementTimer;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationMovementTimer, EnemyFormationResource, GameRngResource, WaveResource,
+};
+use crate::infrastructure::bevy::enemy_formation::systems::spawn_enemies;
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileBundle, EnemyProjectileComponent, EnemyProjectileTimer,
+};
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::lives::resources::{LivesResource, SecondPlayerLivesResource};
+use crate::infrastructure::bevy::netplay::resources::{
+    GameplaySnapshot, MAX_INPUTS_PER_PACKET, NETPLAY_CONNECT_TIMEOUT, NETPLAY_DISCONNECT_TIMEOUT,
+    NETPLAY_FRAME_DURATION, NETPLAY_LINGER_DURATION, NETPLAY_MAX_FRAME_ADVANTAGE,
+    NETPLAY_MAX_FRAMES_PER_UPDATE, NetplayError, NetplayPacket, NetplayResource,
+    NetplayStateResource,
+};
+use crate::infrastructure::bevy::player::components::{PlayerBundle, PlayerComponent};
+use crate::infrastructure::bevy::player::resources::{
+    PlayerResource, SOLO_CONTROLS, player_controls,
+};
+use crate::infrastructure::bevy::player_projectile::components::{
+    PlayerProjectileBundle, PlayerProjectileComponent,
+};
+use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
+use bevy::app::AppExit;
+use bevy::asset::AssetServer;
+use bevy::ecs::query::QueryFilter;
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Entity, KeyCode, Mut, NextState, Res, State, StateTransition, Time, Transform, Update, Virtual,
+    With, World,
+};
+use std::time::Duration;
+use tracing::{error, info};
+
+struct WorldRollback<'w> {
+    world: &'w mut World,
+}
+
+impl RollbackGame for WorldRollback<'_> {
+    type State = GameplaySnapshot;
+
+    fn advance(&mut self, inputs: [ActionInput; 2]) {
+        apply_inputs(
+            inputs,
+            &mut self.world.resource_mut::<ButtonInput<KeyCode>>(),
+        );
+
+        let mut time = Time::default();
+        time.advance_by(frame_duration());
+        *self.world.resource_mut::<Time>() = time;
+
+        self.world.run_schedule(Update);
+        self.world.run_schedule(StateTransition);
+    }
+
+    fn save_state(&mut self) -> GameplaySnapshot {
+        capture_snapshot(self.world)
+    }
+
+    fn load_state(&mut self, state: &GameplaySnapshot) {
+        restore_snapshot(self.world, state);
+    }
+
+    fn checksum(&self, state: &GameplaySnapshot) -> u64 {
+        state.checksum()
+    }
+}
+
+fn frame_duration() -> Duration {
+    Duration::from_secs_f64(NETPLAY_FRAME_DURATION)
+}
+
+pub fn encode_local_input(keyboard: &ButtonInput<KeyCode>) -> ActionInput {
+    let mut input = ActionInput::NONE;
+
+    if keyboard.any_pressed(SOLO_CONTROLS.left.iter().copied()) {
+        input = input.with(ActionInput::LEFT);
+    }
+    if keyboard.any_pressed(SOLO_CONTROLS.right.iter().copied()) {
+        input = input.with(ActionInput::RIGHT);
+    }
+    if keyboard.any_pressed(SOLO_CONTROLS.fire.iter().copied()) {
+        input = input.with(ActionInput::FIRE);
+    }
+
+    input
+}
+
+pub fn apply_inputs(inputs: [ActionInput; 2], keyboard: &mut ButtonInput<KeyCode>) {
+    keyboard.release_all();
+
+    for slot in [PlayerSlot::One, PlayerSlot::Two] {
+        let input = inputs[slot.index()];
+        let controls = player_controls(GameMode::Coop, slot);
+
+        for (action, keys) in [
+            (ActionInput::LEFT, controls.left),
+            (ActionInput::RIGHT, controls.right),
+            (ActionInput::FIRE, controls.fire),
+        ] {
+            if input.contains(action) {
+                keyboard.press(keys[0]);
+            }
+        }
+    }
+}
+
+pub fn capture_snapshot(world: &mut World) -> GameplaySnapshot {
+    let players = world
+        .query::<(&PlayerComponent, &Transform)>()
+        .iter(world)
+        .map(|(player, transform)| (player.slot, transform.translation))
+        .collect();
+    let player_projectiles = world
+        .query::<(&PlayerProjectileComponent, &Transform)>()
+        .iter(world)
+        .map(|(projectile, transform)| (projectile.clone(), transform.translation))
+        .collect();
+    let enemy_projectiles = world
+        .query_filtered::<(&Transform, &EnemyProjectileTimer), With<EnemyProjectileComponent>>()
+        .iter(world)
+        .map(|(transform, timer)| (transform.translation, timer.0.clone()))
+        .collect();
+
+    GameplaySnapshot {
+        state: *world.resource::<State<GameState>>().get(),
+        score: world.resource::<ScoreResource>().0.clone(),
+        second_player_score: world.resource::<SecondPlayerScoreResource>().0.clone(),
+        lives: world.resource::<LivesResource>().0.clone(),
+        second_player_lives: world.resource::<SecondPlayerLivesResource>().0.clone(),
+        wave: world.resource::<WaveResource>().0,
+        formation: world.resource::<EnemyFormationResource>().0.clone(),
+        formation_timer: world.resource::<EnemyFormationMovementTimer>().0.clone(),
+        enemy_fire_timer: world.resource::<EnemyProjectileMovementTimer>().0.clone(),
+        rng: world.resource::<GameRngResource>().0.clone(),
+        weapons: world.resource::<PlayerResource>().0.clone(),
+        players,
+        player_projectiles,
+        enemy_projectiles,
+    }
+}
+
+pub fn restore_snapshot(world: &mut World, snapshot: &GameplaySnapshot) {
+    if *world.resource::<State<GameState>>().get() != snapshot.state {
+        world
+            .resource_mut::<NextState<GameState>>()
+            .set(snapshot.state);
+        world.run_schedule(StateTransition);
+    }
+
+    world.resource_mut::<ScoreResource>().0 = snapshot.score.clone();
+    world.resource_mut::<SecondPlayerScoreResource>().0 = snapshot.second_player_score.clone();
+    world.resource_mut::<LivesResource>().0 = snapshot.lives.clone();
+    world.resource_mut::<SecondPlayerLivesResource>().0 = snapshot.second_player_lives.clone();
+    world.resource_mut::<WaveResource>().0 = snapshot.wave;
+    world.resource_mut::<EnemyFormationResource>().0 = snapshot.formation.clone();
+    world.resource_mut::<EnemyFormationMovementTimer>().0 = snapshot.formation_timer.clone();
+    world.resource_mut::<EnemyProjectileMovementTimer>().0 = snapshot.enemy_fire_timer.clone();
+    world.resource_mut::<GameRngResource>().0 = snapshot.rng.clone();
+    world.resource_mut::<PlayerResource>().0 = snapshot.weapons.clone();
+
+    despawn_all::<With<PlayerComponent>>(world);
+    despawn_all::<With<PlayerProjectileComponent>>(world);
+    despawn_all::<With<EnemyProjectileComponent>>(world);
+    despawn_all::<With<EnemyComponent>>(world);
+
+    let asset_server = world.resource::<AssetServer>().clone();
+
+    for (slot, position) in &snapshot.players {
+        let mut bundle = PlayerBundle::new(&asset_server, GameMode::Coop, *slot);
+        bundle.transform.translation = *position;
+        world.spawn(bundle);
+    }
+    for (projectile, position) in &snapshot.player_projectiles {
+        world.spawn(PlayerProjectileBundle::with_projectile(
+            position.x,
+            position.y,
+            projectile.clone(),
+        ));
+    }
+    for (position, timer) in &snapshot.enemy_projectiles {
+        let mut bundle = EnemyProjectileBundle::new(position.x, position.y);
+        bundle.timer = EnemyProjectileTimer::new(timer.clone());
+        world.spawn(bundle);
+    }
+
+    spawn_enemies(
+        world.commands(),
+        &asset_server,
+        &EnemyFormationResource(snapshot.formation.clone()),
+        None,
+    );
+    world.flush();
+}
+
+fn despawn_all<F: QueryFilter>(world: &mut World) {
+    let entities: Vec<Entity> = world.query_filtered::<Entity, F>().iter(world).collect();
+
+    for entity in entities {
+        world.despawn(entity);
+    }
+}
+
+pub fn is_netplay_stepping(state: Res<NetplayStateResource>) -> bool {
+    state.stepping
+}
+
+pub fn netplay_system(world: &mut World) {
+    if world.resource::<NetplayStateResource>().finished {
+        return;
+    }
+
+    let delta = world.resource::<Time<Virtual>>().delta();
+
+    let result: Result<(), NetplayError> =
+        world.resource_scope(|world, mut netplay: Mut<NetplayResource>| {
+            receive_packets(&mut netplay, delta)?;
+            step_frames(world, &mut netplay, delta);
+            send_packet(&netplay)?;
+            wind_down(world, &mut netplay, delta);
+            Ok(())
+        });
+
+    if let Err(error) = result {
+        error!("Online game aborted: {}", error);
+        world.resource_mut::<NetplayStateResource>().finished = true;
+        world.write_message(AppExit::error());
+    }
+}
+
+fn receive_packets(netplay: &mut NetplayResource, delta: Duration) -> Result<(), NetplayError> {
+    let packets = netplay.socket.receive()?;
+
+    if packets.is_empty() {
+        netplay.silence += delta;
+
+        let timeout = if netplay.heard_from_peer {
+            NETPLAY_DISCONNECT_TIMEOUT
+        } else {
+            NETPLAY_CONNECT_TIMEOUT
+        };
+
+        if netplay.silence.as_secs_f32() > timeout {
+            return Err(NetplayError::Timeout(netplay.socket.get_peer(), timeout));
+        }
+        return Ok(());
+    }
+
+    for packet in packets {
+        if packet.session != netplay.session_id {
+            return Err(NetplayError::SessionMismatch);
+        }
+        if packet.sender == netplay.session.get_local() {
+            return Err(NetplayError::SamePlayer(packet.sender));
+        }
+
+        if !netplay.heard_from_peer {
+            info!("Connected to {}", netplay.socket.get_peer());
+            netplay.heard_from_peer = true;
+        }
+
+        for (offset, input) in packet.inputs.iter().enumerate() {
+            netplay
+                .session
+                .add_remote_input(packet.first_frame + offset as u32, *input);
+        }
+        netplay.session.set_remote_ack(packet.ack);
+        if let Some((frame, checksum)) = packet.checksum {
+            netplay.session.add_remote_checksum(frame, checksum);
+        }
+    }
+
+    netplay.silence = Duration::ZERO;
+    netplay.session.check_desync().map_err(NetplayError::Desync)
+}
+
+fn step_frames(world: &mut World, netplay: &mut NetplayResource, delta: Duration) {
+    if netplay.linger.is_some() {
+        return;
+    }
+
+    let step = frame_duration();
+    netplay.accumulator = (netplay.accumulator + delta).min(step * NETPLAY_MAX_FRAMES_PER_UPDATE);
+
+    if netplay.accumulator < step || !netplay.session.can_advance() {
+        return;
+    }
+
+    if netplay.session.frame_advantage() > NETPLAY_MAX_FRAME_ADVANTAGE {
+        netplay.accumulator -= step;
+        return;
+    }
+
+    let keyboard = world.resource::<ButtonInput<KeyCode>>().clone();
+    let time = *world.resource::<Time>();
+    let local_input = encode_local_input(&keyboard);
+
+    world.resource_mut::<NetplayStateResource>().stepping = true;
+
+    let mut game = WorldRollback { world };
+    while netplay.accumulator >= step && netplay.session.can_advance() {
+        netplay.accumulator -= step;
+        netplay.session.advance(&mut game, local_input);
+    }
+
+    world.resource_mut::<NetplayStateResource>().stepping = false;
+    *world.resource_mut::<ButtonInput<KeyCode>>() = keyboard;
+    *world.resource_mut::<Time>() = time;
+}
+
+fn send_packet(netplay: &NetplayResource) -> Result<(), NetplayError> {
+    let (first_frame, inputs) = netplay.session.unacknowledged_inputs();
+
+    netplay.socket.send(&NetplayPacket {
+        session: netplay.session_id,
+        sender: netplay.session.get_local(),
+        first_frame,
+        inputs: inputs[..inputs.len().min(MAX_INPUTS_PER_PACKET)].to_vec(),
+        ack: netplay.session.received_frames(),
+        checksum: netplay.session.latest_checksum(),
+    })
+}
+
+fn wind_down(world: &mut World, netplay: &mut NetplayResource, delta: Duration) {
+    match netplay.linger {
+        None => {
+            if netplay.session.is_synchronized()
+                && world
+                    .get_resource::<State<GameState>>()
+                    .is_some_and(|state| *state.get() != GameState::Playing)
+            {
+                netplay.linger = Some(Duration::ZERO);
+            }
+        }
+        Some(linger) if linger.as_secs_f32() < NETPLAY_LINGER_DURATION => {
+            netplay.linger = Some(linger + delta);
+        }
+        Some(_) => {
+            info!(
+                "Online game finished after {} frames and {} rollbacks",
+                netplay.session.current_frame(),
+                netplay.session.get_rollbacks()
+            );
+            world.resource_mut::<NetplayStateResource>().finished = true;
+        }
+    }
+}
@@ -1,9 +1,12 @@
+use crate::domain::game_mode::{GameMode, PlayerSlot};
 use crate::infrastructure::bevy::player::resources::{
-    PLAYER_HEIGHT, PLAYER_IMAGE, PLAYER_WIDTH, PLAYER_X, PLAYER_Y,
+    PLAYER_HEIGHT, PLAYER_IMAGE, PLAYER_WIDTH, PLAYER_Y, PlayerControls, SOLO_CONTROLS,
+    player_color, player_controls, player_start_x,
 };
 use bevy::asset::AssetServer;
+use bevy::color::Color;
 use bevy::math::Vec2;
-use bevy::prelude::{Bundle, Component, Sprite, Transform, default};
+use bevy::prelude::{Bundle, Component, Message, Sprite, Transform, default};
 
 #[derive(Bundle)]
 pub struct PlayerBundle {
@@ -13,25 +16,63 @@ pub struct PlayerBundle {
 }
 
 #[derive(Component, PartialEq, Debug)]
-pub struct PlayerComponent;
+pub struct PlayerComponent {
+    pub slot: PlayerSlot,
+    pub controls: PlayerControls,
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerFiredMessage;
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerReloadedMessage {
+    pub player: PlayerSlot,
+}
+
+impl PlayerReloadedMessage {
+    pub fn new(player: PlayerSlot) -> Self {
+        Self { player }
+    }
+}
+
+impl PlayerComponent {
+    pub fn new(slot: PlayerSlot, controls: PlayerControls) -> Self {
+        Self { slot, controls }
+    }
+}
+
+impl Default for PlayerComponent {
+    fn default() -> Self {
+        Self::new(PlayerSlot::One, SOLO_CONTROLS)
+    }
+}
 
 impl PlayerBundle {
-    pub fn new(asset_server: &AssetServer) -> Self {
+    pub fn new(asset_server: &AssetServer, mode: GameMode, slot: PlayerSlot) -> Self {
         Self {
-            player: PlayerComponent,
+            player: PlayerComponent::new(slot, player_controls(mode, slot)),
             sprite: Sprite {
                 image: asset_server.load(PLAYER_IMAGE),
+                color: player_color(slot),
                 custom_size: Some(Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT)),
                 ..default()
             },
-            transform: Transform::from_xyz(PLAYER_X, PLAYER_Y, 0.0),
+            transform: Transform::from_xyz(player_start_x(mode, slot), PLAYER_Y, 0.0),
         }
     }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.sprite.color = color;
+        self
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::bevy::player::resources::{
+        PLAYER_TWO_COLOR, PLAYER_TWO_CONTROLS, PLAYER_X,
+    };
     use bevy::asset::AssetPlugin;
     use bevy::image::Image;
     use bevy::prelude::*;
@@ -50,14 +91,18 @@ mod tests {
         let mut app = setup();
 
         let asset_server = app.world().resource::<AssetServer>().clone();
-        app.world_mut().spawn(PlayerBundle::new(&asset_server));
+        app.world_mut().spawn(PlayerBundle::new(
+            &asset_server,
+            GameMode::Single,
+            PlayerSlot::One,
+        ));
 
         let mut query = app
             .world_mut()
             .query::<(&PlayerComponent, &Transform, &Sprite)>();
         let (player, transform, sprite) = query.single(app.world()).expect("Player not found");
 
-        assert_eq!(*player, PlayerComponent);
+        assert_eq!(*player, PlayerComponent::default());
         assert_eq!(transform.translation.x, PLAYER_X);
         assert_eq!(transform.translation.y, PLAYER_Y);
         assert_eq!(
@@ -65,4 +110,23 @@ mod tests {
             Some(Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT))
         );
     }
+
+    #[test]
+    fn second_coop_player_is_tinted_and_uses_their_own_controls() {
+        let mut app = setup();
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        app.world_mut().spawn(PlayerBundle::new(
+            &asset_server,
+            GameMode::Coop,
+            PlayerSlot::Two,
+        ));
+
+        let mut query = app.world_mut().query::<(&PlayerComponent, &Sprite)>();
+        let (player, sprite) = query.single(app.world()).expect("Player not found");
+
+        assert_eq!(player.slot, PlayerSlot::Two);
+        assert_eq!(player.controls, PLAYER_TWO_CONTROLS);
+        assert_eq!(sprite.color, PLAYER_TWO_COLOR);
+    }
 }
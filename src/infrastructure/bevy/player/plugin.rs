@@ -1,35 +1,57 @@
-use crate::domain::player::Player;
-use crate::infrastructure::bevy::player::resources::PlayerResource;
+use crate::infrastructure::bevy::enemy_formation::systems::collisions_system;
+use crate::infrastructure::bevy::enemy_projectile::systems::collision_system;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::lives::systems::handle_player_killed_system;
+use crate::infrastructure::bevy::player::components::{PlayerFiredMessage, PlayerReloadedMessage};
+use crate::infrastructure::bevy::player::resources::{
+    PLAYER_SPEED, PlayerResource, PlayerSpeed, RulesetResource,
+};
 use crate::infrastructure::bevy::player::systems::{
-    on_enemy_projectile_hitting_player_system, player_fire_system, player_movement_system,
-    reload_player_weapon_system, respawn_player_system, spawn_player_system,
+    cool_down_player_weapons_system, on_enemy_projectile_hitting_player_system, player_fire_system,
+    player_movement_system, reload_player_weapon_system, reset_player_system,
+    respawn_player_system, spawn_player_system, upgrade_player_weapons_system,
 };
+use crate::infrastructure::bevy::player_projectile::systems::player_projectile_lifecycle_system;
 use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::IntoScheduleConfigs;
 
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(PlayerResource(Player::new()))
+        app.init_resource::<PlayerResource>()
+            .init_resource::<RulesetResource>()
+            .insert_resource(PlayerSpeed(PLAYER_SPEED))
+            .add_message::<PlayerFiredMessage>()
+            .add_message::<PlayerReloadedMessage>()
             .add_systems(Startup, spawn_player_system)
             .add_systems(
                 Update,
                 (
                     player_movement_system,
-                    player_fire_system,
-                    reload_player_weapon_system,
-                    respawn_player_system,
-                    on_enemy_projectile_hitting_player_system,
-                ),
-            );
+                    player_fire_system.after(cool_down_player_weapons_system),
+                    cool_down_player_weapons_system,
+                    reload_player_weapon_system.after(player_projectile_lifecycle_system),
+                    upgrade_player_weapons_system
+                        .after(collisions_system)
+                        .after(collision_system),
+                    on_enemy_projectile_hitting_player_system.after(collision_system),
+                    respawn_player_system
+                        .after(on_enemy_projectile_hitting_player_system)
+                        .after(handle_player_killed_system),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_player_system);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
     use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
     use crate::infrastructure::bevy::player_projectile::plugin::PlayerProjectilePlugin;
     use bevy_test::TestAppBuilder;
 
@@ -41,7 +63,9 @@ mod tests {
             .with_plugin(PlayerProjectilePlugin)
             .with_plugin(PlayerPlugin)
             .with_message::<EnemyKilledMessage>()
+            .with_message::<EnemyDamagedMessage>()
             .with_message::<PlayerKilledMessage>()
+            .with_message::<NewGameMessage>()
             .build();
     }
 }
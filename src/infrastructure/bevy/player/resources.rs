@@ -1,6 +1,9 @@
+use crate::domain::game_mode::{GameMode, PlayerSlot};
 use crate::domain::player::Player;
+use crate::domain::ruleset::Ruleset;
 use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
-use bevy::prelude::Resource;
+use bevy::color::Color;
+use bevy::prelude::{KeyCode, Resource};
 
 pub const PLAYER_IMAGE: &str = "player-green.png";
 pub const PLAYER_X: f32 = 0.0;
@@ -9,6 +12,140 @@ pub const PLAYER_WIDTH: f32 = GAME_AREA_WIDTH * 0.045;
 pub const PLAYER_HEIGHT: f32 = GAME_AREA_HEIGHT * 0.043;
 pub const DISTANCE_BETWEEN_PLAYER_AND_PROJECTILE: f32 = 25.0;
 pub const PLAYER_SPEED: f32 = 300.0;
+pub const COOP_PLAYER_OFFSET_X: f32 = GAME_AREA_WIDTH * 0.2;
+pub const PLAYER_ONE_COLOR: Color = Color::WHITE;
+pub const PLAYER_TWO_COLOR: Color = Color::srgb_u8(64, 200, 255);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerControls {
+    pub left: &'static [KeyCode],
+    pub right: &'static [KeyCode],
+    pub fire: &'static [KeyCode],
+}
+
+pub const SOLO_CONTROLS: PlayerControls = PlayerControls {
+    left: &[KeyCode::ArrowLeft, KeyCode::KeyA],
+    right: &[KeyCode::ArrowRight, KeyCode::KeyD],
+    fire: &[KeyCode::Space],
+};
+
+pub const PLAYER_ONE_CONTROLS: PlayerControls = PlayerControls {
+    left: &[KeyCode::KeyA],
+    right: &[KeyCode::KeyD],
+    fire: &[KeyCode::Space],
+};
+
+pub const PLAYER_TWO_CONTROLS: PlayerControls = PlayerControls {
+    left: &[KeyCode::ArrowLeft],
+    right: &[KeyCode::ArrowRight],
+    fire: &[KeyCode::Enter],
+};
+
+#[derive(Resource, Default)]
+pub struct PlayerResource(pub [Player; 2]);
 
 #[derive(Resource)]
-pub struct PlayerResource(pub Player);
+pub struct PlayerSpeed(pub f32);
+
+#[derive(Resource, Default)]
+pub struct RulesetResource(pub Ruleset);
+
+impl PlayerResource {
+    pub fn get(&self, slot: PlayerSlot) -> &Player {
+        &self.0[slot.index()]
+    }
+
+    pub fn get_mut(&mut self, slot: PlayerSlot) -> &mut Player {
+        &mut self.0[slot.index()]
+    }
+}
+
+pub fn player_controls(mode: GameMode, slot: PlayerSlot) -> PlayerControls {
+    match (mode, slot) {
+        (GameMode::Coop | GameMode::Versus | GameMode::Battle, PlayerSlot::One) => {
+            PLAYER_ONE_CONTROLS
+        }
+        (GameMode::Coop, PlayerSlot::Two) => PLAYER_TWO_CONTROLS,
+        _ => SOLO_CONTROLS,
+    }
+}
+
+pub fn player_color(slot: PlayerSlot) -> Color {
+    match slot {
+        PlayerSlot::One => PLAYER_ONE_COLOR,
+        PlayerSlot::Two => PLAYER_TWO_COLOR,
+    }
+}
+
+pub fn player_start_x(mode: GameMode, slot: PlayerSlot) -> f32 {
+    match (mode, slot) {
+        (GameMode::Coop, PlayerSlot::One) => PLAYER_X - COOP_PLAYER_OFFSET_X,
+        (GameMode::Coop, PlayerSlot::Two) => PLAYER_X + COOP_PLAYER_OFFSET_X,
+        _ => PLAYER_X,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::weapons::Armed;
+
+    #[test]
+    fn coop_players_have_separate_controls() {
+        let one = player_controls(GameMode::Coop, PlayerSlot::One);
+        let two = player_controls(GameMode::Coop, PlayerSlot::Two);
+
+        for key in one.left.iter().chain(one.right).chain(one.fire) {
+            assert!(!two.left.contains(key));
+            assert!(!two.right.contains(key));
+            assert!(!two.fire.contains(key));
+        }
+    }
+
+    #[test]
+    fn a_lone_player_keeps_both_key_sets() {
+        assert_eq!(
+            player_controls(GameMode::Single, PlayerSlot::One),
+            SOLO_CONTROLS
+        );
+        assert_eq!(
+            player_controls(GameMode::Alternating, PlayerSlot::One),
+            SOLO_CONTROLS
+        );
+    }
+
+    #[test]
+    fn the_versus_defender_leaves_the_arrows_to_the_gunner() {
+        assert_eq!(
+            player_controls(GameMode::Versus, PlayerSlot::One),
+            PLAYER_ONE_CONTROLS
+        );
+    }
+
+    #[test]
+    fn both_battle_players_share_the_left_hand_keys_inside_their_arenas() {
+        assert_eq!(
+            player_controls(GameMode::Battle, PlayerSlot::One),
+            PLAYER_ONE_CONTROLS
+        );
+    }
+
+    #[test]
+    fn coop_players_start_apart() {
+        assert!(
+            player_start_x(GameMode::Coop, PlayerSlot::One)
+                < player_start_x(GameMode::Coop, PlayerSlot::Two)
+        );
+        assert_eq!(player_start_x(GameMode::Single, PlayerSlot::One), PLAYER_X);
+    }
+
+    #[test]
+    fn each_player_keeps_their_own_weapon() {
+        let mut players = PlayerResource::default();
+
+        players.get_mut(PlayerSlot::Two).weapon_mut().fire();
+
+        assert!(!players.get(PlayerSlot::One).weapon().is_firing());
+        assert!(players.get(PlayerSlot::Two).weapon().is_firing());
+    }
+}
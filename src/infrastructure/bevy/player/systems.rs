@@ -1,37 +1,87 @@
-use crate::domain::weapons::Fireable;
+use crate::domain::game_mode::{GameMode, PlayerSlot};
+use crate::domain::weapons::Armed;
 use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
 use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
-use crate::infrastructure::bevy::game_area::resources::GAME_AREA_WIDTH;
-use crate::infrastructure::bevy::player::components::{PlayerBundle, PlayerComponent};
+use crate::infrastructure::bevy::game_area::resources::{ArenaResource, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::game_area::systems::spawn_in_arena;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::lives::resources::{
+    LivesResource, SecondPlayerLivesResource, is_out_of_coop_lives,
+};
+use crate::infrastructure::bevy::player::components::{
+    PlayerBundle, PlayerComponent, PlayerFiredMessage, PlayerReloadedMessage,
+};
 use crate::infrastructure::bevy::player::resources::{
-    DISTANCE_BETWEEN_PLAYER_AND_PROJECTILE, PLAYER_SPEED, PLAYER_WIDTH, PlayerResource,
+    DISTANCE_BETWEEN_PLAYER_AND_PROJECTILE, PLAYER_WIDTH, PlayerResource, PlayerSpeed,
+    RulesetResource, player_color,
 };
 use crate::infrastructure::bevy::player_projectile::components::{
-    PlayerProjectileBundle, PlayerProjectileExpiredMessage,
+    PlayerProjectileBundle, PlayerProjectileComponent, PlayerProjectileExpiredMessage,
 };
-use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileMovementTimerResource;
+use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileDuration;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+use crate::infrastructure::bevy::turns::resources::{TurnsResource, current_mode};
 use bevy::input::ButtonInput;
 use bevy::prelude::{
-    AssetServer, Commands, Entity, KeyCode, MessageReader, Query, Res, ResMut, Time, Transform,
-    With,
+    AssetServer, Commands, Entity, KeyCode, MessageReader, MessageWriter, Query, Res, ResMut, Time,
+    Transform, With,
 };
+use tracing::info;
+
+fn spawn_player(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    mode: GameMode,
+    slot: PlayerSlot,
+    arena: Option<&ArenaResource>,
+) {
+    let color = player_color(arena.map_or(slot, |arena| arena.slot));
 
-pub fn spawn_player_system(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.spawn(PlayerBundle::new(&asset_server));
+    spawn_in_arena(
+        commands,
+        arena,
+        PlayerBundle::new(asset_server, mode, slot).with_color(color),
+    );
+}
+
+fn spawn_players(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    mode: GameMode,
+    arena: Option<&ArenaResource>,
+) {
+    for &slot in mode.active_slots() {
+        spawn_player(commands, asset_server, mode, slot, arena);
+    }
+}
+
+pub fn spawn_player_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    turns: Option<Res<TurnsResource>>,
+    arena: Option<Res<ArenaResource>>,
+) {
+    spawn_players(
+        &mut commands,
+        &asset_server,
+        current_mode(turns.as_deref()),
+        arena.as_deref(),
+    );
 }
 
 pub fn player_movement_system(
     keyboard: Res<ButtonInput<KeyCode>>,
-    mut player_query: Query<&mut Transform, With<PlayerComponent>>,
+    mut player_query: Query<(&mut Transform, &PlayerComponent)>,
     time: Res<Time>,
+    player_speed: Res<PlayerSpeed>,
 ) {
-    let delta = PLAYER_SPEED * time.delta_secs();
+    let delta = player_speed.0 * time.delta_secs();
 
-    for mut transform in player_query.iter_mut() {
-        if keyboard.pressed(KeyCode::ArrowLeft) || keyboard.pressed(KeyCode::KeyA) {
+    for (mut transform, player) in player_query.iter_mut() {
+        if keyboard.any_pressed(player.controls.left.iter().copied()) {
             transform.translation.x -= delta;
         }
-        if keyboard.pressed(KeyCode::ArrowRight) || keyboard.pressed(KeyCode::KeyD) {
+        if keyboard.any_pressed(player.controls.right.iter().copied()) {
             transform.translation.x += delta;
         }
 
@@ -44,41 +94,102 @@ pub fn player_fire_system(
     mut commands: Commands,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut player_resource: ResMut<PlayerResource>,
-    player_query: Query<&Transform, With<PlayerComponent>>,
-    mut timer: ResMut<PlayerProjectileMovementTimerResource>,
+    player_query: Query<(&Transform, &PlayerComponent)>,
+    projectile_duration: Res<PlayerProjectileDuration>,
+    arena: Option<Res<ArenaResource>>,
+    mut player_fired_writer: MessageWriter<PlayerFiredMessage>,
 ) {
-    if keyboard.pressed(KeyCode::Space) && !player_resource.0.is_firing() {
-        for transform in player_query.iter() {
-            let translation = transform.translation;
+    for (transform, player) in player_query.iter() {
+        let shooter = player_resource.get_mut(player.slot);
 
-            commands.spawn(PlayerProjectileBundle::new(
-                translation.x,
-                translation.y + DISTANCE_BETWEEN_PLAYER_AND_PROJECTILE,
-            ));
+        if !keyboard.any_pressed(player.controls.fire.iter().copied())
+            || shooter.weapon_mut().fire().is_none()
+        {
+            continue;
+        }
 
-            player_resource.0.toggle_fire();
-            timer.0.reset();
+        let translation = transform.translation;
+
+        for shot in shooter.get_upgrade().shots() {
+            spawn_in_arena(
+                &mut commands,
+                arena.as_deref(),
+                PlayerProjectileBundle::with_projectile(
+                    translation.x + shot.offset,
+                    translation.y + DISTANCE_BETWEEN_PLAYER_AND_PROJECTILE,
+                    PlayerProjectileComponent::fired(player.slot, shot, projectile_duration.0),
+                ),
+            );
         }
+
+        player_fired_writer.write(PlayerFiredMessage);
     }
 }
 
 pub fn reload_player_weapon_system(
-    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
     mut projectile_expired_message: MessageReader<PlayerProjectileExpiredMessage>,
     mut player_resource: ResMut<PlayerResource>,
+    mut player_reloaded_writer: MessageWriter<PlayerReloadedMessage>,
 ) {
-    let mut should_reload = false;
+    for shooter in projectile_expired_message
+        .read()
+        .map(|message| message.player)
+    {
+        if player_resource
+            .get_mut(shooter)
+            .weapon_mut()
+            .shot_ended()
+            .is_some()
+        {
+            player_reloaded_writer.write(PlayerReloadedMessage::new(shooter));
+        }
+    }
+}
 
-    if enemy_killed_message.read().count() > 0 {
-        should_reload = true;
+pub fn cool_down_player_weapons_system(
+    time: Res<Time>,
+    power_ups: Option<Res<ActivePowerUpsResource>>,
+    mut player_resource: ResMut<PlayerResource>,
+    mut player_reloaded_writer: MessageWriter<PlayerReloadedMessage>,
+) {
+    let fire_rate_scale = power_ups.map_or(1.0, |power_ups| power_ups.0.fire_rate_scale());
+
+    for slot in PlayerSlot::ALL {
+        if player_resource
+            .get_mut(slot)
+            .weapon_mut()
+            .tick(time.delta_secs() * fire_rate_scale)
+            .is_some()
+        {
+            player_reloaded_writer.write(PlayerReloadedMessage::new(slot));
+        }
     }
+}
+
+pub fn upgrade_player_weapons_system(
+    ruleset: Res<RulesetResource>,
+    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
+    mut player_killed_message: MessageReader<PlayerKilledMessage>,
+    mut player_resource: ResMut<PlayerResource>,
+) {
+    let Some(kills_per_upgrade) = ruleset.0.kills_per_upgrade() else {
+        return;
+    };
 
-    if projectile_expired_message.read().count() > 0 {
-        should_reload = true;
+    for message in enemy_killed_message.read() {
+        let player = player_resource.get_mut(message.player);
+
+        if player.record_kill(kills_per_upgrade) {
+            info!(
+                "Player {} weapon upgraded to {}",
+                message.player.number(),
+                player.get_upgrade().as_str()
+            );
+        }
     }
 
-    if should_reload {
-        player_resource.0.toggle_fire();
+    for message in player_killed_message.read() {
+        player_resource.get_mut(message.player).downgrade();
     }
 }
 
@@ -86,20 +197,67 @@ pub fn respawn_player_system(
     mut commands: Commands,
     mut player_killed_message: MessageReader<PlayerKilledMessage>,
     asset_server: Res<AssetServer>,
+    turns: Option<Res<TurnsResource>>,
+    lives: Option<Res<LivesResource>>,
+    second_player_lives: Option<Res<SecondPlayerLivesResource>>,
+    arena: Option<Res<ArenaResource>>,
 ) {
-    for _ in player_killed_message.read() {
-        commands.spawn(PlayerBundle::new(&asset_server));
+    let mode = current_mode(turns.as_deref());
+
+    for message in player_killed_message.read() {
+        if let (Some(lives), Some(second_player_lives)) = (&lives, &second_player_lives)
+            && is_out_of_coop_lives(message.player, lives, second_player_lives)
+        {
+            info!("Player {} is out of lives", message.player.number());
+            continue;
+        }
+
+        spawn_player(
+            &mut commands,
+            &asset_server,
+            mode,
+            message.player,
+            arena.as_deref(),
+        );
     }
 }
 
+pub fn reset_player_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    asset_server: Res<AssetServer>,
+    turns: Option<Res<TurnsResource>>,
+    arena: Option<Res<ArenaResource>>,
+    mut player_resource: ResMut<PlayerResource>,
+    player_query: Query<Entity, With<PlayerComponent>>,
+) {
+    if new_game_message.read().count() == 0 {
+        return;
+    }
+
+    for player_entity in player_query.iter() {
+        commands.entity(player_entity).despawn();
+    }
+
+    *player_resource = PlayerResource::default();
+    spawn_players(
+        &mut commands,
+        &asset_server,
+        current_mode(turns.as_deref()),
+        arena.as_deref(),
+    );
+}
+
 pub fn on_enemy_projectile_hitting_player_system(
     mut commands: Commands,
     mut player_killed_event_writer: MessageReader<PlayerKilledMessage>,
-    player_query: Query<Entity, With<PlayerComponent>>,
+    player_query: Query<(Entity, &PlayerComponent)>,
 ) {
-    for _ in player_killed_event_writer.read() {
-        for player_entity in player_query.iter() {
-            commands.entity(player_entity).despawn();
+    for message in player_killed_event_writer.read() {
+        for (player_entity, player) in player_query.iter() {
+            if player.slot == message.player {
+                commands.entity(player_entity).despawn();
+            }
         }
     }
 }
@@ -107,21 +265,24 @@ pub fn on_enemy_projectile_hitting_player_system(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::player::Player;
+    use crate::domain::game_mode::{PlayerSlot, Turns};
     use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
     use crate::infrastructure::bevy::player::components::PlayerComponent;
-    use crate::infrastructure::bevy::player::resources::PlayerResource;
+    use crate::infrastructure::bevy::player::resources::{
+        PLAYER_ONE_CONTROLS, PLAYER_SPEED, PLAYER_TWO_CONTROLS, PlayerResource,
+    };
     use crate::infrastructure::bevy::player_projectile::components::{
         PlayerProjectileComponent, PlayerProjectileExpiredMessage,
     };
-    use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileMovementTimerResource;
+    use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileDuration;
     use bevy::app::{App, Update};
     use bevy::image::Image;
     use bevy::input::ButtonInput;
-    use bevy::prelude::{AssetApp, KeyCode, Timer, TimerMode};
+    use bevy::prelude::{AssetApp, KeyCode, Messages};
     use bevy_test::{
         TestAppBuilder, advance_time_by_seconds, contains_single_component, count_components,
-        get_resource_mut_or_fail, get_resource_or_fail, send_message, spawn_dummy_entity,
+        did_message_fire, get_resource_mut_or_fail, get_resource_or_fail, send_message,
+        spawn_dummy_entity,
     };
 
     fn setup() -> App {
@@ -131,11 +292,9 @@ mod tests {
             .with_time()
             .with_setup(|app| {
                 app.init_asset::<Image>()
-                    .insert_resource(PlayerResource(Player::new()))
-                    .insert_resource(PlayerProjectileMovementTimerResource(Timer::from_seconds(
-                        1.0,
-                        TimerMode::Once,
-                    )));
+                    .insert_resource(PlayerResource::default())
+                    .insert_resource(PlayerSpeed(PLAYER_SPEED))
+                    .insert_resource(PlayerProjectileDuration(1.0));
             })
             .build()
     }
@@ -143,7 +302,7 @@ mod tests {
     #[cfg(test)]
     mod spawn_player_system {
         use super::*;
-        use bevy::prelude::Startup;
+        use bevy::prelude::{ChildOf, Sprite, Startup};
 
         #[test]
         fn should_spawn_player() {
@@ -160,6 +319,45 @@ mod tests {
 
             assert_eq!(transform.translation.x, 0.0);
         }
+
+        #[test]
+        fn should_spawn_both_players_in_coop() {
+            let mut app = setup();
+            app.insert_resource(TurnsResource(Turns::new(GameMode::Coop)))
+                .add_systems(Startup, spawn_player_system);
+            app.update();
+
+            let mut slots = app
+                .world_mut()
+                .query::<&PlayerComponent>()
+                .iter(app.world())
+                .map(|player| player.slot)
+                .collect::<Vec<_>>();
+            slots.sort_by_key(|slot| slot.number());
+
+            assert_eq!(slots, vec![PlayerSlot::One, PlayerSlot::Two]);
+        }
+
+        #[test]
+        fn should_spawn_the_player_in_the_arena_with_its_color() {
+            let mut app = setup();
+            let root = app.world_mut().spawn_empty().id();
+            app.insert_resource(ArenaResource {
+                root,
+                slot: PlayerSlot::Two,
+            })
+            .add_systems(Startup, spawn_player_system);
+            app.update();
+
+            let mut query = app
+                .world_mut()
+                .query::<(&PlayerComponent, &Sprite, &ChildOf)>();
+            let (player, sprite, child_of) = query.single(app.world()).expect("Player not found");
+
+            assert_eq!(player.slot, PlayerSlot::One);
+            assert_eq!(sprite.color, player_color(PlayerSlot::Two));
+            assert_eq!(child_of.parent(), root);
+        }
     }
 
     #[cfg(test)]
@@ -170,8 +368,10 @@ mod tests {
 
         fn setup_movement(x_pos: f32) -> App {
             let mut app = setup();
-            app.world_mut()
-                .spawn((PlayerComponent, Transform::from_xyz(x_pos, 0.0, 0.0)));
+            app.world_mut().spawn((
+                PlayerComponent::default(),
+                Transform::from_xyz(x_pos, 0.0, 0.0),
+            ));
             app.add_systems(Update, player_movement_system);
             app
         }
@@ -268,17 +468,58 @@ mod tests {
             let diff = (transform.translation.x - boundary).abs();
             assert!(diff < 0.001);
         }
+
+        #[test]
+        fn should_only_move_the_player_bound_to_the_key() {
+            let mut app = setup();
+            app.add_systems(Update, player_movement_system);
+            let one = app
+                .world_mut()
+                .spawn((
+                    PlayerComponent::new(PlayerSlot::One, PLAYER_ONE_CONTROLS),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+            let two = app
+                .world_mut()
+                .spawn((
+                    PlayerComponent::new(PlayerSlot::Two, PLAYER_TWO_CONTROLS),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::ArrowRight);
+            advance_time_by_seconds(&mut app, 0.1);
+
+            app.update();
+
+            let x = |app: &App, entity| {
+                app.world()
+                    .get::<Transform>(entity)
+                    .expect("Transform not found")
+                    .translation
+                    .x
+            };
+
+            assert_eq!(x(&app, one), 0.0);
+            assert!(x(&app, two) > 0.0);
+        }
     }
 
     #[cfg(test)]
     mod player_fire_system {
         use super::*;
+        use crate::domain::weapons::WeaponUpgrade;
+        use bevy::prelude::IntoScheduleConfigs;
 
         fn setup_fire() -> App {
             let mut app = setup();
-            app.world_mut()
-                .spawn((PlayerComponent, Transform::from_xyz(0.0, 0.0, 0.0)));
-            app.add_systems(Update, player_fire_system);
+            app.world_mut().spawn((
+                PlayerComponent::default(),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ));
+            app.add_message::<PlayerFiredMessage>()
+                .add_systems(Update, player_fire_system);
             app
         }
 
@@ -293,9 +534,11 @@ mod tests {
             assert_eq!(count_components::<PlayerProjectileComponent>(&mut app), 1);
             assert!(
                 get_resource_or_fail::<PlayerResource>(&mut app)
-                    .0
+                    .get(PlayerSlot::One)
+                    .weapon()
                     .is_firing()
             );
+            assert!(did_message_fire::<PlayerFiredMessage>(&mut app));
         }
 
         #[test]
@@ -303,77 +546,424 @@ mod tests {
             let mut app = setup_fire();
 
             get_resource_mut_or_fail::<PlayerResource>(&mut app)
-                .0
-                .toggle_fire();
+                .get_mut(PlayerSlot::One)
+                .weapon_mut()
+                .fire();
             get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::Space);
 
             app.update();
 
             assert_eq!(count_components::<PlayerProjectileComponent>(&mut app), 0);
         }
+
+        #[test]
+        fn should_fire_the_whole_volley_of_the_upgrade() {
+            let mut app = setup_fire();
+            get_resource_mut_or_fail::<PlayerResource>(&mut app)
+                .get_mut(PlayerSlot::One)
+                .equip(WeaponUpgrade::Spread);
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::Space);
+            app.update();
+
+            let drifts = app
+                .world_mut()
+                .query::<&PlayerProjectileComponent>()
+                .iter(app.world())
+                .map(|projectile| projectile.drift)
+                .collect::<Vec<_>>();
+
+            assert_eq!(drifts.len(), WeaponUpgrade::Spread.shots().len());
+            assert!(drifts.iter().any(|drift| *drift < 0.0));
+            assert!(drifts.iter().any(|drift| *drift > 0.0));
+        }
+
+        #[test]
+        fn should_keep_several_volleys_in_flight() {
+            let mut app = setup_fire();
+            get_resource_mut_or_fail::<PlayerResource>(&mut app)
+                .get_mut(PlayerSlot::One)
+                .equip(WeaponUpgrade::PiercingLaser);
+            app.add_message::<PlayerReloadedMessage>().add_systems(
+                Update,
+                cool_down_player_weapons_system.before(player_fire_system),
+            );
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::Space);
+            app.update();
+            advance_time_by_seconds(&mut app, 1.0);
+            app.update();
+
+            assert_eq!(count_components::<PlayerProjectileComponent>(&mut app), 2);
+        }
+
+        #[test]
+        fn should_fire_with_the_second_players_own_weapon() {
+            let mut app = setup_fire();
+            app.world_mut().spawn((
+                PlayerComponent::new(PlayerSlot::Two, PLAYER_TWO_CONTROLS),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ));
+            get_resource_mut_or_fail::<PlayerResource>(&mut app)
+                .get_mut(PlayerSlot::One)
+                .weapon_mut()
+                .fire();
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::Enter);
+
+            app.update();
+
+            let shooters = app
+                .world_mut()
+                .query::<&PlayerProjectileComponent>()
+                .iter(app.world())
+                .map(|projectile| projectile.shooter)
+                .collect::<Vec<_>>();
+
+            assert_eq!(shooters, vec![PlayerSlot::Two]);
+            assert!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::Two)
+                    .weapon()
+                    .is_firing()
+            );
+        }
     }
 
     #[cfg(test)]
     mod reload_player_weapon_system {
         use super::*;
         use crate::domain::enemy::EnemyId;
+        use crate::domain::weapons::WeaponUpgrade;
 
-        fn setup_reload() -> App {
+        fn setup_unfired() -> App {
             let mut app = setup();
             app.add_message::<EnemyKilledMessage>()
                 .add_message::<PlayerProjectileExpiredMessage>()
+                .add_message::<PlayerReloadedMessage>()
                 .add_systems(Update, reload_player_weapon_system);
+            app
+        }
 
-            get_resource_mut_or_fail::<PlayerResource>(&mut app)
-                .0
-                .toggle_fire();
+        fn setup_reload() -> App {
+            let mut app = setup_unfired();
+            let mut players = get_resource_mut_or_fail::<PlayerResource>(&mut app);
+            players.get_mut(PlayerSlot::One).weapon_mut().fire();
+            players.get_mut(PlayerSlot::Two).weapon_mut().fire();
             app
         }
 
         #[test]
-        fn should_reload_on_enemy_killed() {
+        fn should_end_a_single_shot_per_expired_projectile() {
+            let mut app = setup_reload();
+            let mut players = get_resource_mut_or_fail::<PlayerResource>(&mut app);
+            players
+                .get_mut(PlayerSlot::One)
+                .equip(WeaponUpgrade::DoubleShot);
+            players.get_mut(PlayerSlot::One).weapon_mut().fire();
+
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::One),
+            );
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::One)
+                    .weapon()
+                    .shots_in_flight(),
+                2
+            );
+        }
+
+        #[test]
+        fn should_reload_on_projectile_expiry() {
             let mut app = setup_reload();
-            let dummy = spawn_dummy_entity(&mut app);
 
             send_message(
                 &mut app,
-                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy),
+                PlayerProjectileExpiredMessage::new(PlayerSlot::One),
             );
             app.update();
 
             assert!(
                 !get_resource_or_fail::<PlayerResource>(&mut app)
-                    .0
+                    .get(PlayerSlot::One)
+                    .weapon()
                     .is_firing()
             );
         }
 
         #[test]
-        fn should_reload_on_projectile_expiry() {
+        fn should_only_reload_the_shooters_weapon() {
+            let mut app = setup_reload();
+
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::Two),
+            );
+            app.update();
+
+            let players = get_resource_or_fail::<PlayerResource>(&mut app);
+            assert!(players.get(PlayerSlot::One).weapon().is_firing());
+            assert!(!players.get(PlayerSlot::Two).weapon().is_firing());
+        }
+
+        #[test]
+        fn should_announce_the_reload() {
+            let mut app = setup_reload();
+
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::One),
+            );
+            app.update();
+
+            let reloads = app
+                .world_mut()
+                .resource_mut::<Messages<PlayerReloadedMessage>>()
+                .drain()
+                .collect::<Vec<_>>();
+            assert_eq!(reloads, vec![PlayerReloadedMessage::new(PlayerSlot::One)]);
+        }
+
+        #[test]
+        fn should_ignore_a_second_message_for_the_same_shot() {
             let mut app = setup_reload();
+            let dummy = spawn_dummy_entity(&mut app);
 
-            send_message(&mut app, PlayerProjectileExpiredMessage);
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy, PlayerSlot::One),
+            );
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::One),
+            );
+            app.update();
+
+            let players = get_resource_or_fail::<PlayerResource>(&mut app);
+            assert!(players.get(PlayerSlot::One).weapon().can_fire());
+            assert_eq!(players.get(PlayerSlot::One).weapon().shots_in_flight(), 0);
+        }
+
+        #[test]
+        fn should_end_only_one_shot_for_a_kill_and_an_expiry() {
+            let mut app = setup_unfired();
+            let dummy = spawn_dummy_entity(&mut app);
+            let mut players = get_resource_mut_or_fail::<PlayerResource>(&mut app);
+            players
+                .get_mut(PlayerSlot::Two)
+                .equip(WeaponUpgrade::DoubleShot);
+            players.get_mut(PlayerSlot::Two).weapon_mut().fire();
+
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy, PlayerSlot::Two),
+            );
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::Two),
+            );
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::Two)
+                    .weapon()
+                    .shots_in_flight(),
+                1
+            );
+        }
+
+        #[test]
+        fn should_keep_the_slot_of_a_piercing_laser_until_it_expires() {
+            let mut app = setup_unfired();
+            let dummy = spawn_dummy_entity(&mut app);
+            let mut players = get_resource_mut_or_fail::<PlayerResource>(&mut app);
+            players
+                .get_mut(PlayerSlot::Two)
+                .equip(WeaponUpgrade::PiercingLaser);
+            players.get_mut(PlayerSlot::Two).weapon_mut().fire();
+
+            for id in 1..=3 {
+                send_message(
+                    &mut app,
+                    EnemyKilledMessage::new(dummy, EnemyId::new(id), dummy, PlayerSlot::Two),
+                );
+            }
+            app.update();
+
+            assert!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::Two)
+                    .weapon()
+                    .is_firing()
+            );
+
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::Two),
+            );
             app.update();
 
             assert!(
                 !get_resource_or_fail::<PlayerResource>(&mut app)
-                    .0
+                    .get(PlayerSlot::Two)
+                    .weapon()
                     .is_firing()
             );
         }
+
+        #[test]
+        fn should_ignore_stray_messages() {
+            let mut app = setup_unfired();
+
+            send_message(
+                &mut app,
+                PlayerProjectileExpiredMessage::new(PlayerSlot::One),
+            );
+            app.update();
+
+            assert!(!did_message_fire::<PlayerReloadedMessage>(&mut app));
+            assert!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::One)
+                    .weapon()
+                    .can_fire()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod cool_down_player_weapons_system {
+        use super::*;
+        use crate::domain::player::Player;
+        use crate::domain::weapons::Weapon;
+
+        #[test]
+        fn should_reload_once_the_cooldown_is_over() {
+            let mut app = setup();
+            app.add_message::<PlayerReloadedMessage>()
+                .add_systems(Update, cool_down_player_weapons_system);
+            let mut player = Player::new();
+            *player.weapon_mut() = Weapon::new(2, 0.5);
+            player.weapon_mut().fire();
+            get_resource_mut_or_fail::<PlayerResource>(&mut app).0[0] = player;
+
+            advance_time_by_seconds(&mut app, 0.6);
+            app.update();
+
+            assert!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::One)
+                    .weapon()
+                    .can_fire()
+            );
+            assert!(did_message_fire::<PlayerReloadedMessage>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod upgrade_player_weapons_system {
+        use super::*;
+        use crate::domain::enemy::EnemyId;
+        use crate::domain::ruleset::{MODERN_KILLS_PER_UPGRADE, Ruleset};
+        use crate::domain::weapons::WeaponUpgrade;
+        use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+
+        fn setup_upgrades(ruleset: Ruleset) -> App {
+            let mut app = setup();
+            app.insert_resource(RulesetResource(ruleset))
+                .add_message::<EnemyKilledMessage>()
+                .add_message::<PlayerKilledMessage>()
+                .add_systems(Update, upgrade_player_weapons_system);
+            app
+        }
+
+        fn kill_enemies(app: &mut App, count: u32) {
+            let dummy = spawn_dummy_entity(app);
+
+            for id in 1..=count {
+                send_message(
+                    app,
+                    EnemyKilledMessage::new(
+                        dummy,
+                        EnemyId::new(id as usize),
+                        dummy,
+                        PlayerSlot::Two,
+                    ),
+                );
+            }
+            app.update();
+        }
+
+        fn upgrade(app: &mut App, slot: PlayerSlot) -> WeaponUpgrade {
+            get_resource_or_fail::<PlayerResource>(app)
+                .get(slot)
+                .get_upgrade()
+        }
+
+        #[test]
+        fn modern_kills_should_upgrade_the_shooter() {
+            let mut app = setup_upgrades(Ruleset::Modern);
+
+            kill_enemies(&mut app, MODERN_KILLS_PER_UPGRADE);
+
+            assert_eq!(
+                upgrade(&mut app, PlayerSlot::Two),
+                WeaponUpgrade::DoubleShot
+            );
+            assert_eq!(upgrade(&mut app, PlayerSlot::One), WeaponUpgrade::Standard);
+        }
+
+        #[test]
+        fn classic_kills_should_keep_the_standard_weapon() {
+            let mut app = setup_upgrades(Ruleset::Classic);
+
+            kill_enemies(&mut app, MODERN_KILLS_PER_UPGRADE);
+
+            assert_eq!(upgrade(&mut app, PlayerSlot::Two), WeaponUpgrade::Standard);
+        }
+
+        #[test]
+        fn deaths_should_drop_an_upgrade() {
+            let mut app = setup_upgrades(Ruleset::Modern);
+            get_resource_mut_or_fail::<PlayerResource>(&mut app)
+                .get_mut(PlayerSlot::Two)
+                .equip(WeaponUpgrade::Spread);
+            let dummy = spawn_dummy_entity(&mut app);
+
+            send_message(&mut app, PlayerKilledMessage::new(dummy, PlayerSlot::Two));
+            app.update();
+
+            assert_eq!(
+                upgrade(&mut app, PlayerSlot::Two),
+                WeaponUpgrade::DoubleShot
+            );
+        }
     }
 
     #[cfg(test)]
     mod respawn_player_system {
+        use crate::domain::game_mode::{GameMode, PlayerSlot, Turns};
+        use crate::domain::lives::Lives;
         use crate::infrastructure::bevy::enemy_projectile::components::{
             EnemyProjectileComponent, PlayerKilledMessage,
         };
+        use crate::infrastructure::bevy::lives::resources::{
+            LivesResource, SecondPlayerLivesResource,
+        };
         use crate::infrastructure::bevy::player::components::PlayerComponent;
         use crate::infrastructure::bevy::player::systems::respawn_player_system;
         use crate::infrastructure::bevy::player::systems::tests::setup;
+        use crate::infrastructure::bevy::turns::resources::TurnsResource;
         use bevy::app::Update;
         use bevy::prelude::{Entity, With};
-        use bevy_test::{contains_single_component, count_components, despawn, send_message};
+        use bevy_test::{
+            contains_single_component, count_components, despawn, send_message, spawn_dummy_entity,
+        };
 
         #[test]
         fn should_respawn_player_when_killed() {
@@ -381,7 +971,7 @@ mod tests {
             app.add_message::<PlayerKilledMessage>()
                 .add_systems(Update, respawn_player_system);
 
-            app.world_mut().spawn(PlayerComponent);
+            app.world_mut().spawn(PlayerComponent::default());
             app.world_mut().spawn(EnemyProjectileComponent);
 
             let player_entity = app
@@ -400,11 +990,102 @@ mod tests {
 
             assert_eq!(count_components::<PlayerComponent>(&mut app), 0);
 
-            send_message(&mut app, PlayerKilledMessage::new(projectile_entity));
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile_entity, PlayerSlot::One),
+            );
+
+            app.update();
+
+            assert!(contains_single_component::<PlayerComponent>(&mut app));
+        }
+
+        #[test]
+        fn should_not_respawn_a_coop_player_without_lives() {
+            let mut app = setup();
+            app.add_message::<PlayerKilledMessage>()
+                .insert_resource(TurnsResource(Turns::new(GameMode::Coop)))
+                .insert_resource(LivesResource(Lives::with_count(2)))
+                .insert_resource(SecondPlayerLivesResource(Some(Lives::with_count(0))))
+                .add_systems(Update, respawn_player_system);
+            let projectile = spawn_dummy_entity(&mut app);
+
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::Two),
+            );
+            app.update();
+
+            assert_eq!(count_components::<PlayerComponent>(&mut app), 0);
+
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::One),
+            );
+            app.update();
+
+            assert!(contains_single_component::<PlayerComponent>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_player_system {
+        use super::*;
+
+        #[test]
+        fn should_replace_the_player_on_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>()
+                .add_systems(Update, reset_player_system);
+            app.world_mut().spawn(PlayerComponent::default());
+            app.world_mut().spawn(PlayerComponent::default());
+            get_resource_mut_or_fail::<PlayerResource>(&mut app)
+                .get_mut(PlayerSlot::One)
+                .weapon_mut()
+                .fire();
 
+            send_message(&mut app, NewGameMessage);
             app.update();
 
             assert!(contains_single_component::<PlayerComponent>(&mut app));
+            assert!(
+                !get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::One)
+                    .weapon()
+                    .is_firing()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod on_enemy_projectile_hitting_player_system {
+        use super::*;
+        use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+
+        #[test]
+        fn should_only_remove_the_player_that_was_hit() {
+            let mut app = setup();
+            app.add_message::<PlayerKilledMessage>()
+                .add_systems(Update, on_enemy_projectile_hitting_player_system);
+            app.world_mut()
+                .spawn(PlayerComponent::new(PlayerSlot::One, PLAYER_ONE_CONTROLS));
+            app.world_mut()
+                .spawn(PlayerComponent::new(PlayerSlot::Two, PLAYER_TWO_CONTROLS));
+            let projectile = spawn_dummy_entity(&mut app);
+
+            send_message(
+                &mut app,
+                PlayerKilledMessage::new(projectile, PlayerSlot::Two),
+            );
+            app.update();
+
+            let remaining = app
+                .world_mut()
+                .query::<&PlayerComponent>()
+                .single(app.world())
+                .expect("Player not found");
+
+            assert_eq!(remaining.slot, PlayerSlot::One);
         }
     }
 }
@@ -1,12 +1,18 @@
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::weapons::Shot;
+use crate::infrastructure::bevy::player::resources::PLAYER_TWO_COLOR;
 use crate::infrastructure::bevy::player_projectile::resources::{
-    PLAYER_PROJECTILE_COLOR, PLAYER_PROJECTILE_HEIGHT, PLAYER_PROJECTILE_WIDTH,
+    PLAYER_LASER_HEIGHT, PLAYER_PROJECTILE_COLOR, PLAYER_PROJECTILE_DURATION,
+    PLAYER_PROJECTILE_HEIGHT, PLAYER_PROJECTILE_WIDTH,
 };
 use bevy::math::Vec2;
-use bevy::prelude::{Bundle, Sprite, Transform, default};
+use bevy::prelude::{Bundle, Sprite, Timer, TimerMode, Transform, default};
 use bevy::prelude::{Component, Message};
 
 #[derive(Message)]
-pub struct PlayerProjectileExpiredMessage;
+pub struct PlayerProjectileExpiredMessage {
+    pub player: PlayerSlot,
+}
 
 #[derive(Bundle)]
 pub struct PlayerProjectileBundle {
@@ -15,16 +21,56 @@ pub struct PlayerProjectileBundle {
     pub transform: Transform,
 }
 
-#[derive(Component, PartialEq, Debug)]
-pub struct PlayerProjectileComponent;
+#[derive(Component, Clone, PartialEq, Debug)]
+pub struct PlayerProjectileComponent {
+    pub shooter: PlayerSlot,
+    pub lifetime: Timer,
+    pub drift: f32,
+    pub piercing: bool,
+}
+
+impl PlayerProjectileExpiredMessage {
+    pub fn new(player: PlayerSlot) -> Self {
+        PlayerProjectileExpiredMessage { player }
+    }
+}
+
+impl PlayerProjectileComponent {
+    pub fn new(shooter: PlayerSlot) -> Self {
+        Self::fired(shooter, &Shot::default(), PLAYER_PROJECTILE_DURATION)
+    }
+
+    pub fn fired(shooter: PlayerSlot, shot: &Shot, duration: f32) -> Self {
+        Self {
+            shooter,
+            lifetime: Timer::from_seconds(duration, TimerMode::Once),
+            drift: shot.drift,
+            piercing: shot.piercing,
+        }
+    }
+}
 
 impl PlayerProjectileBundle {
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(x: f32, y: f32, shooter: PlayerSlot) -> Self {
+        Self::with_projectile(x, y, PlayerProjectileComponent::new(shooter))
+    }
+
+    pub fn with_projectile(x: f32, y: f32, projectile: PlayerProjectileComponent) -> Self {
+        let color = match projectile.shooter {
+            PlayerSlot::One => PLAYER_PROJECTILE_COLOR,
+            PlayerSlot::Two => PLAYER_TWO_COLOR,
+        };
+        let height = if projectile.piercing {
+            PLAYER_LASER_HEIGHT
+        } else {
+            PLAYER_PROJECTILE_HEIGHT
+        };
+
         Self {
-            projectile: PlayerProjectileComponent,
+            projectile,
             sprite: Sprite {
-                color: PLAYER_PROJECTILE_COLOR,
-                custom_size: Some(Vec2::new(PLAYER_PROJECTILE_WIDTH, PLAYER_PROJECTILE_HEIGHT)),
+                color,
+                custom_size: Some(Vec2::new(PLAYER_PROJECTILE_WIDTH, height)),
                 ..default()
             },
             transform: Transform::from_xyz(x, y, 0.0),
@@ -35,6 +81,7 @@ impl PlayerProjectileBundle {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::weapons::WeaponUpgrade;
     use bevy_test::TestAppBuilder;
 
     #[test]
@@ -44,8 +91,11 @@ mod tests {
         let start_x = 100.0;
         let start_y = 50.0;
 
-        app.world_mut()
-            .spawn(PlayerProjectileBundle::new(start_x, start_y));
+        app.world_mut().spawn(PlayerProjectileBundle::new(
+            start_x,
+            start_y,
+            PlayerSlot::One,
+        ));
 
         let mut query = app
             .world_mut()
@@ -54,7 +104,7 @@ mod tests {
             .single(app.world())
             .expect("PlayerProjectile not found");
 
-        assert_eq!(*projectile, PlayerProjectileComponent);
+        assert_eq!(*projectile, PlayerProjectileComponent::new(PlayerSlot::One));
         assert_eq!(transform.translation.x, start_x);
         assert_eq!(transform.translation.y, start_y);
         assert_eq!(
@@ -63,4 +113,22 @@ mod tests {
         );
         assert_eq!(sprite.color, PLAYER_PROJECTILE_COLOR);
     }
+
+    #[test]
+    fn fired_projectiles_follow_their_shot() {
+        let shot = WeaponUpgrade::PiercingLaser.shots()[0];
+        let bundle = PlayerProjectileBundle::with_projectile(
+            0.0,
+            0.0,
+            PlayerProjectileComponent::fired(PlayerSlot::Two, &shot, 0.5),
+        );
+
+        assert!(bundle.projectile.piercing);
+        assert_eq!(bundle.projectile.lifetime.duration().as_secs_f32(), 0.5);
+        assert_eq!(
+            bundle.sprite.custom_size,
+            Some(Vec2::new(PLAYER_PROJECTILE_WIDTH, PLAYER_LASER_HEIGHT))
+        );
+        assert_eq!(bundle.sprite.color, PLAYER_TWO_COLOR);
+    }
 }
@@ -1,37 +1,45 @@
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
 use crate::infrastructure::bevy::enemy_formation::systems::collisions_system;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
 use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileExpiredMessage;
 use crate::infrastructure::bevy::player_projectile::resources::{
-    PLAYER_PROJECTILE_DURATION, PlayerProjectileMovementTimerResource,
+    PLAYER_PROJECTILE_DURATION, PLAYER_PROJECTILE_SPEED, PlayerProjectileDuration,
+    PlayerProjectileSpeed,
 };
 use crate::infrastructure::bevy::player_projectile::systems::{
     player_projectile_lifecycle_system, player_projectile_movement_system,
+    reset_player_projectiles_system,
 };
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
 use bevy::app::{App, Plugin};
-use bevy::prelude::{IntoScheduleConfigs, Timer, TimerMode, Update};
+use bevy::prelude::{IntoScheduleConfigs, Update};
 
 pub struct PlayerProjectilePlugin;
 
 impl Plugin for PlayerProjectilePlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(PlayerProjectileMovementTimerResource(Timer::from_seconds(
-            PLAYER_PROJECTILE_DURATION,
-            TimerMode::Once,
-        )))
-        .add_message::<PlayerProjectileExpiredMessage>()
-        .add_systems(
-            Update,
-            (
-                player_projectile_movement_system.after(collisions_system),
-                player_projectile_lifecycle_system.after(collisions_system),
-            ),
-        );
+        app.insert_resource(PlayerProjectileDuration(PLAYER_PROJECTILE_DURATION))
+            .insert_resource(PlayerProjectileSpeed(PLAYER_PROJECTILE_SPEED))
+            .init_resource::<DifficultyResource>()
+            .add_message::<PlayerProjectileExpiredMessage>()
+            .add_message::<UfoShotMessage>()
+            .add_systems(
+                Update,
+                (
+                    player_projectile_movement_system.after(collisions_system),
+                    player_projectile_lifecycle_system.after(collisions_system),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_player_projectiles_system);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
     use bevy_test::TestAppBuilder;
 
     #[test]
@@ -39,6 +47,8 @@ mod tests {
         let _app = TestAppBuilder::new()
             .with_plugin(PlayerProjectilePlugin)
             .with_message::<EnemyKilledMessage>()
+            .with_message::<EnemyDamagedMessage>()
+            .with_message::<NewGameMessage>()
             .build();
     }
 }
@@ -1,10 +1,15 @@
 use bevy::color::Color;
-use bevy::prelude::{Resource, Timer};
+use bevy::prelude::Resource;
 
 pub const PLAYER_PROJECTILE_SPEED: f32 = 500.0;
 pub const PLAYER_PROJECTILE_DURATION: f32 = 1.2;
 pub const PLAYER_PROJECTILE_WIDTH: f32 = 5.0;
 pub const PLAYER_PROJECTILE_HEIGHT: f32 = 15.0;
+pub const PLAYER_LASER_HEIGHT: f32 = 30.0;
 pub const PLAYER_PROJECTILE_COLOR: Color = Color::srgb(1.0, 1.0, 1.0);
+
+#[derive(Resource)]
+pub struct PlayerProjectileDuration(pub f32);
+
 #[derive(Resource)]
-pub struct PlayerProjectileMovementTimerResource(pub Timer);
+pub struct PlayerProjectileSpeed(pub f32);
@@ -1,77 +1,114 @@
-use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
-use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 use crate::infrastructure::bevy::player_projectile::components::{
     PlayerProjectileComponent, PlayerProjectileExpiredMessage,
 };
-use crate::infrastructure::bevy::player_projectile::resources::{
-    PLAYER_PROJECTILE_SPEED, PlayerProjectileMovementTimerResource,
-};
+use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileSpeed;
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
 use bevy::prelude::{
-    Commands, Entity, MessageReader, MessageWriter, Query, Res, ResMut, Time, Transform, With,
+    Commands, Entity, MessageReader, MessageWriter, Query, Res, Time, Transform, With,
 };
+use std::collections::HashSet;
+
+pub fn reset_player_projectiles_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    query: Query<Entity, With<PlayerProjectileComponent>>,
+) {
+    if new_game_message.read().count() > 0 {
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
 
 pub fn player_projectile_movement_system(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<PlayerProjectileComponent>>,
+    projectile_speed: Res<PlayerProjectileSpeed>,
+    difficulty: Res<DifficultyResource>,
+    mut query: Query<(&mut Transform, &PlayerProjectileComponent)>,
 ) {
-    for mut transform in query.iter_mut() {
-        transform.translation.y += PLAYER_PROJECTILE_SPEED * time.delta_secs();
+    let distance =
+        projectile_speed.0 * difficulty.0.player_projectile_speed_scale() * time.delta_secs();
+
+    for (mut transform, projectile) in query.iter_mut() {
+        transform.translation.y += distance;
+        transform.translation.x += projectile.drift * distance;
     }
 }
 
 pub fn player_projectile_lifecycle_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut timer: ResMut<PlayerProjectileMovementTimerResource>,
-    query: Query<(Entity, &mut Transform), With<PlayerProjectileComponent>>,
+    mut query: Query<(Entity, &Transform, &mut PlayerProjectileComponent)>,
     mut message_writer: MessageWriter<PlayerProjectileExpiredMessage>,
     mut message_reader: MessageReader<EnemyKilledMessage>,
+    mut enemy_damaged_message: MessageReader<EnemyDamagedMessage>,
+    mut ufo_shot_message: MessageReader<UfoShotMessage>,
 ) {
-    timer.0.tick(time.delta());
-
-    for message in message_reader.read() {
-        commands.entity(message.projectile_entity).despawn();
-        return;
+    let mut ended = HashSet::new();
+
+    let stopped = message_reader
+        .read()
+        .map(|message| (message.projectile_entity, false))
+        .chain(
+            enemy_damaged_message
+                .read()
+                .map(|message| (message.projectile_entity, true)),
+        )
+        .chain(
+            ufo_shot_message
+                .read()
+                .map(|message| (message.projectile_entity, false)),
+        );
+
+    for (projectile_entity, stopped_by_armor) in stopped {
+        let Ok((entity, _, projectile)) = query.get(projectile_entity) else {
+            continue;
+        };
+
+        if (stopped_by_armor || !projectile.piercing) && ended.insert(entity) {
+            commands.entity(entity).despawn();
+            message_writer.write(PlayerProjectileExpiredMessage::new(projectile.shooter));
+        }
     }
 
     let top_bound = GAME_AREA_HEIGHT / 2.0;
-    let mut reset_needed = false;
+    let side_bound = GAME_AREA_WIDTH / 2.0;
 
-    for (entity, transform) in query.iter() {
-        if transform.translation.y > top_bound {
-            commands.entity(entity).despawn();
-            reset_needed = true;
+    for (entity, transform, mut projectile) in query.iter_mut() {
+        if ended.contains(&entity) {
+            continue;
         }
-    }
 
-    if timer.0.just_finished() {
-        for (entity, _) in query.iter() {
-            if !reset_needed {
-                commands.entity(entity).despawn();
-                reset_needed = true;
-            }
-        }
-    }
+        let expired = projectile.lifetime.tick(time.delta()).is_finished()
+            || transform.translation.y > top_bound
+            || transform.translation.x.abs() > side_bound;
 
-    if reset_needed {
-        message_writer.write(PlayerProjectileExpiredMessage);
-        timer.0.finish();
+        if expired {
+            commands.entity(entity).despawn();
+            message_writer.write(PlayerProjectileExpiredMessage::new(projectile.shooter));
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::infrastructure::bevy::enemy::components::{EnemyDamagedMessage, EnemyKilledMessage};
     use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
     use crate::infrastructure::bevy::player_projectile::components::{
         PlayerProjectileComponent, PlayerProjectileExpiredMessage,
     };
     use crate::infrastructure::bevy::player_projectile::resources::{
-        PLAYER_PROJECTILE_SPEED, PlayerProjectileMovementTimerResource,
+        PLAYER_PROJECTILE_SPEED, PlayerProjectileSpeed,
     };
     use bevy::app::{App, Update};
-    use bevy::prelude::{Timer, TimerMode, Transform};
+    use bevy::prelude::{Messages, Transform};
     use bevy_test::{
         TestAppBuilder, advance_time_by_seconds, contains_entity, did_component_despawn,
         did_message_fire, get_component_or_fail, send_message, spawn_dummy_entity,
@@ -83,10 +120,10 @@ mod tests {
             .with_setup(|app| {
                 app.add_message::<PlayerProjectileExpiredMessage>()
                     .add_message::<EnemyKilledMessage>()
-                    .insert_resource(PlayerProjectileMovementTimerResource(Timer::from_seconds(
-                        1.0,
-                        TimerMode::Once,
-                    )));
+                    .add_message::<EnemyDamagedMessage>()
+                    .add_message::<UfoShotMessage>()
+                    .insert_resource(PlayerProjectileSpeed(PLAYER_PROJECTILE_SPEED))
+                    .init_resource::<DifficultyResource>();
             })
             .build()
     }
@@ -95,7 +132,13 @@ mod tests {
     mod player_projectile_lifecycle_system {
         use super::*;
         use crate::domain::enemy::EnemyId;
-        use bevy_test::get_resource_mut_or_fail;
+        use crate::domain::weapons::WeaponUpgrade;
+
+        fn spawn_projectile(app: &mut App, projectile: PlayerProjectileComponent) -> Entity {
+            app.world_mut()
+                .spawn((projectile, Transform::from_xyz(0.0, 0.0, 0.0)))
+                .id()
+        }
 
         #[test]
         fn should_despawn_when_enemy_is_killed() {
@@ -103,16 +146,179 @@ mod tests {
             app.add_systems(Update, player_projectile_lifecycle_system);
 
             let enemy_entity = spawn_dummy_entity(&mut app);
-            let player_projectile_entity = spawn_dummy_entity(&mut app);
+            let player_projectile_entity =
+                spawn_projectile(&mut app, PlayerProjectileComponent::new(PlayerSlot::One));
 
             send_message(
                 &mut app,
-                EnemyKilledMessage::new(enemy_entity, EnemyId::new(1), player_projectile_entity),
+                EnemyKilledMessage::new(
+                    enemy_entity,
+                    EnemyId::new(1),
+                    player_projectile_entity,
+                    PlayerSlot::One,
+                ),
             );
 
             app.update();
 
             assert!(!contains_entity(&app, player_projectile_entity));
+            assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_despawn_when_it_shoots_down_a_ufo() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let projectile =
+                spawn_projectile(&mut app, PlayerProjectileComponent::new(PlayerSlot::One));
+
+            send_message(
+                &mut app,
+                UfoShotMessage::new(projectile, PlayerSlot::One, 100),
+            );
+            app.update();
+
+            assert!(!contains_entity(&app, projectile));
+            assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
+        }
+
+        #[test]
+        fn armor_should_stop_even_piercing_projectiles() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let laser = spawn_projectile(
+                &mut app,
+                PlayerProjectileComponent::fired(
+                    PlayerSlot::One,
+                    &WeaponUpgrade::PiercingLaser.shots()[0],
+                    1.0,
+                ),
+            );
+
+            send_message(
+                &mut app,
+                EnemyDamagedMessage::new(EnemyId::new(1), laser, PlayerSlot::One),
+            );
+            app.update();
+
+            assert!(!contains_entity(&app, laser));
+            assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
+        }
+
+        #[test]
+        fn piercing_projectiles_should_survive_their_kills() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let enemy_entity = spawn_dummy_entity(&mut app);
+            let laser = spawn_projectile(
+                &mut app,
+                PlayerProjectileComponent::fired(
+                    PlayerSlot::One,
+                    &WeaponUpgrade::PiercingLaser.shots()[0],
+                    1.0,
+                ),
+            );
+
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(enemy_entity, EnemyId::new(1), laser, PlayerSlot::One),
+            );
+            app.update();
+
+            assert!(contains_entity(&app, laser));
+            assert!(!did_message_fire::<PlayerProjectileExpiredMessage>(
+                &mut app
+            ));
+        }
+
+        #[test]
+        fn should_ignore_kills_by_unknown_projectiles() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let enemy_entity = spawn_dummy_entity(&mut app);
+            let stray = spawn_dummy_entity(&mut app);
+
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(enemy_entity, EnemyId::new(1), stray, PlayerSlot::One),
+            );
+            app.update();
+
+            assert!(!did_message_fire::<PlayerProjectileExpiredMessage>(
+                &mut app
+            ));
+        }
+
+        #[test]
+        fn each_projectile_should_keep_its_own_lifetime() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let short = spawn_projectile(
+                &mut app,
+                PlayerProjectileComponent::fired(PlayerSlot::One, &Default::default(), 0.5),
+            );
+            let long = spawn_projectile(
+                &mut app,
+                PlayerProjectileComponent::fired(PlayerSlot::One, &Default::default(), 2.0),
+            );
+
+            advance_time_by_seconds(&mut app, 1.0);
+            app.update();
+
+            assert!(!contains_entity(&app, short));
+            assert!(contains_entity(&app, long));
+        }
+
+        #[test]
+        fn should_despawn_every_projectile_that_hit_in_the_same_frame() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            let enemy_entity = spawn_dummy_entity(&mut app);
+            let first = spawn_projectile(&mut app, PlayerProjectileComponent::new(PlayerSlot::One));
+            let second =
+                spawn_projectile(&mut app, PlayerProjectileComponent::new(PlayerSlot::Two));
+
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(enemy_entity, EnemyId::new(1), first, PlayerSlot::One),
+            );
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(enemy_entity, EnemyId::new(2), second, PlayerSlot::Two),
+            );
+
+            app.update();
+
+            assert!(!contains_entity(&app, first));
+            assert!(!contains_entity(&app, second));
+        }
+
+        #[test]
+        fn should_tell_whose_projectile_expired() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_lifecycle_system);
+
+            app.world_mut().spawn((
+                PlayerProjectileComponent::new(PlayerSlot::Two),
+                Transform::from_xyz(0.0, GAME_AREA_HEIGHT, 0.0),
+            ));
+
+            app.update();
+
+            let players = app
+                .world()
+                .resource::<Messages<PlayerProjectileExpiredMessage>>()
+                .iter_current_update_messages()
+                .map(|message| message.player)
+                .collect::<Vec<_>>();
+
+            assert_eq!(players, vec![PlayerSlot::Two]);
         }
 
         #[test]
@@ -123,16 +329,13 @@ mod tests {
             let out_of_bounds_y = (GAME_AREA_HEIGHT / 2.0) + 10.0;
 
             app.world_mut().spawn((
-                PlayerProjectileComponent,
+                PlayerProjectileComponent::new(PlayerSlot::One),
                 Transform::from_xyz(0.0, out_of_bounds_y, 0.0),
             ));
 
             advance_time_by_seconds(&mut app, 0.01);
             app.update();
 
-            let timer = get_resource_mut_or_fail::<PlayerProjectileMovementTimerResource>(&mut app);
-            assert!(timer.0.is_finished());
-
             assert!(did_component_despawn::<PlayerProjectileComponent>(&mut app));
             assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
         }
@@ -143,16 +346,13 @@ mod tests {
             app.add_systems(Update, player_projectile_lifecycle_system);
 
             app.world_mut().spawn((
-                PlayerProjectileComponent,
+                PlayerProjectileComponent::new(PlayerSlot::One),
                 Transform::from_xyz(0.0, 0.0, 0.0),
             ));
 
             advance_time_by_seconds(&mut app, 2.0);
             app.update();
 
-            let timer = get_resource_mut_or_fail::<PlayerProjectileMovementTimerResource>(&mut app);
-            assert!(timer.0.is_finished());
-
             assert!(did_component_despawn::<PlayerProjectileComponent>(&mut app));
             assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
         }
@@ -161,6 +361,9 @@ mod tests {
     #[cfg(test)]
     mod player_projectile_movement_system {
         use super::*;
+        use crate::domain::power_up::{ActivePowerUps, PowerUpKind};
+        use crate::domain::weapons::WeaponUpgrade;
+        use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
 
         #[test]
         fn should_move_projectile_upwards() {
@@ -170,7 +373,7 @@ mod tests {
             let projectile = app
                 .world_mut()
                 .spawn((
-                    PlayerProjectileComponent,
+                    PlayerProjectileComponent::new(PlayerSlot::One),
                     Transform::from_xyz(0.0, 0.0, 0.0),
                 ))
                 .id();
@@ -184,5 +387,100 @@ mod tests {
 
             assert!((transform.translation.y - expected_y).abs() < 0.001);
         }
+
+        #[test]
+        fn should_move_slower_on_harder_difficulties() {
+            let mut app = setup();
+            app.insert_resource(DifficultyResource(Difficulty::Arcade))
+                .add_systems(Update, player_projectile_movement_system);
+
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    PlayerProjectileComponent::new(PlayerSlot::One),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            let delta_time = 0.1;
+            advance_time_by_seconds(&mut app, delta_time);
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_y = PLAYER_PROJECTILE_SPEED
+                * Difficulty::Arcade.player_projectile_speed_scale()
+                * delta_time;
+
+            assert!((transform.translation.y - expected_y).abs() < 0.001);
+        }
+
+        #[test]
+        fn should_keep_its_speed_during_rapid_fire() {
+            let mut power_ups = ActivePowerUps::default();
+            power_ups.activate(PowerUpKind::RapidFire);
+
+            let mut app = setup();
+            app.insert_resource(ActivePowerUpsResource(power_ups))
+                .add_systems(Update, player_projectile_movement_system);
+
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    PlayerProjectileComponent::new(PlayerSlot::One),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            advance_time_by_seconds(&mut app, 0.1);
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_y = PLAYER_PROJECTILE_SPEED * 0.1;
+
+            assert!((transform.translation.y - expected_y).abs() < 0.001);
+        }
+
+        #[test]
+        fn should_drift_spread_shots_sideways() {
+            let mut app = setup();
+            app.add_systems(Update, player_projectile_movement_system);
+
+            let shot = WeaponUpgrade::Spread.shots()[2];
+            let projectile = app
+                .world_mut()
+                .spawn((
+                    PlayerProjectileComponent::fired(PlayerSlot::One, &shot, 1.0),
+                    Transform::from_xyz(0.0, 0.0, 0.0),
+                ))
+                .id();
+
+            advance_time_by_seconds(&mut app, 0.1);
+            app.update();
+
+            let transform = get_component_or_fail::<Transform>(&mut app, projectile);
+            let expected_x = PLAYER_PROJECTILE_SPEED * 0.1 * shot.drift;
+
+            assert!((transform.translation.x - expected_x).abs() < 0.001);
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_player_projectiles_system {
+        use super::*;
+        use bevy_test::{count_components, send_message};
+
+        #[test]
+        fn should_despawn_projectiles_on_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>()
+                .add_systems(Update, reset_player_projectiles_system);
+            app.world_mut()
+                .spawn(PlayerProjectileComponent::new(PlayerSlot::One));
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<PlayerProjectileComponent>(&mut app), 0);
+        }
     }
 }
@@ -0,0 +1,89 @@
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::power_up::PowerUpKind;
+use crate::infrastructure::bevy::power_up::resources::{
+    POWER_UP_FONT_COLOR, POWER_UP_FONT_SIZE, POWER_UP_HEIGHT, POWER_UP_WIDTH, power_up_color,
+};
+use bevy::asset::Handle;
+use bevy::math::Vec2;
+use bevy::prelude::{Bundle, Component, Message, Sprite, Transform, default};
+use bevy::text::{Font, TextColor, TextFont};
+use bevy::ui::widget::Text;
+
+#[derive(Component, PartialEq, Debug)]
+pub struct PowerUpComponent {
+    pub kind: PowerUpKind,
+}
+
+#[derive(Bundle)]
+pub struct PowerUpBundle {
+    pub power_up: PowerUpComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl PowerUpBundle {
+    pub fn new(kind: PowerUpKind, x: f32, y: f32) -> Self {
+        Self {
+            power_up: PowerUpComponent { kind },
+            sprite: Sprite {
+                color: power_up_color(kind),
+                custom_size: Some(Vec2::new(POWER_UP_WIDTH, POWER_UP_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, y, 0.0),
+        }
+    }
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct PowerUpCollectedMessage {
+    pub kind: PowerUpKind,
+    pub player: PlayerSlot,
+}
+
+impl PowerUpCollectedMessage {
+    pub fn new(kind: PowerUpKind, player: PlayerSlot) -> Self {
+        Self { kind, player }
+    }
+}
+
+#[derive(Component, PartialEq, Debug)]
+pub struct PowerUpLabelComponent;
+
+#[derive(Bundle)]
+pub struct PowerUpLabelBundle {
+    pub power_up_label: PowerUpLabelComponent,
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl PowerUpLabelBundle {
+    pub fn new(font: Handle<Font>) -> Self {
+        Self {
+            power_up_label: PowerUpLabelComponent,
+            text: Text::new(""),
+            text_font: TextFont {
+                font,
+                font_size: POWER_UP_FONT_SIZE,
+                ..default()
+            },
+            text_color: TextColor(POWER_UP_FONT_COLOR),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capsules_are_colored_by_kind() {
+        let bundle = PowerUpBundle::new(PowerUpKind::TimeSlow, 10.0, 20.0);
+
+        assert_eq!(bundle.power_up.kind, PowerUpKind::TimeSlow);
+        assert_eq!(bundle.sprite.color, power_up_color(PowerUpKind::TimeSlow));
+        assert_eq!(bundle.transform.translation.x, 10.0);
+        assert_eq!(bundle.transform.translation.y, 20.0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::infrastructure::bevy::difficulty::systems::spawn_difficulty_label_system;
+use crate::infrastructure::bevy::enemy_formation::resources::GameRngResource;
+use crate::infrastructure::bevy::enemy_formation::systems::{
+    collisions_system, enemy_formation_movement_system,
+};
+use crate::infrastructure::bevy::game_state::resources::{GameplaySystems, HudSystems};
+use crate::infrastructure::bevy::lives::components::BonusLifeMessage;
+use crate::infrastructure::bevy::lives::systems::spawn_lives_system;
+use crate::infrastructure::bevy::power_up::components::PowerUpCollectedMessage;
+use crate::infrastructure::bevy::power_up::resources::ActivePowerUpsResource;
+use crate::infrastructure::bevy::power_up::systems::{
+    apply_power_ups_system, collect_power_ups_system, drop_power_ups_system,
+    power_up_movement_system, reset_power_ups_system, spawn_power_up_label_system,
+    tick_power_ups_system, update_power_up_label_system,
+};
+use crate::infrastructure::bevy::shield_formation::components::RebuildShieldsMessage;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::IntoScheduleConfigs;
+
+pub struct PowerUpPlugin;
+
+impl Plugin for PowerUpPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActivePowerUpsResource>()
+            .init_resource::<GameRngResource>()
+            .add_message::<PowerUpCollectedMessage>()
+            .add_message::<BonusLifeMessage>()
+            .add_message::<RebuildShieldsMessage>()
+            .add_systems(
+                Startup,
+                spawn_power_up_label_system
+                    .after(spawn_difficulty_label_system)
+                    .before(spawn_lives_system),
+            )
+            .add_systems(
+                Update,
+                (
+                    drop_power_ups_system
+                        .after(collisions_system)
+                        .before(enemy_formation_movement_system),
+                    power_up_movement_system,
+                    collect_power_ups_system.after(power_up_movement_system),
+                    apply_power_ups_system.after(collect_power_ups_system),
+                    tick_power_ups_system,
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_power_ups_system)
+            .add_systems(
+                Update,
+                update_power_up_label_system
+                    .after(reset_power_ups_system)
+                    .in_set(HudSystems),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::header::plugin::HeaderPlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(HeaderPlugin)
+            .with_plugin(PowerUpPlugin)
+            .with_message::<EnemyKilledMessage>()
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
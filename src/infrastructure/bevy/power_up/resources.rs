@@ -0,0 +1,52 @@
+use crate::domain::power_up::{ActivePowerUps, PowerUpKind};
+use bevy::color::Color;
+use bevy::prelude::Resource;
+
+pub const POWER_UP_SPEED: f32 = 200.0;
+pub const POWER_UP_WIDTH: f32 = 14.0;
+pub const POWER_UP_HEIGHT: f32 = 14.0;
+pub const POWER_UP_FONT_SIZE: f32 = 14.0;
+pub const POWER_UP_FONT_COLOR: Color = Color::srgb_u8(120, 255, 120);
+
+#[derive(Resource, Default)]
+pub struct ActivePowerUpsResource(pub ActivePowerUps);
+
+pub fn power_up_color(kind: PowerUpKind) -> Color {
+    match kind {
+        PowerUpKind::RapidFire => Color::srgb_u8(255, 140, 0),
+        PowerUpKind::ShieldRepair => Color::srgb_u8(0, 200, 0),
+        PowerUpKind::ExtraLife => Color::srgb_u8(255, 60, 120),
+        PowerUpKind::TimeSlow => Color::srgb_u8(80, 160, 255),
+    }
+}
+
+pub fn power_up_label(power_ups: &ActivePowerUps) -> String {
+    power_ups
+        .iter()
+        .map(|active| {
+            format!(
+                "{} {}s",
+                active.get_kind().as_str().to_uppercase(),
+                active.get_remaining().ceil()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_list_the_seconds_left_of_each_power_up() {
+        let mut power_ups = ActivePowerUps::new();
+        assert_eq!(power_up_label(&power_ups), "");
+
+        power_ups.activate(PowerUpKind::RapidFire);
+        power_ups.activate(PowerUpKind::TimeSlow);
+        power_ups.tick(2.5);
+
+        assert_eq!(power_up_label(&power_ups), "RAPID FIRE 8s  TIME SLOW 4s");
+    }
+}
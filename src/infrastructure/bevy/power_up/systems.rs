@@ -0,0 +1,491 @@
+use crate::domain::collision::check_aabb_collision;
+use crate::domain::power_up::{ActivePowerUps, POWER_UP_DROP_CHANCE, PowerUpKind};
+use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+use crate::infrastructure::bevy::enemy_formation::resources::GameRngResource;
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::header::components::HeaderComponent;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::lives::components::BonusLifeMessage;
+use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::power_up::components::{
+    PowerUpBundle, PowerUpCollectedMessage, PowerUpComponent, PowerUpLabelBundle,
+    PowerUpLabelComponent,
+};
+use crate::infrastructure::bevy::power_up::resources::{
+    ActivePowerUpsResource, POWER_UP_SPEED, power_up_label,
+};
+use crate::infrastructure::bevy::shield_formation::components::RebuildShieldsMessage;
+use bevy::asset::AssetServer;
+use bevy::math::Vec2;
+use bevy::prelude::{
+    Commands, DetectChanges, Entity, MessageReader, MessageWriter, Query, Res, ResMut, Sprite,
+    Text, Time, Transform, With,
+};
+use rand::Rng;
+use rand::prelude::IndexedRandom;
+use tracing::info;
+
+pub fn spawn_power_up_label_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    header_query: Query<Entity, With<HeaderComponent>>,
+) {
+    if let Ok(header) = header_query.single() {
+        commands.entity(header).with_children(|parent| {
+            parent.spawn(PowerUpLabelBundle::new(asset_server.load(FONT)));
+        });
+    }
+}
+
+pub fn drop_power_ups_system(
+    mut commands: Commands,
+    mut rng: ResMut<GameRngResource>,
+    mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
+    enemy_query: Query<&Transform>,
+) {
+    for message in enemy_killed_message.read() {
+        let Ok(transform) = enemy_query.get(message.enemy_entity) else {
+            continue;
+        };
+
+        if !rng.0.random_bool(POWER_UP_DROP_CHANCE) {
+            continue;
+        }
+
+        if let Some(kind) = PowerUpKind::ALL.choose(&mut rng.0) {
+            commands.spawn(PowerUpBundle::new(
+                *kind,
+                transform.translation.x,
+                transform.translation.y,
+            ));
+        }
+    }
+}
+
+pub fn power_up_movement_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Transform), With<PowerUpComponent>>,
+) {
+    for (entity, mut transform) in query.iter_mut() {
+        transform.translation.y -= POWER_UP_SPEED * time.delta_secs();
+
+        if transform.translation.y < -GAME_AREA_HEIGHT / 2.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn collect_power_ups_system(
+    mut commands: Commands,
+    power_up_query: Query<(Entity, &Transform, &Sprite, &PowerUpComponent)>,
+    player_query: Query<(&Transform, &Sprite, &PlayerComponent)>,
+    mut power_up_collected_writer: MessageWriter<PowerUpCollectedMessage>,
+) {
+    for (power_up_entity, power_up_transform, power_up_sprite, power_up) in power_up_query.iter() {
+        let power_up_size = power_up_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+        let collector = player_query
+            .iter()
+            .find(|(player_transform, player_sprite, _)| {
+                let player_size = player_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+                check_aabb_collision(
+                    (
+                        power_up_transform.translation.x,
+                        power_up_transform.translation.y,
+                    ),
+                    (power_up_size.x, power_up_size.y),
+                    (
+                        player_transform.translation.x,
+                        player_transform.translation.y,
+                    ),
+                    (player_size.x, player_size.y),
+                )
+            });
+
+        if let Some((_, _, player)) = collector {
+            commands.entity(power_up_entity).despawn();
+            power_up_collected_writer
+                .write(PowerUpCollectedMessage::new(power_up.kind, player.slot));
+        }
+    }
+}
+
+pub fn apply_power_ups_system(
+    mut power_up_collected_message: MessageReader<PowerUpCollectedMessage>,
+    mut power_ups: ResMut<ActivePowerUpsResource>,
+    mut bonus_life_writer: MessageWriter<BonusLifeMessage>,
+    mut rebuild_shields_writer: MessageWriter<RebuildShieldsMessage>,
+) {
+    for message in power_up_collected_message.read() {
+        info!(
+            "Player {:?} collected {}",
+            message.player,
+            message.kind.as_str()
+        );
+
+        match message.kind {
+            PowerUpKind::ExtraLife => {
+                bonus_life_writer.write(BonusLifeMessage {
+                    player: message.player,
+                });
+            }
+            PowerUpKind::ShieldRepair => {
+                rebuild_shields_writer.write(RebuildShieldsMessage);
+            }
+            PowerUpKind::RapidFire | PowerUpKind::TimeSlow => {
+                power_ups.0.activate(message.kind);
+            }
+        }
+    }
+}
+
+pub fn tick_power_ups_system(time: Res<Time>, mut power_ups: ResMut<ActivePowerUpsResource>) {
+    if power_ups.0.is_empty() {
+        return;
+    }
+
+    for kind in power_ups.0.tick(time.delta_secs()) {
+        info!("{} wore off", kind.as_str());
+    }
+}
+
+pub fn reset_power_ups_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    mut power_ups: ResMut<ActivePowerUpsResource>,
+    query: Query<Entity, With<PowerUpComponent>>,
+) {
+    if new_game_message.read().count() > 0 {
+        power_ups.0 = ActivePowerUps::new();
+
+        for entity in query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn update_power_up_label_system(
+    power_ups: Res<ActivePowerUpsResource>,
+    mut query: Query<&mut Text, With<PowerUpLabelComponent>>,
+) {
+    if !power_ups.is_changed() {
+        return;
+    }
+
+    for mut text in query.iter_mut() {
+        text.0 = power_up_label(&power_ups.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyId;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::power_up::{RAPID_FIRE_DURATION, TIME_SLOW_DURATION};
+    use crate::infrastructure::bevy::player::resources::{PLAYER_HEIGHT, PLAYER_WIDTH};
+    use bevy::app::{App, Startup, Update};
+    use bevy::ecs::message::Messages;
+    use bevy::image::Image;
+    use bevy::prelude::AssetApp;
+    use bevy::text::Font;
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, count_components, get_resource_mut_or_fail,
+        get_resource_or_fail, query_single_text, send_message, spawn_dummy_entity,
+    };
+
+    fn setup() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_assets()
+            .with_setup(|app| {
+                app.init_resource::<ActivePowerUpsResource>()
+                    .insert_resource(GameRngResource::seeded(7))
+                    .add_message::<EnemyKilledMessage>()
+                    .add_message::<PowerUpCollectedMessage>()
+                    .add_message::<BonusLifeMessage>()
+                    .add_message::<RebuildShieldsMessage>()
+                    .add_message::<NewGameMessage>();
+            })
+            .build()
+    }
+
+    fn spawn_player(app: &mut App, slot: PlayerSlot, x: f32) {
+        app.world_mut().spawn((
+            PlayerComponent {
+                slot,
+                ..PlayerComponent::default()
+            },
+            Sprite {
+                custom_size: Some(Vec2::new(PLAYER_WIDTH, PLAYER_HEIGHT)),
+                ..Default::default()
+            },
+            Transform::from_xyz(x, 0.0, 0.0),
+        ));
+    }
+
+    #[cfg(test)]
+    mod drop_power_ups_system {
+        use super::*;
+
+        #[test]
+        fn should_drop_some_capsules_where_enemies_died() {
+            let mut app = setup();
+            app.add_systems(Update, drop_power_ups_system);
+
+            let enemy = app
+                .world_mut()
+                .spawn(Transform::from_xyz(40.0, 80.0, 0.0))
+                .id();
+            let dummy = spawn_dummy_entity(&mut app);
+            for id in 0..200 {
+                send_message(
+                    &mut app,
+                    EnemyKilledMessage::new(enemy, EnemyId::new(id), dummy, PlayerSlot::One),
+                );
+            }
+            app.update();
+
+            let capsules = count_components::<PowerUpComponent>(&mut app);
+            assert!(capsules > 0 && capsules < 200);
+
+            let mut query = app
+                .world_mut()
+                .query_filtered::<&Transform, With<PowerUpComponent>>();
+            assert!(
+                query
+                    .iter(app.world())
+                    .all(|transform| transform.translation == Vec2::new(40.0, 80.0).extend(0.0))
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod power_up_movement_system {
+        use super::*;
+
+        #[test]
+        fn capsules_should_fall_and_leave_the_game_area() {
+            let mut app = setup();
+            app.add_systems(Update, power_up_movement_system);
+            app.world_mut()
+                .spawn(PowerUpBundle::new(PowerUpKind::ExtraLife, 0.0, 0.0));
+
+            advance_time_by_seconds(&mut app, 1.0);
+            app.update();
+
+            let mut query = app
+                .world_mut()
+                .query_filtered::<&Transform, With<PowerUpComponent>>();
+            let transform = query.single(app.world()).unwrap();
+            assert_eq!(transform.translation.y, -POWER_UP_SPEED);
+
+            advance_time_by_seconds(&mut app, GAME_AREA_HEIGHT / POWER_UP_SPEED);
+            app.update();
+
+            assert_eq!(count_components::<PowerUpComponent>(&mut app), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod collect_power_ups_system {
+        use super::*;
+
+        #[test]
+        fn touching_a_capsule_should_collect_it() {
+            let mut app = setup();
+            app.add_systems(Update, collect_power_ups_system);
+            spawn_player(&mut app, PlayerSlot::Two, 0.0);
+            app.world_mut().spawn(PowerUpBundle::new(
+                PowerUpKind::RapidFire,
+                0.0,
+                PLAYER_HEIGHT / 4.0,
+            ));
+            app.world_mut()
+                .spawn(PowerUpBundle::new(PowerUpKind::TimeSlow, 0.0, 200.0));
+
+            app.update();
+
+            let collected: Vec<_> =
+                get_resource_mut_or_fail::<Messages<PowerUpCollectedMessage>>(&mut app)
+                    .drain()
+                    .collect();
+            assert_eq!(
+                collected,
+                vec![PowerUpCollectedMessage::new(
+                    PowerUpKind::RapidFire,
+                    PlayerSlot::Two
+                )]
+            );
+            assert_eq!(count_components::<PowerUpComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn a_capsule_should_be_collected_once() {
+            let mut app = setup();
+            app.add_systems(Update, collect_power_ups_system);
+            spawn_player(&mut app, PlayerSlot::One, -5.0);
+            spawn_player(&mut app, PlayerSlot::Two, 5.0);
+            app.world_mut()
+                .spawn(PowerUpBundle::new(PowerUpKind::ExtraLife, 0.0, 0.0));
+
+            app.update();
+
+            assert_eq!(
+                get_resource_mut_or_fail::<Messages<PowerUpCollectedMessage>>(&mut app)
+                    .drain()
+                    .count(),
+                1
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod apply_power_ups_system {
+        use super::*;
+
+        #[test]
+        fn instant_power_ups_should_trigger_their_effect() {
+            let mut app = setup();
+            app.add_systems(Update, apply_power_ups_system);
+
+            send_message(
+                &mut app,
+                PowerUpCollectedMessage::new(PowerUpKind::ExtraLife, PlayerSlot::Two),
+            );
+            send_message(
+                &mut app,
+                PowerUpCollectedMessage::new(PowerUpKind::ShieldRepair, PlayerSlot::One),
+            );
+            app.update();
+
+            let bonus_lives: Vec<_> =
+                get_resource_mut_or_fail::<Messages<BonusLifeMessage>>(&mut app)
+                    .drain()
+                    .collect();
+            assert_eq!(
+                bonus_lives,
+                vec![BonusLifeMessage {
+                    player: PlayerSlot::Two
+                }]
+            );
+            assert_eq!(
+                get_resource_mut_or_fail::<Messages<RebuildShieldsMessage>>(&mut app)
+                    .drain()
+                    .count(),
+                1
+            );
+            assert!(
+                get_resource_or_fail::<ActivePowerUpsResource>(&mut app)
+                    .0
+                    .is_empty()
+            );
+        }
+
+        #[test]
+        fn timed_power_ups_should_become_active() {
+            let mut app = setup();
+            app.add_systems(Update, apply_power_ups_system);
+
+            send_message(
+                &mut app,
+                PowerUpCollectedMessage::new(PowerUpKind::RapidFire, PlayerSlot::One),
+            );
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<ActivePowerUpsResource>(&mut app)
+                    .0
+                    .remaining(PowerUpKind::RapidFire),
+                Some(RAPID_FIRE_DURATION)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod tick_power_ups_system {
+        use super::*;
+
+        #[test]
+        fn power_ups_should_wear_off() {
+            let mut app = setup();
+            app.add_systems(Update, tick_power_ups_system);
+            get_resource_mut_or_fail::<ActivePowerUpsResource>(&mut app)
+                .0
+                .activate(PowerUpKind::TimeSlow);
+
+            advance_time_by_seconds(&mut app, TIME_SLOW_DURATION / 2.0);
+            app.update();
+            assert!(
+                get_resource_or_fail::<ActivePowerUpsResource>(&mut app)
+                    .0
+                    .is_active(PowerUpKind::TimeSlow)
+            );
+
+            advance_time_by_seconds(&mut app, TIME_SLOW_DURATION / 2.0);
+            app.update();
+            assert!(
+                get_resource_or_fail::<ActivePowerUpsResource>(&mut app)
+                    .0
+                    .is_empty()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_power_ups_system {
+        use super::*;
+
+        #[test]
+        fn should_clear_capsules_and_active_power_ups() {
+            let mut app = setup();
+            app.add_systems(Update, reset_power_ups_system);
+            get_resource_mut_or_fail::<ActivePowerUpsResource>(&mut app)
+                .0
+                .activate(PowerUpKind::RapidFire);
+            app.world_mut()
+                .spawn(PowerUpBundle::new(PowerUpKind::ExtraLife, 0.0, 0.0));
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert!(
+                get_resource_or_fail::<ActivePowerUpsResource>(&mut app)
+                    .0
+                    .is_empty()
+            );
+            assert_eq!(count_components::<PowerUpComponent>(&mut app), 0);
+        }
+    }
+
+    #[cfg(test)]
+    mod update_power_up_label_system {
+        use super::*;
+
+        #[test]
+        fn should_show_the_active_power_ups() {
+            let mut app = setup();
+            app.init_asset::<Image>()
+                .init_asset::<Font>()
+                .add_systems(Startup, spawn_power_up_label_system)
+                .add_systems(Update, update_power_up_label_system);
+            app.world_mut().spawn(HeaderComponent);
+            app.update();
+
+            assert_eq!(query_single_text::<PowerUpLabelComponent>(&mut app), "");
+
+            get_resource_mut_or_fail::<ActivePowerUpsResource>(&mut app)
+                .0
+                .activate(PowerUpKind::RapidFire);
+            app.update();
+
+            assert_eq!(
+                query_single_text::<PowerUpLabelComponent>(&mut app),
+                "RAPID FIRE 10s"
+            );
+        }
+    }
+}
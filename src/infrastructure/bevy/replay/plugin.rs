@@ -0,0 +1,72 @@
+use crate::infrastructure::bevy::replay::resources::{
+    Replay, ReplayPlaybackResource, ReplayRecorderResource,
+};
+use crate::infrastructure::bevy::replay::systems::{
+    playback_replay_input_system, playback_replay_time_system, record_replay_frame_system,
+    save_replay_system,
+};
+use bevy::app::{App, First, Last, Plugin, PreUpdate};
+use bevy::input::InputSystems;
+use bevy::prelude::IntoScheduleConfigs;
+use bevy::time::TimeSystems;
+use std::path::PathBuf;
+
+pub struct ReplayRecordPlugin {
+    pub path: PathBuf,
+    pub replay: Replay,
+}
+
+pub struct ReplayPlaybackPlugin {
+    pub replay: Replay,
+}
+
+impl Plugin for ReplayRecordPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ReplayRecorderResource {
+            path: self.path.clone(),
+            replay: self.replay.clone(),
+        })
+        .add_systems(PreUpdate, record_replay_frame_system.after(InputSystems))
+        .add_systems(Last, save_replay_system);
+    }
+}
+
+impl Plugin for ReplayPlaybackPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ReplayPlaybackResource {
+            replay: self.replay.clone(),
+            cursor: 0,
+        })
+        .add_systems(First, playback_replay_time_system.before(TimeSystems))
+        .add_systems(PreUpdate, playback_replay_input_system.after(InputSystems));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::wave::Wave;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn record_plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(ReplayRecordPlugin {
+                path: "unused.ron".into(),
+                replay: Replay::new(1, Difficulty::Normal, Wave::default()),
+            })
+            .build();
+    }
+
+    #[test]
+    fn playback_plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(ReplayPlaybackPlugin {
+                replay: Replay::new(1, Difficulty::Normal, Wave::default()),
+            })
+            .build();
+    }
+}
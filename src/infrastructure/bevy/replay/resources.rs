@@ -0,0 +1,195 @@
+use crate::domain::difficulty::Difficulty;
+use crate::domain::wave::Wave;
+use crate::infrastructure::storage::{from_ron, to_ron};
+use bevy::prelude::Resource;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const REPLAY_VERSION: u32 = 1;
+pub const INPUT_LEFT: u8 = 0b001;
+pub const INPUT_RIGHT: u8 = 0b010;
+pub const INPUT_FIRE: u8 = 0b100;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReplayFrame {
+    pub delta_nanos: u64,
+    pub input: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub difficulty: String,
+    pub starting_wave: u32,
+    pub frames: Vec<ReplayFrame>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReplayError {
+    Io(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+    UnknownDifficulty(String),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReplayError::Io(reason) => write!(f, "{}", reason),
+            ReplayError::Parse(reason) => write!(f, "not a valid replay file: {}", reason),
+            ReplayError::UnsupportedVersion(version) => write!(
+                f,
+                "replay version {} is not supported (expected {})",
+                version, REPLAY_VERSION
+            ),
+            ReplayError::UnknownDifficulty(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl Replay {
+    pub fn new(seed: u64, difficulty: Difficulty, starting_wave: Wave) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            seed,
+            difficulty: difficulty.as_str().to_string(),
+            starting_wave: starting_wave.get_number(),
+            frames: vec![],
+        }
+    }
+
+    pub fn parse(source: &str) -> Result<Self, ReplayError> {
+        let replay: Replay = from_ron(source).map_err(ReplayError::Parse)?;
+
+        if replay.version != REPLAY_VERSION {
+            return Err(ReplayError::UnsupportedVersion(replay.version));
+        }
+
+        replay
+            .difficulty
+            .parse::<Difficulty>()
+            .map_err(ReplayError::UnknownDifficulty)?;
+
+        Ok(replay)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ReplayError> {
+        let source =
+            fs::read_to_string(path).map_err(|error| ReplayError::Io(error.to_string()))?;
+
+        Self::parse(&source)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ReplayError> {
+        let source = to_ron(self).map_err(ReplayError::Parse)?;
+
+        fs::write(path, source).map_err(|error| ReplayError::Io(error.to_string()))
+    }
+
+    pub fn get_difficulty(&self) -> Difficulty {
+        self.difficulty.parse().unwrap_or_default()
+    }
+
+    pub fn get_starting_wave(&self) -> Wave {
+        Wave::new(self.starting_wave)
+    }
+}
+
+#[derive(Resource)]
+pub struct ReplayRecorderResource {
+    pub path: PathBuf,
+    pub replay: Replay,
+}
+
+#[derive(Resource)]
+pub struct ReplayPlaybackResource {
+    pub replay: Replay,
+    pub cursor: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_replay() -> Replay {
+        let mut replay = Replay::new(42, Difficulty::Hard, Wave::new(2));
+        replay.frames.push(ReplayFrame {
+            delta_nanos: 16_666_666,
+            input: INPUT_LEFT | INPUT_FIRE,
+        });
+        replay
+    }
+
+    #[test]
+    fn new_replay_records_the_game_options() {
+        let replay = create_replay();
+
+        assert_eq!(replay.version, REPLAY_VERSION);
+        assert_eq!(replay.seed, 42);
+        assert_eq!(replay.get_difficulty(), Difficulty::Hard);
+        assert_eq!(replay.get_starting_wave(), Wave::new(2));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_round_trip_through_a_file() {
+        let replay = create_replay();
+        let path =
+            std::env::temp_dir().join(format!("space-invaders-replay-{}.ron", std::process::id()));
+
+        replay.save(&path).expect("Replay should be saved");
+        let loaded = Replay::load(&path).expect("Replay should be loaded");
+
+        assert_eq!(loaded, replay);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_other_versions() {
+        let source = to_ron(&Replay {
+            version: REPLAY_VERSION + 1,
+            ..create_replay()
+        })
+        .expect("Replay should serialize");
+
+        assert_eq!(
+            Replay::parse(&source),
+            Err(ReplayError::UnsupportedVersion(REPLAY_VERSION + 1))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_unknown_difficulties() {
+        let source = to_ron(&Replay {
+            difficulty: "impossible".to_string(),
+            ..create_replay()
+        })
+        .expect("Replay should serialize");
+
+        assert!(matches!(
+            Replay::parse(&source),
+            Err(ReplayError::UnknownDifficulty(_))
+        ));
+    }
+
+    #[test]
+    fn should_reject_garbage() {
+        assert!(matches!(
+            Replay::parse("not a replay"),
+            Err(ReplayError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn loading_a_missing_file_is_an_io_error() {
+        assert!(matches!(
+            Replay::load(Path::new("does/not/exist.ron")),
+            Err(ReplayError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::infrastructure::bevy::replay::resources::{
+    INPUT_FIRE, INPUT_LEFT, INPUT_RIGHT, ReplayFrame, ReplayPlaybackResource,
+    ReplayRecorderResource,
+};
+use bevy::app::AppExit;
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, MessageReader, MessageWriter, Res, ResMut, Time};
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+use tracing::{error, info};
+
+pub fn encode_input(keyboard: &ButtonInput<KeyCode>) -> u8 {
+    let mut input = 0;
+
+    if keyboard.any_pressed([KeyCode::ArrowLeft, KeyCode::KeyA]) {
+        input |= INPUT_LEFT;
+    }
+    if keyboard.any_pressed([KeyCode::ArrowRight, KeyCode::KeyD]) {
+        input |= INPUT_RIGHT;
+    }
+    if keyboard.pressed(KeyCode::Space) {
+        input |= INPUT_FIRE;
+    }
+
+    input
+}
+
+pub fn apply_input(input: u8, keyboard: &mut ButtonInput<KeyCode>) {
+    keyboard.release_all();
+
+    if input & INPUT_LEFT != 0 {
+        keyboard.press(KeyCode::ArrowLeft);
+    }
+    if input & INPUT_RIGHT != 0 {
+        keyboard.press(KeyCode::ArrowRight);
+    }
+    if input & INPUT_FIRE != 0 {
+        keyboard.press(KeyCode::Space);
+    }
+}
+
+pub fn record_replay_frame_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut recorder: ResMut<ReplayRecorderResource>,
+) {
+    recorder.replay.frames.push(ReplayFrame {
+        delta_nanos: time.delta().as_nanos() as u64,
+        input: encode_input(&keyboard),
+    });
+}
+
+pub fn save_replay_system(
+    mut app_exit_reader: MessageReader<AppExit>,
+    recorder: Res<ReplayRecorderResource>,
+) {
+    if app_exit_reader.read().next().is_none() {
+        return;
+    }
+
+    match recorder.replay.save(&recorder.path) {
+        Ok(()) => info!(
+            "Replay saved to {} ({} frames)",
+            recorder.path.display(),
+            recorder.replay.frames.len()
+        ),
+        Err(reason) => error!(
+            "Cannot save replay to {}: {}",
+            recorder.path.display(),
+            reason
+        ),
+    }
+}
+
+pub fn playback_replay_time_system(
+    playback: Res<ReplayPlaybackResource>,
+    mut time_update_strategy: ResMut<TimeUpdateStrategy>,
+    mut app_exit_writer: MessageWriter<AppExit>,
+) {
+    match playback.replay.frames.get(playback.cursor) {
+        Some(frame) => {
+            *time_update_strategy =
+                TimeUpdateStrategy::ManualDuration(Duration::from_nanos(frame.delta_nanos));
+        }
+        None => {
+            info!("Replay finished after {} frames", playback.cursor);
+            app_exit_writer.write(AppExit::Success);
+        }
+    }
+}
+
+pub fn playback_replay_input_system(
+    mut playback: ResMut<ReplayPlaybackResource>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+) {
+    let Some(frame) = playback.replay.frames.get(playback.cursor).copied() else {
+        return;
+    };
+
+    apply_input(frame.input, &mut keyboard);
+    playback.cursor += 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::difficulty::Difficulty;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::bevy::replay::resources::Replay;
+    use bevy::app::{App, Update};
+    use bevy::prelude::IntoScheduleConfigs;
+    use bevy_test::{TestAppBuilder, did_message_fire, get_resource_or_fail};
+
+    fn create_replay(inputs: &[u8]) -> Replay {
+        let mut replay = Replay::new(1, Difficulty::Normal, Wave::default());
+        replay.frames = inputs
+            .iter()
+            .map(|input| ReplayFrame {
+                delta_nanos: 10_000_000,
+                input: *input,
+            })
+            .collect();
+        replay
+    }
+
+    #[test]
+    fn should_encode_and_decode_the_same_input() {
+        let mut keyboard = ButtonInput::<KeyCode>::default();
+
+        apply_input(INPUT_RIGHT | INPUT_FIRE, &mut keyboard);
+
+        assert!(keyboard.pressed(KeyCode::ArrowRight));
+        assert!(keyboard.pressed(KeyCode::Space));
+        assert!(!keyboard.pressed(KeyCode::ArrowLeft));
+        assert_eq!(encode_input(&keyboard), INPUT_RIGHT | INPUT_FIRE);
+    }
+
+    #[cfg(test)]
+    mod record_replay_frame_system {
+        use super::*;
+
+        #[test]
+        fn should_record_one_frame_per_update() {
+            let mut app = TestAppBuilder::with_time_disabled()
+                .with_time()
+                .with_input()
+                .with_setup(|app| {
+                    app.insert_resource(ReplayRecorderResource {
+                        path: "unused.ron".into(),
+                        replay: create_replay(&[]),
+                    })
+                    .add_systems(Update, record_replay_frame_system);
+                })
+                .build();
+
+            app.world_mut()
+                .resource_mut::<ButtonInput<KeyCode>>()
+                .press(KeyCode::KeyA);
+            app.update();
+            app.update();
+
+            let frames = &get_resource_or_fail::<ReplayRecorderResource>(&mut app)
+                .replay
+                .frames;
+            assert_eq!(frames.len(), 2);
+            assert_eq!(frames[0].input, INPUT_LEFT);
+        }
+    }
+
+    #[cfg(test)]
+    mod playback_replay_input_system {
+        use super::*;
+
+        fn setup(inputs: &[u8]) -> App {
+            let replay = create_replay(inputs);
+            TestAppBuilder::with_time_disabled()
+                .with_input()
+                .with_setup(|app| {
+                    app.insert_resource(ReplayPlaybackResource { replay, cursor: 0 })
+                        .insert_resource(TimeUpdateStrategy::default())
+                        .add_message::<AppExit>()
+                        .add_systems(
+                            Update,
+                            (playback_replay_time_system, playback_replay_input_system).chain(),
+                        );
+                })
+                .build()
+        }
+
+        #[test]
+        fn should_press_the_recorded_keys() {
+            let mut app = setup(&[INPUT_FIRE, INPUT_LEFT]);
+
+            app.update();
+            assert!(get_resource_or_fail::<ButtonInput<KeyCode>>(&mut app).pressed(KeyCode::Space));
+
+            app.update();
+            let keyboard = get_resource_or_fail::<ButtonInput<KeyCode>>(&mut app);
+            assert!(keyboard.pressed(KeyCode::ArrowLeft));
+            assert!(!keyboard.pressed(KeyCode::Space));
+        }
+
+        #[test]
+        fn should_exit_when_the_replay_is_over() {
+            let mut app = setup(&[INPUT_FIRE]);
+
+            app.update();
+            assert!(!did_message_fire::<AppExit>(&mut app));
+
+            app.update();
+            assert!(did_message_fire::<AppExit>(&mut app));
+        }
+    }
+}
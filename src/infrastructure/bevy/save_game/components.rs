@@ -0,0 +1,7 @@
+use bevy::prelude::{Component, Message};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct ContinueScreenComponent;
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct ResumeGameMessage;
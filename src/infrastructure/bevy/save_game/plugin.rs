@@ -0,0 +1,51 @@
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::save_game::components::ResumeGameMessage;
+use crate::infrastructure::bevy::save_game::resources::{
+    PendingSaveGameResource, SaveGameStorageResource,
+};
+use crate::infrastructure::bevy::save_game::systems::{
+    continue_prompt_input_system, load_save_game_system, resume_game_system,
+    save_game_on_exit_system, spawn_continue_screen_system,
+};
+use bevy::app::{App, Last, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, OnEnter, in_state};
+
+pub struct SaveGamePlugin;
+
+impl Plugin for SaveGamePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveGameStorageResource>()
+            .init_resource::<PendingSaveGameResource>()
+            .add_message::<ResumeGameMessage>()
+            .add_systems(Startup, load_save_game_system)
+            .add_systems(
+                OnEnter(GameState::ContinuePrompt),
+                spawn_continue_screen_system,
+            )
+            .add_systems(
+                Update,
+                (
+                    continue_prompt_input_system.run_if(in_state(GameState::ContinuePrompt)),
+                    resume_game_system,
+                )
+                    .chain(),
+            )
+            .add_systems(Last, save_game_on_exit_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::game_state::plugin::GameStatePlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(GameStatePlugin)
+            .with_plugin(SaveGamePlugin)
+            .build();
+    }
+}
@@ -0,0 +1,407 @@
+use crate::domain::enemy::Enemy;
+use crate::domain::enemy_formation::{EnemyFormation, FormationStatus, MovingDirection};
+use crate::domain::formation_layout::FormationLayout;
+use crate::domain::movement_pattern::FormationMovement;
+use crate::domain::player::Player;
+use crate::infrastructure::storage::{data_file, from_ron, to_pretty_ron, write_atomically};
+use bevy::prelude::Resource;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SAVE_GAME_FILE: &str = "savegame.ron";
+pub const SAVE_GAME_VERSION: u32 = 1;
+const SOLID_CELL: char = '#';
+const EMPTY_CELL: char = '.';
+
+#[derive(Resource)]
+pub struct SaveGameStorageResource {
+    pub path: Option<PathBuf>,
+}
+
+impl Default for SaveGameStorageResource {
+    fn default() -> Self {
+        Self {
+            path: data_file(SAVE_GAME_FILE),
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct PendingSaveGameResource(pub Option<GameSnapshot>);
+
+#[derive(Debug, PartialEq)]
+pub enum SaveGameError {
+    Io(String),
+    Parse(String),
+    UnsupportedVersion(u32),
+    InvalidSnapshot(String),
+}
+
+impl Display for SaveGameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveGameError::Io(reason) => write!(f, "{}", reason),
+            SaveGameError::Parse(reason) => write!(f, "not a valid saved game: {}", reason),
+            SaveGameError::UnsupportedVersion(version) => write!(
+                f,
+                "saved game version {} is not supported (expected {})",
+                version, SAVE_GAME_VERSION
+            ),
+            SaveGameError::InvalidSnapshot(reason) => write!(f, "corrupted saved game: {}", reason),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameSnapshot {
+    pub version: u32,
+    pub wave: u32,
+    pub score: u32,
+    pub lives: u8,
+    pub formation: FormationSnapshot,
+    pub shields: Vec<ShieldSnapshot>,
+    pub player_x: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub player: Player,
+    pub player_projectiles: Vec<PlayerProjectileSnapshot>,
+    pub enemy_projectiles: Vec<ProjectileSnapshot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormationSnapshot {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub layout: Vec<String>,
+    pub enemies: Vec<String>,
+    pub position: (usize, usize),
+    pub direction: MovingDirection,
+    pub status: FormationStatus,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub movement: FormationMovement,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub step: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub damage: Vec<Vec<u32>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ShieldSnapshot {
+    pub x: f32,
+    pub bitmap: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProjectileSnapshot {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerProjectileSnapshot {
+    pub x: f32,
+    pub y: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub drift: f32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub piercing: bool,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+struct SnapshotHeader {
+    version: u32,
+}
+
+impl FormationSnapshot {
+    pub fn new(formation: &EnemyFormation) -> Self {
+        let alive: Vec<Vec<bool>> = formation
+            .get_enemies()
+            .iter()
+            .map(|row| row.iter().map(Option::is_some).collect())
+            .collect();
+
+        Self {
+            layout: formation.get_layout().to_rows(),
+            enemies: encode_rows(&alive),
+            position: formation.get_position(),
+            direction: formation.get_direction(),
+            status: formation.get_status(),
+            movement: formation.get_movement(),
+            step: formation.get_step(),
+            damage: formation
+                .get_enemies()
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|slot| slot.as_ref().map_or(0, Enemy::get_damage))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_formation(&self) -> Result<EnemyFormation, SaveGameError> {
+        let layout = if self.layout.is_empty() {
+            FormationLayout::classic()
+        } else {
+            FormationLayout::parse(&self.layout)
+                .map_err(|error| SaveGameError::InvalidSnapshot(error.to_string()))?
+        };
+
+        Ok(EnemyFormation::restore(
+            &layout,
+            &decode_rows(&self.enemies)?,
+            self.position,
+            self.direction,
+            self.status,
+        )
+        .resume(self.movement, self.step)
+        .with_damage(&self.damage))
+    }
+}
+
+pub fn encode_rows(cells: &[Vec<bool>]) -> Vec<String> {
+    cells
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|solid| if *solid { SOLID_CELL } else { EMPTY_CELL })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn decode_rows(rows: &[String]) -> Result<Vec<Vec<bool>>, SaveGameError> {
+    rows.iter()
+        .map(|row| {
+            row.chars()
+                .map(|cell| match cell {
+                    SOLID_CELL => Ok(true),
+                    EMPTY_CELL => Ok(false),
+                    other => Err(SaveGameError::InvalidSnapshot(format!(
+                        "unexpected cell '{}'",
+                        other
+                    ))),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn parse_snapshot(source: &str) -> Result<GameSnapshot, SaveGameError> {
+    let header: SnapshotHeader = from_ron(source).map_err(SaveGameError::Parse)?;
+
+    if header.version != SAVE_GAME_VERSION {
+        return Err(SaveGameError::UnsupportedVersion(header.version));
+    }
+
+    from_ron(source).map_err(SaveGameError::Parse)
+}
+
+pub fn load_snapshot(path: &Path) -> Result<Option<GameSnapshot>, SaveGameError> {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_snapshot(&source).map(Some),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(SaveGameError::Io(error.to_string())),
+    }
+}
+
+pub fn save_snapshot(path: &Path, snapshot: &GameSnapshot) -> Result<(), SaveGameError> {
+    let source = to_pretty_ron(snapshot).map_err(SaveGameError::Parse)?;
+
+    write_atomically(path, source.as_bytes()).map_err(|error| SaveGameError::Io(error.to_string()))
+}
+
+pub fn delete_snapshot(path: &Path) -> Result<(), SaveGameError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(SaveGameError::Io(error.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyId;
+    #[cfg(feature = "serde")]
+    use crate::domain::weapons::{Armed, WeaponUpgrade};
+    #[cfg(feature = "serde")]
+    use crate::infrastructure::storage::temporary_directory;
+
+    #[cfg(feature = "serde")]
+    fn create_snapshot() -> GameSnapshot {
+        let mut formation = EnemyFormation::new_at_row(2);
+        formation.kill(EnemyId::new(3));
+        formation.advance();
+        let mut player = Player::new();
+        player.equip(WeaponUpgrade::PiercingLaser);
+        player.weapon_mut().fire();
+
+        GameSnapshot {
+            version: SAVE_GAME_VERSION,
+            wave: 3,
+            score: 450,
+            lives: 2,
+            formation: FormationSnapshot::new(&formation),
+            shields: vec![ShieldSnapshot {
+                x: -100.0,
+                bitmap: vec!["#.#".to_string(), "###".to_string()],
+            }],
+            player_x: Some(12.5),
+            player,
+            player_projectiles: vec![PlayerProjectileSnapshot {
+                x: 12.5,
+                y: -40.0,
+                drift: 0.0,
+                piercing: true,
+            }],
+            enemy_projectiles: vec![
+                ProjectileSnapshot { x: 0.0, y: 100.0 },
+                ProjectileSnapshot { x: 30.0, y: 80.0 },
+            ],
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn snapshot_survives_a_save_and_load() {
+        let path = temporary_directory("save-game").join(SAVE_GAME_FILE);
+        let snapshot = create_snapshot();
+
+        save_snapshot(&path, &snapshot).expect("Snapshot should be saved");
+
+        assert_eq!(load_snapshot(&path), Ok(Some(snapshot)));
+
+        delete_snapshot(&path).expect("Snapshot should be deleted");
+        assert_eq!(load_snapshot(&path), Ok(None));
+    }
+
+    #[test]
+    fn formation_snapshot_restores_the_formation() {
+        let mut formation = EnemyFormation::new();
+        formation.kill(EnemyId::new(1));
+        (0..40).for_each(|_| formation.advance());
+
+        let restored = FormationSnapshot::new(&formation)
+            .to_formation()
+            .expect("Formation should be restored");
+
+        assert_eq!(restored.get_position(), formation.get_position());
+        assert_eq!(restored.get_direction(), formation.get_direction());
+        assert_eq!(restored.get_status(), formation.get_status());
+        assert!(restored.get_enemies()[0][0].is_none());
+        assert!(restored.get_enemies()[0][1].is_some());
+    }
+
+    #[test]
+    fn formation_snapshot_keeps_the_damage_of_armored_enemies() {
+        let layout = FormationLayout::parse(&["AA", "CC"]).expect("Layout should parse");
+        let mut formation = EnemyFormation::from_layout(&layout);
+        formation.damage(EnemyId::new(2));
+
+        let restored = FormationSnapshot::new(&formation)
+            .to_formation()
+            .expect("Formation should be restored");
+
+        assert_eq!(
+            restored.get_enemy(EnemyId::new(2)).map(Enemy::get_damage),
+            Some(1)
+        );
+        assert_eq!(
+            restored.get_enemy(EnemyId::new(1)).map(Enemy::get_damage),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn formation_snapshot_keeps_the_movement_pattern() {
+        let mut formation =
+            EnemyFormation::new_at_row(1).with_movement(FormationMovement::Circling);
+        (0..5).for_each(|_| formation.advance());
+        let snapshot = FormationSnapshot::new(&formation);
+
+        let mut restored = snapshot
+            .to_formation()
+            .expect("Formation should be restored");
+
+        assert_eq!(restored.get_movement(), FormationMovement::Circling);
+        assert_eq!(restored.get_position(), formation.get_position());
+        formation.advance();
+        restored.advance();
+        assert_eq!(restored.get_position(), formation.get_position());
+    }
+
+    #[test]
+    fn formation_snapshot_keeps_its_layout() {
+        let layout = FormationLayout::parse(&[".S.", "OCO"]).expect("Layout should parse");
+        let formation = EnemyFormation::from_layout(&layout);
+        let mut snapshot = FormationSnapshot::new(&formation);
+
+        assert_eq!(
+            snapshot
+                .to_formation()
+                .expect("Formation should be restored"),
+            formation
+        );
+
+        snapshot.layout.clear();
+        let restored = snapshot
+            .to_formation()
+            .expect("Formation should be restored");
+
+        assert_eq!(restored.get_layout(), &FormationLayout::classic());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn other_versions_are_rejected_before_reading_the_rest() {
+        let source = "(version: 99, something_new: true)";
+
+        assert_eq!(
+            parse_snapshot(source),
+            Err(SaveGameError::UnsupportedVersion(99))
+        );
+    }
+
+    #[test]
+    fn garbage_is_a_parse_error() {
+        assert!(matches!(
+            parse_snapshot("not a save"),
+            Err(SaveGameError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_formation_values_are_rejected() {
+        let source = "(enemies: [], position: (0, 0), direction: up, status: advancing)";
+
+        assert!(from_ron::<FormationSnapshot>(source).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn formations_saved_with_short_direction_names_still_load() {
+        let source = "(enemies: [], position: (0, 0), direction: left, status: advancing)";
+
+        let snapshot: FormationSnapshot = from_ron(source).expect("Snapshot should parse");
+
+        assert_eq!(snapshot.direction, MovingDirection::ToLeft);
+        assert!(snapshot.damage.is_empty());
+    }
+
+    #[test]
+    fn rows_are_encoded_as_text() {
+        let cells = vec![vec![true, false], vec![false, true]];
+
+        let rows = encode_rows(&cells);
+
+        assert_eq!(rows, vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(decode_rows(&rows), Ok(cells));
+        assert!(decode_rows(&["#x".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,701 @@
+use crate::domain::game_mode::{GameMode, PlayerSlot};
+use crate::domain::lives::Lives;
+use crate::domain::score::Score;
+use crate::domain::shield_formation::ShieldFormation;
+use crate::domain::shield_layout::ShieldShape;
+use crate::domain::wave::Wave;
+use crate::domain::weapons::Shot;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, WaveResource,
+};
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileBundle, EnemyProjectileComponent,
+};
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::high_scores::components::{ScreenBundle, ScreenTextBundle};
+use crate::infrastructure::bevy::high_scores::resources::{
+    SCREEN_TEXT_COLOR, SCREEN_TEXT_FONT_SIZE, SCREEN_TITLE_COLOR, SCREEN_TITLE_FONT_SIZE,
+};
+use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::player::components::{PlayerBundle, PlayerComponent};
+use crate::infrastructure::bevy::player::resources::PlayerResource;
+use crate::infrastructure::bevy::player_projectile::components::{
+    PlayerProjectileBundle, PlayerProjectileComponent,
+};
+use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileDuration;
+use crate::infrastructure::bevy::save_game::components::{
+    ContinueScreenComponent, ResumeGameMessage,
+};
+use crate::infrastructure::bevy::save_game::resources::{
+    FormationSnapshot, GameSnapshot, PendingSaveGameResource, PlayerProjectileSnapshot,
+    ProjectileSnapshot, SAVE_GAME_VERSION, SaveGameError, SaveGameStorageResource, ShieldSnapshot,
+    decode_rows, delete_snapshot, encode_rows, load_snapshot, save_snapshot,
+};
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::shield::components::{
+    ShieldBundle, ShieldComponent, ShieldMaskComponent,
+};
+use crate::infrastructure::bevy::shield_formation::resources::{
+    ShieldFormationResource, ShieldLayoutsResource,
+};
+use crate::infrastructure::bevy::turns::resources::TurnsResource;
+use bevy::app::AppExit;
+use bevy::asset::{AssetServer, Assets};
+use bevy::ecs::system::SystemParam;
+use bevy::image::Image;
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Commands, DespawnOnExit, Entity, KeyCode, MessageReader, MessageWriter, NextState, Query, Res,
+    ResMut, State, Transform, With,
+};
+use tracing::{error, info, warn};
+
+#[derive(SystemParam)]
+pub struct GameSnapshotSource<'w, 's> {
+    score: Res<'w, ScoreResource>,
+    lives: Res<'w, LivesResource>,
+    wave: Res<'w, WaveResource>,
+    enemy_formation: Res<'w, EnemyFormationResource>,
+    player: Res<'w, PlayerResource>,
+    shields: Query<'w, 's, (&'static Transform, &'static ShieldMaskComponent)>,
+    players: Query<'w, 's, &'static Transform, With<PlayerComponent>>,
+    player_projectiles: Query<'w, 's, (&'static Transform, &'static PlayerProjectileComponent)>,
+    enemy_projectiles: Query<'w, 's, &'static Transform, With<EnemyProjectileComponent>>,
+}
+
+impl GameSnapshotSource<'_, '_> {
+    pub fn snapshot(&self) -> GameSnapshot {
+        let mut shields: Vec<ShieldSnapshot> = self
+            .shields
+            .iter()
+            .map(|(transform, mask)| ShieldSnapshot {
+                x: transform.translation.x,
+                bitmap: encode_rows(&mask.0),
+            })
+            .collect();
+        shields.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+        GameSnapshot {
+            version: SAVE_GAME_VERSION,
+            wave: self.wave.0.get_number(),
+            score: self.score.0.get_current(),
+            lives: self.lives.0.get_current(),
+            formation: FormationSnapshot::new(&self.enemy_formation.0),
+            shields,
+            player_x: self
+                .players
+                .iter()
+                .next()
+                .map(|transform| transform.translation.x),
+            player: self.player.get(PlayerSlot::One).clone(),
+            player_projectiles: self
+                .player_projectiles
+                .iter()
+                .map(|(transform, projectile)| PlayerProjectileSnapshot {
+                    x: transform.translation.x,
+                    y: transform.translation.y,
+                    drift: projectile.drift,
+                    piercing: projectile.piercing,
+                })
+                .collect(),
+            enemy_projectiles: projectile_snapshots(&self.enemy_projectiles),
+        }
+    }
+}
+
+#[derive(SystemParam)]
+pub struct GameSnapshotTarget<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    images: ResMut<'w, Assets<Image>>,
+    score: ResMut<'w, ScoreResource>,
+    lives: ResMut<'w, LivesResource>,
+    wave: ResMut<'w, WaveResource>,
+    enemy_formation: ResMut<'w, EnemyFormationResource>,
+    shield_formation: ResMut<'w, ShieldFormationResource>,
+    shield_layouts: Res<'w, ShieldLayoutsResource>,
+    player: ResMut<'w, PlayerResource>,
+    player_projectile_duration: Res<'w, PlayerProjectileDuration>,
+    shields: Query<'w, 's, Entity, With<ShieldComponent>>,
+    players: Query<'w, 's, Entity, With<PlayerComponent>>,
+    player_projectiles: Query<'w, 's, Entity, With<PlayerProjectileComponent>>,
+    enemy_projectiles: Query<'w, 's, Entity, With<EnemyProjectileComponent>>,
+}
+
+impl GameSnapshotTarget<'_, '_> {
+    pub fn restore(&mut self, snapshot: &GameSnapshot) -> Result<(), SaveGameError> {
+        let formation = snapshot.formation.to_formation()?;
+        let shield_bitmaps = snapshot
+            .shields
+            .iter()
+            .map(|shield| decode_rows(&shield.bitmap))
+            .collect::<Result<Vec<_>, SaveGameError>>()?;
+
+        self.score.0 = Score::with_points(snapshot.score);
+        self.lives.0 = Lives::with_count(snapshot.lives);
+        self.wave.0 = Wave::new(snapshot.wave);
+        self.enemy_formation.0 = formation;
+        self.shield_formation.0 =
+            ShieldFormation::from_layout(self.shield_layouts.0.for_wave(self.wave.0));
+
+        for shield_entity in self.shields.iter() {
+            self.commands.entity(shield_entity).despawn();
+        }
+
+        for (shield, bitmap) in snapshot.shields.iter().zip(shield_bitmaps) {
+            let shape = ShieldShape::from_pixels(bitmap);
+
+            self.commands
+                .spawn(ShieldBundle::new(&mut self.images, &shape, shield.x));
+        }
+
+        for player_entity in self.players.iter() {
+            self.commands.entity(player_entity).despawn();
+        }
+
+        if let Some(x) = snapshot.player_x {
+            let mut bundle =
+                PlayerBundle::new(&self.asset_server, GameMode::Single, PlayerSlot::One);
+            bundle.transform.translation.x = x;
+            self.commands.spawn(bundle);
+        }
+
+        for projectile_entity in self
+            .player_projectiles
+            .iter()
+            .chain(self.enemy_projectiles.iter())
+        {
+            self.commands.entity(projectile_entity).despawn();
+        }
+
+        *self.player = PlayerResource::default();
+        *self.player.get_mut(PlayerSlot::One) = snapshot.player.clone();
+
+        for projectile in &snapshot.player_projectiles {
+            let shot = Shot {
+                drift: projectile.drift,
+                piercing: projectile.piercing,
+                ..Shot::default()
+            };
+
+            self.commands.spawn(PlayerProjectileBundle::with_projectile(
+                projectile.x,
+                projectile.y,
+                PlayerProjectileComponent::fired(
+                    PlayerSlot::One,
+                    &shot,
+                    self.player_projectile_duration.0,
+                ),
+            ));
+        }
+
+        for projectile in &snapshot.enemy_projectiles {
+            self.commands
+                .spawn(EnemyProjectileBundle::new(projectile.x, projectile.y));
+        }
+
+        Ok(())
+    }
+}
+
+fn projectile_snapshots<F: bevy::ecs::query::QueryFilter>(
+    query: &Query<&Transform, F>,
+) -> Vec<ProjectileSnapshot> {
+    query
+        .iter()
+        .map(|transform| ProjectileSnapshot {
+            x: transform.translation.x,
+            y: transform.translation.y,
+        })
+        .collect()
+}
+
+pub fn load_save_game_system(
+    storage: Res<SaveGameStorageResource>,
+    turns: Option<Res<TurnsResource>>,
+    mut pending: ResMut<PendingSaveGameResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Some(path) = &storage.path else {
+        return;
+    };
+
+    if turns.is_some_and(|turns| turns.0.get_mode() != GameMode::Single) {
+        return;
+    }
+
+    match load_snapshot(path) {
+        Ok(Some(snapshot)) => {
+            pending.0 = Some(snapshot);
+            next_state.set(GameState::ContinuePrompt);
+        }
+        Ok(None) => {}
+        Err(reason) => warn!("Ignoring saved game {}: {}", path.display(), reason),
+    }
+}
+
+pub fn spawn_continue_screen_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    pending: Res<PendingSaveGameResource>,
+) {
+    let font = asset_server.load(FONT);
+    let summary = pending
+        .0
+        .as_ref()
+        .map(|snapshot| format!("WAVE {}  SCORE {}", snapshot.wave, snapshot.score))
+        .unwrap_or_default();
+
+    commands
+        .spawn((
+            ContinueScreenComponent,
+            ScreenBundle::new(),
+            DespawnOnExit(GameState::ContinuePrompt),
+        ))
+        .with_children(|screen| {
+            screen.spawn(ScreenTextBundle::new(
+                "SAVED GAME FOUND",
+                font.clone(),
+                SCREEN_TITLE_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                summary,
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                "PRESS C TO CONTINUE",
+                font.clone(),
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+            screen.spawn(ScreenTextBundle::new(
+                "PRESS N FOR A NEW GAME",
+                font,
+                SCREEN_TEXT_FONT_SIZE,
+                SCREEN_TEXT_COLOR,
+            ));
+        });
+}
+
+pub fn continue_prompt_input_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    storage: Res<SaveGameStorageResource>,
+    mut pending: ResMut<PendingSaveGameResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut resume_writer: MessageWriter<ResumeGameMessage>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        resume_writer.write(ResumeGameMessage);
+        next_state.set(GameState::Playing);
+    } else if keyboard.just_pressed(KeyCode::KeyN) {
+        pending.0 = None;
+        discard_save_game(&storage);
+        next_state.set(GameState::Playing);
+    }
+}
+
+pub fn resume_game_system(
+    mut resume_reader: MessageReader<ResumeGameMessage>,
+    storage: Res<SaveGameStorageResource>,
+    mut pending: ResMut<PendingSaveGameResource>,
+    mut target: GameSnapshotTarget,
+) {
+    if resume_reader.read().count() == 0 {
+        return;
+    }
+
+    let Some(snapshot) = pending.0.take() else {
+        return;
+    };
+
+    match target.restore(&snapshot) {
+        Ok(()) => {
+            discard_save_game(&storage);
+            info!(
+                "Resumed wave {} with {} points",
+                snapshot.wave, snapshot.score
+            );
+        }
+        Err(reason) => error!("Cannot resume the saved game: {}", reason),
+    }
+}
+
+pub fn save_game_on_exit_system(
+    mut app_exit_reader: MessageReader<AppExit>,
+    state: Option<Res<State<GameState>>>,
+    storage: Res<SaveGameStorageResource>,
+    turns: Option<Res<TurnsResource>>,
+    source: GameSnapshotSource,
+) {
+    if app_exit_reader.read().next().is_none() {
+        return;
+    }
+
+    if state.is_some_and(|state| *state.get() != GameState::Playing) {
+        return;
+    }
+
+    if turns.is_some_and(|turns| turns.0.get_mode() != GameMode::Single) {
+        info!("Two-player games are not saved");
+        return;
+    }
+
+    let Some(path) = &storage.path else {
+        return;
+    };
+
+    match save_snapshot(path, &source.snapshot()) {
+        Ok(()) => info!("Game saved to {}", path.display()),
+        Err(reason) => error!("Cannot save the game to {}: {}", path.display(), reason),
+    }
+}
+
+fn discard_save_game(storage: &SaveGameStorageResource) {
+    if let Some(path) = &storage.path
+        && let Err(reason) = delete_snapshot(path)
+    {
+        error!(
+            "Cannot delete the saved game {}: {}",
+            path.display(),
+            reason
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::game_mode::Turns;
+    use crate::domain::player::Player;
+    use crate::domain::weapons::{Armed, WeaponUpgrade};
+    use crate::infrastructure::bevy::save_game::resources::SAVE_GAME_FILE;
+    use crate::infrastructure::storage::temporary_directory;
+    use bevy::app::{App, Last, Startup, Update};
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{
+        TestAppBuilder, contains_single_component, count_components, get_resource_or_fail,
+        send_message,
+    };
+    use std::path::PathBuf;
+
+    fn setup(path: Option<PathBuf>) -> App {
+        TestAppBuilder::new()
+            .with_assets()
+            .with_input()
+            .with_setup(|app| {
+                app.add_plugins(StatesPlugin)
+                    .init_state::<GameState>()
+                    .add_message::<AppExit>()
+                    .add_message::<ResumeGameMessage>()
+                    .insert_resource(SaveGameStorageResource { path })
+                    .init_resource::<PendingSaveGameResource>()
+                    .insert_resource(ScoreResource(Score::new()))
+                    .insert_resource(LivesResource(Lives::new()))
+                    .insert_resource(WaveResource(Wave::default()))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                    .insert_resource(ShieldFormationResource(ShieldFormation::new()))
+                    .init_resource::<ShieldLayoutsResource>()
+                    .init_resource::<PlayerResource>()
+                    .insert_resource(PlayerProjectileDuration(1.0));
+            })
+            .build()
+    }
+
+    fn create_snapshot() -> GameSnapshot {
+        let mut formation = EnemyFormation::new();
+        formation.advance();
+        let mut player = Player::new();
+        player.equip(WeaponUpgrade::PiercingLaser);
+        player.weapon_mut().fire();
+
+        GameSnapshot {
+            version: SAVE_GAME_VERSION,
+            wave: 4,
+            score: 330,
+            lives: 1,
+            formation: FormationSnapshot::new(&formation),
+            shields: vec![ShieldSnapshot {
+                x: -50.0,
+                bitmap: vec![".".repeat(24); 18],
+            }],
+            player_x: Some(25.0),
+            player,
+            player_projectiles: vec![PlayerProjectileSnapshot {
+                x: 25.0,
+                y: 0.0,
+                drift: 0.0,
+                piercing: true,
+            }],
+            enemy_projectiles: vec![ProjectileSnapshot { x: 10.0, y: 100.0 }],
+        }
+    }
+
+    fn current_state(app: &mut App) -> GameState {
+        *get_resource_or_fail::<State<GameState>>(app).get()
+    }
+
+    #[cfg(test)]
+    mod load_save_game_system {
+        use super::*;
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_offer_to_continue_a_saved_game() {
+            let path = temporary_directory("load-save-game").join(SAVE_GAME_FILE);
+            save_snapshot(&path, &create_snapshot()).expect("Snapshot should be saved");
+
+            let mut app = setup(Some(path.clone()));
+            app.add_systems(Startup, load_save_game_system);
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::ContinuePrompt);
+            assert_eq!(
+                get_resource_or_fail::<PendingSaveGameResource>(&mut app).0,
+                Some(create_snapshot())
+            );
+
+            delete_snapshot(&path).expect("Snapshot should be deleted");
+        }
+
+        #[test]
+        fn should_start_playing_without_a_saved_game() {
+            let mut app = setup(Some(
+                temporary_directory("no-save-game").join(SAVE_GAME_FILE),
+            ));
+            app.add_systems(Startup, load_save_game_system);
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_not_offer_a_saved_game_to_two_players() {
+            let path = temporary_directory("load-save-game-coop").join(SAVE_GAME_FILE);
+            save_snapshot(&path, &create_snapshot()).expect("Snapshot should be saved");
+
+            let mut app = setup(Some(path.clone()));
+            app.insert_resource(TurnsResource(Turns::new(GameMode::Coop)))
+                .add_systems(Startup, load_save_game_system);
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+
+            delete_snapshot(&path).expect("Snapshot should be deleted");
+        }
+    }
+
+    #[cfg(test)]
+    mod continue_prompt_input_system {
+        use super::*;
+        use bevy_test::get_resource_mut_or_fail;
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_discard_the_saved_game_when_starting_a_new_one() {
+            let path = temporary_directory("discard-save-game").join(SAVE_GAME_FILE);
+            save_snapshot(&path, &create_snapshot()).expect("Snapshot should be saved");
+            let mut app = setup(Some(path.clone()));
+            app.insert_resource(PendingSaveGameResource(Some(create_snapshot())))
+                .add_systems(Update, continue_prompt_input_system);
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::KeyN);
+            app.update();
+
+            assert_eq!(load_snapshot(&path), Ok(None));
+            assert_eq!(
+                get_resource_or_fail::<PendingSaveGameResource>(&mut app).0,
+                None
+            );
+        }
+
+        #[test]
+        fn should_resume_on_c() {
+            let mut app = setup(None);
+            app.add_systems(Update, continue_prompt_input_system);
+
+            get_resource_mut_or_fail::<ButtonInput<KeyCode>>(&mut app).press(KeyCode::KeyC);
+            app.update();
+
+            assert!(bevy_test::did_message_fire::<ResumeGameMessage>(&mut app));
+        }
+    }
+
+    #[cfg(test)]
+    mod resume_game_system {
+        use super::*;
+
+        #[test]
+        fn should_restore_the_snapshot() {
+            let snapshot = create_snapshot();
+            let mut app = setup(None);
+            app.insert_resource(PendingSaveGameResource(Some(snapshot.clone())))
+                .add_systems(Update, resume_game_system);
+            app.world_mut().spawn(PlayerComponent::default());
+            app.world_mut().spawn(EnemyProjectileComponent);
+            app.world_mut()
+                .spawn(PlayerProjectileComponent::new(PlayerSlot::One));
+
+            send_message(&mut app, ResumeGameMessage);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<ScoreResource>(&mut app)
+                    .0
+                    .get_current(),
+                330
+            );
+            assert_eq!(
+                get_resource_or_fail::<LivesResource>(&mut app)
+                    .0
+                    .get_current(),
+                1
+            );
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app).0,
+                Wave::new(4)
+            );
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationResource>(&mut app)
+                    .0
+                    .get_position(),
+                (1, 0)
+            );
+            let player = get_resource_or_fail::<PlayerResource>(&mut app).get(PlayerSlot::One);
+            assert_eq!(player.get_upgrade(), WeaponUpgrade::PiercingLaser);
+            assert_eq!(player.weapon().shots_in_flight(), 1);
+            assert!(contains_single_component::<PlayerComponent>(&mut app));
+            assert!(contains_single_component::<ShieldComponent>(&mut app));
+            let mask = app
+                .world_mut()
+                .query::<&ShieldMaskComponent>()
+                .single(app.world())
+                .expect("Shield mask not found")
+                .clone();
+            assert_eq!(encode_rows(&mask.0), snapshot.shields[0].bitmap);
+            let projectile = app
+                .world_mut()
+                .query::<&PlayerProjectileComponent>()
+                .single(app.world())
+                .expect("Player projectile not found")
+                .piercing;
+            assert!(projectile);
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod save_game_on_exit_system {
+        use super::*;
+
+        fn setup_save(name: &str) -> (App, PathBuf) {
+            let path = temporary_directory(name).join(SAVE_GAME_FILE);
+            let mut app = setup(Some(path.clone()));
+            app.add_systems(Last, save_game_on_exit_system);
+            app.world_mut()
+                .resource_mut::<ScoreResource>()
+                .0
+                .increment(90);
+            app.world_mut().spawn((
+                PlayerComponent::default(),
+                Transform::from_xyz(-30.0, 0.0, 0.0),
+            ));
+            app.world_mut().spawn((
+                EnemyProjectileComponent,
+                Transform::from_xyz(5.0, 60.0, 0.0),
+            ));
+            (app, path)
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_save_the_game_in_progress_on_exit() {
+            let (mut app, path) = setup_save("save-on-exit");
+
+            send_message(&mut app, AppExit::Success);
+            app.update();
+
+            let snapshot = load_snapshot(&path)
+                .ok()
+                .flatten()
+                .expect("Snapshot should be saved");
+            assert_eq!(snapshot.score, 90);
+            assert_eq!(snapshot.player_x, Some(-30.0));
+            assert_eq!(
+                snapshot.enemy_projectiles,
+                vec![ProjectileSnapshot { x: 5.0, y: 60.0 }]
+            );
+
+            delete_snapshot(&path).expect("Snapshot should be deleted");
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_save_the_weapon_and_its_shots() {
+            let (mut app, path) = setup_save("save-weapon");
+            let mut players = app.world_mut().resource_mut::<PlayerResource>();
+            let player = players.get_mut(PlayerSlot::One);
+            player.equip(WeaponUpgrade::Spread);
+            player.weapon_mut().fire();
+            let shot = WeaponUpgrade::Spread.shots()[0];
+            app.world_mut().spawn((
+                PlayerProjectileComponent::fired(PlayerSlot::One, &shot, 1.0),
+                Transform::from_xyz(-30.0, 10.0, 0.0),
+            ));
+
+            send_message(&mut app, AppExit::Success);
+            app.update();
+
+            let snapshot = load_snapshot(&path)
+                .ok()
+                .flatten()
+                .expect("Snapshot should be saved");
+            assert_eq!(snapshot.player.get_upgrade(), WeaponUpgrade::Spread);
+            assert_eq!(snapshot.player.weapon().shots_in_flight(), 3);
+            assert_eq!(
+                snapshot.player_projectiles,
+                vec![PlayerProjectileSnapshot {
+                    x: -30.0,
+                    y: 10.0,
+                    drift: shot.drift,
+                    piercing: false,
+                }]
+            );
+
+            delete_snapshot(&path).expect("Snapshot should be deleted");
+        }
+
+        #[test]
+        fn should_not_save_a_finished_game() {
+            let (mut app, path) = setup_save("no-save-when-over");
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::GameOver);
+            app.update();
+
+            send_message(&mut app, AppExit::Success);
+            app.update();
+
+            assert_eq!(load_snapshot(&path), Ok(None));
+        }
+
+        #[test]
+        fn should_not_save_a_two_player_game() {
+            let (mut app, path) = setup_save("no-save-two-players");
+            app.insert_resource(TurnsResource(Turns::new(GameMode::Alternating)));
+
+            send_message(&mut app, AppExit::Success);
+            app.update();
+
+            assert_eq!(load_snapshot(&path), Ok(None));
+        }
+    }
+}
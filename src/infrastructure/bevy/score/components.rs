@@ -15,14 +15,19 @@ pub struct ScoreValueComponent;
 #[derive(Component, PartialEq, Debug)]
 pub struct ScoreLabelComponent;
 
+#[derive(Component, PartialEq, Debug)]
+pub struct ScoreContainerComponent;
+
 #[derive(Bundle)]
 pub struct ScoreContainerBundle {
+    pub score_container: ScoreContainerComponent,
     pub node: Node,
 }
 
 impl ScoreContainerBundle {
     pub fn new() -> Self {
         Self {
+            score_container: ScoreContainerComponent,
             node: Node {
                 width: SCORE_CONTAINER_WIDTH,
                 height: SCORE_CONTAINER_HEIGHT,
@@ -110,6 +115,7 @@ mod tests {
     fn should_create_score_score_container_bundle() {
         let bundle = ScoreContainerBundle::new();
 
+        assert_eq!(bundle.score_container, ScoreContainerComponent);
         assert_eq!(bundle.node.width, Val::Percent(50.0));
         assert_eq!(bundle.node.height, Val::Px(50.0));
         assert_eq!(bundle.node.flex_direction, FlexDirection::Row);
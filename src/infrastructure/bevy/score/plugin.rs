@@ -1,9 +1,18 @@
 use crate::domain::score::Score;
+use crate::infrastructure::bevy::boss::components::BossDefeatedMessage;
+use crate::infrastructure::bevy::boss::systems::boss_collisions_system;
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::enemy_formation::systems::collisions_system;
+use crate::infrastructure::bevy::game_state::resources::HudSystems;
 use crate::infrastructure::bevy::header::systems::spawn_header_system;
-use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::lives::components::BonusLifeMessage;
+use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
 use crate::infrastructure::bevy::score::systems::{
-    handle_enemy_killed_system, spawn_score_system, update_score_text_system,
+    handle_boss_defeated_system, handle_enemy_killed_system, handle_ufo_shot_system,
+    reset_score_system, spawn_score_system, update_score_text_system,
 };
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
+use crate::infrastructure::bevy::ufo::systems::ufo_collisions_system;
 use bevy::app::{App, Plugin, Startup, Update};
 use bevy::prelude::IntoScheduleConfigs;
 
@@ -12,10 +21,21 @@ pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(ScoreResource(Score::new()))
+            .init_resource::<SecondPlayerScoreResource>()
+            .init_resource::<DifficultyResource>()
+            .add_message::<BonusLifeMessage>()
+            .add_message::<BossDefeatedMessage>()
+            .add_message::<UfoShotMessage>()
             .add_systems(Startup, spawn_score_system.after(spawn_header_system))
             .add_systems(
                 Update,
-                (update_score_text_system, handle_enemy_killed_system),
+                (
+                    update_score_text_system.in_set(HudSystems),
+                    handle_enemy_killed_system.after(collisions_system),
+                    handle_boss_defeated_system.after(boss_collisions_system),
+                    handle_ufo_shot_system.after(ufo_collisions_system),
+                    reset_score_system,
+                ),
             );
     }
 }
@@ -24,6 +44,7 @@ impl Plugin for ScorePlugin {
 mod tests {
     use super::*;
     use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
     use crate::infrastructure::bevy::header::plugin::HeaderPlugin;
     use bevy_test::TestAppBuilder;
 
@@ -34,6 +55,7 @@ mod tests {
             .with_plugin(HeaderPlugin)
             .with_plugin(ScorePlugin)
             .with_message::<EnemyKilledMessage>()
+            .with_message::<NewGameMessage>()
             .build();
     }
 }
@@ -14,3 +14,6 @@ pub const SCORE_VALUE_FONT_SIZE: f32 = 14.0;
 pub const SCORE_VALUE_FONT_COLOR: Color = Color::srgb_u8(51, 255, 3);
 #[derive(Resource)]
 pub struct ScoreResource(pub Score);
+
+#[derive(Resource, Default)]
+pub struct SecondPlayerScoreResource(pub Option<Score>);
@@ -1,15 +1,24 @@
+use crate::domain::boss::BOSS_POINTS;
+use crate::domain::difficulty::BonusLives;
+use crate::domain::game_mode::PlayerSlot;
+use crate::domain::score::Score;
+use crate::infrastructure::bevy::boss::components::BossDefeatedMessage;
 use crate::infrastructure::bevy::enemy::components::EnemyKilledMessage;
+use crate::infrastructure::bevy::enemy_formation::resources::DifficultyResource;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
 use crate::infrastructure::bevy::header::components::HeaderComponent;
 use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::lives::components::BonusLifeMessage;
 use crate::infrastructure::bevy::score::components::{
     ScoreContainerBundle, ScoreLabelBundle, ScoreValueBundle, ScoreValueComponent,
 };
-use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
 use bevy::asset::AssetServer;
 use bevy::ecs::entity::Entity;
 use bevy::ecs::query::With;
 use bevy::ecs::system::{Commands, Query, Res};
-use bevy::prelude::{DetectChanges, MessageReader, ResMut};
+use bevy::prelude::{DetectChanges, MessageReader, MessageWriter, ResMut};
 use bevy::ui::widget::Text;
 
 pub fn update_score_text_system(
@@ -23,12 +32,96 @@ pub fn update_score_text_system(
     }
 }
 
+fn award_points(
+    player: PlayerSlot,
+    points: u32,
+    score_resource: &mut ResMut<ScoreResource>,
+    second_player_score: &mut ResMut<SecondPlayerScoreResource>,
+    bonus_lives: BonusLives,
+    bonus_life_writer: &mut MessageWriter<BonusLifeMessage>,
+) {
+    let score = if player == PlayerSlot::Two && second_player_score.0.is_some() {
+        second_player_score.0.get_or_insert_default()
+    } else {
+        &mut score_resource.0
+    };
+
+    let before = score.get_current();
+    score.increment(points);
+
+    for _ in 0..bonus_lives.earned_between(before, score.get_current()) {
+        bonus_life_writer.write(BonusLifeMessage { player });
+    }
+}
+
 pub fn handle_enemy_killed_system(
     mut enemy_killed_message: MessageReader<EnemyKilledMessage>,
     mut score_resource: ResMut<ScoreResource>,
+    mut second_player_score: ResMut<SecondPlayerScoreResource>,
+    difficulty: Res<DifficultyResource>,
+    mut bonus_life_writer: MessageWriter<BonusLifeMessage>,
+) {
+    for message in enemy_killed_message.read() {
+        award_points(
+            message.player,
+            10,
+            &mut score_resource,
+            &mut second_player_score,
+            difficulty.0.bonus_lives(),
+            &mut bonus_life_writer,
+        );
+    }
+}
+
+pub fn handle_boss_defeated_system(
+    mut boss_defeated_message: MessageReader<BossDefeatedMessage>,
+    mut score_resource: ResMut<ScoreResource>,
+    mut second_player_score: ResMut<SecondPlayerScoreResource>,
+    difficulty: Res<DifficultyResource>,
+    mut bonus_life_writer: MessageWriter<BonusLifeMessage>,
 ) {
-    for _ in enemy_killed_message.read() {
-        score_resource.0.increment(10);
+    for message in boss_defeated_message.read() {
+        award_points(
+            message.player,
+            BOSS_POINTS,
+            &mut score_resource,
+            &mut second_player_score,
+            difficulty.0.bonus_lives(),
+            &mut bonus_life_writer,
+        );
+    }
+}
+
+pub fn handle_ufo_shot_system(
+    mut ufo_shot_message: MessageReader<UfoShotMessage>,
+    mut score_resource: ResMut<ScoreResource>,
+    mut second_player_score: ResMut<SecondPlayerScoreResource>,
+    difficulty: Res<DifficultyResource>,
+    mut bonus_life_writer: MessageWriter<BonusLifeMessage>,
+) {
+    for message in ufo_shot_message.read() {
+        award_points(
+            message.player,
+            message.points,
+            &mut score_resource,
+            &mut second_player_score,
+            difficulty.0.bonus_lives(),
+            &mut bonus_life_writer,
+        );
+    }
+}
+
+pub fn reset_score_system(
+    mut new_game_message: MessageReader<NewGameMessage>,
+    mut score_resource: ResMut<ScoreResource>,
+    mut second_player_score: ResMut<SecondPlayerScoreResource>,
+) {
+    if new_game_message.read().count() > 0 {
+        score_resource.0 = Score::new();
+
+        if let Some(second) = &mut second_player_score.0 {
+            *second = Score::new();
+        }
     }
 }
 
@@ -56,9 +149,8 @@ pub fn spawn_score_system(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::score::Score;
     use crate::infrastructure::bevy::header::components::HeaderComponent;
-    use crate::infrastructure::bevy::score::resources::ScoreResource;
+    use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
     use bevy::app::{App, Startup, Update};
     use bevy::image::Image;
     use bevy::prelude::{AssetApp, Text};
@@ -73,6 +165,9 @@ mod tests {
             .with_assets()
             .with_setup(|app| {
                 app.insert_resource(ScoreResource(Score::new()))
+                    .init_resource::<SecondPlayerScoreResource>()
+                    .init_resource::<DifficultyResource>()
+                    .add_message::<BonusLifeMessage>()
                     .init_asset::<Image>()
                     .init_asset::<Font>();
             })
@@ -124,10 +219,36 @@ mod tests {
         }
     }
 
+    #[cfg(test)]
+    mod handle_boss_defeated_system {
+        use super::*;
+        use crate::domain::boss::BOSS_POINTS;
+
+        #[test]
+        fn should_reward_the_player_who_defeated_the_boss() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerScoreResource(Some(Score::new())))
+                .add_message::<BossDefeatedMessage>()
+                .add_systems(Update, handle_boss_defeated_system);
+
+            send_message(&mut app, BossDefeatedMessage::new(PlayerSlot::Two));
+            app.update();
+
+            let second = get_resource_or_fail::<SecondPlayerScoreResource>(&mut app)
+                .0
+                .clone()
+                .expect("Second player score not found");
+
+            assert_eq!(second.get_current(), BOSS_POINTS);
+        }
+    }
+
     #[cfg(test)]
     mod handle_enemy_killed_system {
         use super::*;
+        use crate::domain::difficulty::Difficulty;
         use crate::domain::enemy::EnemyId;
+        use bevy::prelude::Messages;
 
         #[test]
         fn should_increase_score_when_enemy_is_killed() {
@@ -138,7 +259,7 @@ mod tests {
             let dummy = spawn_dummy_entity(&mut app);
             send_message(
                 &mut app,
-                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy),
+                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy, PlayerSlot::One),
             );
 
             app.update();
@@ -146,5 +267,109 @@ mod tests {
             let res = get_resource_or_fail::<ScoreResource>(&mut app);
             assert_eq!(res.0.get_current(), 10);
         }
+
+        #[test]
+        fn should_credit_the_player_whose_shot_hit() {
+            let mut app = setup();
+            app.insert_resource(SecondPlayerScoreResource(Some(Score::new())))
+                .add_message::<EnemyKilledMessage>()
+                .add_systems(Update, handle_enemy_killed_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            send_message(
+                &mut app,
+                EnemyKilledMessage::new(dummy, EnemyId::new(1), dummy, PlayerSlot::Two),
+            );
+
+            app.update();
+
+            let second = get_resource_or_fail::<SecondPlayerScoreResource>(&mut app)
+                .0
+                .clone()
+                .expect("Second player score not found");
+
+            assert_eq!(
+                get_resource_or_fail::<ScoreResource>(&mut app)
+                    .0
+                    .get_current(),
+                0
+            );
+            assert_eq!(second.get_current(), 10);
+        }
+
+        #[test]
+        fn should_award_a_bonus_life_when_the_score_crosses_a_threshold() {
+            let mut app = setup();
+            let threshold = Difficulty::Easy.bonus_lives().first;
+            app.insert_resource(DifficultyResource(Difficulty::Easy))
+                .insert_resource(ScoreResource(Score::with_points(threshold - 10)))
+                .add_message::<EnemyKilledMessage>()
+                .add_systems(Update, handle_enemy_killed_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            for id in 1..=2 {
+                send_message(
+                    &mut app,
+                    EnemyKilledMessage::new(dummy, EnemyId::new(id), dummy, PlayerSlot::One),
+                );
+            }
+            app.update();
+
+            let bonus_lives = app
+                .world_mut()
+                .resource_mut::<Messages<BonusLifeMessage>>()
+                .drain()
+                .collect::<Vec<_>>();
+            assert_eq!(
+                bonus_lives,
+                vec![BonusLifeMessage {
+                    player: PlayerSlot::One
+                }]
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod handle_ufo_shot_system {
+        use super::*;
+
+        #[test]
+        fn should_award_the_mystery_points_of_the_ufo() {
+            let mut app = setup();
+            app.add_message::<UfoShotMessage>()
+                .add_systems(Update, handle_ufo_shot_system);
+
+            let dummy = spawn_dummy_entity(&mut app);
+            send_message(&mut app, UfoShotMessage::new(dummy, PlayerSlot::One, 150));
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<ScoreResource>(&mut app)
+                    .0
+                    .get_current(),
+                150
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_score_system {
+        use super::*;
+
+        #[test]
+        fn should_reset_the_score_on_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>()
+                .add_systems(Update, reset_score_system);
+            get_resource_mut_or_fail::<ScoreResource>(&mut app)
+                .0
+                .increment(50);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            let res = get_resource_or_fail::<ScoreResource>(&mut app);
+            assert_eq!(res.0.get_current(), 0);
+        }
     }
 }
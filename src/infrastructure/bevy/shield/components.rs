@@ -1,11 +1,6 @@
-use crate::infrastructure::bevy::shield::resources::ShieldPart::Full;
-use crate::infrastructure::bevy::shield::resources::ShieldPart::InnerLeft;
-use crate::infrastructure::bevy::shield::resources::ShieldPart::InnerRight;
-use crate::infrastructure::bevy::shield::resources::ShieldPart::OuterLeft;
-use crate::infrastructure::bevy::shield::resources::ShieldPart::OuterRight;
+use crate::domain::shield_layout::ShieldShape;
 use crate::infrastructure::bevy::shield::resources::{
-    SHIELD_HEIGHT, SHIELD_LAYOUT, SHIELD_PART_COLUMNS, SHIELD_PART_ROWS, SHIELD_PART_SIZE,
-    SHIELD_WIDTH, SHIELD_Y,
+    SHIELD_BLAST_RADIUS, SHIELD_COLOR, SHIELD_PIXEL_HEIGHT, SHIELD_PIXEL_WIDTH, SHIELD_Y,
 };
 use bevy::asset::Assets;
 use bevy::color::{Color, ColorToPacked};
@@ -13,133 +8,104 @@ use bevy::image::{Image, ImageSampler, ImageSamplerDescriptor};
 use bevy::math::Vec2;
 use bevy::prelude::{Bundle, Component, Sprite, Transform, default};
 use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use std::ops::Range;
 
 #[derive(Component, PartialEq, Debug)]
 pub struct ShieldComponent;
 
+#[derive(Component, Clone, PartialEq, Debug)]
+pub struct ShieldMaskComponent(pub Vec<Vec<bool>>);
+
+impl ShieldMaskComponent {
+    pub fn get_width(&self) -> usize {
+        self.0.first().map_or(0, Vec::len)
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn first_solid(
+        &self,
+        columns: Range<usize>,
+        rows: Range<usize>,
+        from_below: bool,
+    ) -> Option<(usize, usize)> {
+        let solid_in_row = |row: usize| {
+            columns
+                .clone()
+                .find(|column| self.0[row][*column])
+                .map(|column| (column, row))
+        };
+
+        if from_below {
+            rows.rev().find_map(solid_in_row)
+        } else {
+            rows.into_iter().find_map(solid_in_row)
+        }
+    }
+
+    pub fn erode(&mut self, column: usize, row: usize) -> Vec<(usize, usize)> {
+        let radius = SHIELD_BLAST_RADIUS;
+        let mut cleared = vec![];
+
+        for y in row.saturating_sub(radius)..(row + radius + 1).min(self.get_height()) {
+            for x in column.saturating_sub(radius)..(column + radius + 1).min(self.get_width()) {
+                let distance = x.abs_diff(column).pow(2) + y.abs_diff(row).pow(2);
+
+                if distance <= radius * radius && self.0[y][x] {
+                    self.0[y][x] = false;
+                    cleared.push((x, y));
+                }
+            }
+        }
+
+        cleared
+    }
+}
+
 #[derive(Bundle)]
 pub struct ShieldBundle {
     pub shield: ShieldComponent,
+    pub mask: ShieldMaskComponent,
     pub sprite: Sprite,
     pub transform: Transform,
 }
 
 impl ShieldBundle {
-    pub fn new(images: &mut Assets<Image>, x: f32) -> Self {
-        let shield_image = Self::make_texture(Color::srgb(0.0, 1.0, 0.0));
+    pub fn new(images: &mut Assets<Image>, shape: &ShieldShape, x: f32) -> Self {
+        let shield_image = Self::make_texture(shape, SHIELD_COLOR);
 
         let texture_handle = images.add(shield_image);
 
         Self {
             shield: ShieldComponent,
+            mask: ShieldMaskComponent(shape.get_pixels().clone()),
             sprite: Sprite {
                 image: texture_handle,
-                custom_size: Some(Vec2::new(SHIELD_WIDTH, SHIELD_HEIGHT)),
+                custom_size: Some(Vec2::new(
+                    shape.get_width() as f32 * SHIELD_PIXEL_WIDTH,
+                    shape.get_height() as f32 * SHIELD_PIXEL_HEIGHT,
+                )),
                 ..default()
             },
             transform: Transform::from_xyz(x, SHIELD_Y, 0.0),
         }
     }
 
-    fn make_texture(color: Color) -> Image {
-        let width = SHIELD_PART_COLUMNS * SHIELD_PART_SIZE;
-        let height = SHIELD_PART_ROWS * SHIELD_PART_SIZE;
-        let mut data = vec![0u8; width * height * 4];
-
-        for x in 0..SHIELD_PART_ROWS {
-            for y in 0..SHIELD_PART_COLUMNS {
-                match SHIELD_LAYOUT[x][y] {
-                    Full => Self::fill_part(
-                        &mut data,
-                        width,
-                        y * SHIELD_PART_SIZE,
-                        x * SHIELD_PART_SIZE,
-                        color,
-                        Vec::new(),
-                    ),
-                    OuterLeft => {
-                        let to_skip = Vec::from([(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (2, 0)]);
-                        Self::fill_part(
-                            &mut data,
-                            width,
-                            y * SHIELD_PART_SIZE,
-                            x * SHIELD_PART_SIZE,
-                            color,
-                            to_skip,
-                        )
-                    }
-                    OuterRight => {
-                        let to_skip = Vec::from([(0, 3), (0, 4), (0, 5), (1, 4), (1, 5), (2, 5)]);
-                        Self::fill_part(
-                            &mut data,
-                            width,
-                            y * SHIELD_PART_SIZE,
-                            x * SHIELD_PART_SIZE,
-                            color,
-                            to_skip,
-                        )
-                    }
-                    InnerRight => {
-                        let to_skip = Vec::from([
-                            (2, 0),
-                            (2, 1),
-                            (3, 0),
-                            (3, 1),
-                            (3, 2),
-                            (4, 0),
-                            (4, 1),
-                            (4, 2),
-                            (4, 3),
-                            (5, 0),
-                            (5, 1),
-                            (5, 2),
-                            (5, 3),
-                            (5, 4),
-                        ]);
-                        Self::fill_part(
-                            &mut data,
-                            width,
-                            y * SHIELD_PART_SIZE,
-                            x * SHIELD_PART_SIZE,
-                            color,
-                            to_skip,
-                        )
-                    }
-                    InnerLeft => {
-                        let to_skip = Vec::from([
-                            (2, 4),
-                            (2, 5),
-                            (3, 3),
-                            (3, 4),
-                            (3, 5),
-                            (4, 2),
-                            (4, 3),
-                            (4, 4),
-                            (4, 5),
-                            (5, 1),
-                            (5, 2),
-                            (5, 3),
-                            (5, 4),
-                            (5, 5),
-                        ]);
-                        Self::fill_part(
-                            &mut data,
-                            width,
-                            y * SHIELD_PART_SIZE,
-                            x * SHIELD_PART_SIZE,
-                            color,
-                            to_skip,
-                        )
-                    }
-                    _ => {}
-                }
-            }
-        }
+    fn make_texture(shape: &ShieldShape, color: Color) -> Image {
+        let rgba = color.to_linear().to_u8_array();
+        let data = shape
+            .get_pixels()
+            .iter()
+            .flatten()
+            .flat_map(|solid| if *solid { rgba } else { [0; 4] })
+            .collect();
 
         let mut image = Image::new(
             Extent3d {
-                width: width as u32,
-                height: height as u32,
+                width: shape.get_width() as u32,
+                height: shape.get_height() as u32,
                 depth_or_array_layers: 1,
             },
             TextureDimension::D2,
@@ -152,40 +118,18 @@ impl ShieldBundle {
 
         image
     }
-
-    fn fill_part(
-        data: &mut Vec<u8>,
-        img_width: usize,
-        start_x: usize,
-        start_y: usize,
-        color: Color,
-        to_skip: Vec<(usize, usize)>,
-    ) {
-        let rgba = color.to_linear().to_u8_array();
-
-        for y in 0..SHIELD_PART_SIZE {
-            for x in 0..SHIELD_PART_SIZE {
-                if to_skip.contains(&(y, x)) {
-                    continue;
-                }
-
-                let pixel_index = ((start_y + y) * img_width + (start_x + x)) * 4;
-
-                data[pixel_index] = rgba[0];
-                data[pixel_index + 1] = rgba[1];
-                data[pixel_index + 2] = rgba[2];
-                data[pixel_index + 3] = rgba[3];
-            }
-        }
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::infrastructure::bevy::shield::components::{ShieldBundle, ShieldComponent};
-    use crate::infrastructure::bevy::shield::resources::SHIELD_Y;
+    use crate::domain::shield_layout::ShieldShape;
+    use crate::infrastructure::bevy::shield::components::{
+        ShieldBundle, ShieldComponent, ShieldMaskComponent,
+    };
+    use crate::infrastructure::bevy::shield::resources::{SHIELD_HEIGHT, SHIELD_WIDTH, SHIELD_Y};
     use bevy::asset::{AssetApp, AssetPlugin, Assets};
     use bevy::image::Image;
+    use bevy::math::Vec2;
     use bevy_test::TestAppBuilder;
 
     #[test]
@@ -198,12 +142,65 @@ mod tests {
 
         let x = 250.0;
 
-        let bundle = ShieldBundle::new(&mut images, x);
+        let bundle = ShieldBundle::new(&mut images, &ShieldShape::classic(), x);
 
         assert_eq!(bundle.shield, ShieldComponent);
+        assert_eq!(
+            bundle.sprite.custom_size,
+            Some(Vec2::new(SHIELD_WIDTH, SHIELD_HEIGHT))
+        );
 
         assert_eq!(bundle.transform.translation.x, x);
         assert_eq!(bundle.transform.translation.y, SHIELD_Y);
         assert_eq!(bundle.transform.translation.z, 0.0);
     }
+
+    #[test]
+    fn texture_and_mask_come_from_the_same_shape() {
+        let mut app = TestAppBuilder::new().build();
+        app.add_plugins(AssetPlugin::default())
+            .init_asset::<Image>();
+
+        let shape = ShieldShape::parse(&[".#.", "###"]).expect("Shape should parse");
+        let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+        let bundle = ShieldBundle::new(&mut images, &shape, 0.0);
+        let image = images
+            .get(&bundle.sprite.image)
+            .expect("Shield image should exist");
+        let opaque: Vec<bool> = image
+            .data
+            .as_deref()
+            .unwrap_or_default()
+            .chunks(4)
+            .map(|pixel| pixel[3] > 0)
+            .collect();
+
+        assert_eq!(image.width(), 3);
+        assert_eq!(image.height(), 2);
+        assert_eq!(bundle.mask, ShieldMaskComponent(shape.get_pixels().clone()));
+        assert_eq!(opaque, shape.get_pixels().concat());
+    }
+
+    #[test]
+    fn first_solid_pixel_depends_on_the_side_of_the_hit() {
+        let shape = ShieldShape::parse(&["#..", ".#.", "..#"]).expect("Shape should parse");
+        let mask = ShieldMaskComponent(shape.get_pixels().clone());
+
+        assert_eq!(mask.first_solid(0..3, 0..3, false), Some((0, 0)));
+        assert_eq!(mask.first_solid(0..3, 0..3, true), Some((2, 2)));
+        assert_eq!(mask.first_solid(1..3, 0..1, false), None);
+    }
+
+    #[test]
+    fn erosion_clears_a_blast_around_the_hit() {
+        let shape = ShieldShape::parse(&["#####"; 5]).expect("Shape should parse");
+        let mut mask = ShieldMaskComponent(shape.get_pixels().clone());
+
+        let cleared = mask.erode(0, 0);
+
+        assert_eq!(cleared.len(), 6);
+        assert!(!mask.0[0][2] && !mask.0[1][1] && !mask.0[2][0]);
+        assert!(mask.0[2][1] && mask.0[0][3]);
+        assert!(mask.erode(0, 0).is_empty());
+    }
 }
@@ -1 +1,46 @@
+use crate::infrastructure::bevy::enemy_projectile::systems::enemy_projectile_movement_system;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::player_projectile::systems::{
+    player_projectile_lifecycle_system, player_projectile_movement_system,
+};
+use crate::infrastructure::bevy::shield::systems::shield_collisions_system;
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::IntoScheduleConfigs;
 
+pub struct ShieldPlugin;
+
+impl Plugin for ShieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            shield_collisions_system
+                .after(player_projectile_movement_system)
+                .after(enemy_projectile_movement_system)
+                .before(player_projectile_lifecycle_system)
+                .in_set(GameplaySystems),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileExpiredMessage;
+    use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileExpiredMessage;
+    use bevy::image::Image;
+    use bevy::prelude::AssetApp;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_setup(|app| {
+                app.init_asset::<Image>();
+            })
+            .with_plugin(ShieldPlugin)
+            .with_message::<PlayerProjectileExpiredMessage>()
+            .with_message::<EnemyProjectileExpiredMessage>()
+            .build();
+    }
+}
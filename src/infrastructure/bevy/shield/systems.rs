@@ -1 +1,217 @@
+use crate::infrastructure::bevy::enemy_projectile::components::{
+    EnemyProjectileComponent, EnemyProjectileExpiredMessage,
+};
+use crate::infrastructure::bevy::player_projectile::components::{
+    PlayerProjectileComponent, PlayerProjectileExpiredMessage,
+};
+use crate::infrastructure::bevy::shield::components::{ShieldComponent, ShieldMaskComponent};
+use bevy::asset::Assets;
+use bevy::color::Color;
+use bevy::image::Image;
+use bevy::math::Vec2;
+use bevy::prelude::{Commands, Entity, MessageWriter, Query, ResMut, Sprite, Transform, With};
+use std::ops::Range;
+use tracing::warn;
 
+type ShieldQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static Transform,
+        &'static Sprite,
+        &'static mut ShieldMaskComponent,
+    ),
+    With<ShieldComponent>,
+>;
+
+pub fn shield_collisions_system(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    mut shield_query: ShieldQuery,
+    player_projectile_query: Query<(Entity, &Transform, &Sprite, &PlayerProjectileComponent)>,
+    enemy_projectile_query: Query<(Entity, &Transform, &Sprite), With<EnemyProjectileComponent>>,
+    mut player_expired_writer: MessageWriter<PlayerProjectileExpiredMessage>,
+    mut enemy_expired_writer: MessageWriter<EnemyProjectileExpiredMessage>,
+) {
+    for (entity, transform, sprite, projectile) in player_projectile_query.iter() {
+        if erode_shields(&mut shield_query, &mut images, transform, sprite, true) {
+            commands.entity(entity).despawn();
+            player_expired_writer.write(PlayerProjectileExpiredMessage::new(projectile.shooter));
+        }
+    }
+
+    for (entity, transform, sprite) in enemy_projectile_query.iter() {
+        if erode_shields(&mut shield_query, &mut images, transform, sprite, false) {
+            commands.entity(entity).despawn();
+            enemy_expired_writer.write(EnemyProjectileExpiredMessage);
+        }
+    }
+}
+
+fn erode_shields(
+    shield_query: &mut ShieldQuery,
+    images: &mut Assets<Image>,
+    projectile_transform: &Transform,
+    projectile_sprite: &Sprite,
+    from_below: bool,
+) -> bool {
+    let position = projectile_transform.translation.truncate();
+    let size = projectile_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+    for (shield_transform, shield_sprite, mut mask) in shield_query.iter_mut() {
+        let shield_size = shield_sprite.custom_size.unwrap_or(Vec2::ONE);
+        let pixel = Vec2::new(
+            shield_size.x / mask.get_width() as f32,
+            shield_size.y / mask.get_height() as f32,
+        );
+        let left = shield_transform.translation.x - shield_size.x / 2.0;
+        let top = shield_transform.translation.y + shield_size.y / 2.0;
+
+        let columns = pixel_span(
+            position.x - size.x / 2.0 - left,
+            position.x + size.x / 2.0 - left,
+            pixel.x,
+            mask.get_width(),
+        );
+        let rows = pixel_span(
+            top - (position.y + size.y / 2.0),
+            top - (position.y - size.y / 2.0),
+            pixel.y,
+            mask.get_height(),
+        );
+
+        let Some((column, row)) = mask.first_solid(columns, rows, from_below) else {
+            continue;
+        };
+
+        let cleared = mask.erode(column, row);
+
+        if let Some(image) = images.get_mut(&shield_sprite.image) {
+            for (x, y) in cleared {
+                if let Err(error) = image.set_color_at(x as u32, y as u32, Color::NONE) {
+                    warn!("Cannot erode the shield texture: {}", error);
+                }
+            }
+        }
+
+        return true;
+    }
+
+    false
+}
+
+fn pixel_span(start: f32, end: f32, pixel: f32, count: usize) -> Range<usize> {
+    let last = ((end / pixel).ceil().max(0.0) as usize).min(count);
+    let first = ((start / pixel).floor().max(0.0) as usize).min(last);
+
+    first..last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::domain::shield_layout::ShieldShape;
+    use crate::infrastructure::bevy::shield::components::ShieldBundle;
+    use crate::infrastructure::bevy::shield::resources::SHIELD_Y;
+    use bevy::app::{App, Update};
+    use bevy::prelude::{AssetApp, Timer, TimerMode};
+    use bevy_test::{TestAppBuilder, count_components, did_message_fire};
+
+    fn setup() -> App {
+        let mut app = TestAppBuilder::new()
+            .with_assets()
+            .with_setup(|app| {
+                app.init_asset::<Image>()
+                    .add_message::<PlayerProjectileExpiredMessage>()
+                    .add_message::<EnemyProjectileExpiredMessage>()
+                    .add_systems(Update, shield_collisions_system);
+            })
+            .build();
+
+        let mut images = app.world_mut().resource_mut::<Assets<Image>>();
+        let shield = ShieldBundle::new(&mut images, &ShieldShape::classic(), 0.0);
+        app.world_mut().spawn(shield);
+
+        app
+    }
+
+    fn projectile_sprite() -> Sprite {
+        Sprite {
+            custom_size: Some(Vec2::new(2.0, 10.0)),
+            ..Default::default()
+        }
+    }
+
+    fn solid_pixels(app: &mut App) -> usize {
+        app.world_mut()
+            .query::<&ShieldMaskComponent>()
+            .single(app.world())
+            .expect("Shield mask not found")
+            .0
+            .iter()
+            .flatten()
+            .filter(|solid| **solid)
+            .count()
+    }
+
+    #[cfg(test)]
+    mod shield_collisions_system {
+        use super::*;
+
+        #[test]
+        fn should_erode_the_shield_and_stop_an_enemy_shot() {
+            let mut app = setup();
+            let before = solid_pixels(&mut app);
+            app.world_mut().spawn((
+                EnemyProjectileComponent,
+                projectile_sprite(),
+                Transform::from_xyz(0.0, SHIELD_Y, 0.0),
+            ));
+
+            app.update();
+
+            assert!(solid_pixels(&mut app) < before);
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 0);
+            assert!(did_message_fire::<EnemyProjectileExpiredMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_erode_the_shield_and_reload_the_player() {
+            let mut app = setup();
+            let before = solid_pixels(&mut app);
+            app.world_mut().spawn((
+                PlayerProjectileComponent {
+                    shooter: PlayerSlot::One,
+                    lifetime: Timer::from_seconds(1.0, TimerMode::Once),
+                    drift: 0.0,
+                    piercing: true,
+                },
+                projectile_sprite(),
+                Transform::from_xyz(-20.0, SHIELD_Y, 0.0),
+            ));
+
+            app.update();
+
+            assert!(solid_pixels(&mut app) < before);
+            assert_eq!(count_components::<PlayerProjectileComponent>(&mut app), 0);
+            assert!(did_message_fire::<PlayerProjectileExpiredMessage>(&mut app));
+        }
+
+        #[test]
+        fn should_let_shots_through_the_gaps() {
+            let mut app = setup();
+            let before = solid_pixels(&mut app);
+            app.world_mut().spawn((
+                EnemyProjectileComponent,
+                projectile_sprite(),
+                Transform::from_xyz(0.0, SHIELD_Y - 20.0, 0.0),
+            ));
+
+            app.update();
+
+            assert_eq!(solid_pixels(&mut app), before);
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 1);
+        }
+    }
+}
@@ -1,23 +1,64 @@
 use crate::domain::shield_formation::ShieldFormation;
-use crate::infrastructure::bevy::shield_formation::resources::ShieldFormationResource;
-use crate::infrastructure::bevy::shield_formation::systems::spawn_shields_system;
-use bevy::app::{App, Plugin, Startup};
+use crate::infrastructure::bevy::enemy_formation::resources::{StartingWaveResource, WaveResource};
+use crate::infrastructure::bevy::shield_formation::components::RebuildShieldsMessage;
+use crate::infrastructure::bevy::shield_formation::resources::{
+    SHIELDS_FILE, ShieldFormationResource, ShieldLayoutsResource, load_shields,
+};
+use crate::infrastructure::bevy::shield_formation::systems::{
+    next_wave_shields_system, reset_shields_system, spawn_shields_system,
+};
+use crate::infrastructure::storage::asset_file;
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::IntoScheduleConfigs;
 
 pub struct ShieldFormationPlugin;
 
 impl Plugin for ShieldFormationPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(ShieldFormationResource(ShieldFormation::new()))
-            .add_systems(Startup, spawn_shields_system);
+        if !app.world().contains_resource::<ShieldLayoutsResource>() {
+            app.insert_resource(ShieldLayoutsResource(load_shields(&asset_file(
+                SHIELDS_FILE,
+            ))));
+        }
+
+        app.init_resource::<StartingWaveResource>();
+
+        let starting_wave = app.world().resource::<StartingWaveResource>().0;
+        let layout = app
+            .world()
+            .resource::<ShieldLayoutsResource>()
+            .0
+            .for_wave(starting_wave)
+            .clone();
+
+        app.init_resource::<WaveResource>()
+            .insert_resource(ShieldFormationResource(ShieldFormation::from_layout(
+                &layout,
+            )))
+            .add_message::<RebuildShieldsMessage>()
+            .add_systems(Startup, spawn_shields_system)
+            .add_systems(
+                Update,
+                (
+                    reset_shields_system,
+                    next_wave_shields_system.after(reset_shields_system),
+                ),
+            );
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use bevy_test::TestAppBuilder;
 
     #[test]
     fn plugin_loads_successfully() {
-        let _app = bevy_test::smoke_test_plugin_with_assets(ShieldFormationPlugin);
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_plugin(ShieldFormationPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
     }
 }
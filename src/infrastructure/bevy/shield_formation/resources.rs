@@ -1,5 +1,154 @@
 use crate::domain::shield_formation::ShieldFormation;
+#[cfg(feature = "serde")]
+use crate::domain::shield_layout::{DEFAULT_SHIELD_COUNT, DEFAULT_SHIELD_SPREAD};
+use crate::domain::shield_layout::{ShieldLayout, ShieldLayoutError, ShieldLayouts, ShieldShape};
+use crate::infrastructure::storage::from_ron;
 use bevy::prelude::Resource;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use tracing::warn;
+
+pub const SHIELDS_FILE: &str = "shields.ron";
 
 #[derive(Resource)]
 pub struct ShieldFormationResource(pub ShieldFormation);
+
+#[derive(Resource, Default)]
+pub struct ShieldLayoutsResource(pub ShieldLayouts);
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct ShieldsFile {
+    layouts: Vec<ShieldEntry>,
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+struct ShieldEntry {
+    name: String,
+    #[cfg_attr(feature = "serde", serde(default = "default_count"))]
+    count: usize,
+    #[cfg_attr(feature = "serde", serde(default = "default_spread"))]
+    spread: f32,
+    shape: Vec<String>,
+}
+
+#[cfg(feature = "serde")]
+fn default_count() -> usize {
+    DEFAULT_SHIELD_COUNT
+}
+
+#[cfg(feature = "serde")]
+fn default_spread() -> f32 {
+    DEFAULT_SHIELD_SPREAD
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ShieldsError {
+    Parse(String),
+    Invalid {
+        name: String,
+        error: ShieldLayoutError,
+    },
+}
+
+impl Display for ShieldsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShieldsError::Parse(reason) => write!(f, "cannot parse shields: {}", reason),
+            ShieldsError::Invalid { name, error } => {
+                write!(f, "shield layout '{}' is invalid: {}", name, error)
+            }
+        }
+    }
+}
+
+pub fn parse_shields(source: &str) -> Result<ShieldLayouts, ShieldsError> {
+    let file: ShieldsFile = from_ron(source).map_err(ShieldsError::Parse)?;
+
+    let layouts = file
+        .layouts
+        .into_iter()
+        .map(|entry| {
+            ShieldShape::parse(&entry.shape)
+                .and_then(|shape| ShieldLayout::new(shape, entry.count, entry.spread))
+                .map_err(|error| ShieldsError::Invalid {
+                    name: entry.name,
+                    error,
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ShieldLayouts::new(layouts))
+}
+
+pub fn load_shields(path: &Path) -> ShieldLayouts {
+    match fs::read_to_string(path) {
+        Ok(source) => parse_shields(&source).unwrap_or_else(|error| {
+            warn!("Using the classic shields: {}", error);
+            ShieldLayouts::default()
+        }),
+        Err(error) => {
+            warn!(
+                "Using the classic shields, cannot read {}: {}",
+                path.display(),
+                error
+            );
+            ShieldLayouts::default()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+    use crate::domain::wave::Wave;
+    use crate::infrastructure::storage::asset_file;
+
+    #[test]
+    fn shield_layouts_are_parsed_in_wave_order() {
+        let layouts = parse_shields(
+            r###"(layouts: [
+                (name: "classic", shape: [".#.", "##."]),
+                (name: "pair", count: 2, spread: 0.3, shape: ["##."]),
+            ])"###,
+        )
+        .expect("Shields should parse");
+
+        let classic = layouts.for_wave(Wave::new(1));
+        let pair = layouts.for_wave(Wave::new(2));
+
+        assert_eq!(classic.get_count(), DEFAULT_SHIELD_COUNT);
+        assert_eq!(classic.get_spread(), DEFAULT_SHIELD_SPREAD);
+        assert_eq!(pair.get_count(), 2);
+        assert_eq!(pair.get_shape().get_width(), 3);
+    }
+
+    #[test]
+    fn invalid_shield_layouts_name_the_culprit() {
+        let result = parse_shields(r##"(layouts: [(name: "crowd", count: 20, shape: ["#."])])"##);
+
+        assert_eq!(
+            result.map_err(|error| error.to_string()),
+            Err(
+                "shield layout 'crowd' is invalid: 20 shields requested, at most 8 fit on screen"
+                    .into()
+            )
+        );
+        assert!(matches!(
+            parse_shields("(layouts: [(name: \"x\")])"),
+            Err(ShieldsError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn bundled_shields_start_with_the_classic_layout() {
+        let layouts = load_shields(&asset_file(SHIELDS_FILE));
+
+        assert!(layouts.count() > 1);
+        assert_eq!(layouts.for_wave(Wave::default()), &ShieldLayout::classic());
+    }
+}
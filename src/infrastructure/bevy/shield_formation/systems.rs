@@ -1,52 +1,150 @@
-use crate::infrastructure::bevy::shield::components::ShieldBundle;
-use crate::infrastructure::bevy::shield::resources::SHIELD_X;
-use crate::infrastructure::bevy::shield_formation::resources::ShieldFormationResource;
+use crate::domain::shield_formation::ShieldFormation;
+use crate::domain::shield_layout::ShieldLayout;
+use crate::infrastructure::bevy::enemy_formation::resources::{StartingWaveResource, WaveResource};
+use crate::infrastructure::bevy::game_area::resources::{ArenaResource, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::game_area::systems::spawn_in_arena;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::shield::components::{ShieldBundle, ShieldComponent};
+use crate::infrastructure::bevy::shield_formation::components::RebuildShieldsMessage;
+use crate::infrastructure::bevy::shield_formation::resources::{
+    ShieldFormationResource, ShieldLayoutsResource,
+};
 use bevy::asset::Assets;
-use bevy::ecs::system::{Commands, Res};
+use bevy::ecs::entity::Entity;
+use bevy::ecs::query::With;
+use bevy::ecs::system::{Commands, Query, Res, SystemParam};
 use bevy::image::Image;
-use bevy::prelude::ResMut;
+use bevy::prelude::{DetectChanges, MessageReader, ResMut};
+use tracing::info;
+
+#[derive(SystemParam)]
+pub struct ShieldSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    images: ResMut<'w, Assets<Image>>,
+    arena: Option<Res<'w, ArenaResource>>,
+}
 
 pub fn spawn_shields_system(
-    mut commands: Commands,
-    mut images: ResMut<Assets<Image>>,
+    mut spawner: ShieldSpawner,
     shield_formation_res: Res<ShieldFormationResource>,
 ) {
-    let shields = shield_formation_res.0.get_shields();
+    spawn_shields(&mut spawner, &shield_formation_res);
+}
 
-    if shields.is_empty() {
+pub fn reset_shields_system(
+    mut spawner: ShieldSpawner,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    starting_wave: Res<StartingWaveResource>,
+    layouts: Res<ShieldLayoutsResource>,
+    mut shield_formation_res: ResMut<ShieldFormationResource>,
+    shield_query: Query<Entity, With<ShieldComponent>>,
+) {
+    if new_game_message.read().count() == 0 {
         return;
     }
 
-    let total_span = (-SHIELD_X) - SHIELD_X;
-    let shield_step = total_span / (shields.len() as f32 - 1.0);
+    rebuild_shields(
+        &mut spawner,
+        &mut shield_formation_res,
+        &shield_query,
+        layouts.0.for_wave(starting_wave.0),
+    );
+}
+
+pub fn next_wave_shields_system(
+    mut spawner: ShieldSpawner,
+    mut rebuild_message: MessageReader<RebuildShieldsMessage>,
+    wave: Res<WaveResource>,
+    layouts: Res<ShieldLayoutsResource>,
+    mut shield_formation_res: ResMut<ShieldFormationResource>,
+    shield_query: Query<Entity, With<ShieldComponent>>,
+) {
+    let layout = layouts.0.for_wave(wave.0);
+    let requested = rebuild_message.read().count() > 0;
+
+    if !requested && (!wave.is_changed() || shield_formation_res.0.get_layout() == layout) {
+        return;
+    }
+
+    info!("Wave {} brings new shields", wave.0.get_number());
+    rebuild_shields(
+        &mut spawner,
+        &mut shield_formation_res,
+        &shield_query,
+        layout,
+    );
+}
+
+fn rebuild_shields(
+    spawner: &mut ShieldSpawner,
+    shield_formation_res: &mut ShieldFormationResource,
+    shield_query: &Query<Entity, With<ShieldComponent>>,
+    layout: &ShieldLayout,
+) {
+    for shield_entity in shield_query.iter() {
+        spawner.commands.entity(shield_entity).despawn();
+    }
+
+    shield_formation_res.0 = ShieldFormation::from_layout(layout);
+    spawn_shields(spawner, shield_formation_res);
+}
+
+fn spawn_shields(spawner: &mut ShieldSpawner, shield_formation_res: &ShieldFormationResource) {
+    let formation = &shield_formation_res.0;
+    let layout = formation.get_layout();
+
+    for (_, x) in formation.get_shields().iter().zip(shield_positions(layout)) {
+        let shield = ShieldBundle::new(&mut spawner.images, layout.get_shape(), x);
+
+        spawn_in_arena(&mut spawner.commands, spawner.arena.as_deref(), shield);
+    }
+}
+
+pub fn shield_positions(layout: &ShieldLayout) -> Vec<f32> {
+    let count = layout.get_count();
 
-    for (index, _) in shields.iter().enumerate() {
-        let x = SHIELD_X + (index as f32 * shield_step);
+    match count {
+        0 => vec![],
+        1 => vec![0.0],
+        _ => {
+            let total_span = GAME_AREA_WIDTH * layout.get_spread();
+            let shield_step = total_span / (count as f32 - 1.0);
 
-        commands.spawn(ShieldBundle::new(&mut images, x));
+            (0..count)
+                .map(|index| -(total_span / 2.0) + (index as f32 * shield_step))
+                .collect()
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::shield_formation::ShieldFormation;
-    use crate::infrastructure::bevy::shield::components::ShieldComponent;
-    use crate::infrastructure::bevy::shield_formation::resources::ShieldFormationResource;
+    use crate::domain::shield_layout::{DEFAULT_SHIELD_SPREAD, ShieldLayouts, ShieldShape};
+    use crate::domain::wave::Wave;
     use bevy::app::{App, Startup};
     use bevy::image::Image;
     use bevy::prelude::AssetApp;
-    use bevy_test::{TestAppBuilder, count_components};
+    use bevy_test::{TestAppBuilder, count_components, send_message};
 
     fn setup() -> App {
         TestAppBuilder::new()
             .with_assets()
             .with_setup(|app| {
-                app.init_asset::<Image>();
+                app.init_asset::<Image>()
+                    .init_resource::<StartingWaveResource>()
+                    .init_resource::<WaveResource>()
+                    .init_resource::<ShieldLayoutsResource>();
             })
             .build()
     }
 
+    fn two_layouts() -> ShieldLayoutsResource {
+        let pair = ShieldLayout::new(ShieldShape::classic(), 2, 0.3).expect("Layout is valid");
+
+        ShieldLayoutsResource(ShieldLayouts::new(vec![ShieldLayout::classic(), pair]))
+    }
+
     #[cfg(test)]
     mod spawn_shields_system {
         use super::*;
@@ -62,4 +160,126 @@ mod tests {
             assert_eq!(count_components::<ShieldComponent>(&mut app), 4);
         }
     }
+
+    #[cfg(test)]
+    mod reset_shields_system {
+        use super::*;
+        use bevy::app::Update;
+        use bevy_test::send_message;
+
+        #[test]
+        fn should_rebuild_the_shields_on_new_game() {
+            let mut app = setup();
+            app.insert_resource(ShieldFormationResource(ShieldFormation::new()))
+                .add_message::<NewGameMessage>()
+                .add_systems(Startup, spawn_shields_system)
+                .add_systems(Update, reset_shields_system);
+            app.update();
+            let shield_count = count_components::<ShieldComponent>(&mut app);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<ShieldComponent>(&mut app), shield_count);
+        }
+
+        #[test]
+        fn should_use_the_layout_of_the_starting_wave() {
+            let mut app = setup();
+            app.insert_resource(ShieldFormationResource(ShieldFormation::new()))
+                .insert_resource(StartingWaveResource(Wave::new(2)))
+                .insert_resource(two_layouts())
+                .add_message::<NewGameMessage>()
+                .add_systems(Update, reset_shields_system);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<ShieldComponent>(&mut app), 2);
+        }
+    }
+
+    #[cfg(test)]
+    mod next_wave_shields_system {
+        use super::*;
+        use bevy::app::Update;
+
+        fn setup_with_waves() -> App {
+            let mut app = setup();
+            app.insert_resource(ShieldFormationResource(ShieldFormation::new()))
+                .insert_resource(two_layouts())
+                .add_message::<RebuildShieldsMessage>()
+                .add_systems(Startup, spawn_shields_system)
+                .add_systems(Update, next_wave_shields_system);
+            app.update();
+            app
+        }
+
+        #[test]
+        fn should_rebuild_the_shields_when_the_wave_layout_changes() {
+            let mut app = setup_with_waves();
+
+            app.insert_resource(WaveResource(Wave::new(2)));
+            app.update();
+
+            assert_eq!(count_components::<ShieldComponent>(&mut app), 2);
+        }
+
+        #[test]
+        fn should_keep_damaged_shields_when_the_layout_is_the_same() {
+            let mut app = setup_with_waves();
+            let before = app
+                .world_mut()
+                .query_filtered::<Entity, With<ShieldComponent>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+
+            app.insert_resource(WaveResource(Wave::new(3)));
+            app.update();
+
+            let after = app
+                .world_mut()
+                .query_filtered::<Entity, With<ShieldComponent>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+            assert_eq!(after, before);
+        }
+
+        #[test]
+        fn should_rebuild_the_shields_on_request() {
+            let mut app = setup_with_waves();
+            let before = app
+                .world_mut()
+                .query_filtered::<Entity, With<ShieldComponent>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+
+            send_message(&mut app, RebuildShieldsMessage);
+            app.update();
+
+            let after = app
+                .world_mut()
+                .query_filtered::<Entity, With<ShieldComponent>>()
+                .iter(app.world())
+                .collect::<Vec<_>>();
+            assert_eq!(after.len(), before.len());
+            assert!(after.iter().all(|entity| !before.contains(entity)));
+        }
+    }
+
+    #[test]
+    fn shields_are_spread_symmetrically() {
+        let positions = shield_positions(&ShieldLayout::classic());
+        let edge = GAME_AREA_WIDTH * DEFAULT_SHIELD_SPREAD / 2.0;
+
+        assert_eq!(positions.len(), 4);
+        assert_eq!(positions[0], -edge);
+        assert_eq!(positions[3], edge);
+        assert_eq!(
+            shield_positions(
+                &ShieldLayout::new(ShieldShape::classic(), 1, 0.5).expect("Layout is valid")
+            ),
+            vec![0.0]
+        );
+    }
 }
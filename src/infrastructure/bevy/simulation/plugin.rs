@@ -0,0 +1,156 @@
+use crate::infrastructure::bevy::audio::resources::SettingsStorageResource;
+use crate::infrastructure::bevy::bevy_renderer::plugin::SpaceInvadersPlugin;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFormationResource, WaveResource,
+};
+use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+use crate::infrastructure::bevy::game_options::resources::GameOptions;
+use crate::infrastructure::bevy::game_state::systems::is_game_over;
+use crate::infrastructure::bevy::headless_renderer::plugin::HeadlessPlugin;
+use crate::infrastructure::bevy::high_scores::resources::HighScoreStorageResource;
+use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::simulation::resources::{
+    SIMULATION_FRAME_DURATION, SIMULATION_MAX_FRAMES, SimulationReport, SimulationResult,
+};
+use crate::infrastructure::bevy::simulation::systems::autopilot_system;
+use bevy::MinimalPlugins;
+use bevy::app::{App, Plugin, PreUpdate};
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+use tracing::info;
+
+pub struct AutopilotPlugin;
+
+pub struct SimulationRunner {
+    games: u32,
+    max_frames: u32,
+    options: GameOptions,
+}
+
+impl Plugin for AutopilotPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, autopilot_system);
+    }
+}
+
+impl SimulationRunner {
+    pub fn new(games: u32, options: GameOptions) -> Self {
+        Self {
+            games,
+            max_frames: SIMULATION_MAX_FRAMES,
+            options,
+        }
+    }
+
+    pub fn with_max_frames(mut self, max_frames: u32) -> Self {
+        self.max_frames = max_frames;
+        self
+    }
+
+    pub fn run(&self) -> SimulationReport {
+        let (base_seed, _, _) = self.options.resolved();
+
+        let results = (0..self.games)
+            .map(|game| self.run_game(base_seed.wrapping_add(game as u64)))
+            .collect();
+
+        SimulationReport { results }
+    }
+
+    fn run_game(&self, seed: u64) -> SimulationResult {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                SIMULATION_FRAME_DURATION,
+            )))
+            .insert_resource(HighScoreStorageResource { path: None })
+            .insert_resource(SettingsStorageResource { path: None })
+            .add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: GameOptions {
+                    seed: Some(seed),
+                    ..self.options.clone()
+                },
+            })
+            .add_plugins(SpaceInvadersPlugin)
+            .add_plugins(AutopilotPlugin);
+
+        let mut frames = 0;
+        let mut game_over = false;
+
+        while frames < self.max_frames && !game_over {
+            app.update();
+            frames += 1;
+
+            let world = app.world();
+            game_over = is_game_over(
+                &world.resource::<LivesResource>().0,
+                &world.resource::<EnemyFormationResource>().0,
+            );
+        }
+
+        let world = app.world();
+        let result = SimulationResult {
+            seed,
+            score: world.resource::<ScoreResource>().0.get_current(),
+            wave: world.resource::<WaveResource>().0.get_number(),
+            frames,
+            game_over,
+        };
+
+        info!("Simulated game {:?}", result);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::wave::Wave;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_input()
+            .with_plugin(AutopilotPlugin)
+            .build();
+    }
+
+    #[test]
+    fn should_run_every_requested_game() {
+        let report = SimulationRunner::new(
+            2,
+            GameOptions {
+                seed: Some(10),
+                starting_wave: Wave::new(2),
+                ..GameOptions::default()
+            },
+        )
+        .with_max_frames(30)
+        .run();
+
+        assert_eq!(report.results.len(), 2);
+        assert_eq!(report.results[0].seed, 10);
+        assert_eq!(report.results[1].seed, 11);
+        assert!(report.results.iter().all(|result| result.frames == 30));
+        assert!(report.results.iter().all(|result| result.wave == 2));
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_game() {
+        let options = GameOptions {
+            seed: Some(4),
+            ..GameOptions::default()
+        };
+
+        let first = SimulationRunner::new(1, options.clone())
+            .with_max_frames(600)
+            .run();
+        let second = SimulationRunner::new(1, options).with_max_frames(600).run();
+
+        assert_eq!(first, second);
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+
+pub const SIMULATION_FRAME_DURATION: f64 = 1.0 / 60.0;
+pub const SIMULATION_MAX_FRAMES: u32 = 60 * 60 * 15;
+pub const AUTOPILOT_AIM_TOLERANCE: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimulationResult {
+    pub seed: u64,
+    pub score: u32,
+    pub wave: u32,
+    pub frames: u32,
+    pub game_over: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SimulationReport {
+    pub results: Vec<SimulationResult>,
+}
+
+impl SimulationResult {
+    pub fn seconds(&self) -> f64 {
+        self.frames as f64 * SIMULATION_FRAME_DURATION
+    }
+}
+
+impl SimulationReport {
+    pub fn average_score(&self) -> f64 {
+        self.average(|result| result.score as f64)
+    }
+
+    pub fn best_score(&self) -> u32 {
+        self.results
+            .iter()
+            .map(|result| result.score)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn average_wave(&self) -> f64 {
+        self.average(|result| result.wave as f64)
+    }
+
+    pub fn highest_wave(&self) -> u32 {
+        self.results
+            .iter()
+            .map(|result| result.wave)
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn average_seconds(&self) -> f64 {
+        self.average(SimulationResult::seconds)
+    }
+
+    pub fn unfinished_games(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|result| !result.game_over)
+            .count()
+    }
+
+    fn average(&self, value: impl Fn(&SimulationResult) -> f64) -> f64 {
+        if self.results.is_empty() {
+            return 0.0;
+        }
+
+        self.results.iter().map(value).sum::<f64>() / self.results.len() as f64
+    }
+}
+
+impl Display for SimulationReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Simulated games: {}", self.results.len())?;
+        writeln!(f, "Average score:   {:.1}", self.average_score())?;
+        writeln!(f, "Best score:      {}", self.best_score())?;
+        writeln!(f, "Average wave:    {:.2}", self.average_wave())?;
+        writeln!(f, "Highest wave:    {}", self.highest_wave())?;
+        writeln!(f, "Average length:  {:.1}s", self.average_seconds())?;
+        write!(f, "Hit frame limit: {}", self.unfinished_games())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_result(score: u32, wave: u32, frames: u32) -> SimulationResult {
+        SimulationResult {
+            seed: 0,
+            score,
+            wave,
+            frames,
+            game_over: true,
+        }
+    }
+
+    fn create_report() -> SimulationReport {
+        SimulationReport {
+            results: vec![create_result(100, 1, 60), create_result(300, 2, 180)],
+        }
+    }
+
+    #[test]
+    fn empty_report_has_zero_aggregates() {
+        let report = SimulationReport::default();
+
+        assert_eq!(report.average_score(), 0.0);
+        assert_eq!(report.best_score(), 0);
+        assert_eq!(report.highest_wave(), 0);
+    }
+
+    #[test]
+    fn should_aggregate_scores() {
+        let report = create_report();
+
+        assert_eq!(report.average_score(), 200.0);
+        assert_eq!(report.best_score(), 300);
+    }
+
+    #[test]
+    fn should_aggregate_waves_and_durations() {
+        let report = create_report();
+
+        assert_eq!(report.average_wave(), 1.5);
+        assert_eq!(report.highest_wave(), 2);
+        assert!((report.average_seconds() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_count_games_stopped_by_the_frame_limit() {
+        let mut report = create_report();
+        report.results[0].game_over = false;
+
+        assert_eq!(report.unfinished_games(), 1);
+        assert!(report.to_string().contains("Hit frame limit: 1"));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::simulation::resources::AUTOPILOT_AIM_TOLERANCE;
+use bevy::input::ButtonInput;
+use bevy::prelude::{KeyCode, Query, ResMut, Transform, With};
+
+pub fn autopilot_system(
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    player_query: Query<&Transform, With<PlayerComponent>>,
+    enemy_query: Query<&Transform, With<EnemyComponent>>,
+) {
+    keyboard.release_all();
+
+    let Ok(player) = player_query.single() else {
+        return;
+    };
+
+    keyboard.press(KeyCode::Space);
+
+    let player_x = player.translation.x;
+    let target = enemy_query
+        .iter()
+        .map(|enemy| enemy.translation.x)
+        .min_by(|a, b| (a - player_x).abs().total_cmp(&(b - player_x).abs()));
+
+    if let Some(target_x) = target {
+        if target_x < player_x - AUTOPILOT_AIM_TOLERANCE {
+            keyboard.press(KeyCode::ArrowLeft);
+        } else if target_x > player_x + AUTOPILOT_AIM_TOLERANCE {
+            keyboard.press(KeyCode::ArrowRight);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyId;
+    use bevy::app::{App, Update};
+    use bevy_test::{TestAppBuilder, get_resource_or_fail};
+
+    fn setup(player_x: f32, enemy_x: f32) -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_input()
+            .with_setup(|app| {
+                app.world_mut().spawn((
+                    PlayerComponent::default(),
+                    Transform::from_xyz(player_x, 0.0, 0.0),
+                ));
+                app.world_mut().spawn((
+                    EnemyComponent {
+                        id: EnemyId::new(1),
+                    },
+                    Transform::from_xyz(enemy_x, 100.0, 0.0),
+                ));
+                app.add_systems(Update, autopilot_system);
+            })
+            .build()
+    }
+
+    #[cfg(test)]
+    mod autopilot_system {
+        use super::*;
+
+        #[test]
+        fn should_always_fire() {
+            let mut app = setup(0.0, 0.0);
+
+            app.update();
+
+            let keyboard = get_resource_or_fail::<ButtonInput<KeyCode>>(&mut app);
+            assert!(keyboard.pressed(KeyCode::Space));
+            assert!(!keyboard.pressed(KeyCode::ArrowLeft));
+            assert!(!keyboard.pressed(KeyCode::ArrowRight));
+        }
+
+        #[test]
+        fn should_move_towards_the_closest_enemy() {
+            let mut app = setup(0.0, -200.0);
+
+            app.update();
+
+            let keyboard = get_resource_or_fail::<ButtonInput<KeyCode>>(&mut app);
+            assert!(keyboard.pressed(KeyCode::ArrowLeft));
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::infrastructure::bevy::bevy_renderer::plugin::SpaceInvadersPlugin;
+use crate::infrastructure::bevy::game_options::plugin::GameOptionsPlugin;
+use crate::infrastructure::bevy::game_options::resources::GameOptions;
+use crate::infrastructure::bevy::headless_renderer::plugin::HeadlessPlugin;
+use crate::infrastructure::bevy::headless_renderer::resources::HEADLESS_FRAME_DURATION;
+use crate::infrastructure::bevy::headless_renderer::systems::print_summary_system;
+use crate::infrastructure::bevy::terminal_renderer::resources::{
+    TERMINAL_FRAME_INTERVAL, TerminalFrameTimer,
+};
+use crate::infrastructure::bevy::terminal_renderer::systems::draw_terminal_system;
+use crate::infrastructure::renderer::Renderer;
+use bevy::MinimalPlugins;
+use bevy::app::{App, Last, Plugin, PluginGroup, PostUpdate, ScheduleRunnerPlugin};
+use bevy::prelude::{Timer, TimerMode};
+use std::time::Duration;
+
+pub struct TerminalRenderer {
+    options: GameOptions,
+}
+
+pub struct TerminalPlugin;
+
+impl Plugin for TerminalPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TerminalFrameTimer(Timer::from_seconds(
+            TERMINAL_FRAME_INTERVAL,
+            TimerMode::Repeating,
+        )))
+        .add_systems(PostUpdate, draw_terminal_system);
+    }
+}
+
+impl Renderer for TerminalRenderer {
+    fn render(&self) {
+        App::new()
+            .add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(
+                Duration::from_secs_f64(HEADLESS_FRAME_DURATION),
+            )))
+            .add_plugins(HeadlessPlugin)
+            .add_plugins(GameOptionsPlugin {
+                options: self.options.clone(),
+            })
+            .add_plugins(SpaceInvadersPlugin)
+            .add_plugins(TerminalPlugin)
+            .add_systems(Last, print_summary_system)
+            .run();
+    }
+}
+
+impl TerminalRenderer {
+    pub fn new(options: GameOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .without_auto_update()
+            .with_plugin(TerminalPlugin)
+            .build();
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::{Resource, Timer};
+
+pub const TERMINAL_COLUMNS: usize = 80;
+pub const TERMINAL_ROWS: usize = 30;
+pub const TERMINAL_FRAME_INTERVAL: f32 = 1.0 / 15.0;
+pub const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+pub const PLAYER_GLYPH: char = 'A';
+pub const ENEMY_GLYPH: char = 'W';
+pub const SHIELD_GLYPH: char = '#';
+pub const PLAYER_PROJECTILE_GLYPH: char = '|';
+pub const ENEMY_PROJECTILE_GLYPH: char = '!';
+pub const POWER_UP_GLYPH: char = '+';
+pub const BOSS_GLYPH: char = 'M';
+pub const MINION_GLYPH: char = 'v';
+pub const BOSS_LASER_GLYPH: char = ':';
+pub const UFO_GLYPH: char = '@';
+
+#[derive(Resource)]
+pub struct TerminalFrameTimer(pub Timer);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TerminalSprite {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    pub glyph: char,
+}
@@ -0,0 +1,209 @@
+use crate::infrastructure::bevy::boss::components::{
+    BossLaserComponent, BossMinionComponent, BossPartComponent,
+};
+use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+use crate::infrastructure::bevy::enemy_formation::resources::WaveResource;
+use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileComponent;
+use crate::infrastructure::bevy::game_area::resources::{GAME_AREA_HEIGHT, GAME_AREA_WIDTH};
+use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::player::components::PlayerComponent;
+use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+use crate::infrastructure::bevy::power_up::components::PowerUpComponent;
+use crate::infrastructure::bevy::score::resources::ScoreResource;
+use crate::infrastructure::bevy::shield::components::ShieldComponent;
+use crate::infrastructure::bevy::terminal_renderer::resources::{
+    BOSS_GLYPH, BOSS_LASER_GLYPH, CLEAR_SCREEN, ENEMY_GLYPH, ENEMY_PROJECTILE_GLYPH, MINION_GLYPH,
+    PLAYER_GLYPH, PLAYER_PROJECTILE_GLYPH, POWER_UP_GLYPH, SHIELD_GLYPH, TERMINAL_COLUMNS,
+    TERMINAL_ROWS, TerminalFrameTimer, TerminalSprite, UFO_GLYPH,
+};
+use crate::infrastructure::bevy::ufo::components::UfoComponent;
+use bevy::ecs::system::SystemParam;
+use bevy::math::Vec2;
+use bevy::prelude::{Component, Query, Res, ResMut, Sprite, Time, Transform, With};
+use std::io::Write;
+
+#[derive(SystemParam)]
+pub struct TerminalSprites<'w, 's> {
+    players: Query<'w, 's, (&'static Transform, &'static Sprite), With<PlayerComponent>>,
+    enemies: Query<'w, 's, (&'static Transform, &'static Sprite), With<EnemyComponent>>,
+    shields: Query<'w, 's, (&'static Transform, &'static Sprite), With<ShieldComponent>>,
+    player_projectiles:
+        Query<'w, 's, (&'static Transform, &'static Sprite), With<PlayerProjectileComponent>>,
+    enemy_projectiles:
+        Query<'w, 's, (&'static Transform, &'static Sprite), With<EnemyProjectileComponent>>,
+    power_ups: Query<'w, 's, (&'static Transform, &'static Sprite), With<PowerUpComponent>>,
+    boss_parts: Query<'w, 's, (&'static Transform, &'static Sprite), With<BossPartComponent>>,
+    boss_lasers: Query<'w, 's, (&'static Transform, &'static Sprite), With<BossLaserComponent>>,
+    minions: Query<'w, 's, (&'static Transform, &'static Sprite), With<BossMinionComponent>>,
+    ufos: Query<'w, 's, (&'static Transform, &'static Sprite), With<UfoComponent>>,
+}
+
+impl TerminalSprites<'_, '_> {
+    fn collect(&self) -> Vec<TerminalSprite> {
+        let mut sprites = vec![];
+
+        collect_sprites(&self.shields, SHIELD_GLYPH, &mut sprites);
+        collect_sprites(&self.enemies, ENEMY_GLYPH, &mut sprites);
+        collect_sprites(&self.players, PLAYER_GLYPH, &mut sprites);
+        collect_sprites(
+            &self.player_projectiles,
+            PLAYER_PROJECTILE_GLYPH,
+            &mut sprites,
+        );
+        collect_sprites(
+            &self.enemy_projectiles,
+            ENEMY_PROJECTILE_GLYPH,
+            &mut sprites,
+        );
+        collect_sprites(&self.power_ups, POWER_UP_GLYPH, &mut sprites);
+        collect_sprites(&self.boss_lasers, BOSS_LASER_GLYPH, &mut sprites);
+        collect_sprites(&self.boss_parts, BOSS_GLYPH, &mut sprites);
+        collect_sprites(&self.minions, MINION_GLYPH, &mut sprites);
+        collect_sprites(&self.ufos, UFO_GLYPH, &mut sprites);
+
+        sprites
+    }
+}
+
+fn collect_sprites<T: Component>(
+    query: &Query<(&Transform, &Sprite), With<T>>,
+    glyph: char,
+    sprites: &mut Vec<TerminalSprite>,
+) {
+    for (transform, sprite) in query.iter() {
+        let size = sprite.custom_size.unwrap_or(Vec2::ONE);
+
+        sprites.push(TerminalSprite {
+            x: transform.translation.x,
+            y: transform.translation.y,
+            width: size.x,
+            height: size.y,
+            glyph,
+        });
+    }
+}
+
+pub fn render_frame(sprites: &[TerminalSprite], status: &str) -> String {
+    let mut cells = vec![vec![' '; TERMINAL_COLUMNS]; TERMINAL_ROWS];
+    let cell_width = GAME_AREA_WIDTH / TERMINAL_COLUMNS as f32;
+    let cell_height = GAME_AREA_HEIGHT / TERMINAL_ROWS as f32;
+
+    let to_column = |x: f32| (x + GAME_AREA_WIDTH / 2.0) / cell_width;
+    let to_row = |y: f32| (GAME_AREA_HEIGHT / 2.0 - y) / cell_height;
+
+    for sprite in sprites {
+        let first_column = to_column(sprite.x - sprite.width / 2.0).floor();
+        let last_column = (to_column(sprite.x + sprite.width / 2.0).ceil() - 1.0).max(first_column);
+        let first_row = to_row(sprite.y + sprite.height / 2.0).floor();
+        let last_row = (to_row(sprite.y - sprite.height / 2.0).ceil() - 1.0).max(first_row);
+
+        for row in first_row as i32..=last_row as i32 {
+            for column in first_column as i32..=last_column as i32 {
+                if (0..TERMINAL_ROWS as i32).contains(&row)
+                    && (0..TERMINAL_COLUMNS as i32).contains(&column)
+                {
+                    cells[row as usize][column as usize] = sprite.glyph;
+                }
+            }
+        }
+    }
+
+    let border = format!("+{}+", "-".repeat(TERMINAL_COLUMNS));
+    let mut frame = format!("{}\n{}\n", status, border);
+
+    for row in cells {
+        frame.push('|');
+        frame.extend(row);
+        frame.push_str("|\n");
+    }
+
+    frame.push_str(&border);
+    frame
+}
+
+pub fn draw_terminal_system(
+    time: Res<Time>,
+    mut timer: ResMut<TerminalFrameTimer>,
+    sprites: TerminalSprites,
+    score: Res<ScoreResource>,
+    lives: Res<LivesResource>,
+    wave: Res<WaveResource>,
+) {
+    if !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let status = format!(
+        "SCORE {:05}   LIVES {}   WAVE {}",
+        score.0.get_current(),
+        lives.0.get_current(),
+        wave.0.get_number()
+    );
+
+    let mut stdout = std::io::stdout().lock();
+    let _ = writeln!(
+        stdout,
+        "{}{}",
+        CLEAR_SCREEN,
+        render_frame(&sprites.collect(), &status)
+    );
+    let _ = stdout.flush();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sprite_at(x: f32, y: f32, glyph: char) -> TerminalSprite {
+        TerminalSprite {
+            x,
+            y,
+            width: 1.0,
+            height: 1.0,
+            glyph,
+        }
+    }
+
+    fn frame_rows(frame: &str) -> Vec<&str> {
+        frame.lines().skip(2).take(TERMINAL_ROWS).collect()
+    }
+
+    #[test]
+    fn empty_frame_has_a_border_and_the_status_line() {
+        let frame = render_frame(&[], "SCORE 00000");
+        let lines: Vec<&str> = frame.lines().collect();
+
+        assert_eq!(lines[0], "SCORE 00000");
+        assert_eq!(lines.len(), TERMINAL_ROWS + 3);
+        assert!(lines[1].starts_with('+'));
+        assert_eq!(lines[2].chars().count(), TERMINAL_COLUMNS + 2);
+    }
+
+    #[test]
+    fn sprite_in_the_centre_is_drawn_in_the_middle_cell() {
+        let frame = render_frame(&[sprite_at(0.1, -0.1, PLAYER_GLYPH)], "");
+        let rows = frame_rows(&frame);
+
+        let row: Vec<char> = rows[TERMINAL_ROWS / 2].chars().collect();
+        assert_eq!(row[TERMINAL_COLUMNS / 2 + 1], PLAYER_GLYPH);
+    }
+
+    #[test]
+    fn sprites_outside_the_game_area_are_clipped() {
+        let frame = render_frame(&[sprite_at(GAME_AREA_WIDTH, 0.0, ENEMY_GLYPH)], "");
+
+        assert!(!frame.contains(ENEMY_GLYPH));
+    }
+
+    #[test]
+    fn wide_sprites_cover_several_cells() {
+        let sprite = TerminalSprite {
+            width: GAME_AREA_WIDTH / 4.0,
+            ..sprite_at(0.0, 0.0, SHIELD_GLYPH)
+        };
+
+        let frame = render_frame(&[sprite], "");
+
+        assert!(frame.matches(SHIELD_GLYPH).count() >= TERMINAL_COLUMNS / 4);
+    }
+}
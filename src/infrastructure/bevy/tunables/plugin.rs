@@ -0,0 +1,42 @@
+use crate::infrastructure::bevy::tunables::resources::{
+    TUNABLES_FILE, Tunables, TunablesResource, TunablesWatcherResource,
+};
+use crate::infrastructure::bevy::tunables::systems::{load_tunables_system, watch_tunables_system};
+use crate::infrastructure::storage::asset_file;
+use bevy::app::{App, First, Plugin, Startup};
+
+pub struct TunablesPlugin;
+
+impl Plugin for TunablesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TunablesResource(Tunables::default()))
+            .insert_resource(TunablesWatcherResource::new(asset_file(TUNABLES_FILE)))
+            .add_systems(Startup, load_tunables_system)
+            .add_systems(First, watch_tunables_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_formation::plugin::EnemyFormationPlugin;
+    use crate::infrastructure::bevy::enemy_projectile::plugin::EnemyProjectilePlugin;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::player::plugin::PlayerPlugin;
+    use crate::infrastructure::bevy::player_projectile::plugin::PlayerProjectilePlugin;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_assets()
+            .with_input()
+            .with_plugin(EnemyFormationPlugin)
+            .with_plugin(EnemyProjectilePlugin)
+            .with_plugin(PlayerProjectilePlugin)
+            .with_plugin(PlayerPlugin)
+            .with_plugin(TunablesPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
@@ -0,0 +1,272 @@
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    ENEMY_FIRE_PROBABILITY, ENEMY_FORMATION_STEP_DURATION,
+};
+use crate::infrastructure::bevy::enemy_projectile::resources::{
+    ENEMY_PROJECTILE_DURATION, ENEMY_PROJECTILE_SPEED,
+};
+use crate::infrastructure::bevy::player::resources::PLAYER_SPEED;
+use crate::infrastructure::bevy::player_projectile::resources::{
+    PLAYER_PROJECTILE_DURATION, PLAYER_PROJECTILE_SPEED,
+};
+use crate::infrastructure::storage::from_ron;
+use bevy::prelude::{Resource, Timer, TimerMode};
+use std::fmt::{Display, Formatter};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+pub const TUNABLES_FILE: &str = "tunables.ron";
+pub const TUNABLES_POLL_INTERVAL: f32 = 1.0;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Tunables {
+    pub enemy_fire_probability: f64,
+    pub enemy_formation_step_duration: f32,
+    pub enemy_projectile_spawn_interval: f32,
+    pub player_projectile_duration: f32,
+    pub player_speed: f32,
+    pub player_projectile_speed: f32,
+    pub enemy_projectile_speed: f32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TunablesError {
+    Parse(String),
+    OutOfRange { name: &'static str, value: f64 },
+}
+
+impl Display for TunablesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TunablesError::Parse(reason) => write!(f, "cannot parse tunables: {}", reason),
+            TunablesError::OutOfRange { name, value } => {
+                write!(f, "{} has an invalid value: {}", name, value)
+            }
+        }
+    }
+}
+
+impl Default for Tunables {
+    fn default() -> Self {
+        Self {
+            enemy_fire_probability: ENEMY_FIRE_PROBABILITY,
+            enemy_formation_step_duration: ENEMY_FORMATION_STEP_DURATION,
+            enemy_projectile_spawn_interval: ENEMY_PROJECTILE_DURATION,
+            player_projectile_duration: PLAYER_PROJECTILE_DURATION,
+            player_speed: PLAYER_SPEED,
+            player_projectile_speed: PLAYER_PROJECTILE_SPEED,
+            enemy_projectile_speed: ENEMY_PROJECTILE_SPEED,
+        }
+    }
+}
+
+impl Tunables {
+    pub fn parse(source: &str) -> Result<Self, TunablesError> {
+        let tunables: Tunables = from_ron(source).map_err(TunablesError::Parse)?;
+
+        tunables.validate()?;
+
+        Ok(tunables)
+    }
+
+    pub fn validate(&self) -> Result<(), TunablesError> {
+        if !(0.0..=1.0).contains(&self.enemy_fire_probability) {
+            return Err(TunablesError::OutOfRange {
+                name: "enemy_fire_probability",
+                value: self.enemy_fire_probability,
+            });
+        }
+
+        let durations = [
+            (
+                "enemy_formation_step_duration",
+                self.enemy_formation_step_duration,
+            ),
+            (
+                "enemy_projectile_spawn_interval",
+                self.enemy_projectile_spawn_interval,
+            ),
+            (
+                "player_projectile_duration",
+                self.player_projectile_duration,
+            ),
+        ];
+
+        for (name, value) in durations {
+            if !value.is_finite() || value <= 0.0 {
+                return Err(TunablesError::OutOfRange {
+                    name,
+                    value: value as f64,
+                });
+            }
+        }
+
+        let speeds = [
+            ("player_speed", self.player_speed),
+            ("player_projectile_speed", self.player_projectile_speed),
+            ("enemy_projectile_speed", self.enemy_projectile_speed),
+        ];
+
+        for (name, value) in speeds {
+            if !value.is_finite() || value < 0.0 {
+                return Err(TunablesError::OutOfRange {
+                    name,
+                    value: value as f64,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn changes(&self, updated: &Tunables) -> Vec<(&'static str, f64, f64)> {
+        self.values()
+            .into_iter()
+            .zip(updated.values())
+            .filter(|((_, old), (_, new))| old != new)
+            .map(|((name, old), (_, new))| (name, old, new))
+            .collect()
+    }
+
+    fn values(&self) -> [(&'static str, f64); 7] {
+        [
+            ("enemy_fire_probability", self.enemy_fire_probability),
+            (
+                "enemy_formation_step_duration",
+                self.enemy_formation_step_duration as f64,
+            ),
+            (
+                "enemy_projectile_spawn_interval",
+                self.enemy_projectile_spawn_interval as f64,
+            ),
+            (
+                "player_projectile_duration",
+                self.player_projectile_duration as f64,
+            ),
+            ("player_speed", self.player_speed as f64),
+            (
+                "player_projectile_speed",
+                self.player_projectile_speed as f64,
+            ),
+            ("enemy_projectile_speed", self.enemy_projectile_speed as f64),
+        ]
+    }
+}
+
+#[derive(Resource)]
+pub struct TunablesResource(pub Tunables);
+
+#[derive(Resource)]
+pub struct TunablesWatcherResource {
+    pub path: PathBuf,
+    pub last_modified: Option<SystemTime>,
+    pub timer: Timer,
+}
+
+impl TunablesWatcherResource {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_modified: None,
+            timer: Timer::from_seconds(TUNABLES_POLL_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_tunables_are_valid() {
+        assert_eq!(Tunables::default().validate(), Ok(()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_parse_a_complete_file() {
+        let tunables = Tunables::parse(
+            "(enemy_fire_probability: 0.5, enemy_formation_step_duration: 0.3, \
+             enemy_projectile_spawn_interval: 1.0, player_projectile_duration: 2.0, \
+             player_speed: 400.0, player_projectile_speed: 600.0, enemy_projectile_speed: 250.0)",
+        )
+        .expect("Tunables should parse");
+
+        assert_eq!(tunables.enemy_fire_probability, 0.5);
+        assert_eq!(tunables.enemy_formation_step_duration, 0.3);
+        assert_eq!(tunables.player_speed, 400.0);
+        assert_eq!(tunables.enemy_projectile_speed, 250.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let tunables = Tunables::parse("(player_speed: 420.0)").expect("Tunables should parse");
+
+        assert_eq!(tunables.player_speed, 420.0);
+        assert_eq!(tunables.enemy_fire_probability, ENEMY_FIRE_PROBABILITY);
+    }
+
+    #[test]
+    fn should_reject_unknown_fields() {
+        let result = Tunables::parse("(player_sped: 420.0)");
+
+        assert!(matches!(result, Err(TunablesError::Parse(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_probability_out_of_range() {
+        let result = Tunables::parse("(enemy_fire_probability: 1.5)");
+
+        assert_eq!(
+            result,
+            Err(TunablesError::OutOfRange {
+                name: "enemy_fire_probability",
+                value: 1.5
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_non_positive_durations() {
+        let result = Tunables::parse("(enemy_formation_step_duration: 0.0)");
+
+        assert_eq!(
+            result,
+            Err(TunablesError::OutOfRange {
+                name: "enemy_formation_step_duration",
+                value: 0.0
+            })
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_reject_negative_speeds() {
+        let result = Tunables::parse("(player_speed: -10.0)");
+
+        assert_eq!(
+            result,
+            Err(TunablesError::OutOfRange {
+                name: "player_speed",
+                value: -10.0
+            })
+        );
+    }
+
+    #[test]
+    fn should_list_only_the_changed_values() {
+        let current = Tunables::default();
+        let updated = Tunables {
+            player_speed: 450.0,
+            ..Tunables::default()
+        };
+
+        let changes = current.changes(&updated);
+
+        assert_eq!(changes, vec![("player_speed", PLAYER_SPEED as f64, 450.0)]);
+    }
+}
@@ -0,0 +1,297 @@
+use crate::infrastructure::bevy::enemy::resources::{
+    EnemyFireProbability, EnemyProjectileMovementTimer,
+};
+use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationMovementTimer;
+use crate::infrastructure::bevy::enemy_projectile::resources::EnemyProjectileSpeed;
+use crate::infrastructure::bevy::player::resources::PlayerSpeed;
+use crate::infrastructure::bevy::player_projectile::resources::{
+    PlayerProjectileDuration, PlayerProjectileSpeed,
+};
+use crate::infrastructure::bevy::tunables::resources::{
+    Tunables, TunablesResource, TunablesWatcherResource,
+};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{Res, ResMut, Time};
+use std::fs;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+#[derive(SystemParam)]
+pub struct TunableTargets<'w> {
+    enemy_fire_probability: ResMut<'w, EnemyFireProbability>,
+    enemy_formation_timer: ResMut<'w, EnemyFormationMovementTimer>,
+    enemy_projectile_timer: ResMut<'w, EnemyProjectileMovementTimer>,
+    player_projectile_duration: ResMut<'w, PlayerProjectileDuration>,
+    player_speed: ResMut<'w, PlayerSpeed>,
+    player_projectile_speed: ResMut<'w, PlayerProjectileSpeed>,
+    enemy_projectile_speed: ResMut<'w, EnemyProjectileSpeed>,
+}
+
+impl TunableTargets<'_> {
+    fn apply(&mut self, tunables: &Tunables) {
+        self.enemy_fire_probability.0 = tunables.enemy_fire_probability;
+        self.enemy_formation_timer
+            .0
+            .set_duration(Duration::from_secs_f32(
+                tunables.enemy_formation_step_duration,
+            ));
+        self.enemy_projectile_timer
+            .0
+            .set_duration(Duration::from_secs_f32(
+                tunables.enemy_projectile_spawn_interval,
+            ));
+        self.player_projectile_duration.0 = tunables.player_projectile_duration;
+        self.player_speed.0 = tunables.player_speed;
+        self.player_projectile_speed.0 = tunables.player_projectile_speed;
+        self.enemy_projectile_speed.0 = tunables.enemy_projectile_speed;
+    }
+}
+
+pub fn load_tunables_system(
+    mut watcher: ResMut<TunablesWatcherResource>,
+    mut tunables_resource: ResMut<TunablesResource>,
+    mut targets: TunableTargets,
+) {
+    reload_tunables(&mut watcher, &mut tunables_resource, &mut targets);
+}
+
+pub fn watch_tunables_system(
+    time: Res<Time>,
+    mut watcher: ResMut<TunablesWatcherResource>,
+    mut tunables_resource: ResMut<TunablesResource>,
+    mut targets: TunableTargets,
+) {
+    if watcher.timer.tick(time.delta()).just_finished() {
+        reload_tunables(&mut watcher, &mut tunables_resource, &mut targets);
+    }
+}
+
+fn reload_tunables(
+    watcher: &mut TunablesWatcherResource,
+    tunables_resource: &mut TunablesResource,
+    targets: &mut TunableTargets,
+) {
+    let Ok(modified) = fs::metadata(&watcher.path).and_then(|metadata| metadata.modified()) else {
+        return;
+    };
+
+    if watcher.last_modified == Some(modified) {
+        return;
+    }
+
+    watcher.last_modified = Some(modified);
+
+    let source = match fs::read_to_string(&watcher.path) {
+        Ok(source) => source,
+        Err(error) => {
+            warn!("Cannot read {}: {}", watcher.path.display(), error);
+            return;
+        }
+    };
+
+    match Tunables::parse(&source) {
+        Ok(updated) => {
+            let changes = tunables_resource.0.changes(&updated);
+
+            for (name, old, new) in &changes {
+                info!("Tunable {} changed from {} to {}", name, old, new);
+            }
+
+            if !changes.is_empty() {
+                targets.apply(&updated);
+                tunables_resource.0 = updated;
+            }
+        }
+        Err(reason) => error!(
+            "Rejected tunables from {}: {}",
+            watcher.path.display(),
+            reason
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::enemy_formation::resources::ENEMY_FORMATION_STEP_DURATION;
+    use crate::infrastructure::bevy::player::resources::PLAYER_SPEED;
+    use bevy::app::{App, Startup, Update};
+    use bevy::prelude::{Timer, TimerMode};
+    use bevy_test::{TestAppBuilder, advance_time_by_seconds, get_resource_or_fail};
+    use std::path::PathBuf;
+
+    fn tunables_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "space-invaders-{}-{}.ron",
+            name,
+            std::process::id()
+        ));
+        fs::write(&path, content).expect("Cannot write tunables file");
+        path
+    }
+
+    fn setup(path: PathBuf) -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_setup(|app| {
+                app.insert_resource(TunablesResource(Tunables::default()))
+                    .insert_resource(TunablesWatcherResource::new(path))
+                    .insert_resource(EnemyFireProbability(0.2))
+                    .insert_resource(EnemyFormationMovementTimer(Timer::from_seconds(
+                        ENEMY_FORMATION_STEP_DURATION,
+                        TimerMode::Repeating,
+                    )))
+                    .insert_resource(EnemyProjectileMovementTimer(Timer::from_seconds(
+                        1.2,
+                        TimerMode::Repeating,
+                    )))
+                    .insert_resource(PlayerProjectileDuration(1.2))
+                    .insert_resource(PlayerSpeed(PLAYER_SPEED))
+                    .insert_resource(PlayerProjectileSpeed(500.0))
+                    .insert_resource(EnemyProjectileSpeed(500.0));
+            })
+            .build()
+    }
+
+    #[cfg(test)]
+    mod load_tunables_system {
+        use super::*;
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_apply_the_tunables_from_the_file() {
+            let path = tunables_file(
+                "load",
+                "(enemy_fire_probability: 0.7, enemy_formation_step_duration: 0.25, player_projectile_duration: 0.9, player_speed: 123.0)",
+            );
+            let mut app = setup(path);
+            app.add_systems(Startup, load_tunables_system);
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.7
+            );
+            assert_eq!(get_resource_or_fail::<PlayerSpeed>(&mut app).0, 123.0);
+            assert_eq!(
+                get_resource_or_fail::<PlayerProjectileDuration>(&mut app).0,
+                0.9
+            );
+            assert_eq!(
+                get_resource_or_fail::<EnemyFormationMovementTimer>(&mut app)
+                    .0
+                    .duration(),
+                Duration::from_secs_f32(0.25)
+            );
+            assert_eq!(
+                get_resource_or_fail::<TunablesResource>(&mut app)
+                    .0
+                    .player_speed,
+                123.0
+            );
+        }
+
+        #[test]
+        fn should_keep_defaults_when_the_file_is_missing() {
+            let mut app = setup(PathBuf::from("does/not/exist.ron"));
+            app.add_systems(Startup, load_tunables_system);
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerSpeed>(&mut app).0,
+                PLAYER_SPEED
+            );
+            assert_eq!(
+                get_resource_or_fail::<TunablesResource>(&mut app).0,
+                Tunables::default()
+            );
+        }
+
+        #[test]
+        fn should_reject_invalid_files_without_crashing() {
+            let path = tunables_file("invalid", "(enemy_fire_probability: 3.0)");
+            let mut app = setup(path);
+            app.add_systems(Startup, load_tunables_system);
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<EnemyFireProbability>(&mut app).0,
+                0.2
+            );
+            assert_eq!(
+                get_resource_or_fail::<TunablesResource>(&mut app).0,
+                Tunables::default()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod watch_tunables_system {
+        use super::*;
+        #[cfg(feature = "serde")]
+        use bevy_test::get_resource_mut_or_fail;
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_apply_edits_after_the_poll_interval() {
+            let path = tunables_file("watch", "(player_projectile_speed: 500.0)");
+            let mut app = setup(path.clone());
+            app.add_systems(Update, watch_tunables_system);
+
+            fs::write(
+                &path,
+                "(player_projectile_speed: 900.0, enemy_projectile_speed: 50.0)",
+            )
+            .expect("Cannot write tunables file");
+
+            advance_time_by_seconds(&mut app, 1.1);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerProjectileSpeed>(&mut app).0,
+                900.0
+            );
+            assert_eq!(
+                get_resource_or_fail::<EnemyProjectileSpeed>(&mut app).0,
+                50.0
+            );
+        }
+
+        #[test]
+        fn should_not_reload_before_the_poll_interval() {
+            let path = tunables_file("early", "(player_speed: 10.0)");
+            let mut app = setup(path);
+            app.add_systems(Update, watch_tunables_system);
+
+            advance_time_by_seconds(&mut app, 0.1);
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerSpeed>(&mut app).0,
+                PLAYER_SPEED
+            );
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn should_keep_the_last_valid_tunables_after_a_bad_edit() {
+            let path = tunables_file("bad-edit", "(player_speed: 200.0)");
+            let mut app = setup(path.clone());
+            app.add_systems(Update, watch_tunables_system);
+
+            advance_time_by_seconds(&mut app, 1.1);
+            app.update();
+
+            fs::write(&path, "(player_speed: ").expect("Cannot write tunables file");
+            get_resource_mut_or_fail::<TunablesWatcherResource>(&mut app).last_modified = None;
+
+            advance_time_by_seconds(&mut app, 1.1);
+            app.update();
+
+            assert_eq!(get_resource_or_fail::<PlayerSpeed>(&mut app).0, 200.0);
+        }
+    }
+}
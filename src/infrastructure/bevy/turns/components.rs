@@ -0,0 +1,43 @@
+use crate::domain::game_mode::PlayerSlot;
+use crate::infrastructure::bevy::turns::resources::{
+    PLAYER_SCORE_FONT_SIZE, PLAYER_SCORE_MARGIN_LEFT, WAITING_PLAYER_SCORE_COLOR, player_score_text,
+};
+use bevy::asset::Handle;
+use bevy::prelude::{Bundle, Component, default};
+use bevy::text::{Font, TextColor, TextFont};
+use bevy::ui::widget::Text;
+use bevy::ui::{Node, UiRect, Val};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct PlayerScoreComponent(pub PlayerSlot);
+
+#[derive(Component, PartialEq, Debug)]
+pub struct TurnBannerComponent;
+
+#[derive(Bundle)]
+pub struct PlayerScoreBundle {
+    pub player_score: PlayerScoreComponent,
+    pub node: Node,
+    pub text: Text,
+    pub text_font: TextFont,
+    pub text_color: TextColor,
+}
+
+impl PlayerScoreBundle {
+    pub fn new(font: Handle<Font>, slot: PlayerSlot) -> Self {
+        Self {
+            player_score: PlayerScoreComponent(slot),
+            node: Node {
+                margin: UiRect::left(Val::Px(PLAYER_SCORE_MARGIN_LEFT)),
+                ..default()
+            },
+            text: Text::new(player_score_text(slot.number(), 0)),
+            text_font: TextFont {
+                font,
+                font_size: PLAYER_SCORE_FONT_SIZE,
+                ..default()
+            },
+            text_color: TextColor(WAITING_PLAYER_SCORE_COLOR),
+        }
+    }
+}
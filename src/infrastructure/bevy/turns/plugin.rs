@@ -0,0 +1,96 @@
+use crate::infrastructure::bevy::game_state::resources::{GameState, GameplaySystems, HudSystems};
+use crate::infrastructure::bevy::lives::systems::handle_player_killed_system;
+use crate::infrastructure::bevy::score::systems::spawn_score_system;
+use crate::infrastructure::bevy::turns::resources::{
+    IncomingPlayerResource, TURN_CHANGE_DURATION, TurnChangeTimerResource, TurnsResource,
+    WaitingPlayerResource,
+};
+use crate::infrastructure::bevy::turns::systems::{
+    prepare_turns_system, reset_turns_system, restore_incoming_player_system,
+    spawn_player_scores_system, spawn_turn_banner_system, stash_current_player_system,
+    turn_change_countdown_system, turn_change_system, update_player_scores_system,
+};
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, OnEnter, Timer, TimerMode, in_state};
+
+pub struct TurnsPlugin;
+
+impl Plugin for TurnsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TurnsResource>()
+            .init_resource::<WaitingPlayerResource>()
+            .init_resource::<IncomingPlayerResource>()
+            .insert_resource(TurnChangeTimerResource(Timer::from_seconds(
+                TURN_CHANGE_DURATION,
+                TimerMode::Once,
+            )))
+            .add_systems(
+                Startup,
+                (
+                    prepare_turns_system,
+                    spawn_player_scores_system.after(spawn_score_system),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::PlayerChange),
+                (
+                    stash_current_player_system,
+                    restore_incoming_player_system,
+                    spawn_turn_banner_system,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    turn_change_system
+                        .after(handle_player_killed_system)
+                        .in_set(GameplaySystems),
+                    turn_change_countdown_system.run_if(in_state(GameState::PlayerChange)),
+                    update_player_scores_system.in_set(HudSystems),
+                    reset_turns_system,
+                ),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::lives::Lives;
+    use crate::domain::score::Score;
+    use crate::infrastructure::bevy::enemy_formation::resources::{
+        DifficultyResource, EnemyFormationResource, FormationLayoutsResource,
+        FormationMovementsResource, StartingWaveResource,
+    };
+    use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::lives::resources::LivesResource;
+    use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
+    use crate::infrastructure::bevy::shield_formation::resources::ShieldLayoutsResource;
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = bevy_test::TestAppBuilder::new()
+            .with_assets()
+            .with_setup(|app| {
+                app.add_plugins(StatesPlugin)
+                    .init_state::<GameState>()
+                    .init_resource::<StartingWaveResource>()
+                    .init_resource::<DifficultyResource>()
+                    .init_resource::<FormationLayoutsResource>()
+                    .init_resource::<FormationMovementsResource>()
+                    .init_resource::<ShieldLayoutsResource>()
+                    .insert_resource(ScoreResource(Score::new()))
+                    .init_resource::<SecondPlayerScoreResource>()
+                    .insert_resource(LivesResource(Lives::new()))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::new()));
+            })
+            .with_plugin(TurnsPlugin)
+            .with_message::<PlayerKilledMessage>()
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
@@ -0,0 +1,104 @@
+use crate::domain::enemy_formation::EnemyFormation;
+use crate::domain::formation_layout::FormationLayout;
+use crate::domain::game_mode::{GameMode, Turns};
+use crate::domain::lives::DEFAULT_LIVES;
+use crate::domain::movement_pattern::FormationMovement;
+use crate::domain::player::Player;
+use crate::domain::shield_layout::ShieldLayout;
+use crate::domain::wave::Wave;
+use crate::infrastructure::bevy::player::resources::PLAYER_X;
+use crate::infrastructure::bevy::save_game::resources::{
+    FormationSnapshot, GameSnapshot, SAVE_GAME_VERSION, ShieldSnapshot, encode_rows,
+};
+use crate::infrastructure::bevy::shield_formation::systems::shield_positions;
+use bevy::color::Color;
+use bevy::prelude::{Resource, Timer};
+
+pub const TURN_CHANGE_DURATION: f32 = 2.0;
+pub const PLAYER_SCORE_FONT_SIZE: f32 = 14.0;
+pub const PLAYER_SCORE_MARGIN_LEFT: f32 = 40.0;
+pub const ACTIVE_PLAYER_SCORE_COLOR: Color = Color::srgb_u8(51, 255, 3);
+pub const WAITING_PLAYER_SCORE_COLOR: Color = Color::srgb_u8(128, 128, 128);
+
+#[derive(Resource, Default)]
+pub struct TurnsResource(pub Turns);
+
+#[derive(Resource, Default)]
+pub struct WaitingPlayerResource(pub Option<GameSnapshot>);
+
+#[derive(Resource, Default)]
+pub struct IncomingPlayerResource(pub Option<GameSnapshot>);
+
+#[derive(Resource)]
+pub struct TurnChangeTimerResource(pub Timer);
+
+pub fn current_mode(turns: Option<&TurnsResource>) -> GameMode {
+    turns.map_or(GameMode::Single, |turns| turns.0.get_mode())
+}
+
+pub fn player_score_text(number: u8, score: u32) -> String {
+    format!("{}UP {}", number, score)
+}
+
+pub fn fresh_player_snapshot(
+    wave: Wave,
+    formation: &FormationLayout,
+    movement: FormationMovement,
+    shields: &ShieldLayout,
+) -> GameSnapshot {
+    let bitmap = encode_rows(shields.get_shape().get_pixels());
+
+    GameSnapshot {
+        version: SAVE_GAME_VERSION,
+        wave: wave.get_number(),
+        score: 0,
+        lives: DEFAULT_LIVES,
+        formation: FormationSnapshot::new(
+            &EnemyFormation::from_layout_at_row(formation, wave.starting_row())
+                .with_movement(movement),
+        ),
+        shields: shield_positions(shields)
+            .into_iter()
+            .map(|x| ShieldSnapshot {
+                x,
+                bitmap: bitmap.clone(),
+            })
+            .collect(),
+        player_x: Some(PLAYER_X),
+        player: Player::new(),
+        player_projectiles: vec![],
+        enemy_projectiles: vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fresh_player_starts_a_new_game_at_the_given_wave() {
+        let snapshot = fresh_player_snapshot(
+            Wave::new(3),
+            &FormationLayout::classic(),
+            FormationMovement::ClassicMarch,
+            &ShieldLayout::classic(),
+        );
+
+        assert_eq!(snapshot.wave, 3);
+        assert_eq!(snapshot.score, 0);
+        assert_eq!(snapshot.lives, DEFAULT_LIVES);
+        assert_eq!(snapshot.shields.len(), 4);
+        assert_eq!(
+            snapshot
+                .formation
+                .to_formation()
+                .expect("Formation should be valid"),
+            EnemyFormation::new_at_row(Wave::new(3).starting_row())
+        );
+    }
+
+    #[test]
+    fn player_scores_are_labelled_by_player_number() {
+        assert_eq!(player_score_text(2, 150), "2UP 150");
+    }
+}
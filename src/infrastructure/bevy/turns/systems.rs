@@ -0,0 +1,568 @@
+use crate::domain::enemy_formation::FormationStatus;
+use crate::domain::game_mode::{GameMode, PlayerSlot, TurnChange, Turns};
+use crate::domain::lives::Lives;
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    DifficultyResource, EnemyFormationResource, FormationLayoutsResource,
+    FormationMovementsResource, StartingWaveResource,
+};
+use crate::infrastructure::bevy::enemy_projectile::components::PlayerKilledMessage;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::game_state::resources::GameState;
+use crate::infrastructure::bevy::header::resources::FONT;
+use crate::infrastructure::bevy::high_scores::components::{ScreenBundle, ScreenTextBundle};
+use crate::infrastructure::bevy::high_scores::resources::{
+    SCREEN_TITLE_COLOR, SCREEN_TITLE_FONT_SIZE,
+};
+use crate::infrastructure::bevy::lives::resources::LivesResource;
+use crate::infrastructure::bevy::save_game::resources::GameSnapshot;
+use crate::infrastructure::bevy::save_game::systems::{GameSnapshotSource, GameSnapshotTarget};
+use crate::infrastructure::bevy::score::components::ScoreContainerComponent;
+use crate::infrastructure::bevy::score::resources::{ScoreResource, SecondPlayerScoreResource};
+use crate::infrastructure::bevy::shield_formation::resources::ShieldLayoutsResource;
+use crate::infrastructure::bevy::turns::components::{
+    PlayerScoreBundle, PlayerScoreComponent, TurnBannerComponent,
+};
+use crate::infrastructure::bevy::turns::resources::{
+    ACTIVE_PLAYER_SCORE_COLOR, IncomingPlayerResource, TurnChangeTimerResource, TurnsResource,
+    WAITING_PLAYER_SCORE_COLOR, WaitingPlayerResource, fresh_player_snapshot, player_score_text,
+};
+use bevy::asset::AssetServer;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::{
+    Commands, DespawnOnExit, DetectChanges, Entity, MessageReader, NextState, Query, Res, ResMut,
+    Text, TextColor, Time, With,
+};
+use tracing::{error, info};
+
+#[derive(SystemParam)]
+pub struct FreshPlayerSettings<'w> {
+    starting_wave: Res<'w, StartingWaveResource>,
+    layouts: Res<'w, FormationLayoutsResource>,
+    movements: Res<'w, FormationMovementsResource>,
+    shield_layouts: Res<'w, ShieldLayoutsResource>,
+    difficulty: Res<'w, DifficultyResource>,
+}
+
+impl FreshPlayerSettings<'_> {
+    fn waiting_player(&self, turns: &Turns) -> Option<GameSnapshot> {
+        let starting_wave = self.starting_wave.0;
+
+        (turns.get_mode() == GameMode::Alternating).then(|| GameSnapshot {
+            lives: self.difficulty.0.starting_lives(),
+            ..fresh_player_snapshot(
+                starting_wave,
+                self.layouts.0.for_wave(starting_wave),
+                self.movements.0.for_wave(starting_wave),
+                self.shield_layouts.0.for_wave(starting_wave),
+            )
+        })
+    }
+}
+
+pub fn prepare_turns_system(
+    turns: Res<TurnsResource>,
+    settings: FreshPlayerSettings,
+    mut waiting: ResMut<WaitingPlayerResource>,
+) {
+    waiting.0 = settings.waiting_player(&turns.0);
+}
+
+pub fn reset_turns_system(
+    mut new_game_message: MessageReader<NewGameMessage>,
+    settings: FreshPlayerSettings,
+    mut turns: ResMut<TurnsResource>,
+    mut waiting: ResMut<WaitingPlayerResource>,
+    mut incoming: ResMut<IncomingPlayerResource>,
+) {
+    if new_game_message.read().count() == 0 {
+        return;
+    }
+
+    turns.0.reset();
+    waiting.0 = settings.waiting_player(&turns.0);
+    incoming.0 = None;
+}
+
+pub fn turn_change_system(
+    mut player_killed_message: MessageReader<PlayerKilledMessage>,
+    turns: Res<TurnsResource>,
+    waiting: Res<WaitingPlayerResource>,
+    enemy_formation: Res<EnemyFormationResource>,
+    mut lives: ResMut<LivesResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let killed = player_killed_message.read().count() > 0;
+    let breached = enemy_formation.0.get_status() == FormationStatus::Breached;
+
+    if !killed && !breached {
+        return;
+    }
+
+    let waiting_lives = waiting.0.as_ref().map(|snapshot| snapshot.lives);
+
+    if turns.0.after_death(waiting_lives) != TurnChange::Swap {
+        return;
+    }
+
+    if breached {
+        lives.0 = Lives::with_count(0);
+    }
+
+    next_state.set(GameState::PlayerChange);
+}
+
+pub fn stash_current_player_system(
+    source: GameSnapshotSource,
+    mut waiting: ResMut<WaitingPlayerResource>,
+    mut incoming: ResMut<IncomingPlayerResource>,
+) {
+    incoming.0 = waiting.0.replace(source.snapshot());
+}
+
+pub fn restore_incoming_player_system(
+    mut incoming: ResMut<IncomingPlayerResource>,
+    mut target: GameSnapshotTarget,
+    mut turns: ResMut<TurnsResource>,
+    mut timer: ResMut<TurnChangeTimerResource>,
+) {
+    let Some(snapshot) = incoming.0.take() else {
+        return;
+    };
+
+    if let Err(reason) = target.restore(&snapshot) {
+        error!("Cannot switch players: {}", reason);
+        return;
+    }
+
+    turns.0.swap();
+    timer.0.reset();
+
+    info!("Player {} up", turns.0.get_current().number());
+}
+
+pub fn spawn_turn_banner_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    turns: Res<TurnsResource>,
+) {
+    let font = asset_server.load(FONT);
+
+    commands
+        .spawn((
+            TurnBannerComponent,
+            ScreenBundle::new(),
+            DespawnOnExit(GameState::PlayerChange),
+        ))
+        .with_children(|screen| {
+            screen.spawn(ScreenTextBundle::new(
+                format!("PLAYER {}", turns.0.get_current().number()),
+                font,
+                SCREEN_TITLE_FONT_SIZE,
+                SCREEN_TITLE_COLOR,
+            ));
+        });
+}
+
+pub fn turn_change_countdown_system(
+    time: Res<Time>,
+    mut timer: ResMut<TurnChangeTimerResource>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if timer.0.tick(time.delta()).just_finished() {
+        next_state.set(GameState::Playing);
+    }
+}
+
+pub fn spawn_player_scores_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    turns: Res<TurnsResource>,
+    score_container_query: Query<Entity, With<ScoreContainerComponent>>,
+) {
+    if turns.0.get_mode().player_count() < 2 {
+        return;
+    }
+
+    if let Ok(score_container) = score_container_query.single() {
+        let font = asset_server.load(FONT);
+        let current = turns.0.get_current();
+
+        commands.entity(score_container).with_children(|parent| {
+            parent.spawn(PlayerScoreBundle::new(font.clone(), current));
+            parent.spawn(PlayerScoreBundle::new(font, current.other()));
+        });
+    }
+}
+
+pub fn update_player_scores_system(
+    turns: Res<TurnsResource>,
+    score: Res<ScoreResource>,
+    waiting: Res<WaitingPlayerResource>,
+    second_player_score: Res<SecondPlayerScoreResource>,
+    mut player_score_query: Query<(&PlayerScoreComponent, &mut Text, &mut TextColor)>,
+) {
+    if !turns.is_changed()
+        && !score.is_changed()
+        && !waiting.is_changed()
+        && !second_player_score.is_changed()
+    {
+        return;
+    }
+
+    let current = turns.0.get_current();
+    let waiting_score = waiting.0.as_ref().map_or(0, |snapshot| snapshot.score);
+
+    for (player_score, mut text, mut color) in player_score_query.iter_mut() {
+        let slot = player_score.0;
+
+        if let Some(second) = &second_player_score.0 {
+            let points = match slot {
+                PlayerSlot::One => score.0.get_current(),
+                PlayerSlot::Two => second.get_current(),
+            };
+            text.0 = player_score_text(slot.number(), points);
+            color.0 = ACTIVE_PLAYER_SCORE_COLOR;
+        } else if slot == current {
+            text.0 = player_score_text(slot.number(), score.0.get_current());
+            color.0 = ACTIVE_PLAYER_SCORE_COLOR;
+        } else {
+            text.0 = player_score_text(slot.number(), waiting_score);
+            color.0 = WAITING_PLAYER_SCORE_COLOR;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::formation_layout::FormationLayout;
+    use crate::domain::movement_pattern::FormationMovement;
+    use crate::domain::score::Score;
+    use crate::domain::shield_formation::ShieldFormation;
+    use crate::domain::shield_layout::ShieldLayout;
+    use crate::domain::wave::Wave;
+    use crate::domain::weapons::WeaponUpgrade;
+    use crate::infrastructure::bevy::enemy_formation::resources::WaveResource;
+    use crate::infrastructure::bevy::player::resources::PlayerResource;
+    use crate::infrastructure::bevy::player_projectile::resources::PlayerProjectileDuration;
+    use crate::infrastructure::bevy::shield_formation::resources::ShieldFormationResource;
+    use crate::infrastructure::bevy::turns::resources::TURN_CHANGE_DURATION;
+    use bevy::app::{App, Update};
+    use bevy::asset::AssetApp;
+    use bevy::image::Image;
+    use bevy::prelude::{IntoScheduleConfigs, State, Timer, TimerMode};
+    use bevy::state::app::{AppExtStates, StatesPlugin};
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, get_resource_or_fail, send_message,
+        spawn_dummy_entity,
+    };
+
+    fn setup(mode: GameMode) -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_assets()
+            .with_time()
+            .with_setup(move |app| {
+                app.add_plugins(StatesPlugin)
+                    .init_state::<GameState>()
+                    .init_asset::<Image>()
+                    .add_message::<PlayerKilledMessage>()
+                    .add_message::<NewGameMessage>()
+                    .insert_resource(TurnsResource(Turns::new(mode)))
+                    .insert_resource(StartingWaveResource(Wave::default()))
+                    .init_resource::<DifficultyResource>()
+                    .init_resource::<FormationLayoutsResource>()
+                    .init_resource::<FormationMovementsResource>()
+                    .init_resource::<ShieldLayoutsResource>()
+                    .init_resource::<WaitingPlayerResource>()
+                    .init_resource::<IncomingPlayerResource>()
+                    .insert_resource(TurnChangeTimerResource(Timer::from_seconds(
+                        TURN_CHANGE_DURATION,
+                        TimerMode::Once,
+                    )))
+                    .insert_resource(ScoreResource(Score::with_points(120)))
+                    .insert_resource(LivesResource(Lives::with_count(2)))
+                    .insert_resource(WaveResource(Wave::default()))
+                    .insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                    .insert_resource(ShieldFormationResource(ShieldFormation::new()))
+                    .init_resource::<ShieldLayoutsResource>()
+                    .init_resource::<PlayerResource>()
+                    .init_resource::<SecondPlayerScoreResource>()
+                    .insert_resource(PlayerProjectileDuration(1.0));
+            })
+            .build()
+    }
+
+    fn current_state(app: &mut App) -> GameState {
+        *get_resource_or_fail::<State<GameState>>(app).get()
+    }
+
+    fn waiting_score(app: &mut App) -> Option<u32> {
+        get_resource_or_fail::<WaitingPlayerResource>(app)
+            .0
+            .as_ref()
+            .map(|snapshot| snapshot.score)
+    }
+
+    #[cfg(test)]
+    mod reset_turns_system {
+        use super::*;
+
+        #[test]
+        fn alternating_games_start_with_a_fresh_second_player() {
+            let mut app = setup(GameMode::Alternating);
+            app.add_systems(Update, reset_turns_system);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(waiting_score(&mut app), Some(0));
+        }
+
+        #[test]
+        fn single_player_games_have_nobody_waiting() {
+            let mut app = setup(GameMode::Single);
+            app.add_systems(Update, reset_turns_system);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(waiting_score(&mut app), None);
+        }
+    }
+
+    #[cfg(test)]
+    mod turn_change_system {
+        use super::*;
+
+        fn kill_player(app: &mut App) {
+            let entity = spawn_dummy_entity(app);
+            send_message(app, PlayerKilledMessage::new(entity, PlayerSlot::One));
+            app.update();
+            app.update();
+        }
+
+        #[test]
+        fn death_hands_over_to_the_waiting_player() {
+            let mut app = setup(GameMode::Alternating);
+            app.add_systems(bevy::app::Startup, prepare_turns_system)
+                .add_systems(Update, turn_change_system);
+            app.update();
+
+            kill_player(&mut app);
+
+            assert_eq!(current_state(&mut app), GameState::PlayerChange);
+        }
+
+        #[test]
+        fn death_keeps_the_turn_in_single_player_games() {
+            let mut app = setup(GameMode::Single);
+            app.add_systems(Update, turn_change_system);
+
+            kill_player(&mut app);
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn death_keeps_the_turn_when_the_other_player_is_out() {
+            let mut app = setup(GameMode::Alternating);
+            let mut snapshot = fresh_player_snapshot(
+                Wave::default(),
+                &FormationLayout::classic(),
+                FormationMovement::ClassicMarch,
+                &ShieldLayout::classic(),
+            );
+            snapshot.lives = 0;
+            app.insert_resource(WaitingPlayerResource(Some(snapshot)))
+                .add_systems(Update, turn_change_system);
+
+            kill_player(&mut app);
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+
+        #[test]
+        fn death_keeps_both_players_on_the_field_in_coop() {
+            let mut app = setup(GameMode::Coop);
+            app.add_systems(bevy::app::Startup, prepare_turns_system)
+                .add_systems(Update, turn_change_system);
+            app.update();
+
+            kill_player(&mut app);
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+    }
+
+    #[cfg(test)]
+    mod swap_players {
+        use super::*;
+
+        #[test]
+        fn should_swap_the_active_and_waiting_players() {
+            let mut app = setup(GameMode::Alternating);
+            let mut snapshot = fresh_player_snapshot(
+                Wave::new(2),
+                &FormationLayout::classic(),
+                FormationMovement::ClassicMarch,
+                &ShieldLayout::classic(),
+            );
+            snapshot.score = 40;
+            app.insert_resource(WaitingPlayerResource(Some(snapshot)))
+                .add_systems(
+                    Update,
+                    (stash_current_player_system, restore_incoming_player_system).chain(),
+                );
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<ScoreResource>(&mut app)
+                    .0
+                    .get_current(),
+                40
+            );
+            assert_eq!(
+                get_resource_or_fail::<WaveResource>(&mut app).0,
+                Wave::new(2)
+            );
+            assert_eq!(waiting_score(&mut app), Some(120));
+            assert_eq!(
+                get_resource_or_fail::<TurnsResource>(&mut app)
+                    .0
+                    .get_current(),
+                PlayerSlot::Two
+            );
+        }
+
+        #[test]
+        fn should_keep_the_weapon_upgrade_of_each_player() {
+            let mut app = setup(GameMode::Alternating);
+            let snapshot = fresh_player_snapshot(
+                Wave::new(1),
+                &FormationLayout::classic(),
+                FormationMovement::ClassicMarch,
+                &ShieldLayout::classic(),
+            );
+            app.insert_resource(WaitingPlayerResource(Some(snapshot)))
+                .add_systems(
+                    Update,
+                    (stash_current_player_system, restore_incoming_player_system).chain(),
+                );
+            app.world_mut()
+                .resource_mut::<PlayerResource>()
+                .get_mut(PlayerSlot::One)
+                .equip(WeaponUpgrade::DoubleShot);
+
+            app.update();
+
+            assert_eq!(
+                get_resource_or_fail::<PlayerResource>(&mut app)
+                    .get(PlayerSlot::One)
+                    .get_upgrade(),
+                WeaponUpgrade::Standard
+            );
+            assert_eq!(
+                get_resource_or_fail::<WaitingPlayerResource>(&mut app)
+                    .0
+                    .as_ref()
+                    .map(|snapshot| snapshot.player.get_upgrade()),
+                Some(WeaponUpgrade::DoubleShot)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod turn_change_countdown_system {
+        use super::*;
+
+        #[test]
+        fn should_resume_play_once_the_banner_has_been_shown() {
+            let mut app = setup(GameMode::Alternating);
+            app.insert_state(GameState::PlayerChange).add_systems(
+                Update,
+                turn_change_countdown_system
+                    .run_if(bevy::prelude::in_state(GameState::PlayerChange)),
+            );
+            app.update();
+
+            advance_time_by_seconds(&mut app, TURN_CHANGE_DURATION + 0.1);
+            app.update();
+            app.update();
+
+            assert_eq!(current_state(&mut app), GameState::Playing);
+        }
+    }
+
+    #[cfg(test)]
+    mod update_player_scores_system {
+        use super::*;
+
+        #[test]
+        fn should_show_both_scores_and_highlight_the_active_player() {
+            let mut app = setup(GameMode::Alternating);
+            let mut snapshot = fresh_player_snapshot(
+                Wave::default(),
+                &FormationLayout::classic(),
+                FormationMovement::ClassicMarch,
+                &ShieldLayout::classic(),
+            );
+            snapshot.score = 70;
+            app.insert_resource(WaitingPlayerResource(Some(snapshot)))
+                .add_systems(Update, update_player_scores_system);
+            app.world_mut().spawn((
+                PlayerScoreComponent(PlayerSlot::One),
+                Text::new(""),
+                TextColor::default(),
+            ));
+            app.world_mut().spawn((
+                PlayerScoreComponent(PlayerSlot::Two),
+                Text::new(""),
+                TextColor::default(),
+            ));
+
+            app.update();
+
+            let mut scores: Vec<(String, TextColor)> = app
+                .world_mut()
+                .query::<(&PlayerScoreComponent, &Text, &TextColor)>()
+                .iter(app.world())
+                .map(|(_, text, color)| (text.0.clone(), *color))
+                .collect();
+            scores.sort_by(|a, b| a.0.cmp(&b.0));
+
+            assert_eq!(
+                scores,
+                vec![
+                    ("1UP 120".to_string(), TextColor(ACTIVE_PLAYER_SCORE_COLOR)),
+                    ("2UP 70".to_string(), TextColor(WAITING_PLAYER_SCORE_COLOR)),
+                ]
+            );
+        }
+
+        #[test]
+        fn should_highlight_both_players_in_coop() {
+            let mut app = setup(GameMode::Coop);
+            app.insert_resource(SecondPlayerScoreResource(Some(Score::with_points(30))))
+                .add_systems(Update, update_player_scores_system);
+            app.world_mut().spawn((
+                PlayerScoreComponent(PlayerSlot::Two),
+                Text::new(""),
+                TextColor::default(),
+            ));
+
+            app.update();
+
+            let (text, color) = app
+                .world_mut()
+                .query::<(&Text, &TextColor)>()
+                .single(app.world())
+                .map(|(text, color)| (text.0.clone(), *color))
+                .expect("Player score not found");
+
+            assert_eq!(text, "2UP 30");
+            assert_eq!(color, TextColor(ACTIVE_PLAYER_SCORE_COLOR));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::domain::enemy_formation::MovingDirection;
+use crate::domain::game_mode::PlayerSlot;
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_WIDTH;
+use crate::infrastructure::bevy::ufo::resources::{UFO_COLOR, UFO_HEIGHT, UFO_WIDTH, UFO_Y};
+use bevy::math::Vec2;
+use bevy::prelude::{Bundle, Component, Entity, Message, Sprite, Transform, default};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct UfoComponent {
+    pub direction: MovingDirection,
+}
+
+#[derive(Bundle)]
+pub struct UfoBundle {
+    pub ufo: UfoComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+}
+
+impl UfoBundle {
+    pub fn new(direction: MovingDirection) -> Self {
+        let edge = (GAME_AREA_WIDTH + UFO_WIDTH) / 2.0;
+        let x = match direction {
+            MovingDirection::ToRight => -edge,
+            MovingDirection::ToLeft => edge,
+        };
+
+        Self {
+            ufo: UfoComponent { direction },
+            sprite: Sprite {
+                color: UFO_COLOR,
+                custom_size: Some(Vec2::new(UFO_WIDTH, UFO_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::from_xyz(x, UFO_Y, 0.0),
+        }
+    }
+}
+
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct UfoShotMessage {
+    pub projectile_entity: Entity,
+    pub player: PlayerSlot,
+    pub points: u32,
+}
+
+impl UfoShotMessage {
+    pub fn new(projectile_entity: Entity, player: PlayerSlot, points: u32) -> Self {
+        Self {
+            projectile_entity,
+            player,
+            points,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ufos_enter_from_the_side_they_fly_away_from() {
+        let to_right = UfoBundle::new(MovingDirection::ToRight);
+        let to_left = UfoBundle::new(MovingDirection::ToLeft);
+
+        assert!(to_right.transform.translation.x < -(GAME_AREA_WIDTH / 2.0));
+        assert!(to_left.transform.translation.x > GAME_AREA_WIDTH / 2.0);
+        assert_eq!(to_right.transform.translation.y, UFO_Y);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::infrastructure::bevy::enemy_formation::resources::GameRngResource;
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::player_projectile::systems::player_projectile_lifecycle_system;
+use crate::infrastructure::bevy::ufo::components::UfoShotMessage;
+use crate::infrastructure::bevy::ufo::systems::{
+    reset_ufo_system, ufo_collisions_system, ufo_flight_system,
+};
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::IntoScheduleConfigs;
+
+pub struct UfoPlugin;
+
+impl Plugin for UfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameRngResource>()
+            .add_message::<UfoShotMessage>()
+            .add_systems(
+                Update,
+                (
+                    ufo_flight_system,
+                    ufo_collisions_system
+                        .after(ufo_flight_system)
+                        .before(player_projectile_lifecycle_system),
+                )
+                    .in_set(GameplaySystems),
+            )
+            .add_systems(Update, reset_ufo_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use bevy_test::TestAppBuilder;
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let _app = TestAppBuilder::new()
+            .with_plugin(UfoPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+    }
+}
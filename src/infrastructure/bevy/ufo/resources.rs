@@ -0,0 +1,10 @@
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_HEIGHT;
+use crate::infrastructure::bevy::header::resources::HEADER_HEIGHT;
+use bevy::color::Color;
+
+pub const UFO_SPEED: f32 = 160.0;
+pub const UFO_WIDTH: f32 = 64.0;
+pub const UFO_HEIGHT: f32 = 24.0;
+pub const UFO_Y: f32 = GAME_AREA_HEIGHT / 2.0 - HEADER_HEIGHT / 2.0;
+pub const UFO_COLOR: Color = Color::srgb_u8(255, 64, 64);
+pub const UFO_POINTS: [u32; 4] = [50, 100, 150, 300];
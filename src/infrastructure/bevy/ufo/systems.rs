@@ -0,0 +1,211 @@
+use crate::domain::collision::check_aabb_collision;
+use crate::domain::enemy_formation::MovingDirection;
+use crate::infrastructure::bevy::enemy_formation::resources::GameRngResource;
+use crate::infrastructure::bevy::game_area::resources::GAME_AREA_WIDTH;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileComponent;
+use crate::infrastructure::bevy::ufo::components::{UfoComponent, UfoShotMessage};
+use crate::infrastructure::bevy::ufo::resources::{UFO_POINTS, UFO_SPEED, UFO_WIDTH};
+use bevy::math::Vec2;
+use bevy::prelude::{
+    Commands, Entity, MessageReader, MessageWriter, Query, Res, ResMut, Sprite, Time, Transform,
+    With,
+};
+use rand::Rng;
+use tracing::info;
+
+pub fn ufo_flight_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut ufo_query: Query<(Entity, &UfoComponent, &mut Transform)>,
+) {
+    let distance = UFO_SPEED * time.delta_secs();
+    let edge = (GAME_AREA_WIDTH + UFO_WIDTH) / 2.0;
+
+    for (entity, ufo, mut transform) in ufo_query.iter_mut() {
+        match ufo.direction {
+            MovingDirection::ToRight => transform.translation.x += distance,
+            MovingDirection::ToLeft => transform.translation.x -= distance,
+        }
+
+        if transform.translation.x.abs() > edge {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+pub fn ufo_collisions_system(
+    mut commands: Commands,
+    mut rng: ResMut<GameRngResource>,
+    player_projectile_query: Query<(Entity, &Transform, &Sprite, &PlayerProjectileComponent)>,
+    ufo_query: Query<(Entity, &Transform, &Sprite), With<UfoComponent>>,
+    mut ufo_shot_message_writer: MessageWriter<UfoShotMessage>,
+) {
+    let mut shot_ufos = Vec::new();
+
+    for (projectile_entity, projectile_transform, projectile_sprite, projectile) in
+        player_projectile_query.iter()
+    {
+        let projectile_size = projectile_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+        for (ufo_entity, ufo_transform, ufo_sprite) in ufo_query.iter() {
+            let ufo_size = ufo_sprite.custom_size.unwrap_or(Vec2::ONE);
+
+            if shot_ufos.contains(&ufo_entity)
+                || !check_aabb_collision(
+                    (
+                        projectile_transform.translation.x,
+                        projectile_transform.translation.y,
+                    ),
+                    (projectile_size.x, projectile_size.y),
+                    (ufo_transform.translation.x, ufo_transform.translation.y),
+                    (ufo_size.x, ufo_size.y),
+                )
+            {
+                continue;
+            }
+
+            let points = UFO_POINTS[rng.0.random_range(0..UFO_POINTS.len())];
+
+            shot_ufos.push(ufo_entity);
+            commands.entity(ufo_entity).despawn();
+            ufo_shot_message_writer.write(UfoShotMessage::new(
+                projectile_entity,
+                projectile.shooter,
+                points,
+            ));
+            info!("UFO shot down for {} points", points);
+        }
+    }
+}
+
+pub fn reset_ufo_system(
+    mut commands: Commands,
+    mut new_game_message: MessageReader<NewGameMessage>,
+    ufo_query: Query<Entity, With<UfoComponent>>,
+) {
+    if new_game_message.read().count() > 0 {
+        for entity in ufo_query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::game_mode::PlayerSlot;
+    use crate::infrastructure::bevy::player_projectile::components::PlayerProjectileBundle;
+    use crate::infrastructure::bevy::ufo::components::UfoBundle;
+    use crate::infrastructure::bevy::ufo::resources::UFO_Y;
+    use bevy::app::{App, Update};
+    use bevy::prelude::Messages;
+    use bevy_test::{
+        TestAppBuilder, advance_time_by_seconds, contains_entity, count_components, send_message,
+    };
+
+    fn setup() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_time()
+            .with_setup(|app| {
+                app.insert_resource(GameRngResource::seeded(7))
+                    .add_message::<UfoShotMessage>()
+                    .add_message::<NewGameMessage>();
+            })
+            .build()
+    }
+
+    #[cfg(test)]
+    mod ufo_flight_system {
+        use super::*;
+
+        #[test]
+        fn should_fly_across_the_screen_and_leave() {
+            let mut app = setup();
+            app.add_systems(Update, ufo_flight_system);
+            let ufo = app
+                .world_mut()
+                .spawn(UfoBundle::new(MovingDirection::ToLeft))
+                .id();
+
+            advance_time_by_seconds(&mut app, 1.0);
+            app.update();
+
+            let x = app
+                .world()
+                .get::<Transform>(ufo)
+                .expect("UFO should still fly")
+                .translation
+                .x;
+            assert!(x < (GAME_AREA_WIDTH + UFO_WIDTH) / 2.0);
+
+            advance_time_by_seconds(&mut app, (GAME_AREA_WIDTH + UFO_WIDTH) / UFO_SPEED);
+            app.update();
+
+            assert!(!contains_entity(&app, ufo));
+        }
+    }
+
+    #[cfg(test)]
+    mod ufo_collisions_system {
+        use super::*;
+
+        #[test]
+        fn should_shoot_down_the_ufo_and_credit_the_shooter() {
+            let mut app = setup();
+            app.add_systems(Update, ufo_collisions_system);
+            let mut ufo = UfoBundle::new(MovingDirection::ToRight);
+            ufo.transform.translation.x = 0.0;
+            let ufo = app.world_mut().spawn(ufo).id();
+            let projectile = app
+                .world_mut()
+                .spawn(PlayerProjectileBundle::new(0.0, UFO_Y, PlayerSlot::Two))
+                .id();
+
+            app.update();
+
+            let shots = app
+                .world_mut()
+                .resource_mut::<Messages<UfoShotMessage>>()
+                .drain()
+                .collect::<Vec<_>>();
+            assert!(!contains_entity(&app, ufo));
+            assert_eq!(shots.len(), 1);
+            assert_eq!(shots[0].projectile_entity, projectile);
+            assert_eq!(shots[0].player, PlayerSlot::Two);
+            assert!(UFO_POINTS.contains(&shots[0].points));
+        }
+
+        #[test]
+        fn should_ignore_shots_that_miss() {
+            let mut app = setup();
+            app.add_systems(Update, ufo_collisions_system);
+            app.world_mut()
+                .spawn(UfoBundle::new(MovingDirection::ToRight));
+            app.world_mut()
+                .spawn(PlayerProjectileBundle::new(0.0, UFO_Y, PlayerSlot::One));
+
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+    }
+
+    #[cfg(test)]
+    mod reset_ufo_system {
+        use super::*;
+
+        #[test]
+        fn should_remove_the_ufo_on_new_game() {
+            let mut app = setup();
+            app.add_systems(Update, reset_ufo_system);
+            app.world_mut()
+                .spawn(UfoBundle::new(MovingDirection::ToRight));
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 0);
+        }
+    }
+}
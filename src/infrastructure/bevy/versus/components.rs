@@ -0,0 +1,31 @@
+use crate::infrastructure::bevy::versus::resources::{
+    GUNNER_CURSOR_COLOR, GUNNER_CURSOR_HEIGHT, GUNNER_CURSOR_WIDTH,
+};
+use bevy::math::Vec2;
+use bevy::prelude::{Bundle, Component, Sprite, Transform, Visibility, default};
+
+#[derive(Component, PartialEq, Debug)]
+pub struct GunnerCursorComponent;
+
+#[derive(Bundle)]
+pub struct GunnerCursorBundle {
+    pub cursor: GunnerCursorComponent,
+    pub sprite: Sprite,
+    pub transform: Transform,
+    pub visibility: Visibility,
+}
+
+impl Default for GunnerCursorBundle {
+    fn default() -> Self {
+        Self {
+            cursor: GunnerCursorComponent,
+            sprite: Sprite {
+                color: GUNNER_CURSOR_COLOR,
+                custom_size: Some(Vec2::new(GUNNER_CURSOR_WIDTH, GUNNER_CURSOR_HEIGHT)),
+                ..default()
+            },
+            transform: Transform::default(),
+            visibility: Visibility::Hidden,
+        }
+    }
+}
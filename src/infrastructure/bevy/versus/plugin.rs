@@ -0,0 +1,75 @@
+use crate::infrastructure::bevy::enemy_formation::resources::{
+    EnemyFireController, EnemyFireControllerResource,
+};
+use crate::infrastructure::bevy::game_state::resources::GameplaySystems;
+use crate::infrastructure::bevy::versus::resources::{
+    GUNNER_AIM_STEP_DURATION, GUNNER_FIRE_COOLDOWN, GUNNER_UFO_COOLDOWN, GunnerAimTimer,
+    GunnerCooldownTimer, GunnerResource, GunnerUfoTimer,
+};
+use crate::infrastructure::bevy::versus::systems::{
+    gunner_aim_system, gunner_cursor_system, gunner_fire_system, gunner_ufo_system,
+    reset_gunner_system, spawn_gunner_cursor_system,
+};
+use bevy::app::{App, Plugin, Startup, Update};
+use bevy::prelude::{IntoScheduleConfigs, Timer, TimerMode, resource_equals};
+use std::time::Duration;
+
+pub struct VersusPlugin;
+
+impl Plugin for VersusPlugin {
+    fn build(&self, app: &mut App) {
+        let mut aim_timer = Timer::from_seconds(GUNNER_AIM_STEP_DURATION, TimerMode::Once);
+        aim_timer.tick(Duration::from_secs_f32(GUNNER_AIM_STEP_DURATION));
+
+        app.insert_resource(EnemyFireControllerResource(EnemyFireController::Gunner))
+            .init_resource::<GunnerResource>()
+            .insert_resource(GunnerAimTimer(aim_timer))
+            .insert_resource(GunnerCooldownTimer(Timer::from_seconds(
+                GUNNER_FIRE_COOLDOWN,
+                TimerMode::Once,
+            )))
+            .insert_resource(GunnerUfoTimer(Timer::from_seconds(
+                GUNNER_UFO_COOLDOWN,
+                TimerMode::Once,
+            )))
+            .add_systems(Startup, spawn_gunner_cursor_system)
+            .add_systems(
+                Update,
+                (
+                    gunner_aim_system,
+                    gunner_fire_system.after(gunner_aim_system),
+                    gunner_cursor_system.after(gunner_aim_system),
+                    gunner_ufo_system.after(gunner_aim_system),
+                )
+                    .in_set(GameplaySystems)
+                    .run_if(resource_equals(EnemyFireControllerResource(
+                        EnemyFireController::Gunner,
+                    ))),
+            )
+            .add_systems(Update, reset_gunner_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationResource;
+    use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+    use crate::infrastructure::bevy::versus::components::GunnerCursorComponent;
+    use bevy_test::{TestAppBuilder, contains_single_component};
+
+    #[test]
+    fn plugin_loads_successfully() {
+        let mut app = TestAppBuilder::new()
+            .with_input()
+            .with_setup(|app| {
+                app.insert_resource(EnemyFormationResource(EnemyFormation::new()));
+            })
+            .with_plugin(VersusPlugin)
+            .with_message::<NewGameMessage>()
+            .build();
+
+        assert!(contains_single_component::<GunnerCursorComponent>(&mut app));
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::gunner::Gunner;
+use crate::infrastructure::bevy::player::resources::{PLAYER_TWO_CONTROLS, PlayerControls};
+use bevy::color::Color;
+use bevy::prelude::{KeyCode, Resource, Timer};
+
+pub const GUNNER_FIRE_COOLDOWN: f32 = 0.8;
+pub const GUNNER_AIM_STEP_DURATION: f32 = 0.15;
+pub const GUNNER_UFO_COOLDOWN: f32 = 12.0;
+pub const GUNNER_CURSOR_WIDTH: f32 = 20.0;
+pub const GUNNER_CURSOR_HEIGHT: f32 = 4.0;
+pub const GUNNER_CURSOR_OFFSET_Y: f32 = 6.0;
+pub const GUNNER_CURSOR_COLOR: Color = Color::srgb_u8(64, 200, 255);
+pub const GUNNER_CONTROLS: PlayerControls = PLAYER_TWO_CONTROLS;
+pub const GUNNER_UFO_KEYS: &[KeyCode] = &[KeyCode::ArrowUp];
+
+#[derive(Resource, Default)]
+pub struct GunnerResource(pub Gunner);
+
+#[derive(Resource)]
+pub struct GunnerCooldownTimer(pub Timer);
+
+#[derive(Resource)]
+pub struct GunnerAimTimer(pub Timer);
+
+#[derive(Resource)]
+pub struct GunnerUfoTimer(pub Timer);
@@ -0,0 +1,407 @@
+use crate::domain::enemy_formation::{COLUMNS, MovingDirection};
+use crate::infrastructure::bevy::enemy::components::EnemyComponent;
+use crate::infrastructure::bevy::enemy::resources::ENEMY_HEIGHT;
+use crate::infrastructure::bevy::enemy_formation::resources::EnemyFormationResource;
+use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileBundle;
+use crate::infrastructure::bevy::game_state::components::NewGameMessage;
+use crate::infrastructure::bevy::ufo::components::{UfoBundle, UfoComponent};
+use crate::infrastructure::bevy::versus::components::{GunnerCursorBundle, GunnerCursorComponent};
+use crate::infrastructure::bevy::versus::resources::{
+    GUNNER_CONTROLS, GUNNER_CURSOR_OFFSET_Y, GUNNER_UFO_KEYS, GunnerAimTimer, GunnerCooldownTimer,
+    GunnerResource, GunnerUfoTimer,
+};
+use bevy::input::ButtonInput;
+use bevy::prelude::{
+    Commands, KeyCode, MessageReader, Query, Res, ResMut, Time, Transform, Visibility, With,
+    Without,
+};
+use tracing::info;
+
+pub fn spawn_gunner_cursor_system(mut commands: Commands) {
+    commands.spawn(GunnerCursorBundle::default());
+}
+
+pub fn gunner_aim_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    enemy_formation: Res<EnemyFormationResource>,
+    mut gunner: ResMut<GunnerResource>,
+    mut timer: ResMut<GunnerAimTimer>,
+) {
+    timer.0.tick(time.delta());
+    gunner.0.settle(&enemy_formation.0);
+
+    let direction = if keyboard.any_pressed(GUNNER_CONTROLS.left.iter().copied()) {
+        MovingDirection::ToLeft
+    } else if keyboard.any_pressed(GUNNER_CONTROLS.right.iter().copied()) {
+        MovingDirection::ToRight
+    } else {
+        return;
+    };
+
+    if timer.0.is_finished() {
+        gunner.0.aim(&enemy_formation.0, direction);
+        timer.0.reset();
+    }
+}
+
+pub fn gunner_fire_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    enemy_formation: Res<EnemyFormationResource>,
+    gunner: Res<GunnerResource>,
+    mut cooldown: ResMut<GunnerCooldownTimer>,
+    enemy_query: Query<(&EnemyComponent, &Transform)>,
+) {
+    cooldown.0.tick(time.delta());
+
+    if !cooldown.0.is_finished() || !keyboard.any_pressed(GUNNER_CONTROLS.fire.iter().copied()) {
+        return;
+    }
+
+    let Some(target) = gunner.0.target(&enemy_formation.0) else {
+        return;
+    };
+
+    if let Some((_, transform)) = enemy_query.iter().find(|(enemy, _)| enemy.id == target) {
+        commands.spawn(EnemyProjectileBundle::new(
+            transform.translation.x,
+            transform.translation.y,
+        ));
+        cooldown.0.reset();
+        info!("Gunner fired from column {}", gunner.0.get_column());
+    }
+}
+
+pub fn gunner_ufo_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    gunner: Res<GunnerResource>,
+    mut cooldown: ResMut<GunnerUfoTimer>,
+    ufo_query: Query<(), With<UfoComponent>>,
+) {
+    cooldown.0.tick(time.delta());
+
+    if !cooldown.0.is_finished()
+        || !ufo_query.is_empty()
+        || !keyboard.any_pressed(GUNNER_UFO_KEYS.iter().copied())
+    {
+        return;
+    }
+
+    let direction = if gunner.0.get_column() < COLUMNS / 2 {
+        MovingDirection::ToRight
+    } else {
+        MovingDirection::ToLeft
+    };
+
+    commands.spawn(UfoBundle::new(direction));
+    cooldown.0.reset();
+    info!("Gunner called in the UFO");
+}
+
+pub fn gunner_cursor_system(
+    enemy_formation: Res<EnemyFormationResource>,
+    gunner: Res<GunnerResource>,
+    enemy_query: Query<(&EnemyComponent, &Transform), Without<GunnerCursorComponent>>,
+    mut cursor_query: Query<(&mut Transform, &mut Visibility), With<GunnerCursorComponent>>,
+) {
+    let target = gunner.0.target(&enemy_formation.0).and_then(|target| {
+        enemy_query
+            .iter()
+            .find(|(enemy, _)| enemy.id == target)
+            .map(|(_, transform)| transform.translation)
+    });
+
+    for (mut transform, mut visibility) in cursor_query.iter_mut() {
+        match target {
+            Some(translation) => {
+                transform.translation.x = translation.x;
+                transform.translation.y =
+                    translation.y - ENEMY_HEIGHT / 2.0 - GUNNER_CURSOR_OFFSET_Y;
+                *visibility = Visibility::Inherited;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+pub fn reset_gunner_system(
+    mut new_game_message: MessageReader<NewGameMessage>,
+    mut gunner: ResMut<GunnerResource>,
+    mut cooldown: ResMut<GunnerCooldownTimer>,
+    mut ufo_cooldown: ResMut<GunnerUfoTimer>,
+) {
+    if new_game_message.read().count() > 0 {
+        gunner.0.reset();
+        cooldown.0.reset();
+        ufo_cooldown.0.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::enemy::EnemyKind;
+    use crate::domain::enemy_formation::EnemyFormation;
+    use crate::domain::gunner::Gunner;
+    use crate::infrastructure::bevy::enemy::components::EnemyBundle;
+    use crate::infrastructure::bevy::versus::resources::{
+        GUNNER_AIM_STEP_DURATION, GUNNER_FIRE_COOLDOWN, GUNNER_UFO_COOLDOWN,
+    };
+    use bevy::app::{App, Update};
+    use bevy::asset::AssetServer;
+    use bevy::prelude::{Entity, Timer, TimerMode};
+    use bevy_test::{TestAppBuilder, advance_time_by_seconds};
+    use std::time::Duration;
+
+    fn finished_timer(seconds: f32) -> Timer {
+        let mut timer = Timer::from_seconds(seconds, TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(seconds));
+        timer
+    }
+
+    fn setup() -> App {
+        TestAppBuilder::with_time_disabled()
+            .with_input()
+            .with_time()
+            .with_assets()
+            .with_setup(|app| {
+                app.insert_resource(EnemyFormationResource(EnemyFormation::new()))
+                    .init_resource::<GunnerResource>()
+                    .insert_resource(GunnerAimTimer(finished_timer(GUNNER_AIM_STEP_DURATION)))
+                    .insert_resource(GunnerCooldownTimer(finished_timer(GUNNER_FIRE_COOLDOWN)))
+                    .insert_resource(GunnerUfoTimer(finished_timer(GUNNER_UFO_COOLDOWN)));
+            })
+            .build()
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+    }
+
+    fn gunner(app: &App) -> Gunner {
+        app.world().resource::<GunnerResource>().0
+    }
+
+    fn spawn_bottom_row(app: &mut App) {
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let formation = app.world().resource::<EnemyFormationResource>().0.clone();
+
+        for column in 0..COLUMNS {
+            if let Some(id) = formation.bottom_enemy(column) {
+                app.world_mut().spawn(EnemyBundle::new(
+                    id,
+                    EnemyKind::default(),
+                    column as f32 * 30.0,
+                    100.0,
+                    &asset_server,
+                ));
+            }
+        }
+    }
+
+    mod gunner_aim_system {
+        use super::*;
+
+        #[test]
+        fn should_step_one_column_per_aim_step() {
+            let mut app = setup();
+            app.add_systems(Update, gunner_aim_system);
+            press(&mut app, KeyCode::ArrowLeft);
+
+            app.update();
+
+            assert_eq!(gunner(&app).get_column(), 4);
+
+            app.update();
+
+            assert_eq!(gunner(&app).get_column(), 4);
+
+            advance_time_by_seconds(&mut app, GUNNER_AIM_STEP_DURATION);
+            app.update();
+
+            assert_eq!(gunner(&app).get_column(), 3);
+        }
+
+        #[test]
+        fn should_ignore_the_defender_keys() {
+            let mut app = setup();
+            app.add_systems(Update, gunner_aim_system);
+            press(&mut app, KeyCode::KeyA);
+
+            app.update();
+
+            assert_eq!(gunner(&app).get_column(), 5);
+        }
+    }
+
+    mod gunner_fire_system {
+        use super::*;
+        use crate::infrastructure::bevy::enemy_projectile::components::EnemyProjectileComponent;
+        use bevy_test::count_components;
+
+        #[test]
+        fn should_fire_from_the_targeted_invader() {
+            let mut app = setup();
+            spawn_bottom_row(&mut app);
+            app.add_systems(Update, gunner_fire_system);
+            press(&mut app, KeyCode::Enter);
+
+            app.update();
+
+            let projectile = app
+                .world_mut()
+                .query_filtered::<&Transform, With<EnemyProjectileComponent>>()
+                .single(app.world())
+                .expect("Expected a single projectile")
+                .translation;
+            assert_eq!(projectile.x, 150.0);
+            assert_eq!(projectile.y, 100.0);
+        }
+
+        #[test]
+        fn should_wait_for_the_cooldown_between_shots() {
+            let mut app = setup();
+            spawn_bottom_row(&mut app);
+            app.add_systems(Update, gunner_fire_system);
+            press(&mut app, KeyCode::Enter);
+
+            app.update();
+            advance_time_by_seconds(&mut app, GUNNER_FIRE_COOLDOWN / 2.0);
+            app.update();
+
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 1);
+
+            advance_time_by_seconds(&mut app, GUNNER_FIRE_COOLDOWN / 2.0);
+            app.update();
+
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 2);
+        }
+
+        #[test]
+        fn should_hold_fire_without_the_fire_key() {
+            let mut app = setup();
+            spawn_bottom_row(&mut app);
+            app.add_systems(Update, gunner_fire_system);
+            press(&mut app, KeyCode::Space);
+
+            app.update();
+
+            assert_eq!(count_components::<EnemyProjectileComponent>(&mut app), 0);
+        }
+    }
+
+    mod gunner_ufo_system {
+        use super::*;
+        use bevy_test::count_components;
+
+        fn ufo_direction(app: &mut App) -> MovingDirection {
+            app.world_mut()
+                .query::<&UfoComponent>()
+                .single(app.world())
+                .expect("Expected a single UFO")
+                .direction
+        }
+
+        #[test]
+        fn should_send_the_ufo_away_from_the_aimed_side() {
+            let mut app = setup();
+            app.add_systems(Update, gunner_ufo_system);
+            app.world_mut()
+                .resource_mut::<GunnerResource>()
+                .0
+                .aim(&EnemyFormation::new(), MovingDirection::ToLeft);
+            press(&mut app, KeyCode::ArrowUp);
+
+            app.update();
+
+            assert_eq!(ufo_direction(&mut app), MovingDirection::ToRight);
+        }
+
+        #[test]
+        fn should_call_one_ufo_per_cooldown() {
+            let mut app = setup();
+            app.add_systems(Update, gunner_ufo_system);
+            press(&mut app, KeyCode::ArrowUp);
+
+            app.update();
+            let ufo = app
+                .world_mut()
+                .query_filtered::<Entity, With<UfoComponent>>()
+                .single(app.world())
+                .expect("Expected a single UFO");
+            app.world_mut().despawn(ufo);
+            advance_time_by_seconds(&mut app, GUNNER_UFO_COOLDOWN / 2.0);
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 0);
+
+            advance_time_by_seconds(&mut app, GUNNER_UFO_COOLDOWN / 2.0);
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+
+        #[test]
+        fn should_wait_for_the_flying_ufo_to_leave() {
+            let mut app = setup();
+            app.add_systems(Update, gunner_ufo_system);
+            app.world_mut()
+                .spawn(UfoBundle::new(MovingDirection::ToRight));
+            press(&mut app, KeyCode::ArrowUp);
+
+            app.update();
+
+            assert_eq!(count_components::<UfoComponent>(&mut app), 1);
+        }
+    }
+
+    mod gunner_cursor_system {
+        use super::*;
+
+        #[test]
+        fn should_mark_the_targeted_invader() {
+            let mut app = setup();
+            spawn_bottom_row(&mut app);
+            app.world_mut().spawn(GunnerCursorBundle::default());
+            app.add_systems(Update, gunner_cursor_system);
+
+            app.update();
+
+            let (transform, visibility) = app
+                .world_mut()
+                .query_filtered::<(&Transform, &Visibility), With<GunnerCursorComponent>>()
+                .single(app.world())
+                .expect("Expected a single cursor");
+            assert_eq!(transform.translation.x, 150.0);
+            assert!(transform.translation.y < 100.0);
+            assert_eq!(*visibility, Visibility::Inherited);
+        }
+    }
+
+    mod reset_gunner_system {
+        use super::*;
+        use bevy_test::send_message;
+
+        #[test]
+        fn should_recenter_the_gunner_on_a_new_game() {
+            let mut app = setup();
+            app.add_message::<NewGameMessage>();
+            app.add_systems(Update, reset_gunner_system);
+            app.world_mut()
+                .resource_mut::<GunnerResource>()
+                .0
+                .aim(&EnemyFormation::new(), MovingDirection::ToRight);
+
+            send_message(&mut app, NewGameMessage);
+            app.update();
+
+            assert_eq!(gunner(&app), Gunner::new());
+        }
+    }
+}
@@ -0,0 +1,724 @@
+use crate::domain::difficulty::Difficulty;
+use crate::domain::game_mode::{GameMode, PlayerSlot};
+use crate::domain::ruleset::Ruleset;
+use crate::domain::wave::{FIRST_WAVE, Wave};
+use crate::infrastructure::bevy::bevy_renderer::plugin::BevyRenderer;
+use crate::infrastructure::bevy::game_options::resources::{GameOptions, ReplayMode};
+use crate::infrastructure::bevy::headless_renderer::plugin::HeadlessRenderer;
+use crate::infrastructure::bevy::netplay::resources::{NETPLAY_DEFAULT_PORT, NetplayOptions};
+use crate::infrastructure::bevy::replay::resources::Replay;
+use crate::infrastructure::bevy::simulation::plugin::SimulationRunner;
+use crate::infrastructure::bevy::terminal_renderer::plugin::TerminalRenderer;
+use crate::infrastructure::renderer::Renderer;
+use clap::builder::PossibleValue;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, ValueEnum};
+use std::net::{Ipv4Addr, SocketAddr, ToSocketAddrs};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RendererKind {
+    Window,
+    Headless,
+    Terminal,
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "space-invaders",
+    version,
+    about = "A Rust implementation of the old-fashioned space invaders game"
+)]
+pub struct Cli {
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = RendererKind::Window,
+        help = "Where the game is drawn"
+    )]
+    pub renderer: RendererKind,
+
+    #[arg(long, help = "Seed for the enemy fire randomness, random when omitted")]
+    pub seed: Option<u64>,
+
+    #[arg(long, value_enum, default_value_t = Difficulty::Normal, help = "Difficulty preset")]
+    pub difficulty: Difficulty,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["record", "playback", "peer"],
+        help = "Adjust the enemy fire to the recent deaths and accuracy"
+    )]
+    pub dynamic_difficulty: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Ruleset::Classic,
+        conflicts_with_all = ["record", "playback", "peer"],
+        help = "Classic single shots, or modern weapon upgrades earned by shooting invaders"
+    )]
+    pub ruleset: Ruleset,
+
+    #[arg(
+        long,
+        conflicts_with_all = ["record", "playback", "peer"],
+        help = "Let invaders break from the formation and dive at the cannon"
+    )]
+    pub diving: bool,
+
+    #[arg(
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u32).range(2..),
+        conflicts_with_all = ["record", "playback", "peer", "campaign", "editor"],
+        help = "Replace every Nth wave with a boss"
+    )]
+    pub boss_waves: Option<u32>,
+
+    #[arg(
+        long,
+        value_name = "WAVE",
+        default_value_t = FIRST_WAVE,
+        value_parser = clap::value_parser!(u32).range(FIRST_WAVE as i64..),
+        help = "Wave to start from"
+    )]
+    pub starting_wave: u32,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = GameMode::Single,
+        conflicts_with_all = ["record", "playback", "simulate"],
+        help = "Single player, two players taking turns, two players at once, player two commanding the invaders, or a split-screen battle"
+    )]
+    pub mode: GameMode,
+
+    #[arg(
+        long,
+        value_name = "WIDTHxHEIGHT",
+        value_parser = parse_window_size,
+        help = "Window size in pixels, e.g. 1200x700"
+    )]
+    pub window_size: Option<(u32, u32)>,
+
+    #[arg(long, help = "Start in borderless fullscreen")]
+    pub fullscreen: bool,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["record", "playback", "peer"],
+        help = "Play the waves of a campaign manifest, relative to the assets folder"
+    )]
+    pub campaign: Option<String>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["campaign", "mode", "record", "playback", "simulate", "peer"],
+        help = "Edit a wave file and test-play it, creating the file on first save"
+    )]
+    pub editor: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with = "playback",
+        help = "Record the inputs of this game to a replay file"
+    )]
+    pub record: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["seed", "difficulty", "starting_wave"],
+        help = "Play back a recorded replay file"
+    )]
+    pub playback: Option<PathBuf>,
+
+    #[arg(
+        long,
+        value_name = "GAMES",
+        value_parser = clap::value_parser!(u32).range(1..),
+        conflicts_with_all = ["renderer", "record", "playback", "window_size", "fullscreen"],
+        help = "Run GAMES headless games with an autopilot and print aggregate results"
+    )]
+    pub simulate: Option<u32>,
+
+    #[arg(
+        long,
+        value_name = "HOST:PORT",
+        value_parser = parse_socket_address,
+        requires = "seed",
+        conflicts_with_all = ["mode", "record", "playback", "simulate"],
+        help = "Play co-op online against the game listening at HOST:PORT"
+    )]
+    pub peer: Option<SocketAddr>,
+
+    #[arg(
+        long,
+        value_name = "HOST:PORT",
+        value_parser = parse_socket_address,
+        requires = "peer",
+        help = "Address to listen on in an online game [default: 0.0.0.0:7777]"
+    )]
+    pub bind: Option<SocketAddr>,
+
+    #[arg(
+        long,
+        value_name = "PLAYER",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u8).range(1..=2),
+        requires = "peer",
+        help = "Which player this side of an online game controls"
+    )]
+    pub player: u8,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Launch {
+    Play {
+        renderer: RendererKind,
+        options: GameOptions,
+    },
+    Simulate {
+        games: u32,
+        options: GameOptions,
+    },
+}
+
+macro_rules! value_enum {
+    ($($name:ident),+) => {
+        $(impl ValueEnum for $name {
+            fn value_variants<'a>() -> &'a [Self] {
+                &$name::ALL
+            }
+
+            fn to_possible_value(&self) -> Option<PossibleValue> {
+                Some(PossibleValue::new(self.as_str()))
+            }
+        })+
+    };
+}
+
+value_enum!(Difficulty, Ruleset, GameMode);
+
+fn parse_window_size(value: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("expected WIDTHxHEIGHT (e.g. 1200x700), got '{}'", value);
+
+    let (width, height) = value.split_once(['x', 'X']).ok_or_else(invalid)?;
+    let width: u32 = width.trim().parse().map_err(|_| invalid())?;
+    let height: u32 = height.trim().parse().map_err(|_| invalid())?;
+
+    if width == 0 || height == 0 {
+        return Err(format!("window size must be positive, got '{}'", value));
+    }
+
+    Ok((width, height))
+}
+
+fn parse_socket_address(value: &str) -> Result<SocketAddr, String> {
+    value
+        .to_socket_addrs()
+        .map_err(|error| format!("cannot resolve '{}': {}", value, error))?
+        .next()
+        .ok_or_else(|| format!("'{}' does not resolve to any address", value))
+}
+
+impl Cli {
+    pub fn into_launch(self) -> Result<Launch, clap::Error> {
+        if self.renderer != RendererKind::Window && (self.window_size.is_some() || self.fullscreen)
+        {
+            return Err(Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                "--window-size and --fullscreen only apply to the window renderer",
+            ));
+        }
+
+        let battle_conflicts = self.battle_conflicts();
+        if self.mode == GameMode::Battle && !battle_conflicts.is_empty() {
+            return Err(Cli::command().error(
+                ErrorKind::ArgumentConflict,
+                format!(
+                    "{} cannot be used with --mode battle",
+                    battle_conflicts.join(", ")
+                ),
+            ));
+        }
+
+        let serialized_files = self.serialized_files();
+        if cfg!(not(feature = "serde")) && !serialized_files.is_empty() {
+            return Err(Cli::command().error(
+                ErrorKind::InvalidValue,
+                format!(
+                    "{} need a build with the serde feature",
+                    serialized_files.join(", ")
+                ),
+            ));
+        }
+
+        let replay = match (&self.record, &self.playback) {
+            (Some(path), _) => ReplayMode::Record(path.clone()),
+            (_, Some(path)) => ReplayMode::Playback(Replay::load(path).map_err(|reason| {
+                Cli::command().error(
+                    ErrorKind::Io,
+                    format!("cannot load replay {}: {}", path.display(), reason),
+                )
+            })?),
+            _ => ReplayMode::Off,
+        };
+
+        let netplay = self.peer.map(|peer| NetplayOptions {
+            bind: self
+                .bind
+                .unwrap_or_else(|| (Ipv4Addr::UNSPECIFIED, NETPLAY_DEFAULT_PORT).into()),
+            peer,
+            local_player: if self.player == 2 {
+                PlayerSlot::Two
+            } else {
+                PlayerSlot::One
+            },
+        });
+
+        let defaults = GameOptions::default();
+        let options = GameOptions {
+            seed: self.seed,
+            difficulty: self.difficulty,
+            dynamic_difficulty: self.dynamic_difficulty,
+            ruleset: self.ruleset,
+            diving: self.diving,
+            boss_waves: self.boss_waves,
+            starting_wave: Wave::new(self.starting_wave),
+            mode: if netplay.is_some() {
+                GameMode::Coop
+            } else {
+                self.mode
+            },
+            window_size: self.window_size.unwrap_or(defaults.window_size),
+            fullscreen: self.fullscreen,
+            campaign: self.campaign,
+            editor: self.editor,
+            replay,
+            netplay,
+        };
+
+        Ok(match self.simulate {
+            Some(games) => Launch::Simulate { games, options },
+            None => Launch::Play {
+                renderer: self.renderer,
+                options,
+            },
+        })
+    }
+
+    fn battle_conflicts(&self) -> Vec<&'static str> {
+        [
+            ("--campaign", self.campaign.is_some()),
+            ("--dynamic-difficulty", self.dynamic_difficulty),
+            ("--ruleset modern", self.ruleset == Ruleset::Modern),
+            ("--diving", self.diving),
+            ("--boss-waves", self.boss_waves.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(flag, used)| used.then_some(flag))
+        .collect()
+    }
+
+    fn serialized_files(&self) -> Vec<&'static str> {
+        [
+            ("--campaign", self.campaign.is_some()),
+            ("--editor", self.editor.is_some()),
+            ("--record", self.record.is_some()),
+            ("--playback", self.playback.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(flag, used)| used.then_some(flag))
+        .collect()
+    }
+}
+
+impl Launch {
+    pub fn run(self) {
+        match self {
+            Launch::Play { renderer, options } => {
+                let renderer: Box<dyn Renderer> = match renderer {
+                    RendererKind::Window => Box::new(BevyRenderer::new(options)),
+                    RendererKind::Headless => Box::new(HeadlessRenderer::new(options)),
+                    RendererKind::Terminal => Box::new(TerminalRenderer::new(options)),
+                };
+                renderer.render();
+            }
+            Launch::Simulate { games, options } => {
+                println!("{}", SimulationRunner::new(games, options).run());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Launch, clap::Error> {
+        Cli::try_parse_from(std::iter::once("space-invaders").chain(args.iter().copied()))
+            .and_then(Cli::into_launch)
+    }
+
+    fn parse_or_fail(args: &[&str]) -> Launch {
+        parse(args).expect("Arguments should be valid")
+    }
+
+    fn error_kind(args: &[&str]) -> ErrorKind {
+        parse(args)
+            .expect_err("Arguments should be rejected")
+            .kind()
+    }
+
+    #[test]
+    fn no_arguments_play_in_a_window_with_defaults() {
+        assert_eq!(
+            parse_or_fail(&[]),
+            Launch::Play {
+                renderer: RendererKind::Window,
+                options: GameOptions::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn should_parse_the_game_options() {
+        let launch = parse_or_fail(&[
+            "--renderer",
+            "terminal",
+            "--seed",
+            "42",
+            "--difficulty",
+            "arcade",
+            "--starting-wave",
+            "3",
+        ]);
+
+        let Launch::Play { renderer, options } = launch else {
+            panic!("Expected to play");
+        };
+        assert_eq!(renderer, RendererKind::Terminal);
+        assert_eq!(options.seed, Some(42));
+        assert_eq!(options.difficulty, Difficulty::Arcade);
+        assert_eq!(options.starting_wave, Wave::new(3));
+    }
+
+    #[test]
+    fn should_parse_the_window_options() {
+        let Launch::Play { options, .. } =
+            parse_or_fail(&["--window-size", "800x600", "--fullscreen"])
+        else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.window_size, (800, 600));
+        assert!(options.fullscreen);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_parse_a_campaign() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--campaign", "main.campaign.ron"])
+        else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.campaign, Some("main.campaign.ron".to_string()));
+    }
+
+    #[test]
+    fn should_parse_the_dynamic_difficulty() {
+        let Launch::Play { options, .. } =
+            parse_or_fail(&["--difficulty", "easy", "--dynamic-difficulty"])
+        else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.difficulty, Difficulty::Easy);
+        assert!(options.dynamic_difficulty);
+    }
+
+    #[test]
+    fn should_parse_the_diving_invaders() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--diving"]) else {
+            panic!("Expected to play");
+        };
+
+        assert!(options.diving);
+    }
+
+    #[test]
+    fn should_parse_the_boss_waves() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--boss-waves", "4"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.boss_waves, Some(4));
+        assert_eq!(
+            error_kind(&["--boss-waves", "1"]),
+            ErrorKind::ValueValidation
+        );
+    }
+
+    #[test]
+    fn should_parse_the_ruleset() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--ruleset", "modern"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.ruleset, Ruleset::Modern);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_parse_the_edited_wave_file() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--editor", "custom.wave.ron"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.editor, Some(PathBuf::from("custom.wave.ron")));
+    }
+
+    #[test]
+    fn should_parse_the_game_mode() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--mode", "alternating"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.mode, GameMode::Alternating);
+
+        let Launch::Play { options, .. } = parse_or_fail(&["--mode", "coop"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.mode, GameMode::Coop);
+    }
+
+    #[test]
+    fn should_parse_a_simulation() {
+        let Launch::Simulate { games, options } =
+            parse_or_fail(&["--simulate", "20", "--seed", "1"])
+        else {
+            panic!("Expected to simulate");
+        };
+
+        assert_eq!(games, 20);
+        assert_eq!(options.seed, Some(1));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_record_to_the_given_path() {
+        let Launch::Play { options, .. } = parse_or_fail(&["--record", "game.ron"]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(
+            options.replay,
+            ReplayMode::Record(PathBuf::from("game.ron"))
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_window_sizes() {
+        assert_eq!(
+            error_kind(&["--window-size", "big"]),
+            ErrorKind::ValueValidation
+        );
+        assert_eq!(
+            error_kind(&["--window-size", "0x600"]),
+            ErrorKind::ValueValidation
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_values() {
+        assert_eq!(
+            error_kind(&["--difficulty", "impossible"]),
+            ErrorKind::InvalidValue
+        );
+        assert_eq!(error_kind(&["--renderer", "vr"]), ErrorKind::InvalidValue);
+        assert_eq!(
+            error_kind(&["--starting-wave", "0"]),
+            ErrorKind::ValueValidation
+        );
+        assert_eq!(error_kind(&["--simulate", "0"]), ErrorKind::ValueValidation);
+    }
+
+    #[test]
+    fn should_reject_conflicting_options() {
+        assert_eq!(
+            error_kind(&["--record", "a.ron", "--playback", "b.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--playback", "a.ron", "--seed", "1"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--simulate", "5", "--renderer", "terminal"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--renderer", "headless", "--fullscreen"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--mode", "alternating", "--record", "a.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--campaign", "main.campaign.ron", "--record", "a.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--editor", "a.wave.ron", "--campaign", "main.campaign.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--dynamic-difficulty", "--record", "a.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--ruleset", "modern", "--playback", "a.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--diving", "--peer", "127.0.0.1:7777"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--boss-waves", "5", "--campaign", "main.campaign.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+    }
+
+    #[test]
+    fn should_reject_options_that_battle_arenas_would_share() {
+        assert_eq!(
+            error_kind(&["--mode", "battle", "--campaign", "main.campaign.ron"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--mode", "battle", "--dynamic-difficulty"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--mode", "battle", "--ruleset", "modern"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert!(parse(&["--mode", "battle", "--ruleset", "classic"]).is_ok());
+        assert_eq!(
+            error_kind(&["--mode", "battle", "--diving"]),
+            ErrorKind::ArgumentConflict
+        );
+        assert!(parse(&["--mode", "versus", "--dynamic-difficulty"]).is_ok());
+    }
+
+    #[test]
+    fn should_reject_boss_waves_in_a_battle() {
+        let error = parse(&["--mode", "battle", "--boss-waves", "5"])
+            .expect_err("Arguments should be rejected");
+
+        assert_eq!(error.kind(), ErrorKind::ArgumentConflict);
+        assert!(error.to_string().contains("--boss-waves"));
+        assert!(parse(&["--mode", "versus", "--boss-waves", "5"]).is_ok());
+    }
+
+    #[test]
+    fn should_parse_an_online_game() {
+        let Launch::Play { options, .. } = parse_or_fail(&[
+            "--peer",
+            "127.0.0.1:7001",
+            "--bind",
+            "127.0.0.1:7002",
+            "--player",
+            "2",
+            "--seed",
+            "5",
+        ]) else {
+            panic!("Expected to play");
+        };
+
+        assert_eq!(options.mode, GameMode::Coop);
+        assert_eq!(
+            options.netplay,
+            Some(NetplayOptions {
+                bind: "127.0.0.1:7002".parse().expect("Address should parse"),
+                peer: "127.0.0.1:7001".parse().expect("Address should parse"),
+                local_player: PlayerSlot::One.other(),
+            })
+        );
+    }
+
+    #[test]
+    fn online_games_listen_on_the_default_port() {
+        let Launch::Play { options, .. } =
+            parse_or_fail(&["--peer", "localhost:7001", "--seed", "5"])
+        else {
+            panic!("Expected to play");
+        };
+
+        let netplay = options.netplay.expect("Expected an online game");
+        assert_eq!(netplay.bind.port(), NETPLAY_DEFAULT_PORT);
+        assert_eq!(netplay.local_player, PlayerSlot::One);
+    }
+
+    #[test]
+    fn online_games_need_a_shared_seed() {
+        assert_eq!(
+            error_kind(&["--peer", "127.0.0.1:7001"]),
+            ErrorKind::MissingRequiredArgument
+        );
+        assert_eq!(
+            error_kind(&["--bind", "127.0.0.1:7001"]),
+            ErrorKind::MissingRequiredArgument
+        );
+        assert_eq!(
+            error_kind(&[
+                "--peer",
+                "127.0.0.1:7001",
+                "--seed",
+                "1",
+                "--mode",
+                "single"
+            ]),
+            ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            error_kind(&["--peer", "127.0.0.1:7001", "--seed", "1", "--player", "3"]),
+            ErrorKind::ValueValidation
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn should_report_unreadable_replays() {
+        assert_eq!(
+            error_kind(&["--playback", "does/not/exist.ron"]),
+            ErrorKind::Io
+        );
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn should_reject_serialized_files_without_the_serde_feature() {
+        assert_eq!(
+            error_kind(&["--record", "game.ron"]),
+            ErrorKind::InvalidValue
+        );
+        assert_eq!(
+            error_kind(&["--playback", "game.ron"]),
+            ErrorKind::InvalidValue
+        );
+        assert_eq!(
+            error_kind(&["--campaign", "main.campaign.ron"]),
+            ErrorKind::InvalidValue
+        );
+    }
+}
@@ -1,2 +1,4 @@
 pub mod bevy;
+pub mod cli;
 pub mod renderer;
+pub mod storage;
@@ -0,0 +1,122 @@
+use bevy::asset::io::file::FileAssetReader;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+pub const DATA_DIRECTORY: &str = "space-invaders";
+pub const ASSET_DIRECTORY: &str = "assets";
+pub const SERDE_DISABLED: &str = "this build does not include the serde feature";
+
+pub fn data_file(name: &str) -> Option<PathBuf> {
+    if cfg!(not(feature = "serde")) {
+        return None;
+    }
+
+    dirs::data_dir().map(|directory| directory.join(DATA_DIRECTORY).join(name))
+}
+
+pub fn asset_file(name: &str) -> PathBuf {
+    FileAssetReader::get_base_path()
+        .join(ASSET_DIRECTORY)
+        .join(name)
+}
+
+#[cfg(feature = "serde")]
+pub fn from_ron<T: serde::de::DeserializeOwned>(source: &str) -> Result<T, String> {
+    ron::from_str(source).map_err(|error| error.to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+pub fn from_ron<T>(_source: &str) -> Result<T, String> {
+    Err(SERDE_DISABLED.to_string())
+}
+
+#[cfg(feature = "serde")]
+pub fn to_ron<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    ron::to_string(value).map_err(|error| error.to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+pub fn to_ron<T>(_value: &T) -> Result<String, String> {
+    Err(SERDE_DISABLED.to_string())
+}
+
+#[cfg(feature = "serde")]
+pub fn to_pretty_ron<T: serde::Serialize>(value: &T) -> Result<String, String> {
+    ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+        .map_err(|error| error.to_string())
+}
+
+#[cfg(not(feature = "serde"))]
+pub fn to_pretty_ron<T>(_value: &T) -> Result<String, String> {
+    Err(SERDE_DISABLED.to_string())
+}
+
+pub fn write_atomically(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let mut temporary_path = path.as_os_str().to_owned();
+    temporary_path.push(".tmp");
+    let temporary_path = PathBuf::from(temporary_path);
+
+    let mut temporary_file = fs::File::create(&temporary_path)?;
+    temporary_file.write_all(content)?;
+    temporary_file.sync_all()?;
+
+    fs::rename(&temporary_path, path)
+}
+
+#[cfg(test)]
+pub fn temporary_directory(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("space-invaders-{}-{}", name, std::process::id()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_files_live_in_the_game_directory() {
+        if let Some(path) = data_file("scores.ron") {
+            assert!(path.ends_with("space-invaders/scores.ron"));
+        }
+    }
+
+    #[test]
+    fn asset_files_resolve_against_the_asset_root() {
+        assert!(asset_file("tunables.ron").is_file());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn values_round_trip_through_ron() {
+        let source = to_pretty_ron(&(1, "two")).expect("Value should serialize");
+
+        assert_eq!(
+            from_ron::<(u32, String)>(&source),
+            Ok((1, "two".to_string()))
+        );
+    }
+
+    #[cfg(not(feature = "serde"))]
+    #[test]
+    fn ron_is_unavailable_without_serde() {
+        assert_eq!(from_ron::<u32>("1"), Err(SERDE_DISABLED.to_string()));
+    }
+
+    #[test]
+    fn writing_atomically_replaces_the_file_and_leaves_no_temporary_file() {
+        let directory = temporary_directory("storage");
+        let path = directory.join("nested").join("file.ron");
+
+        write_atomically(&path, b"first").expect("File should be written");
+        write_atomically(&path, b"second").expect("File should be replaced");
+
+        assert_eq!(fs::read_to_string(&path).ok(), Some("second".to_string()));
+        assert!(!directory.join("nested").join("file.ron.tmp").exists());
+
+        let _ = fs::remove_dir_all(directory);
+    }
+}
@@ -1,6 +1,7 @@
+use clap::Parser;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
-use crate::infrastructure::{bevy::bevy_renderer::plugin::BevyRenderer, renderer::Renderer};
+use crate::infrastructure::cli::Cli;
 
 pub mod domain;
 pub mod infrastructure;
@@ -20,5 +21,8 @@ fn setup_tracing_subscriber() {
 fn main() {
     setup_tracing_subscriber();
 
-    BevyRenderer::new().render();
+    match Cli::parse().into_launch() {
+        Ok(launch) => launch.run(),
+        Err(error) => error.exit(),
+    }
 }